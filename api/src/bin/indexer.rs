@@ -0,0 +1,62 @@
+use api::indexer::index_transactions;
+use api::indexer::source::RpcSignatureSource;
+use api::repositories::program_events::PostgresEventStore;
+use env_logger::Env;
+use log::{error, info};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::sync::Arc;
+use std::time::Duration;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+pub struct Params {
+    #[structopt(long = "postgres-url")]
+    pub postgres_url: String,
+
+    #[structopt(long = "rpc-url")]
+    pub rpc_url: String,
+
+    /// slot to start indexing from when no transaction has been indexed yet
+    #[structopt(long = "start-slot", default_value = "0")]
+    pub start_slot: u64,
+
+    /// number of signatures loaded from RPC in one request
+    #[structopt(long = "page-limit", default_value = "1000")]
+    pub page_limit: usize,
+
+    /// seconds to wait between indexing runs, when not set the indexer runs once and exits
+    #[structopt(long = "poll-interval")]
+    pub poll_interval: Option<u64>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
+    info!("Launching events indexer");
+
+    let params = Params::from_args();
+    let rpc_client = Arc::new(RpcClient::new_with_commitment(
+        params.rpc_url.clone(),
+        CommitmentConfig::confirmed(),
+    ));
+    let mut source = RpcSignatureSource::new(rpc_client, params.page_limit);
+    let mut store = PostgresEventStore::connect(&params.postgres_url).await?;
+
+    loop {
+        match index_transactions(&mut source, &mut store, params.start_slot).await {
+            Ok(stats) => info!("Indexing run finished: {:?}", stats),
+            Err(err) => {
+                if params.poll_interval.is_none() {
+                    return Err(err);
+                }
+                // the next run resumes from the last stored slot
+                error!("Indexing run failed: {:?}", err);
+            }
+        }
+        match params.poll_interval {
+            Some(poll_interval) => tokio::time::sleep(Duration::from_secs(poll_interval)).await,
+            None => return Ok(()),
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use async_trait::async_trait;
+use validator_bonds_event_decoder::decode_cpi_event_data;
+
+pub mod records;
+pub mod source;
+
+use records::HistoryRecord;
+
+/// Transaction of the validator bonds program as provided by a transaction source
+#[derive(Clone)]
+pub struct SourceTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    /// data of the inner instructions invoking the validator bonds program (`emit_cpi!` events)
+    pub cpi_event_data: Vec<Vec<u8>>,
+}
+
+/// Source of the validator bonds program transactions, e.g., RPC signature walker or a local ledger
+#[async_trait]
+pub trait TransactionSource {
+    /// Starts walking the successful transactions processed at the slot or later.
+    async fn start_from_slot(&mut self, slot: u64) -> anyhow::Result<()>;
+
+    /// The next page of the walk, the pages and the transactions within them are ordered from the oldest.
+    /// Returns `None` when all the transactions of the walk were returned.
+    async fn next_page(&mut self) -> anyhow::Result<Option<Vec<SourceTransaction>>>;
+}
+
+/// name of the event that cannot be decoded, it is stored with its raw data
+pub const UNKNOWN_EVENT_NAME: &str = "UnknownEvent";
+
+pub struct IndexedEvent {
+    /// position of the event within the transaction
+    pub event_index: u32,
+    pub name: &'static str,
+    /// `None` for the unknown event
+    pub version: Option<u8>,
+    pub record: Option<HistoryRecord>,
+    /// event instruction data of the unknown event
+    pub raw_data: Option<Vec<u8>>,
+}
+
+pub struct IndexedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub events: Vec<IndexedEvent>,
+}
+
+/// Storage of the indexed events, idempotent per transaction signature
+#[async_trait]
+pub trait EventStore {
+    /// the highest slot of a stored transaction
+    async fn last_processed_slot(&mut self) -> anyhow::Result<Option<u64>>;
+
+    async fn is_processed(&mut self, signature: &str) -> anyhow::Result<bool>;
+
+    /// Stores the transaction with its events atomically.
+    /// Returns false when the transaction signature has been already stored.
+    async fn store_transaction(&mut self, transaction: &IndexedTransaction)
+        -> anyhow::Result<bool>;
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct IndexingStats {
+    pub transactions_stored: usize,
+    pub transactions_skipped: usize,
+    pub events_stored: usize,
+    pub unknown_events_stored: usize,
+    pub last_slot: Option<u64>,
+}
+
+/// Decodes the events of the transaction.
+/// An event that fails to decode does not stop the indexing, it is kept as the unknown event with its raw data.
+pub fn decode_transaction(transaction: SourceTransaction) -> IndexedTransaction {
+    let mut events: Vec<IndexedEvent> = vec![];
+    for data in transaction.cpi_event_data.into_iter() {
+        let event_index = events.len() as u32;
+        match decode_cpi_event_data(&data) {
+            Ok(Some(event)) => events.push(IndexedEvent {
+                event_index,
+                name: event.name(),
+                version: Some(event.version()),
+                record: HistoryRecord::from_event(&event),
+                raw_data: None,
+            }),
+            // not an event instruction (e.g., the program invoked by CPI)
+            Ok(None) => continue,
+            Err(err) => {
+                log::warn!(
+                    "Transaction {} event #{} cannot be decoded, stored as unknown: {}",
+                    transaction.signature,
+                    event_index,
+                    err
+                );
+                events.push(IndexedEvent {
+                    event_index,
+                    name: UNKNOWN_EVENT_NAME,
+                    version: None,
+                    record: None,
+                    raw_data: Some(data),
+                });
+            }
+        }
+    }
+    IndexedTransaction {
+        signature: transaction.signature,
+        slot: transaction.slot,
+        block_time: transaction.block_time,
+        events,
+    }
+}
+
+/// Loads the transactions from the last processed slot (or from the `start_slot` when nothing was indexed yet)
+/// and stores their events. The last processed slot is re-read as some of its transactions
+/// may not be processed yet, the already stored signatures are skipped.
+/// The transactions are stored page by page from the oldest one, every stored transaction
+/// moves the last processed slot forward, i.e., a failed run resumes from the last stored page.
+pub async fn index_transactions<S, E>(
+    source: &mut S,
+    store: &mut E,
+    start_slot: u64,
+) -> anyhow::Result<IndexingStats>
+where
+    S: TransactionSource + Send,
+    E: EventStore + Send,
+{
+    let from_slot = store
+        .last_processed_slot()
+        .await?
+        .map_or(start_slot, |slot| slot.max(start_slot));
+    source.start_from_slot(from_slot).await?;
+
+    let mut stats = IndexingStats::default();
+    while let Some(transactions) = source.next_page().await? {
+        for transaction in transactions {
+            if store.is_processed(&transaction.signature).await? {
+                stats.transactions_skipped += 1;
+                continue;
+            }
+            let indexed = decode_transaction(transaction);
+            if store.store_transaction(&indexed).await? {
+                stats.transactions_stored += 1;
+                stats.events_stored += indexed.events.len();
+                stats.unknown_events_stored += indexed
+                    .events
+                    .iter()
+                    .filter(|event| event.raw_data.is_some())
+                    .count();
+                stats.last_slot = Some(indexed.slot);
+            } else {
+                stats.transactions_skipped += 1;
+            }
+        }
+        log::info!(
+            "Indexed page up to slot {:?}: {} transactions stored in total",
+            stats.last_slot,
+            stats.transactions_stored
+        );
+    }
+    log::info!(
+        "Indexed from slot {}: {} transactions stored, {} skipped, {} events stored ({} unknown)",
+        from_slot,
+        stats.transactions_stored,
+        stats.transactions_skipped,
+        stats.events_stored,
+        stats.unknown_events_stored
+    );
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::event::EVENT_IX_TAG_LE;
+    use anchor_lang::Event;
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+    use validator_bonds::constants::EVENT_VERSION;
+    use validator_bonds::events::bond::FundBondEvent;
+
+    struct MockSource {
+        transactions: Vec<SourceTransaction>,
+        page_size: usize,
+        /// pages of the current walk, the oldest one last
+        pages: Vec<Vec<SourceTransaction>>,
+    }
+
+    impl MockSource {
+        fn new(transactions: Vec<SourceTransaction>, page_size: usize) -> Self {
+            Self {
+                transactions,
+                page_size,
+                pages: vec![],
+            }
+        }
+    }
+
+    #[async_trait]
+    impl TransactionSource for MockSource {
+        async fn start_from_slot(&mut self, slot: u64) -> anyhow::Result<()> {
+            let transactions: Vec<SourceTransaction> = self
+                .transactions
+                .iter()
+                .filter(|t| t.slot >= slot)
+                .cloned()
+                .collect();
+            self.pages = transactions
+                .chunks(self.page_size)
+                .rev()
+                .map(|page| page.to_vec())
+                .collect();
+            Ok(())
+        }
+
+        async fn next_page(&mut self) -> anyhow::Result<Option<Vec<SourceTransaction>>> {
+            Ok(self.pages.pop())
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryStore {
+        transactions: HashMap<String, (u64, Vec<Option<HistoryRecord>>)>,
+        /// storing of the transaction with the signature fails
+        failing_signature: Option<String>,
+    }
+
+    #[async_trait]
+    impl EventStore for MemoryStore {
+        async fn last_processed_slot(&mut self) -> anyhow::Result<Option<u64>> {
+            Ok(self.transactions.values().map(|(slot, _)| *slot).max())
+        }
+
+        async fn is_processed(&mut self, signature: &str) -> anyhow::Result<bool> {
+            Ok(self.transactions.contains_key(signature))
+        }
+
+        async fn store_transaction(
+            &mut self,
+            transaction: &IndexedTransaction,
+        ) -> anyhow::Result<bool> {
+            if self.failing_signature.as_ref() == Some(&transaction.signature) {
+                return Err(anyhow::anyhow!("Failed to store {}", transaction.signature));
+            }
+            if self.transactions.contains_key(&transaction.signature) {
+                return Ok(false);
+            }
+            self.transactions.insert(
+                transaction.signature.clone(),
+                (
+                    transaction.slot,
+                    transaction
+                        .events
+                        .iter()
+                        .map(|e| e.record.clone())
+                        .collect(),
+                ),
+            );
+            Ok(true)
+        }
+    }
+
+    fn fund_bond_transaction(signature: &str, slot: u64, amount: u64) -> SourceTransaction {
+        let event = FundBondEvent {
+            version: EVENT_VERSION,
+            bond: Pubkey::new_unique(),
+            vote_account: Pubkey::new_unique(),
+            stake_account: Pubkey::new_unique(),
+            stake_authority_signer: Pubkey::new_unique(),
+            deposited_amount: amount,
+        };
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.extend(event.data());
+        SourceTransaction {
+            signature: signature.to_string(),
+            slot,
+            block_time: None,
+            // the first inner instruction is not an event one
+            cpi_event_data: vec![vec![1, 2, 3], data],
+        }
+    }
+
+    #[tokio::test]
+    async fn index_idempotent_and_resumable() {
+        let mut source = MockSource::new(
+            vec![
+                fund_bond_transaction("sig1", 10, 100),
+                fund_bond_transaction("sig2", 11, 200),
+            ],
+            1,
+        );
+        let mut store = MemoryStore::default();
+
+        let stats = index_transactions(&mut source, &mut store, 0)
+            .await
+            .unwrap();
+        assert_eq!(stats.transactions_stored, 2);
+        assert_eq!(stats.events_stored, 2);
+        assert_eq!(stats.last_slot, Some(11));
+        match &store.transactions["sig2"].1[0] {
+            Some(HistoryRecord::Funding(funding)) => {
+                assert_eq!(funding.event_type, "fund_bond");
+                assert_eq!(funding.amount, 200);
+            }
+            _ => panic!("Expected funding record"),
+        }
+
+        // re-run with a new transaction in the last processed slot and a later one
+        source
+            .transactions
+            .push(fund_bond_transaction("sig3", 11, 300));
+        source
+            .transactions
+            .push(fund_bond_transaction("sig4", 12, 400));
+        let stats = index_transactions(&mut source, &mut store, 0)
+            .await
+            .unwrap();
+        assert_eq!(stats.transactions_skipped, 1);
+        assert_eq!(stats.transactions_stored, 2);
+        assert_eq!(stats.last_slot, Some(12));
+        assert_eq!(store.transactions.len(), 4);
+
+        let stats = index_transactions(&mut source, &mut store, 0)
+            .await
+            .unwrap();
+        assert_eq!(stats.transactions_stored, 0);
+        assert_eq!(stats.transactions_skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn index_resumes_from_last_stored_page() {
+        let mut source = MockSource::new(
+            (1..=5)
+                .map(|i| fund_bond_transaction(&format!("sig{}", i), 10 + i, i * 100))
+                .collect(),
+            2,
+        );
+        let mut store = MemoryStore {
+            failing_signature: Some("sig4".to_string()),
+            ..MemoryStore::default()
+        };
+
+        // the pages stored before the failure are kept
+        assert!(index_transactions(&mut source, &mut store, 0)
+            .await
+            .is_err());
+        assert_eq!(store.transactions.len(), 3);
+        assert_eq!(store.last_processed_slot().await.unwrap(), Some(13));
+
+        store.failing_signature = None;
+        let stats = index_transactions(&mut source, &mut store, 0)
+            .await
+            .unwrap();
+        assert_eq!(stats.transactions_skipped, 1);
+        assert_eq!(stats.transactions_stored, 2);
+        assert_eq!(stats.last_slot, Some(15));
+    }
+
+    #[tokio::test]
+    async fn index_unknown_event() {
+        let mut transaction = fund_bond_transaction("sig1", 10, 100);
+        // known discriminator with the event data matching neither the current nor the legacy layout
+        let mut truncated = transaction.cpi_event_data[1].clone();
+        truncated.truncate(truncated.len() - 5);
+        transaction.cpi_event_data.push(truncated.clone());
+        let mut source = MockSource::new(vec![transaction], 10);
+        let mut store = MemoryStore::default();
+
+        let stats = index_transactions(&mut source, &mut store, 0)
+            .await
+            .unwrap();
+        assert_eq!(stats.transactions_stored, 1);
+        assert_eq!(stats.events_stored, 2);
+        assert_eq!(stats.unknown_events_stored, 1);
+
+        let indexed = decode_transaction(fund_bond_transaction("sig2", 11, 200));
+        assert_eq!(indexed.events.len(), 1);
+        assert_eq!(indexed.events[0].version, Some(EVENT_VERSION));
+        let mut transaction = fund_bond_transaction("sig3", 12, 300);
+        transaction.cpi_event_data = vec![truncated.clone()];
+        let indexed = decode_transaction(transaction);
+        assert_eq!(indexed.events[0].name, UNKNOWN_EVENT_NAME);
+        assert_eq!(indexed.events[0].version, None);
+        assert_eq!(indexed.events[0].raw_data, Some(truncated));
+    }
+}
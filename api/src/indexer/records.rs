@@ -0,0 +1,223 @@
+use validator_bonds_event_decoder::ValidatorBondsEvent;
+
+/// Bond funding history: deposits to the bond and bid charges
+#[derive(Clone, Debug)]
+pub struct FundingRecord {
+    pub event_type: &'static str,
+    pub bond: String,
+    pub vote_account: String,
+    /// funded stake account or collateral vault
+    pub account: String,
+    /// collateral mint when funded with tokens, the amount is then in the mint base units
+    pub mint: Option<String>,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct WithdrawRecord {
+    pub event_type: &'static str,
+    pub withdraw_request: String,
+    pub bond: String,
+    /// requested amount or amount withdrawn by the event
+    pub amount: u64,
+    /// amount withdrawn in total for the withdraw request
+    pub withdrawn_amount: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct SettlementRecord {
+    pub event_type: &'static str,
+    pub settlement: String,
+    pub bond: String,
+    /// max total claim or amount funded by the event
+    pub amount: u64,
+    pub lamports_funded: Option<u64>,
+    pub lamports_claimed: Option<u64>,
+    pub merkle_nodes_claimed: Option<u64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ClaimRecord {
+    pub event_type: &'static str,
+    pub settlement: String,
+    pub settlement_claim: String,
+    /// stake account or token account the claim was paid to
+    pub claimed_to: String,
+    pub stake_account_staker: String,
+    pub stake_account_withdrawer: String,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug)]
+pub enum HistoryRecord {
+    Funding(FundingRecord),
+    Withdraw(WithdrawRecord),
+    Settlement(SettlementRecord),
+    Claim(ClaimRecord),
+}
+
+impl HistoryRecord {
+    /// Maps the event to the funding, withdraw, settlement or claim history.
+    /// Events outside of these histories (e.g., config changes) are kept only in the events table.
+    pub fn from_event(event: &ValidatorBondsEvent) -> Option<Self> {
+        let record = match event {
+            ValidatorBondsEvent::FundBondEvent(e) => Self::Funding(FundingRecord {
+                event_type: "fund_bond",
+                bond: e.bond.to_string(),
+                vote_account: e.vote_account.to_string(),
+                account: e.stake_account.to_string(),
+                mint: None,
+                amount: e.deposited_amount,
+            }),
+            ValidatorBondsEvent::FundBondCollateralEvent(e) => Self::Funding(FundingRecord {
+                event_type: "fund_bond_collateral",
+                bond: e.bond.to_string(),
+                vote_account: e.vote_account.to_string(),
+                account: e.collateral_vault.to_string(),
+                mint: Some(e.mint.to_string()),
+                amount: e.deposited_amount,
+            }),
+            ValidatorBondsEvent::ChargeBidEvent(e) => Self::Funding(FundingRecord {
+                event_type: "charge_bid",
+                bond: e.bond.to_string(),
+                vote_account: e.vote_account.to_string(),
+                account: e.stake_account.to_string(),
+                mint: None,
+                amount: e.charged_amount,
+            }),
+            ValidatorBondsEvent::InitWithdrawRequestEvent(e) => Self::Withdraw(WithdrawRecord {
+                event_type: "init_withdraw_request",
+                withdraw_request: e.withdraw_request.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.requested_amount,
+                withdrawn_amount: 0,
+            }),
+            ValidatorBondsEvent::CancelWithdrawRequestEvent(e) => Self::Withdraw(WithdrawRecord {
+                event_type: "cancel_withdraw_request",
+                withdraw_request: e.withdraw_request.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.requested_amount,
+                withdrawn_amount: e.withdrawn_amount,
+            }),
+            ValidatorBondsEvent::ClaimWithdrawRequestEvent(e) => Self::Withdraw(WithdrawRecord {
+                event_type: "claim_withdraw_request",
+                withdraw_request: e.withdraw_request.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.withdrawing_amount,
+                withdrawn_amount: e.withdrawn_amount.new,
+            }),
+            ValidatorBondsEvent::ClaimWithdrawCollateralEvent(e) => {
+                Self::Withdraw(WithdrawRecord {
+                    event_type: "claim_withdraw_collateral",
+                    withdraw_request: e.withdraw_request.to_string(),
+                    bond: e.bond.to_string(),
+                    amount: e.withdrawing_amount,
+                    withdrawn_amount: e.withdrawn_amount.new,
+                })
+            }
+            ValidatorBondsEvent::InitSettlementEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "init_settlement",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.max_total_claim,
+                lamports_funded: None,
+                lamports_claimed: None,
+                merkle_nodes_claimed: None,
+            }),
+            ValidatorBondsEvent::AmendSettlementEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "amend_settlement",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.max_total_claim.new,
+                lamports_funded: None,
+                lamports_claimed: None,
+                merkle_nodes_claimed: None,
+            }),
+            ValidatorBondsEvent::DisputeSettlementEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "dispute_settlement",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: 0,
+                lamports_funded: None,
+                lamports_claimed: None,
+                merkle_nodes_claimed: None,
+            }),
+            ValidatorBondsEvent::ResolveDisputeEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "resolve_dispute",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: 0,
+                lamports_funded: None,
+                lamports_claimed: None,
+                merkle_nodes_claimed: None,
+            }),
+            ValidatorBondsEvent::FundSettlementEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "fund_settlement",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.funding_amount,
+                lamports_funded: Some(e.lamports_funded),
+                lamports_claimed: Some(e.lamports_claimed),
+                merkle_nodes_claimed: Some(e.merkle_nodes_claimed),
+            }),
+            ValidatorBondsEvent::FundSettlementCollateralEvent(e) => {
+                Self::Settlement(SettlementRecord {
+                    event_type: "fund_settlement_collateral",
+                    settlement: e.settlement.to_string(),
+                    bond: e.bond.to_string(),
+                    amount: e.funding_amount,
+                    lamports_funded: Some(e.lamports_funded),
+                    lamports_claimed: None,
+                    merkle_nodes_claimed: None,
+                })
+            }
+            ValidatorBondsEvent::CloseSettlementEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "close_settlement",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.max_total_claim,
+                lamports_funded: Some(e.lamports_funded),
+                lamports_claimed: Some(e.lamports_claimed),
+                merkle_nodes_claimed: Some(e.merkle_nodes_claimed),
+            }),
+            ValidatorBondsEvent::CancelSettlementEvent(e) => Self::Settlement(SettlementRecord {
+                event_type: "cancel_settlement",
+                settlement: e.settlement.to_string(),
+                bond: e.bond.to_string(),
+                amount: e.max_total_claim,
+                lamports_funded: Some(e.lamports_funded),
+                lamports_claimed: Some(e.lamports_claimed),
+                merkle_nodes_claimed: Some(e.merkle_nodes_claimed),
+            }),
+            ValidatorBondsEvent::ClaimSettlementEvent(e) => Self::Claim(ClaimRecord {
+                event_type: "claim_settlement",
+                settlement: e.settlement.to_string(),
+                settlement_claim: e.settlement_claim.to_string(),
+                claimed_to: e.stake_account_to.to_string(),
+                stake_account_staker: e.stake_account_staker.to_string(),
+                stake_account_withdrawer: e.stake_account_withdrawer.to_string(),
+                amount: e.amount,
+            }),
+            ValidatorBondsEvent::ClaimSettlementCollateralEvent(e) => Self::Claim(ClaimRecord {
+                event_type: "claim_settlement_collateral",
+                settlement: e.settlement.to_string(),
+                settlement_claim: e.settlement_claim.to_string(),
+                claimed_to: e.claimer_token_account.to_string(),
+                stake_account_staker: e.stake_account_staker.to_string(),
+                stake_account_withdrawer: e.stake_account_withdrawer.to_string(),
+                amount: e.amount,
+            }),
+            ValidatorBondsEvent::CloseSettlementClaimEvent(e) => Self::Claim(ClaimRecord {
+                event_type: "close_settlement_claim",
+                settlement: e.settlement.to_string(),
+                settlement_claim: e.settlement_claim.to_string(),
+                claimed_to: e.stake_account_to.to_string(),
+                stake_account_staker: e.stake_account_staker.to_string(),
+                stake_account_withdrawer: e.stake_account_withdrawer.to_string(),
+                amount: e.amount,
+            }),
+            _ => return None,
+        };
+        Some(record)
+    }
+}
@@ -0,0 +1,195 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::RpcTransactionConfig;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{UiInstruction, UiTransactionEncoding};
+
+use super::{SourceTransaction, TransactionSource};
+
+/// Bounds of a page of the program signatures, both bounds are exclusive
+struct PageBounds {
+    /// the oldest signature of the newer page, `None` for the newest page
+    before: Option<Signature>,
+    /// the newest signature of the older page, `None` for the oldest page
+    until: Option<Signature>,
+}
+
+/// Walks the signatures of the validator bonds program from the newest one
+/// back to the requested slot, remembering only the bounds of the signature pages.
+/// The pages are then re-read and their transactions loaded from RPC from the oldest page.
+pub struct RpcSignatureSource {
+    rpc_client: Arc<RpcClient>,
+    program_id: Pubkey,
+    page_limit: usize,
+    from_slot: u64,
+    /// pages of the current walk, the oldest one last
+    pages: Vec<PageBounds>,
+}
+
+impl RpcSignatureSource {
+    pub fn new(rpc_client: Arc<RpcClient>, page_limit: usize) -> Self {
+        Self {
+            rpc_client,
+            program_id: validator_bonds::ID,
+            page_limit,
+            from_slot: 0,
+            pages: vec![],
+        }
+    }
+
+    async fn signatures_page(
+        &self,
+        before: Option<Signature>,
+        until: Option<Signature>,
+    ) -> anyhow::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        Ok(self
+            .rpc_client
+            .get_signatures_for_address_with_config(
+                &self.program_id,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until,
+                    limit: Some(self.page_limit),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )
+            .await?)
+    }
+
+    async fn load_transaction(&self, signature: &Signature) -> anyhow::Result<SourceTransaction> {
+        let transaction = self
+            .rpc_client
+            .get_transaction_with_config(
+                signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await?;
+        let meta = transaction
+            .transaction
+            .meta
+            .ok_or_else(|| anyhow!("Transaction {} has got no status meta", signature))?;
+        let versioned_transaction = transaction
+            .transaction
+            .transaction
+            .decode()
+            .ok_or_else(|| anyhow!("Cannot decode transaction {}", signature))?;
+
+        // instructions index the static keys followed by the keys loaded from lookup tables
+        let mut account_keys = versioned_transaction.message.static_account_keys().to_vec();
+        if let OptionSerializer::Some(loaded_addresses) = meta.loaded_addresses {
+            for address in loaded_addresses
+                .writable
+                .iter()
+                .chain(loaded_addresses.readonly.iter())
+            {
+                account_keys.push(Pubkey::from_str(address)?);
+            }
+        }
+
+        let inner_instructions: Option<Vec<_>> = meta.inner_instructions.into();
+        let mut cpi_event_data = vec![];
+        for instruction in inner_instructions
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|inner| inner.instructions)
+        {
+            if let UiInstruction::Compiled(compiled) = instruction {
+                if account_keys.get(compiled.program_id_index as usize) == Some(&self.program_id) {
+                    cpi_event_data.push(bs58::decode(&compiled.data).into_vec()?);
+                }
+            }
+        }
+
+        Ok(SourceTransaction {
+            signature: signature.to_string(),
+            slot: transaction.slot,
+            block_time: transaction.block_time,
+            cpi_event_data,
+        })
+    }
+}
+
+#[async_trait]
+impl TransactionSource for RpcSignatureSource {
+    async fn start_from_slot(&mut self, slot: u64) -> anyhow::Result<()> {
+        self.from_slot = slot;
+        self.pages.clear();
+        let mut before: Option<Signature> = None;
+        loop {
+            // signatures are returned from the newest one
+            let page = self.signatures_page(before, None).await?;
+            let (newest, oldest) = match (page.first(), page.last()) {
+                (Some(newest), Some(oldest)) => (newest, oldest),
+                _ => break,
+            };
+            if let Some(newer_page) = self.pages.last_mut() {
+                newer_page.until = Some(Signature::from_str(&newest.signature)?);
+            }
+            if newest.slot < slot {
+                break;
+            }
+            self.pages.push(PageBounds {
+                before,
+                until: None,
+            });
+            if oldest.slot < slot || page.len() < self.page_limit {
+                break;
+            }
+            before = Some(Signature::from_str(&oldest.signature)?);
+        }
+        log::info!(
+            "Found {} pages of program signatures from slot {}",
+            self.pages.len(),
+            slot
+        );
+        Ok(())
+    }
+
+    async fn next_page(&mut self) -> anyhow::Result<Option<Vec<SourceTransaction>>> {
+        let bounds = match self.pages.pop() {
+            Some(bounds) => bounds,
+            None => return Ok(None),
+        };
+
+        // the newest page may have grown since the walk started, it is read until its older bound
+        let mut signatures: Vec<Signature> = vec![];
+        let mut before = bounds.before;
+        'page: loop {
+            let page = self.signatures_page(before, bounds.until).await?;
+            let page_len = page.len();
+            for status in page {
+                if status.slot < self.from_slot {
+                    break 'page;
+                }
+                let signature = Signature::from_str(&status.signature)?;
+                before = Some(signature);
+                // failed transactions do not emit events
+                if status.err.is_none() {
+                    signatures.push(signature);
+                }
+            }
+            if page_len < self.page_limit {
+                break;
+            }
+        }
+
+        let mut transactions = Vec::with_capacity(signatures.len());
+        for signature in signatures.iter().rev() {
+            transactions.push(self.load_transaction(signature).await?);
+        }
+        Ok(Some(transactions))
+    }
+}
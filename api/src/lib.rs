@@ -2,4 +2,5 @@ pub mod api_docs;
 pub mod context;
 pub mod dto;
 pub mod handlers;
+pub mod indexer;
 pub mod repositories;
@@ -1,3 +1,4 @@
 pub mod bond;
 pub mod common;
+pub mod program_events;
 pub mod protected_events;
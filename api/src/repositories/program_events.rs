@@ -0,0 +1,184 @@
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use rust_decimal::Decimal;
+use tokio_postgres::{Client, NoTls};
+
+use crate::indexer::records::HistoryRecord;
+use crate::indexer::{EventStore, IndexedTransaction};
+
+pub struct PostgresEventStore {
+    psql_client: Client,
+}
+
+impl PostgresEventStore {
+    pub async fn connect(postgres_url: &str) -> anyhow::Result<Self> {
+        let (psql_client, psql_conn) = tokio_postgres::connect(postgres_url, NoTls).await?;
+
+        tokio::spawn(async move {
+            if let Err(err) = psql_conn.await {
+                log::error!("Connection error: {}", err);
+                std::process::exit(1);
+            }
+        });
+
+        Ok(Self { psql_client })
+    }
+}
+
+#[async_trait]
+impl EventStore for PostgresEventStore {
+    async fn last_processed_slot(&mut self) -> anyhow::Result<Option<u64>> {
+        let row = self
+            .psql_client
+            .query_one("SELECT MAX(slot) AS slot FROM indexed_transactions", &[])
+            .await?;
+        Ok(row
+            .get::<_, Option<i64>>("slot")
+            .map(|slot| slot.try_into())
+            .transpose()?)
+    }
+
+    async fn is_processed(&mut self, signature: &str) -> anyhow::Result<bool> {
+        let rows = self
+            .psql_client
+            .query(
+                "SELECT 1 FROM indexed_transactions WHERE signature = $1",
+                &[&signature],
+            )
+            .await?;
+        Ok(!rows.is_empty())
+    }
+
+    async fn store_transaction(
+        &mut self,
+        transaction: &IndexedTransaction,
+    ) -> anyhow::Result<bool> {
+        let slot: i64 = transaction.slot.try_into()?;
+        let block_time = transaction
+            .block_time
+            .and_then(|block_time| Utc.timestamp_opt(block_time, 0).single());
+
+        let db_transaction = self.psql_client.transaction().await?;
+        let inserted = db_transaction
+            .execute(
+                "
+                INSERT INTO indexed_transactions (signature, slot, block_time)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (signature) DO NOTHING",
+                &[&transaction.signature, &slot, &block_time],
+            )
+            .await?;
+        if inserted == 0 {
+            // already stored by a concurrent or previous run
+            db_transaction.rollback().await?;
+            return Ok(false);
+        }
+
+        for event in transaction.events.iter() {
+            let event_index = event.event_index as i32;
+            db_transaction
+                .execute(
+                    "
+                    INSERT INTO program_events (signature, event_index, slot, name, version, raw_data)
+                    VALUES ($1, $2, $3, $4, $5, $6)",
+                    &[
+                        &transaction.signature,
+                        &event_index,
+                        &slot,
+                        &event.name,
+                        &event.version.map(|version| version as i16),
+                        &event.raw_data,
+                    ],
+                )
+                .await?;
+
+            match &event.record {
+                Some(HistoryRecord::Funding(record)) => {
+                    db_transaction
+                        .execute(
+                            "
+                            INSERT INTO bond_funding_history (signature, event_index, slot, event_type, bond, vote_account, account, mint, amount)
+                            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+                            &[
+                                &transaction.signature,
+                                &event_index,
+                                &slot,
+                                &record.event_type,
+                                &record.bond,
+                                &record.vote_account,
+                                &record.account,
+                                &record.mint,
+                                &Decimal::from(record.amount),
+                            ],
+                        )
+                        .await?;
+                }
+                Some(HistoryRecord::Withdraw(record)) => {
+                    db_transaction
+                        .execute(
+                            "
+                            INSERT INTO withdraw_history (signature, event_index, slot, event_type, withdraw_request, bond, amount, withdrawn_amount)
+                            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+                            &[
+                                &transaction.signature,
+                                &event_index,
+                                &slot,
+                                &record.event_type,
+                                &record.withdraw_request,
+                                &record.bond,
+                                &Decimal::from(record.amount),
+                                &Decimal::from(record.withdrawn_amount),
+                            ],
+                        )
+                        .await?;
+                }
+                Some(HistoryRecord::Settlement(record)) => {
+                    db_transaction
+                        .execute(
+                            "
+                            INSERT INTO settlement_history (signature, event_index, slot, event_type, settlement, bond, amount, lamports_funded, lamports_claimed, merkle_nodes_claimed)
+                            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+                            &[
+                                &transaction.signature,
+                                &event_index,
+                                &slot,
+                                &record.event_type,
+                                &record.settlement,
+                                &record.bond,
+                                &Decimal::from(record.amount),
+                                &record.lamports_funded.map(Decimal::from),
+                                &record.lamports_claimed.map(Decimal::from),
+                                &record.merkle_nodes_claimed.map(Decimal::from),
+                            ],
+                        )
+                        .await?;
+                }
+                Some(HistoryRecord::Claim(record)) => {
+                    db_transaction
+                        .execute(
+                            "
+                            INSERT INTO settlement_claim_history (signature, event_index, slot, event_type, settlement, settlement_claim, claimed_to, stake_account_staker, stake_account_withdrawer, amount)
+                            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+                            &[
+                                &transaction.signature,
+                                &event_index,
+                                &slot,
+                                &record.event_type,
+                                &record.settlement,
+                                &record.settlement_claim,
+                                &record.claimed_to,
+                                &record.stake_account_staker,
+                                &record.stake_account_withdrawer,
+                                &Decimal::from(record.amount),
+                            ],
+                        )
+                        .await?;
+                }
+                None => {}
+            }
+        }
+
+        db_transaction.commit().await?;
+        Ok(true)
+    }
+}
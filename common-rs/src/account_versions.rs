@@ -0,0 +1,123 @@
+use crate::utils::get_accounts_for_pubkeys_with;
+use anyhow::anyhow;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+use validator_bonds::constants::ACCOUNT_VERSION;
+use validator_bonds::state::VersionedAccount;
+
+/// Deserializes the program account of any known layout version.
+/// Account data shorter than the current layout (created before a reallocating migration)
+/// is zero-extended, the version 0 (before versioning) shares the layout with the version 1.
+pub fn deserialize_versioned_account<T: VersionedAccount>(data: &[u8]) -> anyhow::Result<T> {
+    let data_size = 8 + std::mem::size_of::<T>();
+    let account = if data.len() < data_size {
+        let mut extended_data = data.to_vec();
+        extended_data.resize(data_size, 0);
+        T::try_deserialize(&mut extended_data.as_slice())?
+    } else {
+        T::try_deserialize(&mut &data[..])?
+    };
+    match account.account_version() {
+        0..=ACCOUNT_VERSION => Ok(account),
+        unknown_version => Err(anyhow!(
+            "Unknown account layout version {}, the latest known version is {}",
+            unknown_version,
+            ACCOUNT_VERSION
+        )),
+    }
+}
+
+/// Loads all program accounts of the type, additional filters may be applied
+pub async fn get_versioned_accounts<T: VersionedAccount>(
+    rpc_client: Arc<RpcClient>,
+    filters: Vec<RpcFilterType>,
+) -> anyhow::Result<Vec<(Pubkey, T)>> {
+    let discriminator_filter = RpcFilterType::Memcmp(Memcmp::new(
+        0,
+        MemcmpEncodedBytes::Bytes(T::DISCRIMINATOR.to_vec()),
+    ));
+    let accounts = rpc_client
+        .get_program_accounts_with_config(
+            &validator_bonds::ID,
+            RpcProgramAccountsConfig {
+                filters: Some([vec![discriminator_filter], filters].concat()),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await?;
+    accounts
+        .into_iter()
+        .map(|(pubkey, account)| {
+            deserialize_versioned_account(&account.data)
+                .map(|versioned| (pubkey, versioned))
+                .map_err(|e| anyhow!("Cannot deserialize account {}: {:?}", pubkey, e))
+        })
+        .collect()
+}
+
+pub async fn get_versioned_accounts_for_pubkeys<T: VersionedAccount>(
+    rpc_client: Arc<RpcClient>,
+    pubkeys: &[Pubkey],
+) -> anyhow::Result<Vec<(Pubkey, Option<T>)>> {
+    get_accounts_for_pubkeys_with(rpc_client, pubkeys, deserialize_versioned_account::<T>).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::AccountSerialize;
+    use validator_bonds::state::withdraw_request::WithdrawRequest;
+
+    fn withdraw_request(account_version: u8) -> WithdrawRequest {
+        WithdrawRequest {
+            vote_account: Pubkey::new_unique(),
+            bond: Pubkey::new_unique(),
+            epoch: 10,
+            requested_amount: 1_000,
+            withdrawn_amount: 0,
+            bump: 255,
+            account_version,
+            reserved: [0; 92],
+        }
+    }
+
+    #[test]
+    fn deserialize_known_versions() {
+        for account_version in [0, ACCOUNT_VERSION] {
+            let mut data: Vec<u8> = vec![];
+            withdraw_request(account_version)
+                .try_serialize(&mut data)
+                .unwrap();
+            let deserialized: WithdrawRequest = deserialize_versioned_account(&data).unwrap();
+            assert_eq!(deserialized.account_version, account_version);
+            assert_eq!(deserialized.requested_amount, 1_000);
+        }
+    }
+
+    #[test]
+    fn deserialize_shorter_account() {
+        let mut data: Vec<u8> = vec![];
+        withdraw_request(0).try_serialize(&mut data).unwrap();
+        // account created with a smaller layout
+        data.truncate(data.len() - 50);
+        let deserialized: WithdrawRequest = deserialize_versioned_account(&data).unwrap();
+        assert_eq!(deserialized.bump, 255);
+    }
+
+    #[test]
+    fn deserialize_unknown_version() {
+        let mut data: Vec<u8> = vec![];
+        withdraw_request(ACCOUNT_VERSION + 1)
+            .try_serialize(&mut data)
+            .unwrap();
+        assert!(deserialize_versioned_account::<WithdrawRequest>(&data).is_err());
+    }
+}
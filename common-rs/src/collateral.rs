@@ -0,0 +1,48 @@
+use anchor_spl::token::TokenAccount;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Arc;
+use validator_bonds::state::collateral_mint::{find_collateral_vault_address, CollateralMint};
+
+use crate::get_validator_bonds_program;
+use crate::utils::get_accounts_for_pubkeys;
+
+pub async fn get_collateral_mints(
+    rpc_client: Arc<RpcClient>,
+) -> anyhow::Result<Vec<(Pubkey, CollateralMint)>> {
+    let program = get_validator_bonds_program(rpc_client, None)?;
+    Ok(program.accounts(Default::default()).await?)
+}
+
+/// Token amounts of the collateral vaults owned by the bonds (or settlements), only the existing vaults are returned
+pub async fn get_collateral_vaults(
+    rpc_client: Arc<RpcClient>,
+    owners: &[Pubkey],
+    collateral_mints: &[(Pubkey, CollateralMint)],
+) -> anyhow::Result<Vec<(Pubkey, Pubkey, u64)>> {
+    let vaults = owners
+        .iter()
+        .flat_map(|owner| {
+            collateral_mints.iter().map(move |(_, collateral_mint)| {
+                (
+                    *owner,
+                    collateral_mint.mint,
+                    find_collateral_vault_address(owner, &collateral_mint.mint).0,
+                )
+            })
+        })
+        .collect::<Vec<(Pubkey, Pubkey, Pubkey)>>();
+    let vault_addresses = vaults
+        .iter()
+        .map(|(_, _, vault)| *vault)
+        .collect::<Vec<Pubkey>>();
+    let vault_accounts =
+        get_accounts_for_pubkeys::<TokenAccount>(rpc_client, &vault_addresses).await?;
+    Ok(vaults
+        .into_iter()
+        .zip(vault_accounts)
+        .filter_map(|((owner, mint, _), (_, vault_account))| {
+            vault_account.map(|vault_account| (owner, mint, vault_account.amount))
+        })
+        .collect())
+}
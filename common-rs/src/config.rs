@@ -1,4 +1,4 @@
-use crate::get_validator_bonds_program;
+use crate::account_versions::deserialize_versioned_account;
 use anyhow::anyhow;
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_program::pubkey::Pubkey;
@@ -9,13 +9,18 @@ pub async fn get_config(
     rpc_client: Arc<RpcClient>,
     config_address: Pubkey,
 ) -> anyhow::Result<Config> {
-    let program = get_validator_bonds_program(rpc_client, None)?;
-    let config = program.account(config_address).await.map_err(|e| {
+    let config_account = rpc_client.get_account(&config_address).await.map_err(|e| {
         anyhow!(
             "Cannot load validator-bonds config account {}: {:?}",
             config_address,
             e
         )
     })?;
-    Ok(config)
+    if config_account.owner != validator_bonds::ID {
+        return Err(anyhow!(
+            "Config account {} is not owned by the validator-bonds program",
+            config_address
+        ));
+    }
+    deserialize_versioned_account(&config_account.data)
 }
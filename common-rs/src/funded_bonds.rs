@@ -1,10 +1,13 @@
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
 use std::{collections::HashMap, sync::Arc};
-use validator_bonds::state::{bond::Bond, config::find_bonds_withdrawer_authority};
+use validator_bonds::state::{
+    bond::Bond, collateral_mint::CollateralMint, config::find_bonds_withdrawer_authority,
+};
 
 use crate::{
     bonds::get_bonds,
+    collateral::{get_collateral_mints, get_collateral_vaults},
     settlements::get_settlements,
     stake_accounts::{collect_stake_accounts, get_clock},
     withdraw_requests::get_withdraw_requests,
@@ -16,6 +19,8 @@ pub struct Funds {
     pub effective_amount: u64,
     pub remaining_witdraw_request_amount: u64,
     pub remainining_settlement_claim_amount: u64,
+    /// lamports value (with haircut) of the token collateral, part of the funded amount
+    pub collateral_amount: u64,
 }
 
 pub async fn collect_validator_bonds_with_funds(
@@ -33,11 +38,23 @@ pub async fn collect_validator_bonds_with_funds(
     let witdraw_requests = get_withdraw_requests(rpc_client.clone()).await?;
     let settlements = get_settlements(rpc_client.clone()).await?;
     let clock = get_clock(rpc_client.clone()).await?;
+    let collateral_mints = get_collateral_mints(rpc_client.clone()).await?;
+    let bond_addresses = bonds.keys().cloned().collect::<Vec<Pubkey>>();
+    let collateral_vaults =
+        get_collateral_vaults(rpc_client.clone(), &bond_addresses, &collateral_mints).await?;
 
     log::info!("Found bonds: {}", bonds.len());
     log::info!("Found stake accounts: {}", stake_accounts.len());
     log::info!("Found witdraw requests: {}", witdraw_requests.len());
     log::info!("Found settlements: {}", settlements.len());
+    log::info!("Found collateral vaults: {}", collateral_vaults.len());
+
+    // stake accounts of a migrated bond are delegated to the new vote account
+    let migrated_vote_accounts: HashMap<Pubkey, Pubkey> = bonds
+        .values()
+        .filter(|bond| bond.stake_vote_account() != bond.vote_account)
+        .map(|bond| (bond.stake_vote_account(), bond.vote_account))
+        .collect();
 
     for (pubkey, _, stake_account) in stake_accounts {
         if let Some(lockup) = stake_account.lockup() {
@@ -46,12 +63,40 @@ pub async fn collect_validator_bonds_with_funds(
             }
         }
         if let Some(delegation) = stake_account.delegation() {
-            let funded_bond = validator_funds.entry(delegation.voter_pubkey).or_default();
+            let bond_vote_account = migrated_vote_accounts
+                .get(&delegation.voter_pubkey)
+                .unwrap_or(&delegation.voter_pubkey);
+            let funded_bond = validator_funds.entry(*bond_vote_account).or_default();
             funded_bond.funded_amount += delegation.stake;
             funded_bond.effective_amount += delegation.stake;
         }
     }
 
+    let collateral_mints: HashMap<(Pubkey, Pubkey), CollateralMint> = collateral_mints
+        .into_iter()
+        .map(|(_, collateral_mint)| {
+            (
+                (collateral_mint.config, collateral_mint.mint),
+                collateral_mint,
+            )
+        })
+        .collect();
+    for (bond_address, mint, token_amount) in collateral_vaults {
+        let bond = match bonds.get(&bond_address) {
+            Some(bond) => bond,
+            None => continue,
+        };
+        let collateral_mint = match collateral_mints.get(&(bond.config, mint)) {
+            Some(collateral_mint) => collateral_mint,
+            None => continue,
+        };
+        let collateral_value = collateral_mint.collateral_value(token_amount);
+        let funded_bond = validator_funds.entry(bond.vote_account).or_default();
+        funded_bond.collateral_amount += collateral_value;
+        funded_bond.funded_amount += collateral_value;
+        funded_bond.effective_amount += collateral_value;
+    }
+
     for (_, withdraw_request) in witdraw_requests {
         let funded_bond = validator_funds
             .entry(withdraw_request.vote_account)
@@ -75,9 +120,15 @@ pub async fn collect_validator_bonds_with_funds(
         };
 
         let funded_bond = validator_funds.entry(bond.vote_account).or_default();
+        // collateral funded to the settlement is moved out of the bond vault, only the funded stake is subtracted
         let remainining_settlement_claim_amount = settlement
             .lamports_funded
-            .saturating_sub(settlement.lamports_claimed);
+            .saturating_sub(settlement.collateral_funded)
+            .saturating_sub(
+                settlement
+                    .lamports_claimed
+                    .saturating_sub(settlement.collateral_claimed),
+            );
         funded_bond.remainining_settlement_claim_amount += remainining_settlement_claim_amount;
         funded_bond.effective_amount = funded_bond
             .effective_amount
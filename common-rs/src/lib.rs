@@ -3,7 +3,9 @@ use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::signature::Keypair;
 use std::{str::FromStr, sync::Arc};
 
+pub mod account_versions;
 pub mod bonds;
+pub mod collateral;
 pub mod config;
 pub mod constants;
 pub mod funded_bonds;
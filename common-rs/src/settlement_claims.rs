@@ -1,4 +1,4 @@
-use crate::get_validator_bonds_program;
+use crate::account_versions::get_versioned_accounts;
 
 use anyhow::anyhow;
 use solana_account_decoder::UiDataSliceConfig;
@@ -13,21 +13,21 @@ use validator_bonds::state::settlement_claim::SettlementClaim;
 pub async fn get_settlement_claims(
     rpc_client: Arc<RpcClient>,
 ) -> anyhow::Result<Vec<(Pubkey, SettlementClaim)>> {
-    let program = get_validator_bonds_program(rpc_client, None)?;
-    Ok(program.accounts(Default::default()).await?)
+    get_versioned_accounts(rpc_client, vec![]).await
 }
 
 pub async fn get_settlement_claims_for_settlement(
     rpc_client: Arc<RpcClient>,
     settlement_address: &Pubkey,
 ) -> anyhow::Result<Vec<(Pubkey, SettlementClaim)>> {
-    let program = get_validator_bonds_program(rpc_client, None)?;
-    Ok(program
-        .accounts(vec![RpcFilterType::Memcmp(Memcmp::new(
+    get_versioned_accounts(
+        rpc_client,
+        vec![RpcFilterType::Memcmp(Memcmp::new(
             8,
             solana_client::rpc_filter::MemcmpEncodedBytes::Base58(settlement_address.to_string()),
-        ))])
-        .await?)
+        ))],
+    )
+    .await
 }
 
 pub async fn collect_existence_settlement_claims_from_addresses(
@@ -1,27 +1,43 @@
+use crate::account_versions::{get_versioned_accounts, get_versioned_accounts_for_pubkeys};
 use crate::bonds::get_bonds_for_pubkeys;
-use crate::get_validator_bonds_program;
-
-use crate::utils::get_accounts_for_pubkeys;
 
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::clock::Clock;
 use solana_sdk::pubkey::Pubkey;
 use std::collections::HashSet;
 use std::sync::Arc;
 use validator_bonds::state::bond::Bond;
+use validator_bonds::state::config::Config;
 use validator_bonds::state::settlement::Settlement;
 
 pub async fn get_settlements(
     rpc_client: Arc<RpcClient>,
 ) -> anyhow::Result<Vec<(Pubkey, Settlement)>> {
-    let program = get_validator_bonds_program(rpc_client, None)?;
-    Ok(program.accounts(Default::default()).await?)
+    get_versioned_accounts(rpc_client, vec![]).await
 }
 
 pub async fn get_settlements_for_pubkeys(
     rpc_client: Arc<RpcClient>,
     pubkeys: &[Pubkey],
 ) -> anyhow::Result<Vec<(Pubkey, Option<Settlement>)>> {
-    get_accounts_for_pubkeys(rpc_client, pubkeys).await
+    get_versioned_accounts_for_pubkeys(rpc_client, pubkeys).await
+}
+
+/// The last epoch the settlement can be claimed at, the settlement `claim_expiry_extension` included
+pub fn settlement_expiration_epoch(settlement: &Settlement, config: &Config) -> u64 {
+    settlement.expiration_epoch(config.epochs_to_claim_settlement)
+}
+
+/// The settlement claiming period is over and the settlement can be closed
+pub fn is_settlement_expired(settlement: &Settlement, config: &Config, current_epoch: u64) -> bool {
+    current_epoch > settlement_expiration_epoch(settlement, config)
+}
+
+/// The settlement is in the claiming window and not blocked by a dispute
+pub fn is_settlement_claimable(settlement: &Settlement, config: &Config, clock: &Clock) -> bool {
+    clock.epoch <= settlement_expiration_epoch(settlement, config)
+        && clock.slot >= settlement.slot_created_at + config.slots_to_start_settlement_claiming
+        && !settlement.disputed
 }
 
 pub async fn get_bonds_for_settlements(
@@ -58,3 +74,123 @@ pub async fn get_bonds_for_settlements(
 
     Ok(settlements_bonds)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator_bonds::state::settlement::Bumps;
+
+    fn settlement(epoch_created_for: u64, claim_expiry_extension: u32) -> Settlement {
+        Settlement {
+            bond: Pubkey::new_unique(),
+            staker_authority: Pubkey::new_unique(),
+            merkle_root: [1; 32],
+            max_total_claim: 100,
+            max_merkle_nodes: 1,
+            lamports_funded: 100,
+            lamports_claimed: 0,
+            merkle_nodes_claimed: 0,
+            epoch_created_for,
+            slot_created_at: 1_000,
+            rent_collector: Pubkey::new_unique(),
+            split_rent_collector: None,
+            split_rent_amount: 0,
+            bumps: Bumps::default(),
+            collateral_funded: 0,
+            collateral_claimed: 0,
+            amended: false,
+            amended_merkle_root: [0; 32],
+            disputed: false,
+            dispute_reason_hash: [0; 32],
+            claim_expiry_extension,
+            account_version: 0,
+            reserved: [0; 4],
+        }
+    }
+
+    fn config(epochs_to_claim_settlement: u64) -> Config {
+        Config {
+            admin_authority: Pubkey::new_unique(),
+            operator_authority: Pubkey::new_unique(),
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs: 0,
+            minimum_stake_lamports: 0,
+            bonds_withdrawer_authority_bump: 0,
+            pause_authority: Pubkey::new_unique(),
+            paused: false,
+            slots_to_start_settlement_claiming: 100,
+            min_bond_max_stake_wanted: 0,
+            pending_admin_authority: Pubkey::default(),
+            pending_operator_authority: Pubkey::default(),
+            pending_pause_authority: Pubkey::default(),
+            config_change_delay_epochs: 0,
+            pending_config_changes: Default::default(),
+            pause_flags: 0,
+            settlement_creator_authority: Pubkey::default(),
+            settlement_funder_authority: Pubkey::default(),
+            settlement_closer_authority: Pubkey::default(),
+            bid_fee_collector: Pubkey::default(),
+            claimer_fee_lamports: 0,
+            claimer_fee_max_bps: 0,
+            account_version: 0,
+            bid_max_delegated_lamports: 0,
+            max_claim_expiry_extension: 0,
+            reserved: [0; 127],
+        }
+    }
+
+    fn clock(epoch: u64, slot: u64) -> Clock {
+        Clock {
+            slot,
+            epoch,
+            ..Clock::default()
+        }
+    }
+
+    #[test]
+    fn settlement_expiration_with_extension() {
+        let config = config(3);
+        assert_eq!(settlement_expiration_epoch(&settlement(10, 0), &config), 13);
+        assert!(is_settlement_expired(&settlement(10, 0), &config, 14));
+
+        let extended = settlement(10, 2);
+        assert_eq!(settlement_expiration_epoch(&extended, &config), 15);
+        assert!(!is_settlement_expired(&extended, &config, 14));
+        assert!(!is_settlement_expired(&extended, &config, 15));
+        assert!(is_settlement_expired(&extended, &config, 16));
+    }
+
+    #[test]
+    fn settlement_claimable_window() {
+        let config = config(3);
+        let settlement_to_claim = settlement(10, 2);
+        assert!(!is_settlement_claimable(
+            &settlement_to_claim,
+            &config,
+            &clock(11, 1_099)
+        ));
+        assert!(is_settlement_claimable(
+            &settlement_to_claim,
+            &config,
+            &clock(11, 1_100)
+        ));
+        assert!(is_settlement_claimable(
+            &settlement_to_claim,
+            &config,
+            &clock(15, 2_000)
+        ));
+        assert!(!is_settlement_claimable(
+            &settlement_to_claim,
+            &config,
+            &clock(16, 2_000)
+        ));
+
+        let mut disputed = settlement(10, 0);
+        disputed.disputed = true;
+        assert!(!is_settlement_claimable(
+            &disputed,
+            &config,
+            &clock(11, 2_000)
+        ));
+    }
+}
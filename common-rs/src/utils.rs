@@ -12,6 +12,20 @@ pub async fn get_accounts_for_pubkeys<T: AccountDeserialize>(
     rpc_client: Arc<RpcClient>,
     pubkeys: &[Pubkey],
 ) -> anyhow::Result<Vec<(Pubkey, Option<T>)>> {
+    get_accounts_for_pubkeys_with(rpc_client, pubkeys, |mut data| {
+        Ok(T::try_deserialize(&mut data)?)
+    })
+    .await
+}
+
+pub async fn get_accounts_for_pubkeys_with<T, F>(
+    rpc_client: Arc<RpcClient>,
+    pubkeys: &[Pubkey],
+    deserialize: F,
+) -> anyhow::Result<Vec<(Pubkey, Option<T>)>>
+where
+    F: Fn(&[u8]) -> anyhow::Result<T>,
+{
     let settlement_addresses = pubkeys
         // permitted to fetch 100 accounts at once; https://solana.com/docs/rpc/http/getmultipleaccounts
         .chunks(100)
@@ -29,8 +43,7 @@ pub async fn get_accounts_for_pubkeys<T: AccountDeserialize>(
             .zip(address_chunk.iter())
             .for_each(|(account, pubkey)| {
                 let account = account.as_ref().and_then(|account| {
-                    let mut data: &[u8] = &account.data;
-                    T::try_deserialize(&mut data).map_or_else(
+                    deserialize(&account.data).map_or_else(
                         |e| {
                             error!(
                                 "Cannot deserialize account data for settlement account {}: {}",
@@ -0,0 +1,589 @@
+//! Event layouts emitted by the program before the events were versioned.
+//!
+//! The legacy events share the discriminators (the event names) with the current ones
+//! but their data neither starts with the `version` byte nor carries the fields added later.
+//! They are mapped to the current typed events with `version` set to [LEGACY_EVENT_VERSION]
+//! and the fields missing in the legacy layout filled with defaults (zeros, `None`, default pubkey).
+
+use anchor_lang::prelude::*;
+use validator_bonds::events::{
+    bond, config, settlement, settlement_claim, stake, withdraw, DelegationInfo, PubkeyValueChange,
+    SplitStakeData, U64ValueChange,
+};
+
+/// version reported by the events decoded from the legacy layout
+pub const LEGACY_EVENT_VERSION: u8 = 0;
+
+fn no_change() -> U64ValueChange {
+    U64ValueChange { old: 0, new: 0 }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct InitBondEventV0 {
+    pub bond: Pubkey,
+    pub config: Pubkey,
+    pub vote_account: Pubkey,
+    pub validator_identity: Pubkey,
+    pub authority: Pubkey,
+    pub cpmpe: u64,
+    pub max_stake_wanted: u64,
+}
+
+impl From<InitBondEventV0> for bond::InitBondEvent {
+    fn from(event: InitBondEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            config: event.config,
+            vote_account: event.vote_account,
+            validator_identity: event.validator_identity,
+            authority: event.authority,
+            cpmpe: event.cpmpe,
+            max_stake_wanted: event.max_stake_wanted,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct ConfigureBondEventV0 {
+    pub bond_authority: Option<PubkeyValueChange>,
+    pub cpmpe: Option<U64ValueChange>,
+    pub max_stake_wanted: Option<U64ValueChange>,
+}
+
+impl From<ConfigureBondEventV0> for bond::ConfigureBondEvent {
+    fn from(event: ConfigureBondEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond_authority: event.bond_authority,
+            cpmpe: event.cpmpe,
+            max_stake_wanted: event.max_stake_wanted,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct ConfigureBondWithMintEventV0 {
+    pub validator_identity: Pubkey,
+    pub bond_authority: Option<PubkeyValueChange>,
+    pub cpmpe: Option<U64ValueChange>,
+    pub max_stake_wanted: Option<U64ValueChange>,
+}
+
+impl From<ConfigureBondWithMintEventV0> for bond::ConfigureBondWithMintEvent {
+    fn from(event: ConfigureBondWithMintEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            validator_identity: event.validator_identity,
+            // the legacy program always burnt the bond mint token
+            token_burned: true,
+            bond_authority: event.bond_authority,
+            cpmpe: event.cpmpe,
+            max_stake_wanted: event.max_stake_wanted,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct FundBondEventV0 {
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub stake_account: Pubkey,
+    pub stake_authority_signer: Pubkey,
+    pub deposited_amount: u64,
+}
+
+impl From<FundBondEventV0> for bond::FundBondEvent {
+    fn from(event: FundBondEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            vote_account: event.vote_account,
+            stake_account: event.stake_account,
+            stake_authority_signer: event.stake_authority_signer,
+            deposited_amount: event.deposited_amount,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct MintBondEventV0 {
+    pub bond: Pubkey,
+    pub validator_identity: Pubkey,
+    pub validator_identity_token_account: Pubkey,
+    pub token_metadata: Pubkey,
+}
+
+impl From<MintBondEventV0> for bond::MintBondEvent {
+    fn from(event: MintBondEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            validator_identity: event.validator_identity,
+            validator_identity_token_account: event.validator_identity_token_account,
+            token_metadata: event.token_metadata,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct InitConfigEventV0 {
+    pub config: Pubkey,
+    pub admin_authority: Pubkey,
+    pub operator_authority: Pubkey,
+    pub withdraw_lockup_epochs: u64,
+    pub epochs_to_claim_settlement: u64,
+    pub minimum_stake_lamports: u64,
+    pub bonds_withdrawer_authority: Pubkey,
+    pub slots_to_start_settlement_claiming: u64,
+}
+
+impl From<InitConfigEventV0> for config::InitConfigEvent {
+    fn from(event: InitConfigEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            config: event.config,
+            admin_authority: event.admin_authority,
+            operator_authority: event.operator_authority,
+            withdraw_lockup_epochs: event.withdraw_lockup_epochs,
+            epochs_to_claim_settlement: event.epochs_to_claim_settlement,
+            minimum_stake_lamports: event.minimum_stake_lamports,
+            bonds_withdrawer_authority: event.bonds_withdrawer_authority,
+            slots_to_start_settlement_claiming: event.slots_to_start_settlement_claiming,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct ConfigureConfigEventV0 {
+    pub admin_authority: Option<PubkeyValueChange>,
+    pub operator_authority: Option<PubkeyValueChange>,
+    pub pause_authority: Option<PubkeyValueChange>,
+    pub epochs_to_claim_settlement: Option<U64ValueChange>,
+    pub minimum_stake_lamports: Option<U64ValueChange>,
+    pub withdraw_lockup_epochs: Option<U64ValueChange>,
+    pub slots_to_start_settlement_claiming: Option<U64ValueChange>,
+    pub min_bond_max_stake_wanted: Option<U64ValueChange>,
+}
+
+impl From<ConfigureConfigEventV0> for config::ConfigureConfigEvent {
+    fn from(event: ConfigureConfigEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            admin_authority: event.admin_authority,
+            operator_authority: event.operator_authority,
+            pause_authority: event.pause_authority,
+            settlement_creator_authority: None,
+            settlement_funder_authority: None,
+            settlement_closer_authority: None,
+            bid_fee_collector: None,
+            bid_max_delegated_lamports: None,
+            max_claim_expiry_extension: None,
+            epochs_to_claim_settlement: event.epochs_to_claim_settlement,
+            minimum_stake_lamports: event.minimum_stake_lamports,
+            withdraw_lockup_epochs: event.withdraw_lockup_epochs,
+            slots_to_start_settlement_claiming: event.slots_to_start_settlement_claiming,
+            min_bond_max_stake_wanted: event.min_bond_max_stake_wanted,
+            config_change_delay_epochs: None,
+            claimer_fee_lamports: None,
+            claimer_fee_max_bps: None,
+            pending_admin_authority: None,
+            pending_operator_authority: None,
+            pending_pause_authority: None,
+            pending_config_changes: None,
+        }
+    }
+}
+
+/// the same legacy layout is used for both the emergency pause and resume events
+#[derive(AnchorDeserialize)]
+pub struct EmergencyPauseEventV0 {
+    pub config: Pubkey,
+    pub admin_authority: Pubkey,
+    pub operator_authority: Pubkey,
+    pub epochs_to_claim_settlement: u64,
+    pub withdraw_lockup_epochs: u64,
+    pub minimum_stake_lamports: u64,
+    pub pause_authority: Pubkey,
+}
+
+pub type EmergencyResumeEventV0 = EmergencyPauseEventV0;
+
+impl From<EmergencyPauseEventV0> for config::EmergencyPauseEvent {
+    fn from(event: EmergencyPauseEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            config: event.config,
+            admin_authority: event.admin_authority,
+            operator_authority: event.operator_authority,
+            epochs_to_claim_settlement: event.epochs_to_claim_settlement,
+            withdraw_lockup_epochs: event.withdraw_lockup_epochs,
+            minimum_stake_lamports: event.minimum_stake_lamports,
+            pause_authority: event.pause_authority,
+            pause_flags: no_change(),
+        }
+    }
+}
+
+impl From<EmergencyResumeEventV0> for config::EmergencyResumeEvent {
+    fn from(event: EmergencyResumeEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            config: event.config,
+            admin_authority: event.admin_authority,
+            operator_authority: event.operator_authority,
+            epochs_to_claim_settlement: event.epochs_to_claim_settlement,
+            withdraw_lockup_epochs: event.withdraw_lockup_epochs,
+            minimum_stake_lamports: event.minimum_stake_lamports,
+            pause_authority: event.pause_authority,
+            pause_flags: no_change(),
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct InitSettlementEventV0 {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub vote_account: Pubkey,
+    pub staker_authority: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_merkle_nodes: u64,
+    pub epoch_created_for: u64,
+    pub slot_created_at: u64,
+    pub rent_collector: Pubkey,
+}
+
+impl From<InitSettlementEventV0> for settlement::InitSettlementEvent {
+    fn from(event: InitSettlementEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            settlement: event.settlement,
+            vote_account: event.vote_account,
+            staker_authority: event.staker_authority,
+            merkle_root: event.merkle_root,
+            max_total_claim: event.max_total_claim,
+            max_merkle_nodes: event.max_merkle_nodes,
+            epoch_created_for: event.epoch_created_for,
+            slot_created_at: event.slot_created_at,
+            rent_collector: event.rent_collector,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct CloseSettlementEventV0 {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_merkle_nodes: u64,
+    pub lamports_funded: u64,
+    pub lamports_claimed: u64,
+    pub merkle_nodes_claimed: u64,
+    pub split_rent_collector: Option<Pubkey>,
+    pub split_rent_refund: Option<Pubkey>,
+    pub rent_collector: Pubkey,
+    pub expiration_epoch: u64,
+    pub current_epoch: u64,
+}
+
+impl From<CloseSettlementEventV0> for settlement::CloseSettlementEvent {
+    fn from(event: CloseSettlementEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            settlement: event.settlement,
+            merkle_root: event.merkle_root,
+            max_total_claim: event.max_total_claim,
+            max_merkle_nodes: event.max_merkle_nodes,
+            lamports_funded: event.lamports_funded,
+            lamports_claimed: event.lamports_claimed,
+            merkle_nodes_claimed: event.merkle_nodes_claimed,
+            split_rent_collector: event.split_rent_collector,
+            split_rent_refund: event.split_rent_refund,
+            rent_collector: event.rent_collector,
+            expiration_epoch: event.expiration_epoch,
+            current_epoch: event.current_epoch,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct CancelSettlementEventV0 {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub max_total_claim: u64,
+    pub max_merkle_nodes: u64,
+    pub lamports_funded: u64,
+    pub lamports_claimed: u64,
+    pub merkle_nodes_claimed: u64,
+    pub split_rent_collector: Option<Pubkey>,
+    pub split_rent_refund: Option<Pubkey>,
+    pub rent_collector: Pubkey,
+    pub authority: Pubkey,
+}
+
+impl From<CancelSettlementEventV0> for settlement::CancelSettlementEvent {
+    fn from(event: CancelSettlementEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            settlement: event.settlement,
+            merkle_root: event.merkle_root,
+            max_total_claim: event.max_total_claim,
+            max_merkle_nodes: event.max_merkle_nodes,
+            lamports_funded: event.lamports_funded,
+            lamports_claimed: event.lamports_claimed,
+            merkle_nodes_claimed: event.merkle_nodes_claimed,
+            split_rent_collector: event.split_rent_collector,
+            split_rent_refund: event.split_rent_refund,
+            rent_collector: event.rent_collector,
+            authority: event.authority,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct FundSettlementEventV0 {
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub funding_amount: u64,
+    pub stake_account: Pubkey,
+    pub lamports_funded: u64,
+    pub lamports_claimed: u64,
+    pub merkle_nodes_claimed: u64,
+    pub split_stake_account: Option<SplitStakeData>,
+    pub split_rent_collector: Option<Pubkey>,
+    pub split_rent_amount: u64,
+}
+
+impl From<FundSettlementEventV0> for settlement::FundSettlementEvent {
+    fn from(event: FundSettlementEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            bond: event.bond,
+            settlement: event.settlement,
+            funding_amount: event.funding_amount,
+            stake_account: event.stake_account,
+            lamports_funded: event.lamports_funded,
+            lamports_claimed: event.lamports_claimed,
+            merkle_nodes_claimed: event.merkle_nodes_claimed,
+            split_stake_account: event.split_stake_account,
+            split_rent_collector: event.split_rent_collector,
+            split_rent_amount: event.split_rent_amount,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct ClaimSettlementEventV0 {
+    pub settlement_claim: Pubkey,
+    pub settlement: Pubkey,
+    pub settlement_lamports_claimed: U64ValueChange,
+    pub settlement_merkle_nodes_claimed: u64,
+    pub stake_account_to: Pubkey,
+    pub stake_account_withdrawer: Pubkey,
+    pub stake_account_staker: Pubkey,
+    pub amount: u64,
+    pub rent_collector: Pubkey,
+}
+
+impl From<ClaimSettlementEventV0> for settlement_claim::ClaimSettlementEvent {
+    fn from(event: ClaimSettlementEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            settlement_claim: event.settlement_claim,
+            settlement: event.settlement,
+            settlement_lamports_claimed: event.settlement_lamports_claimed,
+            settlement_merkle_nodes_claimed: event.settlement_merkle_nodes_claimed,
+            stake_account_to: event.stake_account_to,
+            stake_account_withdrawer: event.stake_account_withdrawer,
+            stake_account_staker: event.stake_account_staker,
+            amount: event.amount,
+            rent_collector: event.rent_collector,
+            // the legacy program did not charge any claimer fee
+            claimer_fee: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct CloseSettlementClaimEventV0 {
+    pub settlement: Pubkey,
+    pub rent_collector: Pubkey,
+}
+
+impl From<CloseSettlementClaimEventV0> for settlement_claim::CloseSettlementClaimEvent {
+    fn from(event: CloseSettlementClaimEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            settlement: event.settlement,
+            settlement_claim: Pubkey::default(),
+            stake_account_to: Pubkey::default(),
+            stake_account_withdrawer: Pubkey::default(),
+            stake_account_staker: Pubkey::default(),
+            amount: 0,
+            rent_collector: event.rent_collector,
+            rent_refund: 0,
+            epoch: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct MergeStakeEventV0 {
+    pub config: Pubkey,
+    pub staker_authority: Pubkey,
+    pub destination_stake: Pubkey,
+    pub destination_delegation: Option<DelegationInfo>,
+    pub source_stake: Pubkey,
+    pub source_delegation: Option<DelegationInfo>,
+}
+
+impl From<MergeStakeEventV0> for stake::MergeStakeEvent {
+    fn from(event: MergeStakeEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            config: event.config,
+            settlement: Pubkey::default(),
+            staker_authority: event.staker_authority,
+            destination_stake: event.destination_stake,
+            destination_delegation: event.destination_delegation,
+            destination_lamports: no_change(),
+            source_stake: event.source_stake,
+            source_delegation: event.source_delegation,
+            source_lamports: 0,
+            epoch: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct ResetStakeEventV0 {
+    pub config: Pubkey,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub stake_account: Pubkey,
+    pub vote_account: Pubkey,
+    pub settlement_staker_authority: Pubkey,
+}
+
+impl From<ResetStakeEventV0> for stake::ResetStakeEvent {
+    fn from(event: ResetStakeEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            config: event.config,
+            bond: event.bond,
+            settlement: event.settlement,
+            stake_account: event.stake_account,
+            vote_account: event.vote_account,
+            settlement_staker_authority: event.settlement_staker_authority,
+            stake_amount: 0,
+            operator_authority: None,
+            epoch: 0,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct WithdrawStakeEventV0 {
+    pub config: Pubkey,
+    pub operator_authority: Pubkey,
+    pub settlement: Pubkey,
+    pub stake_account: Pubkey,
+    pub withdraw_to: Pubkey,
+    pub settlement_staker_authority: Pubkey,
+    pub withdrawn_amount: u64,
+}
+
+impl From<WithdrawStakeEventV0> for stake::WithdrawStakeEvent {
+    fn from(event: WithdrawStakeEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            config: event.config,
+            operator_authority: event.operator_authority,
+            settlement: event.settlement,
+            stake_account: event.stake_account,
+            withdraw_to: event.withdraw_to,
+            settlement_staker_authority: event.settlement_staker_authority,
+            withdrawn_amount: event.withdrawn_amount,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct InitWithdrawRequestEventV0 {
+    pub withdraw_request: Pubkey,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub epoch: u64,
+    pub requested_amount: u64,
+}
+
+impl From<InitWithdrawRequestEventV0> for withdraw::InitWithdrawRequestEvent {
+    fn from(event: InitWithdrawRequestEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            withdraw_request: event.withdraw_request,
+            bond: event.bond,
+            vote_account: event.vote_account,
+            epoch: event.epoch,
+            requested_amount: event.requested_amount,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct CancelWithdrawRequestEventV0 {
+    pub withdraw_request: Pubkey,
+    pub bond: Pubkey,
+    pub authority: Pubkey,
+    pub requested_amount: u64,
+    pub withdrawn_amount: u64,
+}
+
+impl From<CancelWithdrawRequestEventV0> for withdraw::CancelWithdrawRequestEvent {
+    fn from(event: CancelWithdrawRequestEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            withdraw_request: event.withdraw_request,
+            bond: event.bond,
+            authority: event.authority,
+            requested_amount: event.requested_amount,
+            withdrawn_amount: event.withdrawn_amount,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize)]
+pub struct ClaimWithdrawRequestEventV0 {
+    pub withdraw_request: Pubkey,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub stake_account: Pubkey,
+    pub split_stake: Option<SplitStakeData>,
+    pub new_stake_account_owner: Pubkey,
+    pub withdrawing_amount: u64,
+    pub withdrawn_amount: U64ValueChange,
+}
+
+impl From<ClaimWithdrawRequestEventV0> for withdraw::ClaimWithdrawRequestEvent {
+    fn from(event: ClaimWithdrawRequestEventV0) -> Self {
+        Self {
+            version: LEGACY_EVENT_VERSION,
+            withdraw_request: event.withdraw_request,
+            bond: event.bond,
+            vote_account: event.vote_account,
+            stake_account: event.stake_account,
+            merged_stake_accounts: vec![],
+            split_stake: event.split_stake,
+            new_stake_account_owner: event.new_stake_account_owner,
+            withdrawing_amount: event.withdrawing_amount,
+            withdrawn_amount: event.withdrawn_amount,
+        }
+    }
+}
@@ -0,0 +1,360 @@
+//! Decoding of the validator bonds program events for indexers.
+//!
+//! The program emits the events with `emit_cpi!`, i.e., the event data is the data of a self-invoked
+//! inner instruction, prefixed with the anchor event instruction tag.
+//! Events emitted with `emit!` land in the transaction logs as `Program data: <base64>` lines,
+//! both forms are supported.
+//!
+//! Events emitted before the event versioning was introduced are decoded from their [legacy] layout.
+
+use anchor_lang::event::EVENT_IX_TAG_LE;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use anyhow::anyhow;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use validator_bonds::events::{
+    account, bond, collateral, config, settlement, settlement_claim, stake, withdraw,
+};
+
+pub mod legacy;
+
+const PROGRAM_LOG_DATA_PREFIX: &str = "Program data: ";
+
+macro_rules! validator_bonds_events {
+    ($($module:ident::$event:ident $(| legacy::$legacy:ident)?),* $(,)?) => {
+        /// Typed event of the validator bonds program
+        #[allow(clippy::large_enum_variant)]
+        pub enum ValidatorBondsEvent {
+            $($event($module::$event),)*
+        }
+
+        impl ValidatorBondsEvent {
+            /// Decodes the event from the data that starts with the 8 bytes event discriminator.
+            /// The current layout has to consume all the event data and report a non-legacy version,
+            /// otherwise the legacy layout of the event (if there is any) is tried.
+            /// Returns `None` when the discriminator does not match any known event.
+            pub fn decode(data: &[u8]) -> anyhow::Result<Option<Self>> {
+                if data.len() < 8 {
+                    return Ok(None);
+                }
+                let (discriminator, event_data) = data.split_at(8);
+                $(
+                    if discriminator == $module::$event::DISCRIMINATOR {
+                        let decoded = $module::$event::try_from_slice(event_data)
+                            .map_err(|e| anyhow!("{:?}", e))
+                            .and_then(|event| {
+                                if event.version == legacy::LEGACY_EVENT_VERSION {
+                                    Err(anyhow!("version {} of the current layout", event.version))
+                                } else {
+                                    Ok(event)
+                                }
+                            });
+                        $(
+                            let decoded = decoded.or_else(|current_err| {
+                                legacy::$legacy::try_from_slice(event_data)
+                                    .map($module::$event::from)
+                                    .map_err(|legacy_err| {
+                                        anyhow!("{}, legacy layout: {:?}", current_err, legacy_err)
+                                    })
+                            });
+                        )?
+                        let event = decoded.map_err(|e| {
+                            anyhow!("Failed to deserialize {}: {}", stringify!($event), e)
+                        })?;
+                        return Ok(Some(Self::$event(event)));
+                    }
+                )*
+                Ok(None)
+            }
+
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$event(_) => stringify!($event),)*
+                }
+            }
+
+            /// version of the event layout, see `validator_bonds::constants::EVENT_VERSION`
+            pub fn version(&self) -> u8 {
+                match self {
+                    $(Self::$event(event) => event.version,)*
+                }
+            }
+        }
+    };
+}
+
+validator_bonds_events!(
+    account::MigrateAccountEvent,
+    bond::InitBondEvent | legacy::InitBondEventV0,
+    bond::ConfigureBondEvent | legacy::ConfigureBondEventV0,
+    bond::ConfigureBondWithMintEvent | legacy::ConfigureBondWithMintEventV0,
+    bond::ConfigureBondAuthorityMintEvent,
+    bond::FundBondEvent | legacy::FundBondEventV0,
+    bond::MintBondEvent | legacy::MintBondEventV0,
+    bond::ChargeBidEvent,
+    bond::MigrateBondEvent,
+    collateral::InitCollateralMintEvent,
+    collateral::ConfigureCollateralMintEvent,
+    collateral::FundBondCollateralEvent,
+    collateral::ClaimWithdrawCollateralEvent,
+    collateral::FundSettlementCollateralEvent,
+    collateral::ClaimSettlementCollateralEvent,
+    collateral::ResetSettlementCollateralEvent,
+    config::InitConfigEvent | legacy::InitConfigEventV0,
+    config::ConfigureConfigEvent | legacy::ConfigureConfigEventV0,
+    config::EmergencyPauseEvent | legacy::EmergencyPauseEventV0,
+    config::EmergencyResumeEvent | legacy::EmergencyResumeEventV0,
+    config::SetPauseFlagsEvent,
+    settlement::InitSettlementEvent | legacy::InitSettlementEventV0,
+    settlement::AmendSettlementEvent,
+    settlement::DisputeSettlementEvent,
+    settlement::ResolveDisputeEvent,
+    settlement::ExtendSettlementExpiryEvent,
+    settlement::CloseSettlementEvent | legacy::CloseSettlementEventV0,
+    settlement::CancelSettlementEvent | legacy::CancelSettlementEventV0,
+    settlement::FundSettlementEvent | legacy::FundSettlementEventV0,
+    settlement_claim::ClaimSettlementEvent | legacy::ClaimSettlementEventV0,
+    settlement_claim::CloseSettlementClaimEvent | legacy::CloseSettlementClaimEventV0,
+    stake::MergeStakeEvent | legacy::MergeStakeEventV0,
+    stake::ResetStakeEvent | legacy::ResetStakeEventV0,
+    stake::WithdrawStakeEvent | legacy::WithdrawStakeEventV0,
+    stake::RedelegateStakeEvent,
+    withdraw::InitWithdrawRequestEvent | legacy::InitWithdrawRequestEventV0,
+    withdraw::CancelWithdrawRequestEvent | legacy::CancelWithdrawRequestEventV0,
+    withdraw::ClaimWithdrawRequestEvent | legacy::ClaimWithdrawRequestEventV0,
+);
+
+/// Decodes the data of an inner instruction created by `emit_cpi!`.
+/// The caller is expected to filter the inner instructions invoking the validator bonds program.
+/// Returns `None` when the data is not an event instruction or the event is not known.
+pub fn decode_cpi_event_data(
+    instruction_data: &[u8],
+) -> anyhow::Result<Option<ValidatorBondsEvent>> {
+    match instruction_data.strip_prefix(&EVENT_IX_TAG_LE) {
+        Some(event_data) => ValidatorBondsEvent::decode(event_data),
+        None => Ok(None),
+    }
+}
+
+/// Decodes events from the transaction log messages.
+/// Only `Program data:` lines logged while the validator bonds program is executing are considered.
+pub fn decode_program_logs<S: AsRef<str>>(logs: &[S]) -> anyhow::Result<Vec<ValidatorBondsEvent>> {
+    let program_id = validator_bonds::ID.to_string();
+    let mut invocation_stack: Vec<String> = vec![];
+    let mut events = vec![];
+    for log in logs.iter().map(AsRef::as_ref) {
+        if let Some(data) = log.strip_prefix(PROGRAM_LOG_DATA_PREFIX) {
+            if invocation_stack.last() != Some(&program_id) {
+                continue;
+            }
+            for encoded in data.split_whitespace() {
+                let decoded = STANDARD.decode(encoded).map_err(|e| {
+                    anyhow!("Failed to decode base64 log data '{}': {}", encoded, e)
+                })?;
+                if let Some(event) = ValidatorBondsEvent::decode(&decoded)? {
+                    events.push(event);
+                }
+            }
+        } else if let Some(invoked) = parse_invoke_log(log) {
+            invocation_stack.push(invoked);
+        } else if is_program_exit_log(log) {
+            invocation_stack.pop();
+        }
+    }
+    Ok(events)
+}
+
+/// `Program <program id> invoke [<depth>]`
+fn parse_invoke_log(log: &str) -> Option<String> {
+    let mut parts = log.split_whitespace();
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some("Program"), Some(program), Some("invoke")) => program
+            .parse::<Pubkey>()
+            .ok()
+            .map(|program| program.to_string()),
+        _ => None,
+    }
+}
+
+/// `Program <program id> success` or `Program <program id> failed: <error>`
+fn is_program_exit_log(log: &str) -> bool {
+    let mut parts = log.split_whitespace();
+    matches!(
+        (parts.next(), parts.next(), parts.next()),
+        (Some("Program"), Some(_), Some("success" | "failed:"))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Event;
+    use validator_bonds::constants::EVENT_VERSION;
+    use validator_bonds::events::bond::ConfigureBondEvent;
+    use validator_bonds::events::settlement_claim::CloseSettlementClaimEvent;
+    use validator_bonds::events::U64ValueChange;
+
+    fn close_settlement_claim_event() -> CloseSettlementClaimEvent {
+        CloseSettlementClaimEvent {
+            version: EVENT_VERSION,
+            settlement: Pubkey::new_unique(),
+            settlement_claim: Pubkey::new_unique(),
+            stake_account_to: Pubkey::new_unique(),
+            stake_account_withdrawer: Pubkey::new_unique(),
+            stake_account_staker: Pubkey::new_unique(),
+            amount: 42,
+            rent_collector: Pubkey::new_unique(),
+            rent_refund: 1_000,
+            epoch: 600,
+        }
+    }
+
+    #[test]
+    fn decode_cpi_event() {
+        let event = close_settlement_claim_event();
+        let mut data = EVENT_IX_TAG_LE.to_vec();
+        data.extend(event.data());
+
+        let decoded = decode_cpi_event_data(&data).unwrap().unwrap();
+        assert_eq!(decoded.name(), "CloseSettlementClaimEvent");
+        assert_eq!(decoded.version(), EVENT_VERSION);
+        match decoded {
+            ValidatorBondsEvent::CloseSettlementClaimEvent(decoded) => {
+                assert_eq!(decoded.settlement, event.settlement);
+                assert_eq!(decoded.amount, 42);
+                assert_eq!(decoded.rent_refund, 1_000);
+            }
+            _ => panic!("Unexpected event type"),
+        }
+
+        // not an event instruction
+        assert!(decode_cpi_event_data(&event.data()).unwrap().is_none());
+    }
+
+    #[test]
+    fn decode_logs_of_program() {
+        let event = close_settlement_claim_event();
+        let encoded = STANDARD.encode(event.data());
+        let other_program = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {} invoke [1]", validator_bonds::ID),
+            format!("Program data: {}", encoded),
+            format!("Program {} invoke [2]", other_program),
+            format!("Program data: {}", encoded),
+            format!("Program {} success", other_program),
+            format!(
+                "Program {} consumed 1000 of 200000 compute units",
+                validator_bonds::ID
+            ),
+            format!("Program {} success", validator_bonds::ID),
+            format!("Program data: {}", encoded),
+        ];
+
+        let events = decode_program_logs(&logs).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name(), "CloseSettlementClaimEvent");
+    }
+
+    // `Program data:` of events emitted by the program before the events were versioned,
+    // pubkeys filled with `[n; 32]`
+    const LEGACY_CLOSE_SETTLEMENT_CLAIM: &str = "g3ih/PmImTUBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIC";
+    const LEGACY_CLAIM_SETTLEMENT: &str = "h/2R6eMdvI0BAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICZAAAAAAAAACOAAAAAAAAAAMAAAAAAAAAAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFKgAAAAAAAAAGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBg==";
+    const LEGACY_MERGE_STAKE: &str = "bwYt0E81dzkBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMBBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBASIEwAAAAAAAAoAAAAAAAAA//////////8FBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQA=";
+    const LEGACY_RESET_STAKE: &str = "/zHbx3cKw7EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgYGBgY=";
+    // starts with the option tag `1` which is the same as the current version byte
+    const LEGACY_CONFIGURE_BOND: &str =
+        "t3ei9FK2cuQBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQECAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgABAAAAAAAAAADoAwAAAAAAAA==";
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    #[test]
+    fn decode_legacy_events() {
+        let logs: Vec<String> = [
+            LEGACY_CLOSE_SETTLEMENT_CLAIM,
+            LEGACY_CLAIM_SETTLEMENT,
+            LEGACY_MERGE_STAKE,
+            LEGACY_RESET_STAKE,
+            LEGACY_CONFIGURE_BOND,
+        ]
+        .iter()
+        .map(|data| format!("Program data: {}", data))
+        .collect();
+        let logs = [
+            vec![format!("Program {} invoke [1]", validator_bonds::ID)],
+            logs,
+            vec![format!("Program {} success", validator_bonds::ID)],
+        ]
+        .concat();
+
+        let events = decode_program_logs(&logs).unwrap();
+        assert_eq!(events.len(), 5);
+        assert!(events
+            .iter()
+            .all(|event| event.version() == legacy::LEGACY_EVENT_VERSION));
+        match &events[0] {
+            ValidatorBondsEvent::CloseSettlementClaimEvent(event) => {
+                assert_eq!(event.settlement, key(1));
+                assert_eq!(event.rent_collector, key(2));
+                assert_eq!(event.amount, 0);
+            }
+            _ => panic!("Unexpected event type"),
+        }
+        match &events[1] {
+            ValidatorBondsEvent::ClaimSettlementEvent(event) => {
+                assert_eq!(event.settlement_claim, key(1));
+                assert_eq!(event.settlement_lamports_claimed.new, 142);
+                assert_eq!(event.settlement_merkle_nodes_claimed, 3);
+                assert_eq!(event.amount, 42);
+                assert_eq!(event.rent_collector, key(6));
+                assert_eq!(event.claimer_fee, 0);
+            }
+            _ => panic!("Unexpected event type"),
+        }
+        match &events[2] {
+            ValidatorBondsEvent::MergeStakeEvent(event) => {
+                assert_eq!(event.destination_stake, key(3));
+                let delegation = event.destination_delegation.as_ref().unwrap();
+                assert_eq!(delegation.voter_pubkey, key(4));
+                assert_eq!(delegation.stake, 5_000);
+                assert_eq!(event.source_stake, key(5));
+                assert!(event.source_delegation.is_none());
+            }
+            _ => panic!("Unexpected event type"),
+        }
+        match &events[3] {
+            ValidatorBondsEvent::ResetStakeEvent(event) => {
+                assert_eq!(event.stake_account, key(4));
+                assert_eq!(event.settlement_staker_authority, key(6));
+                assert!(event.operator_authority.is_none());
+            }
+            _ => panic!("Unexpected event type"),
+        }
+        match &events[4] {
+            ValidatorBondsEvent::ConfigureBondEvent(event) => {
+                assert_eq!(event.bond_authority.as_ref().unwrap().new, key(2));
+                assert!(event.cpmpe.is_none());
+                assert_eq!(event.max_stake_wanted.as_ref().unwrap().new, 1_000);
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    fn decode_current_event_not_legacy() {
+        let event = ConfigureBondEvent {
+            version: EVENT_VERSION,
+            bond_authority: None,
+            cpmpe: Some(U64ValueChange { old: 1, new: 2 }),
+            max_stake_wanted: None,
+        };
+        let decoded = ValidatorBondsEvent::decode(&event.data()).unwrap().unwrap();
+        assert_eq!(decoded.version(), EVENT_VERSION);
+
+        // neither the current nor the legacy layout
+        let mut data = event.data();
+        data.push(0);
+        assert!(ValidatorBondsEvent::decode(&data).is_err());
+    }
+}
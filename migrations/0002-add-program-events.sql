@@ -0,0 +1,74 @@
+CREATE TABLE indexed_transactions (
+  signature TEXT NOT NULL,
+  slot BIGINT NOT NULL,
+  block_time TIMESTAMP WITH TIME ZONE,
+  indexed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+  PRIMARY KEY(signature)
+);
+CREATE INDEX indexed_transactions_slot ON indexed_transactions (slot);
+
+CREATE TABLE program_events (
+  signature TEXT NOT NULL REFERENCES indexed_transactions(signature),
+  event_index INTEGER NOT NULL,
+  slot BIGINT NOT NULL,
+  name TEXT NOT NULL,
+  version SMALLINT NOT NULL,
+  PRIMARY KEY(signature, event_index)
+);
+
+CREATE TABLE bond_funding_history (
+  signature TEXT NOT NULL REFERENCES indexed_transactions(signature),
+  event_index INTEGER NOT NULL,
+  slot BIGINT NOT NULL,
+  event_type TEXT NOT NULL,
+  bond TEXT NOT NULL,
+  vote_account TEXT NOT NULL,
+  account TEXT NOT NULL,
+  mint TEXT,
+  amount NUMERIC NOT NULL,
+  PRIMARY KEY(signature, event_index)
+);
+CREATE INDEX bond_funding_history_bond ON bond_funding_history (bond);
+
+CREATE TABLE withdraw_history (
+  signature TEXT NOT NULL REFERENCES indexed_transactions(signature),
+  event_index INTEGER NOT NULL,
+  slot BIGINT NOT NULL,
+  event_type TEXT NOT NULL,
+  withdraw_request TEXT NOT NULL,
+  bond TEXT NOT NULL,
+  amount NUMERIC NOT NULL,
+  withdrawn_amount NUMERIC NOT NULL,
+  PRIMARY KEY(signature, event_index)
+);
+CREATE INDEX withdraw_history_bond ON withdraw_history (bond);
+
+CREATE TABLE settlement_history (
+  signature TEXT NOT NULL REFERENCES indexed_transactions(signature),
+  event_index INTEGER NOT NULL,
+  slot BIGINT NOT NULL,
+  event_type TEXT NOT NULL,
+  settlement TEXT NOT NULL,
+  bond TEXT NOT NULL,
+  amount NUMERIC NOT NULL,
+  lamports_funded NUMERIC,
+  lamports_claimed NUMERIC,
+  merkle_nodes_claimed NUMERIC,
+  PRIMARY KEY(signature, event_index)
+);
+CREATE INDEX settlement_history_settlement ON settlement_history (settlement);
+
+CREATE TABLE settlement_claim_history (
+  signature TEXT NOT NULL REFERENCES indexed_transactions(signature),
+  event_index INTEGER NOT NULL,
+  slot BIGINT NOT NULL,
+  event_type TEXT NOT NULL,
+  settlement TEXT NOT NULL,
+  settlement_claim TEXT NOT NULL,
+  claimed_to TEXT NOT NULL,
+  stake_account_staker TEXT NOT NULL,
+  stake_account_withdrawer TEXT NOT NULL,
+  amount NUMERIC NOT NULL,
+  PRIMARY KEY(signature, event_index)
+);
+CREATE INDEX settlement_claim_history_settlement ON settlement_claim_history (settlement);
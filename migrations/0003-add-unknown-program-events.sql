@@ -0,0 +1,3 @@
+-- events that cannot be decoded are stored with their raw data to be decoded later
+ALTER TABLE program_events ALTER COLUMN version DROP NOT NULL;
+ALTER TABLE program_events ADD COLUMN raw_data BYTEA;
@@ -0,0 +1,189 @@
+import { createTempFileKeypair } from '@marinade.finance/web3js-common'
+import { shellMatchers } from '@marinade.finance/jest-utils'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  collateralMintAddress,
+  collateralVaultAddress,
+  getCollateralMint,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '@marinade.finance/validator-bonds-sdk/__tests__/utils/testTransactions'
+import { createTokenMintAndFund } from '@marinade.finance/validator-bonds-sdk/__tests__/utils/helpers'
+import { initTest } from '@marinade.finance/validator-bonds-sdk/__tests__/test-validator/testValidator'
+import { createVoteAccount } from '@marinade.finance/validator-bonds-sdk/__tests__/utils/staking'
+import { AnchorExtendedProvider } from '@marinade.finance/anchor-common'
+import { getAccount as getTokenAccount } from 'solana-spl-token-modern'
+
+describe('Token collateral using CLI', () => {
+  const tokenAmount = 10 * LAMPORTS_PER_SOL
+  let provider: AnchorExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let bondAccount: PublicKey
+  let voteAccount: PublicKey
+  let mint: PublicKey
+  let adminPath: string
+  let adminCleanup: () => Promise<void>
+  let tokenAuthorityPath: string
+  let tokenAuthorityKeypair: Keypair
+  let tokenAuthorityCleanup: () => Promise<void>
+
+  beforeAll(async () => {
+    shellMatchers()
+    ;({ provider, program } = await initTest())
+  })
+
+  beforeEach(async () => {
+    let adminKeypair: Keypair
+    ;({
+      path: adminPath,
+      keypair: adminKeypair,
+      cleanup: adminCleanup,
+    } = await createTempFileKeypair())
+    ;({
+      path: tokenAuthorityPath,
+      keypair: tokenAuthorityKeypair,
+      cleanup: tokenAuthorityCleanup,
+    } = await createTempFileKeypair())
+    ;({ configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+      adminAuthority: adminKeypair,
+      epochsToClaimSettlement: 1,
+      withdrawLockupEpochs: 2,
+    }))
+    const { voteAccount: voteAccountAddress, validatorIdentity } =
+      await createVoteAccount({ provider })
+    voteAccount = voteAccountAddress
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+    ;({ mint } = await createTokenMintAndFund({
+      provider,
+      owner: tokenAuthorityKeypair.publicKey,
+      amount: tokenAmount,
+    }))
+  })
+
+  afterEach(async () => {
+    await adminCleanup()
+    await tokenAuthorityCleanup()
+  })
+
+  it('init, configure collateral mint and fund bond collateral', async () => {
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'init-collateral-mint',
+          mint.toBase58(),
+          '--config',
+          configAccount.toBase58(),
+          '--admin-authority',
+          adminPath,
+          '--lamports-per-token',
+          '1100000000',
+          '--haircut-bps',
+          '1000',
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Collateral mint account.*successfully created/,
+    })
+    const [collateralMintAccount] = collateralMintAddress(
+      configAccount,
+      mint,
+      program.programId
+    )
+    let collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount
+    )
+    expect(collateralMintData.lamportsPerToken).toEqual(1_100_000_000)
+    expect(collateralMintData.haircutBps).toEqual(1_000)
+
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'configure-collateral-mint',
+          collateralMintAccount.toBase58(),
+          '--admin-authority',
+          adminPath,
+          '--haircut-bps',
+          '2000',
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Collateral mint account.*successfully configured/,
+    })
+    collateralMintData = await getCollateralMint(program, collateralMintAccount)
+    expect(collateralMintData.lamportsPerToken).toEqual(1_100_000_000)
+    expect(collateralMintData.haircutBps).toEqual(2_000)
+
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'fund-bond-collateral',
+          voteAccount.toBase58(),
+          '--config',
+          configAccount.toBase58(),
+          '--mint',
+          mint.toBase58(),
+          '--amount',
+          tokenAmount.toString(),
+          '--token-authority',
+          tokenAuthorityPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Bond account.*successfully funded/,
+    })
+    const [collateralVault] = collateralVaultAddress(
+      bondAccount,
+      mint,
+      program.programId
+    )
+    expect(
+      (await getTokenAccount(provider.connection, collateralVault)).amount
+    ).toEqual(BigInt(tokenAmount))
+  })
+})
@@ -266,6 +266,73 @@ describe('Configure bond account using CLI', () => {
     expect(bondsData.maxStakeWanted).toEqual(999 * LAMPORTS_PER_SOL)
   })
 
+  it('configure bond authority mint', async () => {
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'configure-bond',
+          bondAccount.toBase58(),
+          '--authority',
+          bondAuthorityPath,
+          '--authority-mint',
+          'true',
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Bond account.*successfully configured/,
+    })
+    const [bondMint] = bondMintAddress(
+      bondAccount,
+      validatorIdentity.publicKey,
+      program.programId
+    )
+    let bondsData = await getBond(program, bondAccount)
+    expect(bondsData.authorityMint).toEqual(bondMint)
+    expect(bondsData.cpmpe).toEqual(33)
+
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'configure-bond',
+          bondAccount.toBase58(),
+          '--authority',
+          bondAuthorityPath,
+          '--authority-mint',
+          'false',
+          '--cpmpe',
+          3,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Bond account.*successfully configured/,
+    })
+    bondsData = await getBond(program, bondAccount)
+    expect(bondsData.authorityMint).toEqual(PublicKey.default)
+    expect(bondsData.cpmpe).toEqual(3)
+  })
+
   it('configure bond in print-only mode', async () => {
     await (
       expect([
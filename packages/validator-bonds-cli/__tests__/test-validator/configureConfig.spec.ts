@@ -49,7 +49,15 @@ describe('Configure config account using CLI', () => {
   })
 
   it('configure config account', async () => {
-    const newAdmin = Keypair.generate()
+    const {
+      path: newAdminPath,
+      keypair: newAdmin,
+      cleanup: newAdminCleanup,
+    } = await createTempFileKeypair()
+    const settlementCreator = Keypair.generate().publicKey
+    const settlementFunder = Keypair.generate().publicKey
+    const settlementCloser = Keypair.generate().publicKey
+    const bidFeeCollector = Keypair.generate().publicKey
 
     await (
       expect([
@@ -88,12 +96,8 @@ describe('Configure config account using CLI', () => {
           configAccount.toBase58(),
           '--admin-authority',
           adminPath,
-          '--operator',
-          PublicKey.default.toBase58(),
           '--admin',
           newAdmin.publicKey.toBase58(),
-          '--pause-authority',
-          PublicKey.default.toBase58(),
           '--epochs-to-claim-settlement',
           111,
           '--slots-to-start-settlement-claiming',
@@ -104,6 +108,22 @@ describe('Configure config account using CLI', () => {
           134,
           '--min-bond-max-stake-wanted',
           111,
+          '--settlement-creator',
+          settlementCreator.toBase58(),
+          '--settlement-funder',
+          settlementFunder.toBase58(),
+          '--settlement-closer',
+          settlementCloser.toBase58(),
+          '--bid-fee-collector',
+          bidFeeCollector.toBase58(),
+          '--bid-max-delegated-lamports',
+          999,
+          '--max-claim-expiry-extension',
+          7,
+          '--claimer-fee-lamports',
+          5000,
+          '--claimer-fee-max-bps',
+          50,
           '--confirmation-finality',
           'confirmed',
           '-v',
@@ -117,14 +137,78 @@ describe('Configure config account using CLI', () => {
     })
 
     const configData = await getConfig(program, configAccount)
-    expect(configData.adminAuthority).toEqual(newAdmin.publicKey)
-    expect(configData.operatorAuthority).toEqual(PublicKey.default)
-    expect(configData.pauseAuthority).toEqual(PublicKey.default)
+    expect(configData.adminAuthority).toEqual(adminKeypair.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(newAdmin.publicKey)
+    expect(configData.operatorAuthority).toEqual(operatorAuthority.publicKey)
     expect(configData.epochsToClaimSettlement).toEqual(111)
     expect(configData.slotsToStartSettlementClaiming).toEqual(143)
     expect(configData.withdrawLockupEpochs).toEqual(112)
     expect(configData.minimumStakeLamports).toEqual(134)
     expect(configData.minBondMaxStakeWanted).toEqual(111)
+    expect(configData.settlementCreatorAuthority).toEqual(settlementCreator)
+    expect(configData.settlementFunderAuthority).toEqual(settlementFunder)
+    expect(configData.settlementCloserAuthority).toEqual(settlementCloser)
+    expect(configData.bidFeeCollector).toEqual(bidFeeCollector)
+    expect(configData.bidMaxDelegatedLamports).toEqual(999)
+    expect(configData.maxClaimExpiryExtension).toEqual(7)
+    expect(configData.claimerFeeLamports).toEqual(5000)
+    expect(configData.claimerFeeMaxBps).toEqual(50)
+
+    try {
+      await (
+        expect([
+          'pnpm',
+          [
+            'cli',
+            '-u',
+            provider.connection.rpcEndpoint,
+            '--program-id',
+            program.programId.toBase58(),
+            'accept-config-authority',
+            configAccount.toBase58(),
+            '--authority',
+            newAdminPath,
+            '--confirmation-finality',
+            'confirmed',
+          ],
+          // eslint-disable-next-line @typescript-eslint/no-explicit-any
+        ]) as any
+      ).toHaveMatchingSpawnOutput({
+        code: 0,
+        // stderr: '',
+        stdout: /successfully accepted/,
+      })
+    } finally {
+      await newAdminCleanup()
+    }
+
+    const configDataAccepted = await getConfig(program, configAccount)
+    expect(configDataAccepted.adminAuthority).toEqual(newAdmin.publicKey)
+    expect(configDataAccepted.pendingAdminAuthority).toEqual(PublicKey.default)
+  })
+
+  it('apply config changes with nothing staged', async () => {
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'apply-config-changes',
+          configAccount.toBase58(),
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 200,
+      // stderr: '',
+      stdout: /No pending config changes to apply/,
+    })
   })
 
   it('configure config in print-only mode', async () => {
@@ -0,0 +1,138 @@
+import { createTempFileKeypair } from '@marinade.finance/web3js-common'
+import { shellMatchers } from '@marinade.finance/jest-utils'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  getSettlement,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '@marinade.finance/validator-bonds-sdk/__tests__/utils/testTransactions'
+import { initTest } from '@marinade.finance/validator-bonds-sdk/__tests__/test-validator/testValidator'
+import { createVoteAccount } from '@marinade.finance/validator-bonds-sdk/__tests__/utils/staking'
+import { AnchorExtendedProvider } from '@marinade.finance/anchor-common'
+import { createHash } from 'crypto'
+
+describe('Dispute settlement using CLI', () => {
+  let provider: AnchorExtendedProvider
+  let program: ValidatorBondsProgram
+  let settlementAccount: PublicKey
+  let adminPath: string
+  let adminCleanup: () => Promise<void>
+  let bondAuthorityPath: string
+  let bondAuthorityKeypair: Keypair
+  let bondAuthorityCleanup: () => Promise<void>
+
+  beforeAll(async () => {
+    shellMatchers()
+    ;({ provider, program } = await initTest())
+  })
+
+  beforeEach(async () => {
+    let adminKeypair: Keypair
+    ;({
+      path: adminPath,
+      keypair: adminKeypair,
+      cleanup: adminCleanup,
+    } = await createTempFileKeypair())
+    ;({
+      path: bondAuthorityPath,
+      keypair: bondAuthorityKeypair,
+      cleanup: bondAuthorityCleanup,
+    } = await createTempFileKeypair())
+    const { configAccount, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        adminAuthority: adminKeypair,
+        epochsToClaimSettlement: 1,
+        // the dispute window has to stay open during the test
+        slotsToStartSettlementClaiming: 1_000_000,
+      })
+    const { voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    })
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+      bondAuthority: bondAuthorityKeypair,
+    })
+    ;({ settlementAccount } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+    }))
+  })
+
+  afterEach(async () => {
+    await adminCleanup()
+    await bondAuthorityCleanup()
+  })
+
+  it('dispute settlement and resolve dispute', async () => {
+    const reason = 'settlement does not match the validator performance'
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'dispute-settlement',
+          settlementAccount.toBase58(),
+          '--reason',
+          reason,
+          '--authority',
+          bondAuthorityPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Settlement account.*successfully disputed/,
+    })
+    let settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.disputed).toEqual(true)
+    expect(settlementData.disputeReasonHash).toEqual(
+      Array.from(createHash('sha256').update(reason).digest())
+    )
+
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'resolve-dispute',
+          settlementAccount.toBase58(),
+          '--admin-authority',
+          adminPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Dispute of settlement account.*successfully resolved/,
+    })
+    settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.disputed).toEqual(false)
+  })
+})
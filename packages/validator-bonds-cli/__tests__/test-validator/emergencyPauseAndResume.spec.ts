@@ -2,7 +2,9 @@ import { createTempFileKeypair } from '@marinade.finance/web3js-common'
 import { shellMatchers } from '@marinade.finance/jest-utils'
 import { Keypair, PublicKey } from '@solana/web3.js'
 import {
+  PAUSE_FLAGS,
   ValidatorBondsProgram,
+  acceptConfigAuthorityInstruction,
   configureConfigInstruction,
   getConfig,
 } from '@marinade.finance/validator-bonds-sdk'
@@ -42,7 +44,16 @@ describe('Pause and resume using CLI', () => {
       configAccount,
       newPauseAuthority: pauseAuthorityKeypair.publicKey,
     })
-    await provider.sendIx([adminAuthority], configIx)
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount,
+      newAuthority: pauseAuthorityKeypair,
+    })
+    await provider.sendIx(
+      [adminAuthority, pauseAuthorityKeypair],
+      configIx,
+      acceptIx
+    )
   })
 
   afterEach(async () => {
@@ -106,6 +117,68 @@ describe('Pause and resume using CLI', () => {
     expect(configData.paused).toEqual(false)
   })
 
+  it('set pause flags', async () => {
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'set-pause-flags',
+          config.toBase58(),
+          '--flags',
+          'settlement,claim',
+          '--authority',
+          pauseAuthorityPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Succeeded to set pause flags/,
+    })
+    let configData = await getConfig(program, config)
+    expect(configData.pauseFlags).toEqual(
+      PAUSE_FLAGS.settlement | PAUSE_FLAGS.claim
+    )
+    expect(configData.paused).toEqual(true)
+
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'set-pause-flags',
+          config.toBase58(),
+          '--flags',
+          'none',
+          '--authority',
+          pauseAuthorityPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Succeeded to set pause flags/,
+    })
+    configData = await getConfig(program, config)
+    expect(configData.pauseFlags).toEqual(0)
+    expect(configData.paused).toEqual(false)
+  })
+
   it('pause and resume in print-only mode', async () => {
     await (
       expect([
@@ -0,0 +1,60 @@
+import { shellMatchers } from '@marinade.finance/jest-utils'
+import { PublicKey } from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  getBond,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '@marinade.finance/validator-bonds-sdk/__tests__/utils/testTransactions'
+import { initTest } from '@marinade.finance/validator-bonds-sdk/__tests__/test-validator/testValidator'
+import { AnchorExtendedProvider } from '@marinade.finance/anchor-common'
+
+describe('Migrate account using CLI', () => {
+  let provider: AnchorExtendedProvider
+  let program: ValidatorBondsProgram
+  let bondAccount: PublicKey
+
+  beforeAll(async () => {
+    shellMatchers()
+    ;({ provider, program } = await initTest())
+  })
+
+  beforeEach(async () => {
+    const { configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+    })
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+    }))
+  })
+
+  it('migrate account of the current version fails', async () => {
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'migrate-account',
+          bondAccount.toBase58(),
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 200,
+      // stderr: '',
+      stdout: /Failed to migrate account/,
+    })
+    expect((await getBond(program, bondAccount)).accountVersion).toEqual(1)
+  })
+})
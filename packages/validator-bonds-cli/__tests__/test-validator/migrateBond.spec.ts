@@ -0,0 +1,92 @@
+import { shellMatchers } from '@marinade.finance/jest-utils'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  getBond,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '@marinade.finance/validator-bonds-sdk/__tests__/utils/testTransactions'
+import { initTest } from '@marinade.finance/validator-bonds-sdk/__tests__/test-validator/testValidator'
+import {
+  AnchorExtendedProvider,
+  getAnchorValidatorInfo,
+} from '@marinade.finance/anchor-common'
+import { createVoteAccountWithIdentity } from '@marinade.finance/validator-bonds-sdk/__tests__/utils/staking'
+
+describe('Migrate bond account using CLI', () => {
+  let provider: AnchorExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let bondAccount: PublicKey
+  let voteAccount: PublicKey
+  let newVoteAccount: PublicKey
+  let validatorIdentity: Keypair
+  let validatorIdentityPath: string
+
+  beforeAll(async () => {
+    shellMatchers()
+    ;({ provider, program } = await initTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+      epochsToClaimSettlement: 1,
+      withdrawLockupEpochs: 2,
+    }))
+    ;({ validatorIdentity, validatorIdentityPath } =
+      await getAnchorValidatorInfo(provider.connection))
+    ;({ voteAccount } = await createVoteAccountWithIdentity(
+      provider,
+      validatorIdentity
+    ))
+    ;({ voteAccount: newVoteAccount } = await createVoteAccountWithIdentity(
+      provider,
+      validatorIdentity
+    ))
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+  })
+
+  it('migrate bond account', async () => {
+    await (
+      expect([
+        'pnpm',
+        [
+          'cli',
+          '-u',
+          provider.connection.rpcEndpoint,
+          '--program-id',
+          program.programId.toBase58(),
+          'migrate-bond',
+          voteAccount.toBase58(),
+          '--config',
+          configAccount.toBase58(),
+          '--new-vote-account',
+          newVoteAccount.toBase58(),
+          '--authority',
+          validatorIdentityPath,
+          '--confirmation-finality',
+          'confirmed',
+        ],
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      ]) as any
+    ).toHaveMatchingSpawnOutput({
+      code: 0,
+      // stderr: '',
+      stdout: /Bond account.*successfully migrated/,
+    })
+
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.voteAccount).toEqual(voteAccount)
+    expect(bondData.migratedVoteAccount).toEqual(newVoteAccount)
+  })
+})
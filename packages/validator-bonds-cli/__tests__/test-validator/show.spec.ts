@@ -3,6 +3,7 @@ import YAML from 'yaml'
 import {
   initConfigInstruction,
   ValidatorBondsProgram,
+  getBond,
   getWithdrawRequest,
   cancelWithdrawRequestInstruction,
   bondsWithdrawerAuthority,
@@ -65,6 +66,47 @@ describe('Show command using CLI', () => {
       provider.wallet,
       configKeypair,
     ])
+    const noAuthority = PublicKey.default.toBase58()
+    const expectedConfigAccount = {
+      adminAuthority: admin.toBase58(),
+      operatorAuthority: operator.toBase58(),
+      epochsToClaimSettlement: 101,
+      withdrawLockupEpochs: 103,
+      minimumStakeLamports: LAMPORTS_PER_SOL,
+      pauseAuthority: admin.toBase58(),
+      paused: false,
+      slotsToStartSettlementClaiming: 102,
+      minBondMaxStakeWanted: 0,
+      pendingAdminAuthority: noAuthority,
+      pendingOperatorAuthority: noAuthority,
+      pendingPauseAuthority: noAuthority,
+      configChangeDelayEpochs: 0,
+      pendingConfigChanges: {
+        effectiveEpoch: 0,
+        stagedParams: 0,
+        epochsToClaimSettlement: 0,
+        withdrawLockupEpochs: 0,
+        minimumStakeLamports: 0,
+        slotsToStartSettlementClaiming: 0,
+        minBondMaxStakeWanted: 0,
+        configChangeDelayEpochs: 0,
+        claimerFeeLamports: 0,
+        claimerFeeMaxBps: 0,
+        bidMaxDelegatedLamports: 0,
+        maxClaimExpiryExtension: 0,
+      },
+      pauseFlags: 0,
+      settlementCreatorAuthority: noAuthority,
+      settlementFunderAuthority: noAuthority,
+      settlementCloserAuthority: noAuthority,
+      bidFeeCollector: noAuthority,
+      claimerFeeLamports: 0,
+      claimerFeeMaxBps: 0,
+      accountVersion: 1,
+      bidMaxDelegatedLamports: 0,
+      maxClaimExpiryExtension: 0,
+      reserved: [140],
+    }
 
     await (
       expect([
@@ -90,18 +132,7 @@ describe('Show command using CLI', () => {
       stdout: YAML.stringify({
         programId: program.programId,
         publicKey: configPubkey.toBase58(),
-        account: {
-          adminAuthority: admin.toBase58(),
-          operatorAuthority: operator.toBase58(),
-          epochsToClaimSettlement: 101,
-          withdrawLockupEpochs: 103,
-          minimumStakeLamports: LAMPORTS_PER_SOL,
-          pauseAuthority: admin.toBase58(),
-          paused: false,
-          slotsToStartSettlementClaiming: 102,
-          minBondMaxStakeWanted: 0,
-          reserved: [463],
-        },
+        account: expectedConfigAccount,
         bondsWithdrawerAuthority: bondsWithdrawerAuthority(
           configPubkey,
           program.programId
@@ -138,18 +169,7 @@ describe('Show command using CLI', () => {
         {
           programId: program.programId,
           publicKey: configPubkey.toBase58(),
-          account: {
-            adminAuthority: admin.toBase58(),
-            operatorAuthority: operator.toBase58(),
-            epochsToClaimSettlement: 101,
-            withdrawLockupEpochs: 103,
-            minimumStakeLamports: LAMPORTS_PER_SOL,
-            pauseAuthority: admin.toBase58(),
-            paused: false,
-            slotsToStartSettlementClaiming: 102,
-            minBondMaxStakeWanted: 0,
-            reserved: [463],
-          },
+          account: expectedConfigAccount,
           bondsWithdrawerAuthority: bondsWithdrawerAuthority(
             configPubkey,
             program.programId
@@ -210,18 +230,7 @@ describe('Show command using CLI', () => {
         {
           programId: program.programId,
           publicKey: configPubkey.toBase58(),
-          account: {
-            adminAuthority: admin.toBase58(),
-            operatorAuthority: operator.toBase58(),
-            epochsToClaimSettlement: 101,
-            withdrawLockupEpochs: 103,
-            minimumStakeLamports: LAMPORTS_PER_SOL,
-            pauseAuthority: admin.toBase58(),
-            paused: false,
-            slotsToStartSettlementClaiming: 102,
-            minBondMaxStakeWanted: 0,
-            reserved: [463],
-          },
+          account: expectedConfigAccount,
           bondsWithdrawerAuthority: bondsWithdrawerAuthority(
             configPubkey,
             program.programId
@@ -263,6 +272,7 @@ describe('Show command using CLI', () => {
       cpmpe: 222,
       maxStakeWanted: 2000 * LAMPORTS_PER_SOL,
     })
+    const bondData = await getBond(program, bondAccount)
 
     const voteAccountShow = await loadTestingVoteAccount(
       provider.connection,
@@ -277,6 +287,12 @@ describe('Show command using CLI', () => {
         authority: bondAuthority.publicKey.toBase58(),
         costPerMillePerEpoch: '222 lamports',
         maxStakeWanted: '2000 SOLs',
+        bidChargedEpoch: 0,
+        bidChargedAmount: '0.000000000 SOL',
+        migratedVoteAccount: PublicKey.default.toBase58(),
+        authorityMint: PublicKey.default.toBase58(),
+        accountVersion: 1,
+        createdEpoch: bondData.createdEpoch.toNumber(),
       },
     }
     const expectedDataFundingSingleItem = {
@@ -501,6 +517,7 @@ describe('Show command using CLI', () => {
       validatorIdentity,
       cpmpe: 1,
     })
+    const bondData = await getBond(program, bondAccount)
     const stakeAccountLamports: number[] = [3, 10, 23].map(
       l => l * LAMPORTS_PER_SOL
     )
@@ -524,6 +541,12 @@ describe('Show command using CLI', () => {
         authority: bondAuthority.publicKey,
         costPerMillePerEpoch: '1 lamport',
         maxStakeWanted: '0 SOL',
+        bidChargedEpoch: 0,
+        bidChargedAmount: '0.000000000 SOL',
+        migratedVoteAccount: PublicKey.default,
+        authorityMint: PublicKey.default,
+        accountVersion: 1,
+        createdEpoch: bondData.createdEpoch.toNumber(),
       },
     }
     const voteAccountShow = await loadTestingVoteAccount(
@@ -623,6 +646,7 @@ describe('Show command using CLI', () => {
               withdrawRequestAmount / LAMPORTS_PER_SOL
             }.000000000 SOLs`,
             withdrawnAmount: '0.000000000 SOL',
+            accountVersion: 1,
           },
         },
       }),
@@ -0,0 +1,105 @@
+import { parsePubkey, parseWalletOrPubkey } from '@marinade.finance/cli-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  instanceOfWallet,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  acceptConfigAuthorityInstruction,
+} from '@marinade.finance/validator-bonds-sdk'
+import { ACCEPT_CONFIG_AUTHORITY_LIMIT_UNITS } from '../../computeUnits'
+
+export function installAcceptConfigAuthority(program: Command) {
+  program
+    .command('accept-config-authority')
+    .description(
+      'Accepting the admin, operator or pause authority proposed by the config admin.'
+    )
+    .argument(
+      '[address]',
+      'Address of the validator bonds config account ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .option(
+      '--authority <keypair_or_ledger_or_pubkey>',
+      'The proposed authority that accepts the role (default: wallet)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<undefined | PublicKey>,
+        {
+          authority,
+        }: {
+          authority?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageAcceptConfigAuthority({
+          address: await address,
+          authority: await authority,
+        })
+      }
+    )
+}
+
+async function manageAcceptConfigAuthority({
+  address = MARINADE_CONFIG_ADDRESS,
+  authority,
+}: {
+  address?: PublicKey
+  authority?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(address)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  authority = authority ?? wallet.publicKey
+  if (instanceOfWallet(authority)) {
+    signers.push(authority)
+    authority = authority.publicKey
+  }
+
+  const { instruction } = await acceptConfigAuthorityInstruction({
+    program,
+    configAccount: address,
+    newAuthority: authority,
+  })
+  tx.add(instruction)
+
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to accept authority ${authority.toBase58()} of config account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: ACCEPT_CONFIG_AUTHORITY_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Authority ${authority.toBase58()} of config account ${address.toBase58()} successfully accepted`
+  )
+}
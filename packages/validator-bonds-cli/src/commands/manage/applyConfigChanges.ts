@@ -0,0 +1,80 @@
+import { parsePubkey } from '@marinade.finance/cli-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  applyConfigChangesInstruction,
+} from '@marinade.finance/validator-bonds-sdk'
+import { APPLY_CONFIG_CHANGES_LIMIT_UNITS } from '../../computeUnits'
+
+export function installApplyConfigChanges(program: Command) {
+  program
+    .command('apply-config-changes')
+    .description(
+      'Applying the config parameter changes staged by the config admin when the effective epoch is reached. ' +
+        'This is a permission-less operation.'
+    )
+    .argument(
+      '[address]',
+      'Address of the validator bonds config account ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .action(async (address: Promise<undefined | PublicKey>) => {
+      await manageApplyConfigChanges({
+        address: await address,
+      })
+    })
+}
+
+async function manageApplyConfigChanges({
+  address = MARINADE_CONFIG_ADDRESS,
+}: {
+  address?: PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(address)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  const { instruction } = await applyConfigChangesInstruction({
+    program,
+    configAccount: address,
+  })
+  tx.add(instruction)
+
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to apply config changes of config account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: APPLY_CONFIG_CHANGES_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Config changes of config account ${address.toBase58()} successfully applied`
+  )
+}
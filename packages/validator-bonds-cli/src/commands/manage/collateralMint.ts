@@ -0,0 +1,340 @@
+import { parsePubkey, parseWalletOrPubkey } from '@marinade.finance/cli-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  instanceOfWallet,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  ValidatorBondsProgram,
+  configureCollateralMintInstruction,
+  getCollateralMint,
+  getConfig,
+  initCollateralMintInstruction,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  CONFIGURE_COLLATERAL_MINT_LIMIT_UNITS,
+  INIT_COLLATERAL_MINT_LIMIT_UNITS,
+} from '../../computeUnits'
+import BN from 'bn.js'
+
+export function installInitCollateralMint(program: Command) {
+  program
+    .command('init-collateral-mint')
+    .description(
+      'Whitelisting an SPL token mint (e.g., liquid staking token) to be used as the bond collateral.'
+    )
+    .argument('<mint>', 'Address of the SPL token mint', parsePubkey)
+    .option(
+      '--config <pubkey>',
+      'The config account the mint is whitelisted for ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .option(
+      '--admin-authority <keypair_or_ledger_or_pubkey>',
+      'Admin authority that is permitted to whitelist the mint (default: wallet)',
+      parseWalletOrPubkey
+    )
+    .requiredOption(
+      '--lamports-per-token <number>',
+      'Price of one token (not the token base unit) in lamports',
+      value => new BN(value, 10)
+    )
+    .requiredOption(
+      '--haircut-bps <number>',
+      'Haircut in basis points deducted from the token price when the collateral is valued as the bond funds',
+      parseFloat
+    )
+    .option(
+      '--rent-payer <keypair_or_ledger_or_pubkey>',
+      'Rent payer for the collateral mint account creation (default: wallet keypair)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        mint: Promise<PublicKey>,
+        {
+          config,
+          adminAuthority,
+          lamportsPerToken,
+          haircutBps,
+          rentPayer,
+        }: {
+          config?: Promise<PublicKey>
+          adminAuthority?: Promise<WalletInterface | PublicKey>
+          lamportsPerToken: BN
+          haircutBps: number
+          rentPayer?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageInitCollateralMint({
+          mint: await mint,
+          config: await config,
+          adminAuthority: await adminAuthority,
+          lamportsPerToken,
+          haircutBps,
+          rentPayer: await rentPayer,
+        })
+      }
+    )
+}
+
+export function installConfigureCollateralMint(program: Command) {
+  program
+    .command('configure-collateral-mint')
+    .description(
+      'Configuring the price and the haircut of the whitelisted collateral mint.'
+    )
+    .argument(
+      '<address>',
+      'Address of the collateral mint account or the SPL token mint',
+      parsePubkey
+    )
+    .option(
+      '--config <pubkey>',
+      'The config account the mint is whitelisted for ' +
+        '(optional; to derive collateral mint address from the SPL token mint address) ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .option(
+      '--admin-authority <keypair_or_ledger_or_pubkey>',
+      'Admin authority that is permitted to do the configuration change (default: wallet)',
+      parseWalletOrPubkey
+    )
+    .option(
+      '--lamports-per-token <number>',
+      'New price of one token (not the token base unit) in lamports',
+      value => new BN(value, 10)
+    )
+    .option(
+      '--haircut-bps <number>',
+      'New haircut in basis points deducted from the token price',
+      parseFloat
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          config,
+          adminAuthority,
+          lamportsPerToken,
+          haircutBps,
+        }: {
+          config?: Promise<PublicKey>
+          adminAuthority?: Promise<WalletInterface | PublicKey>
+          lamportsPerToken?: BN
+          haircutBps?: number
+        }
+      ) => {
+        await manageConfigureCollateralMint({
+          address: await address,
+          config: await config,
+          adminAuthority: await adminAuthority,
+          lamportsPerToken,
+          haircutBps,
+        })
+      }
+    )
+}
+
+async function manageInitCollateralMint({
+  mint,
+  config = MARINADE_CONFIG_ADDRESS,
+  adminAuthority,
+  lamportsPerToken,
+  haircutBps,
+  rentPayer,
+}: {
+  mint: PublicKey
+  config?: PublicKey
+  adminAuthority?: WalletInterface | PublicKey
+  lamportsPerToken: BN
+  haircutBps: number
+  rentPayer?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(config)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  adminAuthority = await adminAuthorityOrWallet({
+    program,
+    config,
+    adminAuthority,
+    wallet,
+    printOnly,
+  })
+  if (instanceOfWallet(adminAuthority)) {
+    signers.push(adminAuthority)
+    adminAuthority = adminAuthority.publicKey
+  }
+  rentPayer = rentPayer ?? wallet.publicKey
+  if (instanceOfWallet(rentPayer)) {
+    signers.push(rentPayer)
+    rentPayer = rentPayer.publicKey
+  }
+
+  const { instruction, collateralMintAccount } =
+    await initCollateralMintInstruction({
+      program,
+      configAccount: config,
+      mint,
+      lamportsPerToken,
+      haircutBps,
+      adminAuthority,
+      rentPayer,
+    })
+  tx.add(instruction)
+
+  logger.info(
+    `Whitelisting mint ${mint.toBase58()} as collateral mint ${collateralMintAccount.toBase58()}`
+  )
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to whitelist collateral mint ${mint.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: INIT_COLLATERAL_MINT_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Collateral mint account ${collateralMintAccount.toBase58()} successfully created`
+  )
+}
+
+async function manageConfigureCollateralMint({
+  address,
+  config,
+  adminAuthority,
+  lamportsPerToken,
+  haircutBps,
+}: {
+  address: PublicKey
+  config?: PublicKey
+  adminAuthority?: WalletInterface | PublicKey
+  lamportsPerToken?: BN
+  haircutBps?: number
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(config)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  // the address is the collateral mint account or the SPL token mint
+  let collateralMintAccount: PublicKey | undefined = address
+  let mint: PublicKey | undefined
+  try {
+    config = (await getCollateralMint(program, address)).config
+  } catch (e) {
+    logger.debug(
+      `Address ${address.toBase58()} is not a collateral mint account, ` +
+        'deriving it as SPL token mint'
+    )
+    collateralMintAccount = undefined
+    mint = address
+    config = config ?? MARINADE_CONFIG_ADDRESS
+  }
+
+  adminAuthority = await adminAuthorityOrWallet({
+    program,
+    config,
+    adminAuthority,
+    wallet,
+    printOnly,
+  })
+  if (instanceOfWallet(adminAuthority)) {
+    signers.push(adminAuthority)
+    adminAuthority = adminAuthority.publicKey
+  }
+
+  const { instruction, collateralMintAccount: collateralMintAddress } =
+    await configureCollateralMintInstruction({
+      program,
+      collateralMintAccount,
+      configAccount: config,
+      mint,
+      adminAuthority,
+      newLamportsPerToken: lamportsPerToken,
+      newHaircutBps: haircutBps,
+    })
+  tx.add(instruction)
+
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to configure collateral mint ${collateralMintAddress.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: CONFIGURE_COLLATERAL_MINT_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Collateral mint account ${collateralMintAddress.toBase58()} successfully configured`
+  )
+}
+
+async function adminAuthorityOrWallet({
+  program,
+  config,
+  adminAuthority,
+  wallet,
+  printOnly,
+}: {
+  program: ValidatorBondsProgram
+  config: PublicKey
+  adminAuthority?: WalletInterface | PublicKey
+  wallet: WalletInterface
+  printOnly: boolean
+}): Promise<WalletInterface | PublicKey> {
+  if (adminAuthority !== undefined) {
+    return adminAuthority
+  }
+  const configData = await getConfig(program, config)
+  if (!printOnly && !configData.adminAuthority.equals(wallet.publicKey)) {
+    throw new Error(
+      'Current wallet does not have permission to manage the collateral mints. ' +
+        `Current admin authority: ${configData.adminAuthority.toBase58()}`
+    )
+  }
+  return configData.adminAuthority
+}
@@ -19,12 +19,14 @@ import {
 } from '@marinade.finance/web3js-common'
 import {
   MARINADE_CONFIG_ADDRESS,
+  configureBondAuthorityMintInstruction,
   configureBondInstruction,
   configureBondWithMintInstruction,
 } from '@marinade.finance/validator-bonds-sdk'
 import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
 import { getBondFromAddress } from '../utils'
 import {
+  CONFIGURE_BOND_AUTHORITY_MINT_LIMIT_UNITS,
   CONFIGURE_BOND_LIMIT_UNITS,
   CONFIGURE_BOND_MINT_LIMIT_UNITS,
 } from '../../computeUnits'
@@ -75,6 +77,13 @@ export function installConfigureBond(program: Command) {
       'New value of maximum stake amount, in SOLs, the validator wants to be delegated to them.',
       value => new BN(value, 10)
     )
+    .option(
+      '--authority-mint <true|false>',
+      'Switching the bond token (see "mint-bond" command) to work as a transferable bond authority credential. ' +
+        'When "true" the holder of the bond token may configure the bond with "--with-token" without burning the token. ' +
+        'Requires the "--authority" to be the bond authority or the vote account validator identity.',
+      parseAuthorityMint
+    )
     .action(
       async (
         address: Promise<PublicKey>,
@@ -86,6 +95,7 @@ export function installConfigureBond(program: Command) {
           bondAuthority,
           cpmpe,
           maxStakeWanted,
+          authorityMint,
         }: {
           config?: Promise<PublicKey>
           voteAccount?: Promise<PublicKey>
@@ -94,6 +104,7 @@ export function installConfigureBond(program: Command) {
           bondAuthority?: Promise<PublicKey>
           cpmpe?: BN
           maxStakeWanted?: BN
+          authorityMint?: boolean
         }
       ) => {
         await manageConfigureBond({
@@ -105,11 +116,21 @@ export function installConfigureBond(program: Command) {
           newBondAuthority: await bondAuthority,
           cpmpe,
           maxStakeWanted,
+          authorityMint,
         })
       }
     )
 }
 
+function parseAuthorityMint(value: string): boolean {
+  if (value !== 'true' && value !== 'false') {
+    throw new Error(
+      `Invalid value "${value}" of --authority-mint, expected "true" or "false"`
+    )
+  }
+  return value === 'true'
+}
+
 async function manageConfigureBond({
   address,
   config = MARINADE_CONFIG_ADDRESS,
@@ -119,6 +140,7 @@ async function manageConfigureBond({
   newBondAuthority,
   cpmpe,
   maxStakeWanted,
+  authorityMint,
 }: {
   address: PublicKey
   config?: PublicKey
@@ -128,6 +150,7 @@ async function manageConfigureBond({
   newBondAuthority?: PublicKey
   cpmpe?: BN
   maxStakeWanted?: BN
+  authorityMint?: boolean
 }) {
   const {
     program,
@@ -161,10 +184,21 @@ async function manageConfigureBond({
   config = bondAccountData.account.data.config
   voteAccount = bondAccountData.account.data.voteAccount
 
-  let bondAccount: PublicKey
+  if (withToken && authorityMint !== undefined) {
+    throw new Error(
+      'The bond authority mint cannot be configured with the bond token, ' +
+        'use the bond authority or the validator identity as "--authority"'
+    )
+  }
+
+  let bondAccount = bondAccountAddress
   let instruction: TransactionInstruction
-  let computeUnitLimit: number
+  let computeUnitLimit = 0
   const newMaxStakeWanted = maxStakeWanted?.mul(new BN(LAMPORTS_PER_SOL))
+  const configureBondChanges =
+    newBondAuthority !== undefined ||
+    cpmpe !== undefined ||
+    maxStakeWanted !== undefined
   if (withToken) {
     computeUnitLimit = CONFIGURE_BOND_MINT_LIMIT_UNITS
     ;({ instruction, bondAccount } = await configureBondWithMintInstruction({
@@ -177,7 +211,8 @@ async function manageConfigureBond({
       newCpmpe: cpmpe,
       newMaxStakeWanted,
     }))
-  } else {
+    tx.add(instruction)
+  } else if (configureBondChanges || authorityMint === undefined) {
     computeUnitLimit = CONFIGURE_BOND_LIMIT_UNITS
     ;({ instruction, bondAccount } = await configureBondInstruction({
       program,
@@ -189,8 +224,20 @@ async function manageConfigureBond({
       newCpmpe: cpmpe,
       newMaxStakeWanted,
     }))
+    tx.add(instruction)
+  }
+  if (authorityMint !== undefined) {
+    computeUnitLimit += CONFIGURE_BOND_AUTHORITY_MINT_LIMIT_UNITS
+    ;({ instruction } = await configureBondAuthorityMintInstruction({
+      program,
+      bondAccount: bondAccountAddress,
+      configAccount: config,
+      voteAccount,
+      authority,
+      enabled: authorityMint,
+    }))
+    tx.add(instruction)
   }
-  tx.add(instruction)
 
   logger.info(
     `Configuring bond account ${bondAccount.toBase58()} (finalization may take seconds)`
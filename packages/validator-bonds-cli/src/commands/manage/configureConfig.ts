@@ -38,19 +38,44 @@ export function installConfigureConfig(program: Command) {
     )
     .option(
       '--admin <pubkey>',
-      'New admin authority to be configured',
+      'New admin authority to be proposed, the new authority accepts with "accept-config-authority"',
       parsePubkeyOrPubkeyFromWallet
     )
     .option(
       '--operator <pubkey>',
-      'New operator authority to be configured',
+      'New operator authority to be proposed, the new authority accepts with "accept-config-authority"',
       parsePubkeyOrPubkeyFromWallet
     )
     .option(
       '--pause-authority <pubkey>',
-      'New pause authority to be configured',
+      'New pause authority to be proposed, the new authority accepts with "accept-config-authority"',
       parsePubkeyOrPubkeyFromWallet
     )
+    .option(
+      '--settlement-creator <pubkey>',
+      'New operator role permitted to create settlements (default pubkey makes the operator authority to hold the role)',
+      parsePubkeyOrPubkeyFromWallet
+    )
+    .option(
+      '--settlement-funder <pubkey>',
+      'New operator role permitted to fund settlements (default pubkey makes the operator authority to hold the role)',
+      parsePubkeyOrPubkeyFromWallet
+    )
+    .option(
+      '--settlement-closer <pubkey>',
+      'New operator role permitted to reset and withdraw stake accounts of closed settlements (default pubkey makes the operator authority to hold the role)',
+      parsePubkeyOrPubkeyFromWallet
+    )
+    .option(
+      '--bid-fee-collector <pubkey>',
+      'New authority of the stake accounts split from bonds when the bid is charged',
+      parsePubkeyOrPubkeyFromWallet
+    )
+    .option(
+      '--bid-max-delegated-lamports <number>',
+      'New upper bound of the delegated lamports, the bond bid can be charged for',
+      value => new BN(value, 10)
+    )
     .option(
       '--epochs-to-claim-settlement <number>',
       'New number of epochs after which claim can be settled',
@@ -76,6 +101,31 @@ export function installConfigureConfig(program: Command) {
       'New value of minimum for max-stake-wanted field, in lamports, configured by validators in bond.',
       value => new BN(value, 10)
     )
+    .option(
+      '--config-change-delay-epochs <number>',
+      'New number of epochs the parameter changes are staged before they can be applied with "apply-config-changes" (0 applies changes immediately)',
+      parseFloat
+    )
+    .option(
+      '--max-claim-expiry-extension <number>',
+      'New maximum number of epochs the claiming of a single settlement can be extended by (see "extend_settlement_expiry")',
+      parseFloat
+    )
+    .option(
+      '--claimer-fee-lamports <number>',
+      'New fee, in lamports, paid from the claimed amount to the permission-less claimer of a settlement',
+      value => new BN(value, 10)
+    )
+    .option(
+      '--claimer-fee-max-bps <number>',
+      'New maximum of the claimer fee in basis points of the claimed amount',
+      parseFloat
+    )
+    .option(
+      '--cancel-pending-changes',
+      'Drop all pending authority and parameter changes before the new ones are processed',
+      false
+    )
     .action(
       async (
         address: Promise<undefined | PublicKey>,
@@ -84,21 +134,41 @@ export function installConfigureConfig(program: Command) {
           admin,
           operator,
           pauseAuthority,
+          settlementCreator,
+          settlementFunder,
+          settlementCloser,
+          bidFeeCollector,
+          bidMaxDelegatedLamports,
           epochsToClaimSettlement,
           slotsToStartSettlementClaiming,
           withdrawLockupEpochs,
           minimumStakeLamports,
           minBondMaxStakeWanted,
+          configChangeDelayEpochs,
+          maxClaimExpiryExtension,
+          claimerFeeLamports,
+          claimerFeeMaxBps,
+          cancelPendingChanges,
         }: {
           adminAuthority?: Promise<WalletInterface | PublicKey>
           admin?: Promise<PublicKey>
           operator?: Promise<PublicKey>
           pauseAuthority?: Promise<PublicKey>
+          settlementCreator?: Promise<PublicKey>
+          settlementFunder?: Promise<PublicKey>
+          settlementCloser?: Promise<PublicKey>
+          bidFeeCollector?: Promise<PublicKey>
+          bidMaxDelegatedLamports?: BN
           epochsToClaimSettlement?: number
           slotsToStartSettlementClaiming?: number
           withdrawLockupEpochs?: number
           minimumStakeLamports?: BN
           minBondMaxStakeWanted?: BN
+          configChangeDelayEpochs?: number
+          maxClaimExpiryExtension?: number
+          claimerFeeLamports?: BN
+          claimerFeeMaxBps?: number
+          cancelPendingChanges: boolean
         }
       ) => {
         await manageConfigureConfig({
@@ -107,11 +177,21 @@ export function installConfigureConfig(program: Command) {
           admin: await admin,
           operator: await operator,
           pauseAuthority: await pauseAuthority,
+          settlementCreator: await settlementCreator,
+          settlementFunder: await settlementFunder,
+          settlementCloser: await settlementCloser,
+          bidFeeCollector: await bidFeeCollector,
+          bidMaxDelegatedLamports,
           epochsToClaimSettlement,
           slotsToStartSettlementClaiming,
           withdrawLockupEpochs,
           minimumStakeLamports,
           minBondMaxStakeWanted,
+          configChangeDelayEpochs,
+          maxClaimExpiryExtension,
+          claimerFeeLamports,
+          claimerFeeMaxBps,
+          cancelPendingChanges,
         })
       }
     )
@@ -123,22 +203,42 @@ async function manageConfigureConfig({
   admin,
   operator,
   pauseAuthority,
+  settlementCreator,
+  settlementFunder,
+  settlementCloser,
+  bidFeeCollector,
+  bidMaxDelegatedLamports,
   epochsToClaimSettlement,
   slotsToStartSettlementClaiming,
   withdrawLockupEpochs,
   minimumStakeLamports,
   minBondMaxStakeWanted,
+  configChangeDelayEpochs,
+  maxClaimExpiryExtension,
+  claimerFeeLamports,
+  claimerFeeMaxBps,
+  cancelPendingChanges,
 }: {
   address?: PublicKey
   adminAuthority?: WalletInterface | PublicKey
   admin?: PublicKey
   operator?: PublicKey
   pauseAuthority?: PublicKey
+  settlementCreator?: PublicKey
+  settlementFunder?: PublicKey
+  settlementCloser?: PublicKey
+  bidFeeCollector?: PublicKey
+  bidMaxDelegatedLamports?: BN
   epochsToClaimSettlement?: number
   slotsToStartSettlementClaiming?: number
   withdrawLockupEpochs?: number
   minimumStakeLamports?: BN
   minBondMaxStakeWanted?: BN
+  configChangeDelayEpochs?: number
+  maxClaimExpiryExtension?: number
+  claimerFeeLamports?: BN
+  claimerFeeMaxBps?: number
+  cancelPendingChanges: boolean
 }) {
   const {
     program,
@@ -178,11 +278,21 @@ async function manageConfigureConfig({
     newAdmin: admin,
     newOperator: operator,
     newPauseAuthority: pauseAuthority,
+    newSettlementCreator: settlementCreator,
+    newSettlementFunder: settlementFunder,
+    newSettlementCloser: settlementCloser,
+    newBidFeeCollector: bidFeeCollector,
+    newBidMaxDelegatedLamports: bidMaxDelegatedLamports,
     newEpochsToClaimSettlement: epochsToClaimSettlement,
     newSlotsToStartSettlementClaiming: slotsToStartSettlementClaiming,
     newWithdrawLockupEpochs: withdrawLockupEpochs,
     newMinimumStakeLamports: minimumStakeLamports,
     newMinBondMaxStakeWanted: minBondMaxStakeWanted,
+    newConfigChangeDelayEpochs: configChangeDelayEpochs,
+    newMaxClaimExpiryExtension: maxClaimExpiryExtension,
+    newClaimerFeeLamports: claimerFeeLamports,
+    newClaimerFeeMaxBps: claimerFeeMaxBps,
+    cancelPendingChanges,
   })
   tx.add(instruction)
 
@@ -0,0 +1,203 @@
+import { parsePubkey, parseWalletOrPubkey } from '@marinade.finance/cli-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  instanceOfWallet,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
+import {
+  disputeSettlementInstruction,
+  resolveDisputeInstruction,
+} from '@marinade.finance/validator-bonds-sdk'
+import {
+  DISPUTE_SETTLEMENT_LIMIT_UNITS,
+  RESOLVE_DISPUTE_LIMIT_UNITS,
+} from '../../computeUnits'
+import { createHash } from 'crypto'
+
+export function installDisputeSettlement(program: Command) {
+  program
+    .command('dispute-settlement')
+    .description(
+      'Disputing the settlement created for the bond before the settlement claiming starts. ' +
+        'The claiming is blocked until the config admin resolves the dispute.'
+    )
+    .argument('<address>', 'Address of the settlement account', parsePubkey)
+    .requiredOption(
+      '--reason <text>',
+      'Reason of the dispute. Only the SHA-256 hash of the reason is stored on-chain, ' +
+        'the reason text is expected to be published off-chain.'
+    )
+    .option(
+      '--authority <keypair_or_ledger_or_pubkey>',
+      'Bond authority or validator identity that is permitted to dispute the settlement ' +
+        '(default: wallet keypair)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          reason,
+          authority,
+        }: {
+          reason: string
+          authority?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageDisputeSettlement({
+          address: await address,
+          reason,
+          authority: await authority,
+        })
+      }
+    )
+}
+
+export function installResolveDispute(program: Command) {
+  program
+    .command('resolve-dispute')
+    .description(
+      'Resolving the dispute of the settlement, the settlement claiming is unblocked.'
+    )
+    .argument('<address>', 'Address of the settlement account', parsePubkey)
+    .option(
+      '--admin-authority <keypair_or_ledger_or_pubkey>',
+      'Admin authority that is permitted to resolve the dispute (default: wallet keypair)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          adminAuthority,
+        }: {
+          adminAuthority?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageResolveDispute({
+          address: await address,
+          adminAuthority: await adminAuthority,
+        })
+      }
+    )
+}
+
+async function manageDisputeSettlement({
+  address,
+  reason,
+  authority,
+}: {
+  address: PublicKey
+  reason: string
+  authority?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(address)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  authority = authority ?? wallet.publicKey
+  if (instanceOfWallet(authority)) {
+    signers.push(authority)
+    authority = authority.publicKey
+  }
+
+  const reasonHash = createHash('sha256').update(reason).digest()
+  const { instruction } = await disputeSettlementInstruction({
+    program,
+    settlementAccount: address,
+    reasonHash,
+    authority,
+  })
+  tx.add(instruction)
+
+  logger.info(
+    `Disputing settlement account ${address.toBase58()} with reason hash ${reasonHash.toString('hex')}`
+  )
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to dispute settlement account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: DISPUTE_SETTLEMENT_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(`Settlement account ${address.toBase58()} successfully disputed`)
+}
+
+async function manageResolveDispute({
+  address,
+  adminAuthority,
+}: {
+  address: PublicKey
+  adminAuthority?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(address)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  adminAuthority = adminAuthority ?? wallet.publicKey
+  if (instanceOfWallet(adminAuthority)) {
+    signers.push(adminAuthority)
+    adminAuthority = adminAuthority.publicKey
+  }
+
+  const { instruction } = await resolveDisputeInstruction({
+    program,
+    settlementAccount: address,
+    adminAuthority,
+  })
+  tx.add(instruction)
+
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to resolve dispute of settlement account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: RESOLVE_DISPUTE_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Dispute of settlement account ${address.toBase58()} successfully resolved`
+  )
+}
@@ -13,6 +13,8 @@ import {
   MARINADE_CONFIG_ADDRESS,
   emergencyPauseInstruction,
   emergencyResumeInstruction,
+  setPauseFlagsInstruction,
+  PAUSE_FLAGS,
 } from '@marinade.finance/validator-bonds-sdk'
 import { EMERGENCY_LIMIT_UNITS } from '../../computeUnits'
 
@@ -82,14 +84,78 @@ export function installEmergencyResume(program: Command) {
     )
 }
 
+export function installSetPauseFlags(program: Command) {
+  program
+    .command('set-pause-flags')
+    .description(
+      'Pausing and resuming the instruction families of Validator Bond contract independently'
+    )
+    .argument(
+      '[address]',
+      'Address of the validator bonds config account to be configured ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .requiredOption(
+      '--flags <flags>',
+      'Comma separated list of the instruction families to be paused, ' +
+        `the not listed ones are resumed (one of: ${Object.keys(
+          PAUSE_FLAGS
+        ).join(', ')}, none)`,
+      parsePauseFlags
+    )
+    .option(
+      '--authority <keypair_or_ledger_or_pubkey>',
+      'Pause authority with permission to pause the contract (default: wallet)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<undefined | PublicKey>,
+        {
+          flags,
+          authority,
+        }: {
+          flags: number
+          authority?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageEmergencyPauseAndResume({
+          action: 'set pause flags of',
+          address: await address,
+          authority: await authority,
+          pauseFlags: flags,
+        })
+      }
+    )
+}
+
+function parsePauseFlags(flags: string): number {
+  return flags
+    .split(',')
+    .map(flag => flag.trim().toLowerCase())
+    .filter(flag => flag !== '' && flag !== 'none')
+    .reduce((pauseFlags, flag) => {
+      if (!(flag in PAUSE_FLAGS)) {
+        throw new Error(
+          `Unknown pause flag '${flag}', expected one of: ` +
+            `${Object.keys(PAUSE_FLAGS).join(', ')}, none`
+        )
+      }
+      return pauseFlags | PAUSE_FLAGS[flag as keyof typeof PAUSE_FLAGS]
+    }, 0)
+}
+
 async function manageEmergencyPauseAndResume({
   action,
   address = MARINADE_CONFIG_ADDRESS,
   authority,
+  pauseFlags = 0,
 }: {
-  action: 'pause' | 'resume'
+  action: 'pause' | 'resume' | 'set pause flags of'
   address?: PublicKey
   authority?: WalletInterface | PublicKey
+  pauseFlags?: number
 }) {
   const {
     program,
@@ -120,12 +186,19 @@ async function manageEmergencyPauseAndResume({
       configAccount: address,
       pauseAuthority: authority,
     }))
-  } else {
+  } else if (action === 'resume') {
     ;({ instruction } = await emergencyResumeInstruction({
       program,
       configAccount: address,
       pauseAuthority: authority,
     }))
+  } else {
+    ;({ instruction } = await setPauseFlagsInstruction({
+      program,
+      configAccount: address,
+      pauseAuthority: authority,
+      pauseFlags,
+    }))
   }
   tx.add(instruction)
 
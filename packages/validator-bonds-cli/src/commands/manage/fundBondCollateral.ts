@@ -0,0 +1,173 @@
+import {
+  parsePubkey,
+  parsePubkeyOrPubkeyFromWallet,
+  parseWalletOrPubkey,
+} from '@marinade.finance/cli-common'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  instanceOfWallet,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import {
+  fundBondCollateralInstruction,
+  MARINADE_CONFIG_ADDRESS,
+} from '@marinade.finance/validator-bonds-sdk'
+import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { getBondFromAddress } from '../utils'
+import { FUND_BOND_COLLATERAL_LIMIT_UNITS } from '../../computeUnits'
+import BN from 'bn.js'
+
+export function installFundBondCollateral(program: Command) {
+  program
+    .command('fund-bond-collateral')
+    .description(
+      'Funding a bond account with tokens of a whitelisted collateral mint (e.g., liquid staking token).'
+    )
+    .argument(
+      '<address>',
+      'Address of the bond account or vote account.',
+      parsePubkey
+    )
+    .option(
+      '--config <pubkey>',
+      'The config account that the bond account is created under ' +
+        '(optional; to derive bond address from vote account address) ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .requiredOption(
+      '--mint <pubkey>',
+      'SPL token mint whitelisted as the bond collateral',
+      parsePubkey
+    )
+    .requiredOption(
+      '--amount <number>',
+      'Amount of tokens in the token base units to be deposited',
+      value => new BN(value, 10)
+    )
+    .option(
+      '--token-account <pubkey>',
+      'Token account the tokens are deposited from ' +
+        '(default: associated token account of the token authority)',
+      parsePubkeyOrPubkeyFromWallet
+    )
+    .option(
+      '--token-authority <keypair_or_ledger_or_pubkey>',
+      'Owner of the token account that is permitted to transfer the tokens. ' +
+        '(default: wallet keypair)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          config,
+          mint,
+          amount,
+          tokenAccount,
+          tokenAuthority,
+        }: {
+          config?: Promise<PublicKey>
+          mint: Promise<PublicKey>
+          amount: BN
+          tokenAccount?: Promise<PublicKey>
+          tokenAuthority?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageFundBondCollateral({
+          address: await address,
+          config: await config,
+          mint: await mint,
+          amount,
+          tokenAccount: await tokenAccount,
+          tokenAuthority: await tokenAuthority,
+        })
+      }
+    )
+}
+
+async function manageFundBondCollateral({
+  address,
+  config,
+  mint,
+  amount,
+  tokenAccount,
+  tokenAuthority,
+}: {
+  address: PublicKey
+  config?: PublicKey
+  mint: PublicKey
+  amount: BN
+  tokenAccount?: PublicKey
+  tokenAuthority?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(config)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  tokenAuthority = tokenAuthority ?? wallet.publicKey
+  if (instanceOfWallet(tokenAuthority)) {
+    signers.push(tokenAuthority)
+    tokenAuthority = tokenAuthority.publicKey
+  }
+
+  const bondAccountData = await getBondFromAddress({
+    program,
+    address,
+    config,
+    logger,
+  })
+  const bondAccountAddress = bondAccountData.publicKey
+  config = bondAccountData.account.data.config
+
+  const { instruction, bondAccount, collateralVault } =
+    await fundBondCollateralInstruction({
+      program,
+      bondAccount: bondAccountAddress,
+      configAccount: config,
+      mint,
+      amount,
+      tokenAccount,
+      tokenAuthority,
+      rentPayer: wallet.publicKey,
+    })
+  tx.add(instruction)
+
+  logger.info(
+    `Funding bond account ${bondAccount.toBase58()} with ${amount.toString()} tokens of mint ${mint.toBase58()}`
+  )
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to fund bond account ${bondAccount.toBase58()} with collateral`,
+    signers,
+    logger,
+    computeUnitLimit: FUND_BOND_COLLATERAL_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Bond account ${bondAccount.toBase58()} successfully funded ` +
+      `with collateral to vault ${collateralVault.toBase58()}`
+  )
+}
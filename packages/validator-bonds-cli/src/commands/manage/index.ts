@@ -1,30 +1,54 @@
 import { Command } from 'commander'
 import { installInitConfig } from './initConfig'
 import { installConfigureConfig } from './configureConfig'
+import { installAcceptConfigAuthority } from './acceptConfigAuthority'
+import { installApplyConfigChanges } from './applyConfigChanges'
 import { installInitBond } from './initBond'
 import { installConfigureBond } from './configureBond'
+import { installMigrateBond } from './migrateBond'
+import { installMigrateAccount } from './migrateAccount'
 import { installMintBond } from './mintBond'
 import { installStakeMerge } from './mergeStake'
 import { installFundBond } from './fundBond'
+import { installFundBondCollateral } from './fundBondCollateral'
+import {
+  installConfigureCollateralMint,
+  installInitCollateralMint,
+} from './collateralMint'
+import {
+  installDisputeSettlement,
+  installResolveDispute,
+} from './disputeSettlement'
 import { installInitWithdrawRequest } from './initWithdrawRequest'
 import { installCancelWithdrawRequest } from './cancelWithdrawRequest'
 import { installClaimWithdrawRequest } from './claimWithdrawRequest'
 import {
   installEmergencyPause,
   installEmergencyResume,
+  installSetPauseFlags,
 } from './emergencyPauseAndResume'
 
 export function installManage(program: Command) {
   installInitConfig(program)
   installConfigureConfig(program)
+  installAcceptConfigAuthority(program)
+  installApplyConfigChanges(program)
+  installInitCollateralMint(program)
+  installConfigureCollateralMint(program)
   installMintBond(program)
   installInitBond(program)
   installConfigureBond(program)
+  installMigrateBond(program)
   installStakeMerge(program)
   installFundBond(program)
+  installFundBondCollateral(program)
   installInitWithdrawRequest(program)
   installCancelWithdrawRequest(program)
   installClaimWithdrawRequest(program)
+  installDisputeSettlement(program)
+  installResolveDispute(program)
   installEmergencyPause(program)
   installEmergencyResume(program)
+  installSetPauseFlags(program)
+  installMigrateAccount(program)
 }
@@ -0,0 +1,96 @@
+import { parsePubkey, parseWalletOrPubkey } from '@marinade.finance/cli-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  instanceOfWallet,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
+import { migrateAccountInstruction } from '@marinade.finance/validator-bonds-sdk'
+import { MIGRATE_ACCOUNT_LIMIT_UNITS } from '../../computeUnits'
+
+export function installMigrateAccount(program: Command) {
+  program
+    .command('migrate-account')
+    .description(
+      'Migrating the program account (bond, config, settlement, settlement claim or withdraw request) ' +
+        'to the current layout version. This is a permission-less operation.'
+    )
+    .argument('<address>', 'Address of the account to migrate', parsePubkey)
+    .option(
+      '--rent-payer <keypair_or_ledger_or_pubkey>',
+      'Rent payer for the account reallocation (default: wallet keypair)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          rentPayer,
+        }: {
+          rentPayer?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageMigrateAccount({
+          address: await address,
+          rentPayer: await rentPayer,
+        })
+      }
+    )
+}
+
+async function manageMigrateAccount({
+  address,
+  rentPayer,
+}: {
+  address: PublicKey
+  rentPayer?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(address)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  rentPayer = rentPayer ?? wallet.publicKey
+  if (instanceOfWallet(rentPayer)) {
+    signers.push(rentPayer)
+    rentPayer = rentPayer.publicKey
+  }
+
+  const { instruction } = await migrateAccountInstruction({
+    program,
+    account: address,
+    rentPayer,
+  })
+  tx.add(instruction)
+
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to migrate account ${address.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: MIGRATE_ACCOUNT_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(`Account ${address.toBase58()} successfully migrated`)
+}
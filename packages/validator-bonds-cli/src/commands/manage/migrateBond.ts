@@ -0,0 +1,140 @@
+import { parsePubkey, parseWalletOrPubkey } from '@marinade.finance/cli-common'
+import { PublicKey, Signer } from '@solana/web3.js'
+import { Command } from 'commander'
+import { setProgramIdByOwner } from '../../context'
+import {
+  Wallet,
+  executeTx,
+  instanceOfWallet,
+  transaction,
+} from '@marinade.finance/web3js-common'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  migrateBondInstruction,
+} from '@marinade.finance/validator-bonds-sdk'
+import { Wallet as WalletInterface } from '@marinade.finance/web3js-common'
+import { getBondFromAddress } from '../utils'
+import { MIGRATE_BOND_LIMIT_UNITS } from '../../computeUnits'
+
+export function installMigrateBond(program: Command) {
+  program
+    .command('migrate-bond')
+    .description(
+      'Migrate the bond to a new vote account of the same validator. ' +
+        'The bond stake accounts are redelegated to the new vote account afterwards.'
+    )
+    .argument(
+      '<address>',
+      'Address of the bond account or vote account.',
+      parsePubkey
+    )
+    .requiredOption(
+      '--new-vote-account <pubkey>',
+      'Vote account the bond is migrated to. No bond may exist for the vote account.',
+      parsePubkey
+    )
+    .option(
+      '--config <pubkey>',
+      'The config account that the bond account is created under ' +
+        '(optional; to derive bond address from vote account address) ' +
+        `(default: ${MARINADE_CONFIG_ADDRESS.toBase58()})`,
+      parsePubkey
+    )
+    .option(
+      '--authority <keypair_or_ledger_or_pubkey>',
+      'Validator identity or authorized withdrawer of both the current and the new vote account ' +
+        '(default: wallet keypair)',
+      parseWalletOrPubkey
+    )
+    .action(
+      async (
+        address: Promise<PublicKey>,
+        {
+          newVoteAccount,
+          config,
+          authority,
+        }: {
+          newVoteAccount: Promise<PublicKey>
+          config?: Promise<PublicKey>
+          authority?: Promise<WalletInterface | PublicKey>
+        }
+      ) => {
+        await manageMigrateBond({
+          address: await address,
+          newVoteAccount: await newVoteAccount,
+          config: await config,
+          authority: await authority,
+        })
+      }
+    )
+}
+
+async function manageMigrateBond({
+  address,
+  newVoteAccount,
+  config = MARINADE_CONFIG_ADDRESS,
+  authority,
+}: {
+  address: PublicKey
+  newVoteAccount: PublicKey
+  config?: PublicKey
+  authority?: WalletInterface | PublicKey
+}) {
+  const {
+    program,
+    provider,
+    logger,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    wallet,
+    confirmationFinality,
+    confirmWaitTime,
+    skipPreflight,
+  } = await setProgramIdByOwner(config)
+
+  const tx = await transaction(provider)
+  const signers: (Signer | Wallet)[] = [wallet]
+
+  authority = authority ?? wallet.publicKey
+  if (instanceOfWallet(authority)) {
+    signers.push(authority)
+    authority = authority.publicKey
+  }
+
+  const bondAccountData = await getBondFromAddress({
+    program,
+    address,
+    config,
+    logger,
+  })
+
+  const { instruction, bondAccount } = await migrateBondInstruction({
+    program,
+    bondAccount: bondAccountData.publicKey,
+    newVoteAccount,
+    authority,
+  })
+  tx.add(instruction)
+
+  logger.info(
+    `Migrating bond account ${bondAccount.toBase58()} to vote account ${newVoteAccount.toBase58()}`
+  )
+  await executeTx({
+    connection: provider.connection,
+    transaction: tx,
+    errMessage: `'Failed to migrate bond account ${bondAccount.toBase58()}`,
+    signers,
+    logger,
+    computeUnitLimit: MIGRATE_BOND_LIMIT_UNITS,
+    computeUnitPrice,
+    simulate,
+    printOnly,
+    confirmOpts: confirmationFinality,
+    confirmWaitTime,
+    sendOpts: { skipPreflight },
+  })
+  logger.info(
+    `Bond account ${bondAccount.toBase58()} successfully migrated to vote account ${newVoteAccount.toBase58()}`
+  )
+}
@@ -0,0 +1,228 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  amendSettlementInstruction,
+  claimSettlementCollateralInstruction,
+  fundBondCollateralInstruction,
+  fundSettlementCollateralInstruction,
+  getSettlement,
+  initCollateralMintInstruction,
+} from '../../src'
+import { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import { createVoteAccount } from '../utils/staking'
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+} from 'solana-spl-token-modern'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { createTokenMintAndFund } from '../utils/helpers'
+import {
+  MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+  createWithdrawerUsers,
+  totalClaimVoteAccount1,
+  treeNodesVoteAccount1,
+  withdrawer1,
+} from '../utils/merkleTreeTestData'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds amend settlement', () => {
+  const slotsToStartSettlementClaiming = 10
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let voteAccount: PublicKey
+  let bondAccount: PublicKey
+  let settlementAccount: PublicKey
+  let merkleRoot: number[] | Uint8Array | Buffer
+  let mint: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    await createWithdrawerUsers(provider)
+  })
+
+  beforeEach(async () => {
+    let adminAuthority: Keypair
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement: 4,
+        slotsToStartSettlementClaiming,
+      }))
+    const { voteAccount: vote, validatorIdentity } = await createVoteAccount({
+      provider,
+    })
+    voteAccount = vote
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+    ;({ settlementAccount, merkleRoot } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+      maxMerkleNodes: 1,
+      maxTotalClaim: 1,
+    }))
+
+    // the claims are paid from the token collateral funded to the settlement
+    ;({ mint } = await createTokenMintAndFund({
+      provider,
+      owner: provider.walletPubkey,
+      amount: LAMPORTS_PER_SOL,
+    }))
+    const { instruction: initMintIx } = await initCollateralMintInstruction({
+      program,
+      configAccount,
+      mint,
+      lamportsPerToken: LAMPORTS_PER_SOL,
+      haircutBps: 0,
+    })
+    const { instruction: fundBondIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL,
+    })
+    await provider.sendIx([adminAuthority], initMintIx, fundBondIx)
+  })
+
+  it('amend settlement and claim against amended merkle root', async () => {
+    const settlementDataBefore = await getSettlement(
+      program,
+      settlementAccount
+    )
+    await warpOffsetSlots(slotsToStartSettlementClaiming + 1)
+
+    const { instruction } = await amendSettlementInstruction({
+      program,
+      settlementAccount,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxTotalClaim: totalClaimVoteAccount1,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+
+    const settlementData = await getSettlement(program, settlementAccount)
+    // the settlement address stays derived from the original merkle root
+    expect(settlementData.merkleRoot).toEqual(Array.from(merkleRoot))
+    expect(settlementData.amended).toEqual(true)
+    expect(settlementData.amendedMerkleRoot).toEqual(
+      Array.from(MERKLE_ROOT_VOTE_ACCOUNT_1_BUF)
+    )
+    expect(settlementData.maxTotalClaim).toEqual(totalClaimVoteAccount1)
+    expect(settlementData.maxMerkleNodes).toEqual(treeNodesVoteAccount1.length)
+    // the claiming window is restarted from the amendment slot
+    const slotNow = (await provider.context.banksClient.getClock()).slot
+    expect(settlementData.slotCreatedAt.toString()).toEqual(slotNow.toString())
+    expect(
+      settlementData.slotCreatedAt.gt(settlementDataBefore.slotCreatedAt)
+    ).toBe(true)
+
+    const { instruction: fundIx } = await fundSettlementCollateralInstruction({
+      program,
+      settlementAccount,
+      mint,
+    })
+    await provider.sendIx([operatorAuthority], fundIx)
+
+    const treeNode = treeNodesVoteAccount1.find(item =>
+      item.treeNode.data.withdrawAuthority.equals(withdrawer1)
+    )!
+    const { instruction: claimIx, claimerTokenAccount } =
+      await claimSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+        claimAmount: treeNode.treeNode.data.claim,
+        merkleProof: treeNode.proof,
+        stakeAccountStaker: treeNode.treeNode.data.stakeAuthority,
+        stakeAccountWithdrawer: withdrawer1,
+      })
+    const createTokenAccountIx =
+      createAssociatedTokenAccountIdempotentInstruction(
+        provider.walletPubkey,
+        claimerTokenAccount,
+        withdrawer1,
+        mint
+      )
+    try {
+      await provider.sendIx([], createTokenAccountIx, claimIx)
+      throw new Error('failure expected; claiming window restarted')
+    } catch (e) {
+      verifyError(e, Errors, 6061, 'slots to start claiming not expired')
+    }
+
+    await warpOffsetSlots(slotsToStartSettlementClaiming + 1)
+    await provider.sendIx([], createTokenAccountIx, claimIx)
+    expect(
+      (await getSettlement(program, settlementAccount)).merkleNodesClaimed
+    ).toEqual(1)
+
+    // no amendment after the first claim
+    const { instruction: amendAgainIx } = await amendSettlementInstruction({
+      program,
+      settlementAccount,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxTotalClaim: totalClaimVoteAccount1.addn(1),
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], amendAgainIx)
+      throw new Error('failure expected; settlement already claimed')
+    } catch (e) {
+      verifyError(e, Errors, 6078, 'has been already claimed')
+    }
+  })
+
+  it('cannot amend settlement with empty merkle tree', async () => {
+    const { instruction } = await amendSettlementInstruction({
+      program,
+      settlementAccount,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxTotalClaim: 0,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], instruction)
+      throw new Error('failure expected; empty merkle tree')
+    } catch (e) {
+      verifyError(e, Errors, 6034, 'Empty merkle tree')
+    }
+  })
+
+  it('cannot amend settlement with wrong operator', async () => {
+    const wrongOperator = Keypair.generate()
+    const { instruction } = await amendSettlementInstruction({
+      program,
+      settlementAccount,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxTotalClaim: totalClaimVoteAccount1,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+      operatorAuthority: wrongOperator,
+    })
+    try {
+      await provider.sendIx([wrongOperator], instruction)
+      throw new Error('failure expected; wrong operator')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+  })
+
+  async function warpOffsetSlots(slots: number) {
+    const clock = await provider.context.banksClient.getClock()
+    provider.context.warpToSlot(clock.slot + BigInt(slots))
+  }
+})
@@ -4,6 +4,7 @@ import {
   cancelSettlementInstruction,
   closeSettlementInstruction,
   configureConfigInstruction,
+  acceptConfigAuthorityInstruction,
   getSettlement,
 } from '../../src'
 import {
@@ -109,7 +110,16 @@ describe('Validator Bonds cancel settlement', () => {
         newPauseAuthority: pauseAuthority.publicKey,
       }
     )
-    await provider.sendIx([adminAuthority], configureConfigIx)
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount,
+      newAuthority: pauseAuthority,
+    })
+    await provider.sendIx(
+      [adminAuthority, pauseAuthority],
+      configureConfigIx,
+      acceptIx
+    )
 
     const { instruction } = await cancelSettlementInstruction({
       program,
@@ -0,0 +1,352 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  chargeBidInstruction,
+  configureConfigInstruction,
+  getBond,
+  getRentExemptStake,
+} from '../../src'
+import {
+  BankrunExtendedProvider,
+  assertNotExist,
+  currentEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import {
+  StakeStates,
+  createBondsFundedStakeAccount,
+  createVoteAccount,
+  getAndCheckStakeAccount,
+} from '../utils/staking'
+import { pubkey, signer } from '@marinade.finance/web3js-common'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds charge bid', () => {
+  // 1 SOL per 1000 SOLs delegated
+  const cpmpe = LAMPORTS_PER_SOL
+  const bidMaxDelegatedLamports = 100_000 * LAMPORTS_PER_SOL
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let operatorAuthority: Keypair
+  let adminAuthority: Keypair
+  let bidFeeCollector: PublicKey
+  let bondAccount: PublicKey
+  let voteAccount: PublicKey
+  let stakeAccount: PublicKey
+  let bondCreatedEpoch: number
+  let rentExemptStake: number
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    rentExemptStake = await getRentExemptStake(provider)
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+      }))
+    bidFeeCollector = Keypair.generate().publicKey
+    const { instruction: configIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newBidFeeCollector: bidFeeCollector,
+      newBidMaxDelegatedLamports: bidMaxDelegatedLamports,
+    })
+    await provider.sendIx([adminAuthority], configIx)
+
+    await warpToNextEpoch(provider)
+    const { voteAccount: vote, validatorIdentity } = await createVoteAccount({
+      provider,
+    })
+    voteAccount = vote
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+      cpmpe,
+    }))
+    bondCreatedEpoch = await currentEpoch(provider)
+    stakeAccount = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: 100 * LAMPORTS_PER_SOL,
+    })
+  })
+
+  it('charge bid', async () => {
+    await warpToNextEpoch(provider)
+    const delegatedLamports = 10_000 * LAMPORTS_PER_SOL
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch,
+      delegatedLamports,
+    })
+    await provider.sendIx(
+      [operatorAuthority, signer(splitStakeAccount)],
+      instruction
+    )
+
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.bidChargedEpoch).toEqual(bondCreatedEpoch)
+    expect(bondData.bidChargedAmount).toEqual(10 * LAMPORTS_PER_SOL)
+    const [splitStakeData, splitStakeInfo] = await getAndCheckStakeAccount(
+      provider,
+      pubkey(splitStakeAccount),
+      StakeStates.Delegated
+    )
+    // split stake account rent is paid by the split stake rent payer
+    expect(splitStakeInfo.lamports).toEqual(
+      10 * LAMPORTS_PER_SOL + rentExemptStake
+    )
+    expect(splitStakeData.Stake?.meta.authorized.staker).toEqual(
+      bidFeeCollector
+    )
+    expect(splitStakeData.Stake?.meta.authorized.withdrawer).toEqual(
+      bidFeeCollector
+    )
+    expect(
+      (await provider.connection.getAccountInfo(stakeAccount))?.lamports
+    ).toEqual(90 * LAMPORTS_PER_SOL)
+
+    // the same epoch cannot be charged twice
+    const { instruction: againIx, splitStakeAccount: againSplit } =
+      await chargeBidInstruction({
+        program,
+        bondAccount,
+        stakeAccount,
+        epoch: bondCreatedEpoch,
+        delegatedLamports,
+      })
+    try {
+      await provider.sendIx([operatorAuthority, signer(againSplit)], againIx)
+      throw new Error('failure expected; bid already charged')
+    } catch (e) {
+      verifyError(e, Errors, 6068, 'has been already charged')
+    }
+    assertNotExist(provider, pubkey(againSplit))
+  })
+
+  it('charge rest of partially charged bid', async () => {
+    await warpToNextEpoch(provider)
+    // bid of 100 SOLs is over what the stake account can provide
+    const delegatedLamports = 100_000 * LAMPORTS_PER_SOL
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch,
+      delegatedLamports,
+    })
+    await provider.sendIx(
+      [operatorAuthority, signer(splitStakeAccount)],
+      instruction
+    )
+    let bondData = await getBond(program, bondAccount)
+    expect(bondData.bidChargedEpoch).toEqual(bondCreatedEpoch)
+    const stakeAccountLamports = (
+      await provider.connection.getAccountInfo(stakeAccount)
+    )?.lamports
+    expect(stakeAccountLamports).toBeGreaterThan(0)
+    expect(bondData.bidChargedAmount.toNumber()).toEqual(
+      100 * LAMPORTS_PER_SOL - stakeAccountLamports!
+    )
+
+    // the rest of the bid is charged from another bond stake account
+    const otherStakeAccount = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: 100 * LAMPORTS_PER_SOL,
+    })
+    const { instruction: restIx, splitStakeAccount: restSplit } =
+      await chargeBidInstruction({
+        program,
+        bondAccount,
+        stakeAccount: otherStakeAccount,
+        epoch: bondCreatedEpoch,
+        delegatedLamports,
+      })
+    await provider.sendIx([operatorAuthority, signer(restSplit)], restIx)
+    bondData = await getBond(program, bondAccount)
+    expect(bondData.bidChargedAmount).toEqual(100 * LAMPORTS_PER_SOL)
+    expect(
+      (await provider.connection.getAccountInfo(pubkey(restSplit)))?.lamports
+    ).toEqual(stakeAccountLamports! + rentExemptStake)
+
+    // fully charged bid cannot be charged again
+    const { instruction: againIx, splitStakeAccount: againSplit } =
+      await chargeBidInstruction({
+        program,
+        bondAccount,
+        stakeAccount: otherStakeAccount,
+        epoch: bondCreatedEpoch,
+        delegatedLamports,
+      })
+    try {
+      await provider.sendIx([operatorAuthority, signer(againSplit)], againIx)
+      throw new Error('failure expected; bid already charged')
+    } catch (e) {
+      verifyError(e, Errors, 6068, 'has been already charged')
+    }
+  })
+
+  it('cannot charge bid for not finished epoch', async () => {
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: await currentEpoch(provider),
+      delegatedLamports: 10_000 * LAMPORTS_PER_SOL,
+    })
+    try {
+      await provider.sendIx(
+        [operatorAuthority, signer(splitStakeAccount)],
+        instruction
+      )
+      throw new Error('failure expected; epoch not finished')
+    } catch (e) {
+      verifyError(e, Errors, 6069, 'already finished epoch')
+    }
+  })
+
+  it('cannot charge bid for epoch before bond creation', async () => {
+    await warpToNextEpoch(provider)
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch - 1,
+      delegatedLamports: 10_000 * LAMPORTS_PER_SOL,
+    })
+    try {
+      await provider.sendIx(
+        [operatorAuthority, signer(splitStakeAccount)],
+        instruction
+      )
+      throw new Error('failure expected; epoch before bond creation')
+    } catch (e) {
+      verifyError(e, Errors, 6090, 'before the bond was created')
+    }
+  })
+
+  it('cannot charge bid over delegated lamports bound', async () => {
+    await warpToNextEpoch(provider)
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch,
+      delegatedLamports: bidMaxDelegatedLamports + 1,
+    })
+    try {
+      await provider.sendIx(
+        [operatorAuthority, signer(splitStakeAccount)],
+        instruction
+      )
+      throw new Error('failure expected; delegated lamports exceeded')
+    } catch (e) {
+      verifyError(e, Errors, 6091, 'exceed the config maximum')
+    }
+
+    const { instruction: boundIx, splitStakeAccount: boundSplit } =
+      await chargeBidInstruction({
+        program,
+        bondAccount,
+        stakeAccount,
+        epoch: bondCreatedEpoch,
+        delegatedLamports: bidMaxDelegatedLamports,
+      })
+    await provider.sendIx([operatorAuthority, signer(boundSplit)], boundIx)
+    // bid of 100 SOLs is capped by the stake account minimal size
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.bidChargedEpoch).toEqual(bondCreatedEpoch)
+    expect(bondData.bidChargedAmount.toNumber()).toBeLessThan(
+      100 * LAMPORTS_PER_SOL
+    )
+  })
+
+  it('cannot charge zero bid', async () => {
+    await warpToNextEpoch(provider)
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch,
+      // bid is less than a lamport
+      delegatedLamports: 999,
+    })
+    try {
+      await provider.sendIx(
+        [operatorAuthority, signer(splitStakeAccount)],
+        instruction
+      )
+      throw new Error('failure expected; zero bid')
+    } catch (e) {
+      verifyError(e, Errors, 6092, 'is zero')
+    }
+  })
+
+  it('cannot charge bid with wrong operator', async () => {
+    await warpToNextEpoch(provider)
+    const wrongOperator = Keypair.generate()
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch,
+      delegatedLamports: 10_000 * LAMPORTS_PER_SOL,
+      operatorAuthority: wrongOperator,
+    })
+    try {
+      await provider.sendIx(
+        [wrongOperator, signer(splitStakeAccount)],
+        instruction
+      )
+      throw new Error('failure expected; wrong operator')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+  })
+
+  it('cannot charge bid without bid fee collector', async () => {
+    const { instruction: configIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newBidFeeCollector: PublicKey.default,
+    })
+    await provider.sendIx([adminAuthority], configIx)
+    await warpToNextEpoch(provider)
+    const { instruction, splitStakeAccount } = await chargeBidInstruction({
+      program,
+      bondAccount,
+      stakeAccount,
+      epoch: bondCreatedEpoch,
+      delegatedLamports: 10_000 * LAMPORTS_PER_SOL,
+    })
+    try {
+      await provider.sendIx(
+        [operatorAuthority, signer(splitStakeAccount)],
+        instruction
+      )
+      throw new Error('failure expected; bid fee collector not configured')
+    } catch (e) {
+      verifyError(e, Errors, 6070, 'not configured')
+    }
+  })
+})
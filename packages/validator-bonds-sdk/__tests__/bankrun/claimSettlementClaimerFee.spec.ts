@@ -0,0 +1,166 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  claimSettlementInstruction,
+  configureConfigInstruction,
+  getConfig,
+} from '../../src'
+import {
+  BankrunExtendedProvider,
+  currentEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import {
+  createSettlementFundedInitializedStake,
+  createVoteAccount,
+  createInitializedStakeAccount,
+} from '../utils/staking'
+import {
+  createUserAndFund,
+  pubkey,
+  signer,
+} from '@marinade.finance/web3js-common'
+import {
+  ITEMS_VOTE_ACCOUNT_1,
+  MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+  configAccountKeypair,
+  createWithdrawerUsers,
+  totalClaimVoteAccount1,
+  treeNodeByWithdrawer,
+  withdrawer1,
+} from '../utils/merkleTreeTestData'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds claim settlement with claimer fee', () => {
+  const claimerFeeLamports = 10_000
+  const claimerFeeMaxBps = 100
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let settlementAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement: 3,
+        configAccountKeypair: configAccountKeypair,
+      }))
+    const { voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    })
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    })
+
+    await warpToNextEpoch(provider)
+    ;({ settlementAccount } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxMerkleNodes: 1,
+      maxTotalClaim: totalClaimVoteAccount1,
+    }))
+    await createWithdrawerUsers(provider)
+  })
+
+  it('cannot configure claimer fee over the maximum basis points', async () => {
+    const { instruction } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newClaimerFeeMaxBps: 1_001,
+    })
+    try {
+      await provider.sendIx([adminAuthority], instruction)
+      throw new Error('failure expected; claimer fee bps over maximum')
+    } catch (e) {
+      verifyError(e, Errors, 6086, 'Claimer fee basis points exceeds')
+    }
+  })
+
+  it('claim settlement pays the claimer fee to the rent payer', async () => {
+    const { instruction: configureIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newClaimerFeeLamports: claimerFeeLamports,
+      newClaimerFeeMaxBps: claimerFeeMaxBps,
+    })
+    await provider.sendIx([adminAuthority], configureIx)
+    const configData = await getConfig(program, configAccount)
+    expect(configData.claimerFeeLamports).toEqual(claimerFeeLamports)
+    expect(configData.claimerFeeMaxBps).toEqual(claimerFeeMaxBps)
+
+    const treeNode1Withdrawer1 = treeNodeByWithdrawer(
+      ITEMS_VOTE_ACCOUNT_1,
+      withdrawer1
+    )
+    const stakeAccount = await createSettlementFundedInitializedStake({
+      program,
+      provider,
+      lamports: LAMPORTS_PER_SOL * 100,
+      configAccount,
+      settlementAccount,
+    })
+    const stakeAccountToLamportsBefore = 14 * LAMPORTS_PER_SOL
+    const { stakeAccount: stakeAccountTo } =
+      await createInitializedStakeAccount({
+        provider,
+        rentExempt: stakeAccountToLamportsBefore,
+        staker: treeNode1Withdrawer1.treeNode.stakeAuthority,
+        withdrawer: treeNode1Withdrawer1.treeNode.withdrawAuthority,
+      })
+    // anybody may crank the claim, the claimer pays the rent and gets the fee
+    const claimer = await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+    })
+    const { instruction, settlementClaimAccount } =
+      await claimSettlementInstruction({
+        program,
+        merkleProof: treeNode1Withdrawer1.proof,
+        settlementAccount,
+        stakeAccountFrom: stakeAccount,
+        stakeAccountTo,
+        stakeAccountStaker: treeNode1Withdrawer1.treeNode.stakeAuthority,
+        stakeAccountWithdrawer: treeNode1Withdrawer1.treeNode.withdrawAuthority,
+        claimAmount: treeNode1Withdrawer1.treeNode.data.claim,
+        rentPayer: claimer,
+      })
+    await provider.sendIx([signer(claimer)], instruction)
+
+    const claim = treeNode1Withdrawer1.treeNode.data.claim.toNumber()
+    const claimerFee = Math.min(
+      claimerFeeLamports,
+      Math.floor((claim * claimerFeeMaxBps) / 10_000)
+    )
+    expect(claimerFee).toBeGreaterThan(0)
+    expect(
+      (await provider.connection.getAccountInfo(stakeAccountTo))?.lamports
+    ).toEqual(stakeAccountToLamportsBefore + claim - claimerFee)
+    const settlementClaimRent = (await provider.connection.getAccountInfo(
+      settlementClaimAccount
+    ))!.lamports
+    expect(
+      (await provider.connection.getAccountInfo(pubkey(claimer)))?.lamports
+    ).toEqual(LAMPORTS_PER_SOL - settlementClaimRent + claimerFee)
+  })
+})
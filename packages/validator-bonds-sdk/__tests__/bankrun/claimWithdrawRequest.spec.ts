@@ -521,6 +521,122 @@ describe('Validator Bonds claim withdraw request', () => {
     await assertNotExist(provider, withdrawRequest)
   })
 
+  it('claim withdraw request merging stake accounts', async () => {
+    const requestedAmount = 10 * LAMPORTS_PER_SOL
+    const stake1Amount = 2 * LAMPORTS_PER_SOL
+    const stake2Amount = 3 * LAMPORTS_PER_SOL
+    const stake3Amount = 4 * LAMPORTS_PER_SOL
+    const { withdrawRequest, stakeAccount: stakeAccount1 } =
+      await createStakeAccountAndInitWithdraw(stake1Amount, requestedAmount)
+    const { stakeAccount: stakeAccount2 } = await delegateAndFund({
+      program,
+      provider,
+      lamports: stake2Amount,
+      voteAccount,
+      bondAccount: bond.publicKey,
+    })
+    const { stakeAccount: stakeAccount3 } = await delegateAndFund({
+      program,
+      provider,
+      lamports: stake3Amount,
+      voteAccount,
+      bondAccount: bond.publicKey,
+    })
+    const withdrawer = Keypair.generate().publicKey
+
+    const { instruction, splitStakeAccount } =
+      await claimWithdrawRequestInstruction({
+        program,
+        authority: validatorIdentity,
+        withdrawRequestAccount: withdrawRequest,
+        bondAccount: bond.publicKey,
+        stakeAccount: stakeAccount1,
+        withdrawer,
+        mergeStakeAccounts: [stakeAccount2, stakeAccount3],
+      })
+    await warpToUnlockClaiming()
+    await provider.sendIx([splitStakeAccount, validatorIdentity], instruction)
+
+    await assertNotExist(provider, stakeAccount2)
+    await assertNotExist(provider, stakeAccount3)
+    await assertNotExist(provider, splitStakeAccount.publicKey)
+
+    const withdrawRequestData = await getWithdrawRequest(
+      program,
+      withdrawRequest
+    )
+    expect(withdrawRequestData.withdrawnAmount).toEqual(
+      stake1Amount + stake2Amount + stake3Amount
+    )
+
+    const stakeAccountInfo =
+      await provider.connection.getAccountInfo(stakeAccount1)
+    assert(stakeAccountInfo !== null, 'merged stake account not found')
+    expect(stakeAccountInfo.lamports).toEqual(
+      stake1Amount + stake2Amount + stake3Amount
+    )
+    const stakeAccountData = deserializeStakeState(stakeAccountInfo.data)
+    expect(stakeAccountData.Stake?.meta.authorized.staker).toEqual(withdrawer)
+    expect(stakeAccountData.Stake?.meta.authorized.withdrawer).toEqual(
+      withdrawer
+    )
+  })
+
+  it('cannot claim merging the same stake account', async () => {
+    const { withdrawRequest, stakeAccount } =
+      await createStakeAccountAndInitWithdraw(
+        2 * LAMPORTS_PER_SOL,
+        10 * LAMPORTS_PER_SOL
+      )
+    await warpToUnlockClaiming()
+    const { instruction, splitStakeAccount } =
+      await claimWithdrawRequestInstruction({
+        program,
+        authority: validatorIdentity,
+        withdrawRequestAccount: withdrawRequest,
+        bondAccount: bond.publicKey,
+        stakeAccount,
+        mergeStakeAccounts: [stakeAccount],
+      })
+
+    try {
+      await provider.sendIx([splitStakeAccount, validatorIdentity], instruction)
+      throw new Error('failure expected; merging the same stake account')
+    } catch (e) {
+      verifyError(e, Errors, 6056, 'cannot be the same')
+    }
+  })
+
+  it('cannot claim merging stake account with wrong delegation', async () => {
+    const { withdrawRequest, stakeAccount } =
+      await createStakeAccountAndInitWithdraw(
+        2 * LAMPORTS_PER_SOL,
+        10 * LAMPORTS_PER_SOL
+      )
+    const { stakeAccount: wrongDelegationStakeAccount } =
+      await delegatedStakeAccount({
+        provider,
+        lamports: LAMPORTS_PER_SOL * 2,
+      })
+    await warpToUnlockClaiming()
+    const { instruction, splitStakeAccount } =
+      await claimWithdrawRequestInstruction({
+        program,
+        authority: validatorIdentity,
+        withdrawRequestAccount: withdrawRequest,
+        bondAccount: bond.publicKey,
+        stakeAccount,
+        mergeStakeAccounts: [wrongDelegationStakeAccount],
+      })
+
+    try {
+      await provider.sendIx([splitStakeAccount, validatorIdentity], instruction)
+      throw new Error('failure expected as delegated to wrong validator')
+    } catch (e) {
+      verifyError(e, Errors, 6020, 'delegated to a wrong validator')
+    }
+  })
+
   it('cannot claim with wrong bonds authority', async () => {
     const wrongAuthority = Keypair.generate()
     const { stakeAccount, withdrawRequest } =
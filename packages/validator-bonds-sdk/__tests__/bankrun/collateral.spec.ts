@@ -0,0 +1,578 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  claimSettlementCollateralInstruction,
+  claimWithdrawCollateralInstruction,
+  closeSettlementInstruction,
+  collateralVaultAddress,
+  configureCollateralMintInstruction,
+  configureConfigInstruction,
+  fundBondCollateralInstruction,
+  fundSettlementCollateralInstruction,
+  getCollateralMint,
+  getSettlement,
+  getWithdrawRequest,
+  initCollateralMintInstruction,
+  resetSettlementCollateralInstruction,
+  settlementCollateralAddress,
+} from '../../src'
+import {
+  BankrunExtendedProvider,
+  assertNotExist,
+  warpOffsetEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+  executeInitWithdrawRequestInstruction,
+} from '../utils/testTransactions'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import { createVoteAccount } from '../utils/staking'
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAccount as getTokenAccount,
+  getAssociatedTokenAddressSync,
+} from 'solana-spl-token-modern'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { createTokenMintAndFund } from '../utils/helpers'
+import {
+  MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+  createWithdrawerUsers,
+  totalClaimVoteAccount1,
+  treeNodesVoteAccount1,
+  withdrawer1,
+} from '../utils/merkleTreeTestData'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds token collateral', () => {
+  // 1 token (9 decimals) = 1.1 SOL, 10% haircut
+  const lamportsPerToken = 1_100_000_000
+  const haircutBps = 1_000
+  const tokenAmount = 10 * LAMPORTS_PER_SOL
+  const epochsToClaimSettlement = 1
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let validatorIdentity: Keypair
+  let voteAccount: PublicKey
+  let bondAccount: PublicKey
+  let mint: PublicKey
+  let tokenAccount: PublicKey
+  let collateralMintAccount: PublicKey
+  let collateralVault: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    await createWithdrawerUsers(provider)
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement,
+        withdrawLockupEpochs: 0,
+      }))
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+    ;({ mint, tokenAccount } = await createTokenMintAndFund({
+      provider,
+      owner: provider.walletPubkey,
+      amount: tokenAmount,
+    }))
+    const { instruction, collateralMintAccount: collateralMintAddr } =
+      await initCollateralMintInstruction({
+        program,
+        configAccount,
+        mint,
+        lamportsPerToken,
+        haircutBps,
+      })
+    await provider.sendIx([adminAuthority], instruction)
+    collateralMintAccount = collateralMintAddr
+    ;[collateralVault] = collateralVaultAddress(
+      bondAccount,
+      mint,
+      program.programId
+    )
+  })
+
+  it('init and configure collateral mint', async () => {
+    let collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount
+    )
+    expect(collateralMintData.config).toEqual(configAccount)
+    expect(collateralMintData.mint).toEqual(mint)
+    expect(collateralMintData.decimals).toEqual(9)
+    expect(collateralMintData.lamportsPerToken).toEqual(lamportsPerToken)
+    expect(collateralMintData.haircutBps).toEqual(haircutBps)
+
+    const { instruction } = await configureCollateralMintInstruction({
+      program,
+      collateralMintAccount,
+      newHaircutBps: 2_000,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+    collateralMintData = await getCollateralMint(program, collateralMintAccount)
+    expect(collateralMintData.lamportsPerToken).toEqual(lamportsPerToken)
+    expect(collateralMintData.haircutBps).toEqual(2_000)
+
+    const { instruction: haircutIx } = await configureCollateralMintInstruction(
+      {
+        program,
+        collateralMintAccount,
+        newHaircutBps: 10_001,
+      }
+    )
+    try {
+      await provider.sendIx([adminAuthority], haircutIx)
+      throw new Error('failure expected; haircut over 100%')
+    } catch (e) {
+      verifyError(e, Errors, 6074, 'haircut basis points exceeds')
+    }
+  })
+
+  it('cannot configure collateral mint with wrong admin', async () => {
+    const wrongAdmin = Keypair.generate()
+    const { instruction } = await configureCollateralMintInstruction({
+      program,
+      collateralMintAccount,
+      adminAuthority: wrongAdmin,
+      newLamportsPerToken: 1,
+    })
+    try {
+      await provider.sendIx([wrongAdmin], instruction)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'admin')
+    }
+  })
+
+  it('fund bond collateral and claim withdraw', async () => {
+    const { instruction: fundIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: tokenAmount,
+    })
+    await provider.sendIx([], fundIx)
+    expect(
+      (await getTokenAccount(provider.connection, collateralVault)).amount
+    ).toEqual(BigInt(tokenAmount))
+    expect(
+      (await getTokenAccount(provider.connection, tokenAccount)).amount
+    ).toEqual(0n)
+
+    const { withdrawRequestAccount } =
+      await executeInitWithdrawRequestInstruction({
+        program,
+        provider,
+        bondAccount,
+        configAccount,
+        validatorIdentity,
+        amount: 2.2 * LAMPORTS_PER_SOL,
+      })
+    await warpToNextEpoch(provider)
+
+    const withdrawerTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      validatorIdentity.publicKey
+    )
+    const { instruction } = await claimWithdrawCollateralInstruction({
+      program,
+      mint,
+      withdrawRequestAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx(
+      [validatorIdentity],
+      createAssociatedTokenAccountIdempotentInstruction(
+        provider.walletPubkey,
+        withdrawerTokenAccount,
+        validatorIdentity.publicKey,
+        mint
+      ),
+      instruction
+    )
+    // withdrawn at the market value, no haircut
+    expect(
+      (await getTokenAccount(provider.connection, withdrawerTokenAccount))
+        .amount
+    ).toEqual(BigInt(2 * LAMPORTS_PER_SOL))
+    expect(
+      (await getTokenAccount(provider.connection, collateralVault)).amount
+    ).toEqual(BigInt(8 * LAMPORTS_PER_SOL))
+    const withdrawRequestData = await getWithdrawRequest(
+      program,
+      withdrawRequestAccount
+    )
+    expect(withdrawRequestData.withdrawnAmount).toEqual(
+      2.2 * LAMPORTS_PER_SOL
+    )
+  })
+
+  it('cannot fund bond with zero collateral', async () => {
+    const { instruction } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: 0,
+    })
+    try {
+      await provider.sendIx([], instruction)
+      throw new Error('failure expected; zero amount')
+    } catch (e) {
+      verifyError(e, Errors, 6075, 'has got no value')
+    }
+  })
+
+  it('fund settlement collateral, claim and reset', async () => {
+    const { instruction: fundIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: tokenAmount,
+    })
+    await provider.sendIx([], fundIx)
+
+    const { settlementAccount, epoch: settlementEpoch } =
+      await executeInitSettlement({
+        program,
+        provider,
+        configAccount,
+        voteAccount,
+        operatorAuthority,
+        merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+        maxMerkleNodes: treeNodesVoteAccount1.length,
+        maxTotalClaim: totalClaimVoteAccount1,
+      })
+    const { instruction: fundSettlementIx, settlementCollateralVault } =
+      await fundSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+      })
+    await provider.sendIx([operatorAuthority], fundSettlementIx)
+    let settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.lamportsFunded).toEqual(totalClaimVoteAccount1)
+    expect(settlementData.collateralFunded).toEqual(totalClaimVoteAccount1)
+    // tokens are funded at the haircut value, rounded up
+    const fundedTokens = BigInt(
+      Math.ceil((totalClaimVoteAccount1.toNumber() * 100) / 99)
+    )
+    expect(
+      (await getTokenAccount(provider.connection, settlementCollateralVault))
+        .amount
+    ).toEqual(fundedTokens)
+    const [settlementCollateral] = settlementCollateralAddress(
+      settlementAccount,
+      mint,
+      program.programId
+    )
+    const settlementCollateralData =
+      await program.account.settlementCollateral.fetch(settlementCollateral)
+    expect(settlementCollateralData.tokensFunded.toString()).toEqual(
+      fundedTokens.toString()
+    )
+    expect(settlementCollateralData.lamportsFunded).toEqual(
+      totalClaimVoteAccount1
+    )
+
+    const treeNode = treeNodesVoteAccount1.find(item =>
+      item.treeNode.data.withdrawAuthority.equals(withdrawer1)
+    )!
+    const claimerTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      withdrawer1
+    )
+    const { instruction: claimIx } =
+      await claimSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+        claimAmount: treeNode.treeNode.data.claim,
+        merkleProof: treeNode.proof,
+        stakeAccountStaker: treeNode.treeNode.data.stakeAuthority,
+        stakeAccountWithdrawer: withdrawer1,
+      })
+    await provider.sendIx(
+      [],
+      createAssociatedTokenAccountIdempotentInstruction(
+        provider.walletPubkey,
+        claimerTokenAccount,
+        withdrawer1,
+        mint
+      ),
+      claimIx
+    )
+    // claims are paid pro rata to the funded tokens, rounded down
+    const claimedTokens =
+      (BigInt(treeNode.treeNode.data.claim.toString()) * fundedTokens) /
+      BigInt(totalClaimVoteAccount1.toString())
+    expect(
+      (await getTokenAccount(provider.connection, claimerTokenAccount)).amount
+    ).toEqual(claimedTokens)
+    settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.collateralClaimed).toEqual(
+      treeNode.treeNode.data.claim
+    )
+    const settlementCollateralData =
+      await program.account.settlementCollateral.fetch(settlementCollateral)
+    expect(settlementCollateralData.lamportsClaimed).toEqual(
+      treeNode.treeNode.data.claim
+    )
+    expect(settlementCollateralData.tokensClaimed.toString()).toEqual(
+      claimedTokens.toString()
+    )
+
+    // unclaimed collateral is returned to the bond after the settlement is closed
+    await warpOffsetEpoch(provider, epochsToClaimSettlement + 1)
+    const { instruction: closeIx } = await closeSettlementInstruction({
+      program,
+      settlementAccount,
+    })
+    await provider.sendIx([], closeIx)
+    const { instruction: resetIx } =
+      await resetSettlementCollateralInstruction({
+        program,
+        bondAccount,
+        mint,
+        settlementMerkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+        settlementEpoch,
+      })
+    await provider.sendIx([], resetIx)
+    assertNotExist(provider, settlementCollateralVault)
+    assertNotExist(provider, settlementCollateral)
+    expect(
+      (await getTokenAccount(provider.connection, collateralVault)).amount
+    ).toEqual(BigInt(tokenAmount) - claimedTokens)
+  })
+
+  it('claim settlement collateral pays the claimer fee in tokens', async () => {
+    const claimerFeeLamports = 10_000_000
+    const claimerFeeMaxBps = 100
+    const { instruction: configureIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newClaimerFeeLamports: claimerFeeLamports,
+      newClaimerFeeMaxBps: claimerFeeMaxBps,
+    })
+    await provider.sendIx([adminAuthority], configureIx)
+    const { instruction: fundIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: tokenAmount,
+    })
+    await provider.sendIx([], fundIx)
+    const { settlementAccount } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+      maxTotalClaim: totalClaimVoteAccount1,
+    })
+    const { instruction: fundSettlementIx, settlementCollateralVault } =
+      await fundSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+      })
+    await provider.sendIx([operatorAuthority], fundSettlementIx)
+    const fundedTokens = (
+      await getTokenAccount(provider.connection, settlementCollateralVault)
+    ).amount
+
+    const treeNode = treeNodesVoteAccount1.find(item =>
+      item.treeNode.data.withdrawAuthority.equals(withdrawer1)
+    )!
+    const claimerTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      withdrawer1
+    )
+    // the bond funding took all tokens of the rent payer
+    expect(
+      (await getTokenAccount(provider.connection, tokenAccount)).amount
+    ).toEqual(0n)
+    const { instruction: claimIx } =
+      await claimSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+        claimAmount: treeNode.treeNode.data.claim,
+        merkleProof: treeNode.proof,
+        stakeAccountStaker: treeNode.treeNode.data.stakeAuthority,
+        stakeAccountWithdrawer: withdrawer1,
+        rentPayerTokenAccount: tokenAccount,
+      })
+    await provider.sendIx(
+      [],
+      createAssociatedTokenAccountIdempotentInstruction(
+        provider.walletPubkey,
+        claimerTokenAccount,
+        withdrawer1,
+        mint
+      ),
+      claimIx
+    )
+    const claim = BigInt(treeNode.treeNode.data.claim.toString())
+    const claimedTokens =
+      (claim * fundedTokens) / BigInt(totalClaimVoteAccount1.toString())
+    const maxClaimerFee = (claim * BigInt(claimerFeeMaxBps)) / 10_000n
+    const claimerFee =
+      maxClaimerFee < BigInt(claimerFeeLamports)
+        ? maxClaimerFee
+        : BigInt(claimerFeeLamports)
+    const claimerFeeTokens = (claimedTokens * claimerFee) / claim
+    expect(claimerFeeTokens).toBeGreaterThan(0n)
+    expect(
+      (await getTokenAccount(provider.connection, tokenAccount)).amount
+    ).toEqual(claimerFeeTokens)
+    expect(
+      (await getTokenAccount(provider.connection, claimerTokenAccount)).amount
+    ).toEqual(claimedTokens - claimerFeeTokens)
+    expect(
+      (await getTokenAccount(provider.connection, settlementCollateralVault))
+        .amount
+    ).toEqual(fundedTokens - claimedTokens)
+  })
+
+  it('claim settlement collateral at the funded price', async () => {
+    const { instruction: fundIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: tokenAmount,
+    })
+    await provider.sendIx([], fundIx)
+    const { settlementAccount } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+      maxTotalClaim: totalClaimVoteAccount1,
+    })
+    const { instruction: fundSettlementIx, settlementCollateralVault } =
+      await fundSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+      })
+    await provider.sendIx([operatorAuthority], fundSettlementIx)
+    const fundedTokens = (
+      await getTokenAccount(provider.connection, settlementCollateralVault)
+    ).amount
+
+    // the collateral becomes valueless after the settlement was funded
+    const { instruction: configureIx } =
+      await configureCollateralMintInstruction({
+        program,
+        collateralMintAccount,
+        newHaircutBps: 10_000,
+      })
+    await provider.sendIx([adminAuthority], configureIx)
+
+    let claimedTokens = BigInt(0)
+    for (const treeNode of treeNodesVoteAccount1) {
+      const withdrawer = treeNode.treeNode.data.withdrawAuthority
+      const claimerTokenAccount = getAssociatedTokenAddressSync(
+        mint,
+        withdrawer
+      )
+      const { instruction: claimIx } =
+        await claimSettlementCollateralInstruction({
+          program,
+          settlementAccount,
+          mint,
+          claimAmount: treeNode.treeNode.data.claim,
+          merkleProof: treeNode.proof,
+          stakeAccountStaker: treeNode.treeNode.data.stakeAuthority,
+          stakeAccountWithdrawer: withdrawer,
+        })
+      await provider.sendIx(
+        [],
+        createAssociatedTokenAccountIdempotentInstruction(
+          provider.walletPubkey,
+          claimerTokenAccount,
+          withdrawer,
+          mint
+        ),
+        claimIx
+      )
+      const claimerTokens = (
+        await getTokenAccount(provider.connection, claimerTokenAccount)
+      ).amount
+      expect(claimerTokens).toBeGreaterThan(BigInt(0))
+      claimedTokens += claimerTokens
+    }
+    // all claims together take exactly the funded tokens
+    expect(claimedTokens).toEqual(fundedTokens)
+    expect(
+      (await getTokenAccount(provider.connection, settlementCollateralVault))
+        .amount
+    ).toEqual(BigInt(0))
+  })
+
+  it('cannot reset collateral of not closed settlement', async () => {
+    const { instruction: fundIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: tokenAmount,
+    })
+    await provider.sendIx([], fundIx)
+    const {
+      settlementAccount,
+      epoch: settlementEpoch,
+      merkleRoot,
+    } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+      maxTotalClaim: LAMPORTS_PER_SOL,
+    })
+    const { instruction: fundSettlementIx } =
+      await fundSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+      })
+    await provider.sendIx([operatorAuthority], fundSettlementIx)
+
+    const { instruction } = await resetSettlementCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      settlementMerkleRoot: merkleRoot,
+      settlementEpoch,
+    })
+    try {
+      await provider.sendIx([], instruction)
+      throw new Error('failure expected; settlement exists')
+    } catch (e) {
+      verifyError(e, Errors, 6027, 'has to be closed')
+    }
+  })
+})
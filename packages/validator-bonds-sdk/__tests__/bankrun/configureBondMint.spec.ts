@@ -1,6 +1,7 @@
 import {
   Errors,
   ValidatorBondsProgram,
+  configureBondAuthorityMintInstruction,
   configureBondWithMintInstruction,
   getBond,
   mintBondInstruction,
@@ -330,4 +331,187 @@ describe('Validator Bonds mint configure bond account', () => {
     mintData = await getMint(provider.connection, bondMint)
     expect(mintData.supply).toEqual(2)
   })
+
+  it('configure with bond authority mint held by a delegate', async () => {
+    const {
+      instruction: ixMint,
+      bondMint,
+      validatorIdentityTokenAccount,
+    } = await mintBondInstruction({
+      program,
+      bondAccount,
+      configAccount,
+      validatorIdentity: validatorIdentity.publicKey,
+    })
+    const { instruction: ixAuthorityMint } =
+      await configureBondAuthorityMintInstruction({
+        program,
+        bondAccount,
+        authority: bondAuthority,
+        enabled: true,
+      })
+    await provider.sendIx([bondAuthority], ixMint, ixAuthorityMint)
+    expect((await getBond(program, bondAccount)).authorityMint).toEqual(
+      bondMint
+    )
+
+    const delegate = signer(await createUserAndFund({ provider }))
+    const delegateTokenAccount = getAssociatedTokenAddressSync(
+      bondMint,
+      delegate.publicKey
+    )
+    await provider.sendIx(
+      [validatorIdentity],
+      createAssociatedTokenAccountInstruction(
+        provider.wallet.publicKey,
+        delegateTokenAccount,
+        delegate.publicKey,
+        bondMint
+      ),
+      createTransferInstruction(
+        validatorIdentityTokenAccount,
+        delegateTokenAccount,
+        validatorIdentity.publicKey,
+        1
+      )
+    )
+
+    const { instruction: ixConfigure } = await configureBondWithMintInstruction(
+      {
+        newCpmpe: 1,
+        program,
+        bondAccount,
+        tokenAuthority: delegate,
+      }
+    )
+    await provider.sendIx([delegate], ixConfigure)
+    expect((await getBond(program, bondAccount)).cpmpe).toEqual(1)
+    // the token is not burnt, the delegate may configure repeatedly
+    expect((await getMint(provider.connection, bondMint)).supply).toEqual(1)
+    expect(
+      (await getTokenAccount(provider.connection, delegateTokenAccount)).amount
+    ).toEqual(1)
+
+    // the credential persists over the validator identity change
+    const validatorIdentityNew = Keypair.generate()
+    await provider.sendIx(
+      [authorizedWithdrawer, validatorIdentityNew],
+      VoteProgram.updateValidatorIdentity({
+        votePubkey: voteAccount,
+        nodePubkey: validatorIdentityNew.publicKey,
+        authorizedWithdrawerPubkey: authorizedWithdrawer.publicKey,
+      })
+    )
+    const { instruction: ixConfigure2 } =
+      await configureBondWithMintInstruction({
+        newCpmpe: 2,
+        program,
+        bondAccount,
+        validatorIdentity: validatorIdentity.publicKey,
+        tokenAuthority: delegate,
+      })
+    await provider.sendIx([delegate], ixConfigure2)
+    expect((await getBond(program, bondAccount)).cpmpe).toEqual(2)
+  })
+
+  it('fail to configure with bond authority mint not held', async () => {
+    const { instruction: ixMint, bondMint } = await mintBondInstruction({
+      program,
+      bondAccount,
+      configAccount,
+      validatorIdentity: validatorIdentity.publicKey,
+    })
+    const { instruction: ixAuthorityMint } =
+      await configureBondAuthorityMintInstruction({
+        program,
+        bondAccount,
+        authority: validatorIdentity,
+        enabled: true,
+      })
+    await provider.sendIx([validatorIdentity], ixMint, ixAuthorityMint)
+
+    const user = signer(await createUserAndFund({ provider }))
+    const userTokenAccount = getAssociatedTokenAddressSync(
+      bondMint,
+      user.publicKey
+    )
+    await provider.sendIx(
+      [],
+      createAssociatedTokenAccountInstruction(
+        provider.wallet.publicKey,
+        userTokenAccount,
+        user.publicKey,
+        bondMint
+      )
+    )
+    const { instruction: ixConfigure } = await configureBondWithMintInstruction(
+      {
+        newCpmpe: 1,
+        program,
+        bondAccount,
+        tokenAuthority: user,
+      }
+    )
+    try {
+      await provider.sendIx([user], ixConfigure)
+      throw new Error('failure expected; bond authority mint token not held')
+    } catch (e) {
+      verifyError(e, Errors, 6084, 'does not hold the bond authority mint')
+    }
+  })
+
+  it('disable bond authority mint burns the token on configuration', async () => {
+    const { instruction: ixMint, bondMint } = await mintBondInstruction({
+      program,
+      bondAccount,
+      configAccount,
+      validatorIdentity: validatorIdentity.publicKey,
+    })
+    const { instruction: ixEnable } =
+      await configureBondAuthorityMintInstruction({
+        program,
+        bondAccount,
+        authority: bondAuthority,
+        enabled: true,
+      })
+    const { instruction: ixDisable } =
+      await configureBondAuthorityMintInstruction({
+        program,
+        bondAccount,
+        authority: bondAuthority,
+        enabled: false,
+      })
+    await provider.sendIx([bondAuthority], ixMint, ixEnable, ixDisable)
+    expect((await getBond(program, bondAccount)).authorityMint).toEqual(
+      PublicKey.default
+    )
+
+    const { instruction: ixConfigure } = await configureBondWithMintInstruction(
+      {
+        newCpmpe: 1,
+        program,
+        bondAccount,
+        tokenAuthority: validatorIdentity,
+      }
+    )
+    await provider.sendIx([validatorIdentity], ixConfigure)
+    expect((await getBond(program, bondAccount)).cpmpe).toEqual(1)
+    expect((await getMint(provider.connection, bondMint)).supply).toEqual(0)
+  })
+
+  it('fail to configure bond authority mint with wrong authority', async () => {
+    const wrongAuthority = Keypair.generate()
+    const { instruction } = await configureBondAuthorityMintInstruction({
+      program,
+      bondAccount,
+      authority: wrongAuthority,
+      enabled: true,
+    })
+    try {
+      await provider.sendIx([wrongAuthority], instruction)
+      throw new Error('failure expected; wrong authority')
+    } catch (e) {
+      verifyError(e, Errors, 6018, 'Wrong authority')
+    }
+  })
 })
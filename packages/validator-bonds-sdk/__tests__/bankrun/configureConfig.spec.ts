@@ -3,6 +3,8 @@ import {
   ValidatorBondsProgram,
   getConfig,
   configureConfigInstruction,
+  acceptConfigAuthorityInstruction,
+  applyConfigChangesInstruction,
   Errors,
 } from '../../src'
 import {
@@ -10,6 +12,8 @@ import {
   bankrunExecute,
   bankrunExecuteIx,
   bankrunTransaction,
+  currentEpoch,
+  warpToEpoch,
 } from '@marinade.finance/bankrun-utils'
 import { ProgramAccount } from '@coral-xyz/anchor'
 import {
@@ -73,7 +77,9 @@ describe('Validator Bonds configure config tests', () => {
       newMinBondMaxStakeWanted: LAMPORTS_PER_SOL * 10_000,
     })
     const config = await getConfig(program, configInitialized.publicKey)
-    expect(config.adminAuthority).toEqual(newAdminAuthority.publicKey)
+    // admin is only proposed, params are changed immediately without the delay
+    expect(config.adminAuthority).toEqual(adminAuthority.publicKey)
+    expect(config.pendingAdminAuthority).toEqual(newAdminAuthority.publicKey)
     expect(config.operatorAuthority).toEqual(
       configInitialized.account.operatorAuthority
     )
@@ -84,14 +90,30 @@ describe('Validator Bonds configure config tests', () => {
     )
     expect(config.minBondMaxStakeWanted).toEqual(LAMPORTS_PER_SOL * 10_000)
 
-    const pauseAuthority = PublicKey.unique()
+    const { instruction: acceptAdminIx } =
+      await acceptConfigAuthorityInstruction({
+        program,
+        configAccount: configInitialized.publicKey,
+        newAuthority: newAdminAuthority,
+      })
+    await bankrunExecuteIx(
+      provider,
+      [provider.wallet, newAdminAuthority],
+      acceptAdminIx
+    )
+    const configAccepted = await getConfig(program, configInitialized.publicKey)
+    expect(configAccepted.adminAuthority).toEqual(newAdminAuthority.publicKey)
+    expect(configAccepted.pendingAdminAuthority).toEqual(PublicKey.default)
+
+    const newOperatorAuthority = Keypair.generate()
+    const pauseAuthority = Keypair.generate()
     const { instruction: instruction2 } = await configureConfigInstruction({
       program,
       configAccount: configInitialized.publicKey,
       newEpochsToClaimSettlement: 3,
       newWithdrawLockupEpochs: 4,
-      newOperator: PublicKey.default,
-      newPauseAuthority: pauseAuthority,
+      newOperator: newOperatorAuthority.publicKey,
+      newPauseAuthority: pauseAuthority.publicKey,
     })
     await bankrunExecuteIx(
       provider,
@@ -100,13 +122,38 @@ describe('Validator Bonds configure config tests', () => {
     )
     const config2 = await getConfig(program, configInitialized.publicKey)
     expect(config2.adminAuthority).toEqual(newAdminAuthority.publicKey)
-    expect(config2.operatorAuthority).toEqual(PublicKey.default)
-    expect(config2.pauseAuthority).toEqual(pauseAuthority)
+    expect(config2.operatorAuthority).toEqual(
+      configInitialized.account.operatorAuthority
+    )
+    expect(config2.pendingOperatorAuthority).toEqual(
+      newOperatorAuthority.publicKey
+    )
+    expect(config2.pendingPauseAuthority).toEqual(pauseAuthority.publicKey)
     expect(config2.paused).toBeFalsy()
     expect(config2.epochsToClaimSettlement).toEqual(3)
     expect(config2.slotsToStartSettlementClaiming).toEqual(10)
     expect(config2.withdrawLockupEpochs).toEqual(4)
     expect(config.minBondMaxStakeWanted).toEqual(LAMPORTS_PER_SOL * 10_000)
+
+    for (const newAuthority of [newOperatorAuthority, pauseAuthority]) {
+      const { instruction: acceptIx } = await acceptConfigAuthorityInstruction(
+        {
+          program,
+          configAccount: configInitialized.publicKey,
+          newAuthority,
+        }
+      )
+      await bankrunExecuteIx(
+        provider,
+        [provider.wallet, newAuthority],
+        acceptIx
+      )
+    }
+    const config3 = await getConfig(program, configInitialized.publicKey)
+    expect(config3.operatorAuthority).toEqual(newOperatorAuthority.publicKey)
+    expect(config3.pauseAuthority).toEqual(pauseAuthority.publicKey)
+    expect(config3.pendingOperatorAuthority).toEqual(PublicKey.default)
+    expect(config3.pendingPauseAuthority).toEqual(PublicKey.default)
   })
 
   it('configure config wrong keys', async () => {
@@ -134,6 +181,174 @@ describe('Validator Bonds configure config tests', () => {
     }
   })
 
+  it('accept config authority not proposed', async () => {
+    const newAdminAuthority = Keypair.generate()
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      newAdmin: newAdminAuthority.publicKey,
+    })
+
+    const randomKey = Keypair.generate()
+    const { instruction } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount: configInitialized.publicKey,
+      newAuthority: randomKey,
+    })
+    try {
+      await bankrunExecuteIx(
+        provider,
+        [provider.wallet, randomKey],
+        instruction
+      )
+      throw new Error('failure expected as authority not proposed')
+    } catch (e) {
+      verifyError(e, Errors, 6064, 'No pending authority change')
+    }
+
+    // cancelling the proposal, the proposed admin cannot accept
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      cancelPendingChanges: true,
+    })
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount: configInitialized.publicKey,
+      newAuthority: newAdminAuthority,
+    })
+    try {
+      await bankrunExecuteIx(
+        provider,
+        [provider.wallet, newAdminAuthority],
+        acceptIx
+      )
+      throw new Error('failure expected as proposal was cancelled')
+    } catch (e) {
+      verifyError(e, Errors, 6064, 'No pending authority change')
+    }
+    const config = await getConfig(program, configInitialized.publicKey)
+    expect(config.adminAuthority).toEqual(adminAuthority.publicKey)
+  })
+
+  it('apply staged config changes at the effective epoch', async () => {
+    const configChangeDelayEpochs = 2
+    // delay is not configured yet, the change is applied immediately
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      newConfigChangeDelayEpochs: configChangeDelayEpochs,
+    })
+    let config = await getConfig(program, configInitialized.publicKey)
+    expect(config.configChangeDelayEpochs).toEqual(configChangeDelayEpochs)
+    expect(config.pendingConfigChanges.stagedParams).toEqual(0)
+
+    const stagedAtEpoch = await currentEpoch(provider)
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      newEpochsToClaimSettlement: 5,
+      newWithdrawLockupEpochs: 6,
+      newBidMaxDelegatedLamports: 1000,
+      newMaxClaimExpiryExtension: 3,
+    })
+    config = await getConfig(program, configInitialized.publicKey)
+    expect(config.epochsToClaimSettlement).toEqual(1)
+    expect(config.withdrawLockupEpochs).toEqual(2)
+    expect(config.bidMaxDelegatedLamports).toEqual(0)
+    expect(config.maxClaimExpiryExtension).toEqual(0)
+    expect(config.pendingConfigChanges.stagedParams).not.toEqual(0)
+    expect(config.pendingConfigChanges.effectiveEpoch).toEqual(
+      stagedAtEpoch + configChangeDelayEpochs
+    )
+    expect(config.pendingConfigChanges.epochsToClaimSettlement).toEqual(5)
+    expect(config.pendingConfigChanges.withdrawLockupEpochs).toEqual(6)
+    expect(config.pendingConfigChanges.bidMaxDelegatedLamports).toEqual(1000)
+    expect(config.pendingConfigChanges.maxClaimExpiryExtension).toEqual(3)
+
+    const { instruction: applyIx } = await applyConfigChangesInstruction({
+      program,
+      configAccount: configInitialized.publicKey,
+    })
+    try {
+      await bankrunExecuteIx(provider, [provider.wallet], applyIx)
+      throw new Error('failure expected as changes are not effective')
+    } catch (e) {
+      verifyError(e, Errors, 6066, 'not effective yet')
+    }
+
+    // one epoch before the effective epoch the changes cannot be applied
+    warpToEpoch(provider, stagedAtEpoch + configChangeDelayEpochs - 1)
+    try {
+      await bankrunExecuteIx(provider, [provider.wallet], applyIx)
+      throw new Error('failure expected as changes are not effective')
+    } catch (e) {
+      verifyError(e, Errors, 6066, 'not effective yet')
+    }
+
+    warpToEpoch(provider, stagedAtEpoch + configChangeDelayEpochs)
+    await bankrunExecuteIx(provider, [provider.wallet], applyIx)
+    config = await getConfig(program, configInitialized.publicKey)
+    expect(config.epochsToClaimSettlement).toEqual(5)
+    expect(config.withdrawLockupEpochs).toEqual(6)
+    expect(config.bidMaxDelegatedLamports).toEqual(1000)
+    expect(config.maxClaimExpiryExtension).toEqual(3)
+    expect(config.slotsToStartSettlementClaiming).toEqual(
+      configInitialized.account.slotsToStartSettlementClaiming
+    )
+    expect(config.pendingConfigChanges.stagedParams).toEqual(0)
+
+    // nothing staged to be applied again
+    // pushing clock forward to get new latest blockhash from the client
+    provider.context.warpToSlot(
+      (await provider.context.banksClient.getClock()).slot + BigInt(1)
+    )
+    try {
+      await bankrunExecuteIx(provider, [provider.wallet], applyIx)
+      throw new Error('failure expected as nothing is staged')
+    } catch (e) {
+      verifyError(e, Errors, 6065, 'No pending config changes')
+    }
+  })
+
+  it('cancel staged config changes', async () => {
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      newConfigChangeDelayEpochs: 1,
+    })
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      newWithdrawLockupEpochs: 10,
+    })
+    let config = await getConfig(program, configInitialized.publicKey)
+    expect(config.pendingConfigChanges.stagedParams).not.toEqual(0)
+
+    await executeConfigureConfigInstruction({
+      program,
+      provider,
+      configAccount: configInitialized.publicKey,
+      adminAuthority,
+      cancelPendingChanges: true,
+    })
+    config = await getConfig(program, configInitialized.publicKey)
+    expect(config.pendingConfigChanges.stagedParams).toEqual(0)
+    expect(config.withdrawLockupEpochs).toEqual(2)
+  })
+
   async function getConfigureConfigTx(
     adminAuthority?: PublicKey
   ): Promise<Transaction> {
@@ -0,0 +1,326 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  amendSettlementInstruction,
+  claimSettlementCollateralInstruction,
+  disputeSettlementInstruction,
+  fundBondCollateralInstruction,
+  fundSettlementCollateralInstruction,
+  getSettlement,
+  initCollateralMintInstruction,
+  resolveDisputeInstruction,
+} from '../../src'
+import { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import { createVoteAccount } from '../utils/staking'
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+} from 'solana-spl-token-modern'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { createTokenMintAndFund } from '../utils/helpers'
+import {
+  MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+  MERKLE_ROOT_VOTE_ACCOUNT_2_BUF,
+  createWithdrawerUsers,
+  totalClaimVoteAccount1,
+  treeNodesVoteAccount1,
+  withdrawer1,
+} from '../utils/merkleTreeTestData'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds dispute settlement', () => {
+  const slotsToStartSettlementClaiming = 10
+  const reasonHash = Buffer.alloc(32, 7)
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let validatorIdentity: Keypair
+  let bondAuthority: Keypair
+  let settlementAccount: PublicKey
+  let mint: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+    await createWithdrawerUsers(provider)
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement: 4,
+        slotsToStartSettlementClaiming,
+      }))
+    let voteAccount: PublicKey
+    ;({ voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    }))
+    let bondAccount: PublicKey
+    ;({ bondAccount, bondAuthority } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+    ;({ settlementAccount } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_1_BUF,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+      maxTotalClaim: totalClaimVoteAccount1,
+    }))
+
+    // the claims are paid from the token collateral funded to the settlement
+    ;({ mint } = await createTokenMintAndFund({
+      provider,
+      owner: provider.walletPubkey,
+      amount: LAMPORTS_PER_SOL,
+    }))
+    const { instruction: initMintIx } = await initCollateralMintInstruction({
+      program,
+      configAccount,
+      mint,
+      lamportsPerToken: LAMPORTS_PER_SOL,
+      haircutBps: 0,
+    })
+    const { instruction: fundBondIx } = await fundBondCollateralInstruction({
+      program,
+      bondAccount,
+      mint,
+      amount: LAMPORTS_PER_SOL,
+    })
+    const { instruction: fundSettlementIx } =
+      await fundSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+      })
+    await provider.sendIx(
+      [adminAuthority, operatorAuthority],
+      initMintIx,
+      fundBondIx,
+      fundSettlementIx
+    )
+  })
+
+  it('dispute settlement blocks claiming until resolved', async () => {
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    let settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.disputed).toEqual(true)
+    expect(settlementData.disputeReasonHash).toEqual(Array.from(reasonHash))
+
+    await warpOffsetSlots(slotsToStartSettlementClaiming + 1)
+    const treeNode = treeNodesVoteAccount1.find(item =>
+      item.treeNode.data.withdrawAuthority.equals(withdrawer1)
+    )!
+    const { instruction: claimIx, claimerTokenAccount } =
+      await claimSettlementCollateralInstruction({
+        program,
+        settlementAccount,
+        mint,
+        claimAmount: treeNode.treeNode.data.claim,
+        merkleProof: treeNode.proof,
+        stakeAccountStaker: treeNode.treeNode.data.stakeAuthority,
+        stakeAccountWithdrawer: withdrawer1,
+      })
+    const createTokenAccountIx =
+      createAssociatedTokenAccountIdempotentInstruction(
+        provider.walletPubkey,
+        claimerTokenAccount,
+        withdrawer1,
+        mint
+      )
+    try {
+      await provider.sendIx([], createTokenAccountIx, claimIx)
+      throw new Error('failure expected; settlement disputed')
+    } catch (e) {
+      verifyError(e, Errors, 6080, 'Settlement is disputed')
+    }
+
+    const { instruction: resolveIx } = await resolveDisputeInstruction({
+      program,
+      settlementAccount,
+    })
+    await provider.sendIx([adminAuthority], resolveIx)
+    settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.disputed).toEqual(false)
+    // the reason hash is kept for the record
+    expect(settlementData.disputeReasonHash).toEqual(Array.from(reasonHash))
+
+    await provider.sendIx([], createTokenAccountIx, claimIx)
+    expect(
+      (await getSettlement(program, settlementAccount)).merkleNodesClaimed
+    ).toEqual(1)
+  })
+
+  it('cannot dispute settlement twice', async () => {
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash,
+      authority: bondAuthority,
+    })
+    await provider.sendIx([bondAuthority], instruction)
+
+    const { instruction: disputeAgainIx } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash: Buffer.alloc(32, 8),
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], disputeAgainIx)
+      throw new Error('failure expected; already disputed')
+    } catch (e) {
+      verifyError(e, Errors, 6081, 'already disputed')
+    }
+  })
+
+  it('cannot dispute settlement with empty reason hash', async () => {
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash: Buffer.alloc(32),
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], instruction)
+      throw new Error('failure expected; empty reason hash')
+    } catch (e) {
+      verifyError(e, Errors, 6098, 'reason hash cannot be empty')
+    }
+  })
+
+  it('dispute settlement again after amendment', async () => {
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    const { instruction: resolveIx } = await resolveDisputeInstruction({
+      program,
+      settlementAccount,
+    })
+    await provider.sendIx([adminAuthority], resolveIx)
+
+    const { instruction: amendIx } = await amendSettlementInstruction({
+      program,
+      settlementAccount,
+      merkleRoot: MERKLE_ROOT_VOTE_ACCOUNT_2_BUF,
+      maxTotalClaim: totalClaimVoteAccount1,
+      maxMerkleNodes: treeNodesVoteAccount1.length,
+    })
+    await provider.sendIx([operatorAuthority], amendIx)
+    let settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.disputed).toEqual(false)
+    expect(settlementData.disputeReasonHash).toEqual(Array(32).fill(0))
+
+    const amendedReasonHash = Buffer.alloc(32, 9)
+    const { instruction: disputeAmendedIx } =
+      await disputeSettlementInstruction({
+        program,
+        settlementAccount,
+        reasonHash: amendedReasonHash,
+        authority: validatorIdentity,
+      })
+    await provider.sendIx([validatorIdentity], disputeAmendedIx)
+    settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.disputed).toEqual(true)
+    expect(settlementData.disputeReasonHash).toEqual(
+      Array.from(amendedReasonHash)
+    )
+  })
+
+  it('cannot dispute settlement after the dispute window', async () => {
+    await warpOffsetSlots(slotsToStartSettlementClaiming + 1)
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], instruction)
+      throw new Error('failure expected; dispute window closed')
+    } catch (e) {
+      verifyError(e, Errors, 6079, 'dispute window has already passed')
+    }
+  })
+
+  it('cannot dispute settlement with wrong authority', async () => {
+    const wrongAuthority = Keypair.generate()
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash,
+      authority: wrongAuthority,
+    })
+    try {
+      await provider.sendIx([wrongAuthority], instruction)
+      throw new Error('failure expected; wrong authority')
+    } catch (e) {
+      verifyError(e, Errors, 6018, 'Wrong authority')
+    }
+  })
+
+  it('cannot resolve not disputed settlement', async () => {
+    const { instruction } = await resolveDisputeInstruction({
+      program,
+      settlementAccount,
+    })
+    try {
+      await provider.sendIx([adminAuthority], instruction)
+      throw new Error('failure expected; not disputed')
+    } catch (e) {
+      verifyError(e, Errors, 6082, 'is not disputed')
+    }
+  })
+
+  it('cannot resolve dispute with wrong admin', async () => {
+    const { instruction } = await disputeSettlementInstruction({
+      program,
+      settlementAccount,
+      reasonHash,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+
+    const wrongAdmin = Keypair.generate()
+    const { instruction: resolveIx } = await resolveDisputeInstruction({
+      program,
+      settlementAccount,
+      adminAuthority: wrongAdmin,
+    })
+    try {
+      await provider.sendIx([wrongAdmin], resolveIx)
+      throw new Error('failure expected; wrong admin')
+    } catch (e) {
+      verifyError(e, Errors, 6001, 'admin')
+    }
+  })
+
+  async function warpOffsetSlots(slots: number) {
+    const clock = await provider.context.banksClient.getClock()
+    provider.context.warpToSlot(clock.slot + BigInt(slots))
+  }
+})
@@ -7,6 +7,7 @@ import {
   closeSettlementInstruction,
   configureBondInstruction,
   configureConfigInstruction,
+  acceptConfigAuthorityInstruction,
   emergencyPauseInstruction,
   emergencyResumeInstruction,
   fundBondInstruction,
@@ -21,6 +22,9 @@ import {
   configureBondWithMintInstruction,
   withdrawStakeInstruction,
   cancelSettlementInstruction,
+  setPauseFlagsInstruction,
+  PAUSE_FLAGS,
+  PAUSE_FLAGS_ALL,
 } from '../../src'
 import {
   BankrunExtendedProvider,
@@ -28,7 +32,10 @@ import {
   warpOffsetEpoch,
   warpToNextEpoch,
 } from '@marinade.finance/bankrun-utils'
-import { executeInitConfigInstruction } from '../utils/testTransactions'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
 import {
   Keypair,
   LAMPORTS_PER_SOL,
@@ -91,7 +98,12 @@ describe('Validator Bonds pause&resume', () => {
       configAccount,
       newPauseAuthority: pauseAuthority.publicKey,
     })
-    await provider.sendIx([adminAuthority], configIx)
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount,
+      newAuthority: pauseAuthority,
+    })
+    await provider.sendIx([adminAuthority, pauseAuthority], configIx, acceptIx)
     ;({ validatorIdentity } = await createVoteAccount({
       voteAccount: voteAccount1Keypair,
       provider,
@@ -109,7 +121,12 @@ describe('Validator Bonds pause&resume', () => {
       configAccount,
       newOperator: adminAuthority.publicKey,
     })
-    await provider.sendIx([adminAuthority], configIx)
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount,
+      newAuthority: adminAuthority,
+    })
+    await provider.sendIx([adminAuthority], configIx, acceptIx)
     configData = await getConfig(program, configAccount)
     expect(configData.operatorAuthority).toEqual(adminAuthority.publicKey)
 
@@ -409,6 +426,78 @@ describe('Validator Bonds pause&resume', () => {
     await provider.sendIx([adminAuthority], withdrawIx)
   })
 
+  it('pause and resume instruction families independently', async () => {
+    const { bondAccount, bondAuthority, voteAccount } =
+      await executeInitBondInstruction({
+        program,
+        provider,
+        configAccount,
+      })
+    const { instruction: configBondIx } = await configureBondInstruction({
+      program,
+      bondAccount,
+      newCpmpe: 33,
+      authority: bondAuthority.publicKey,
+    })
+    const { instruction: initWithdrawIx } =
+      await initWithdrawRequestInstruction({
+        program,
+        configAccount,
+        bondAccount,
+        voteAccount,
+        amount: LAMPORTS_PER_SOL,
+        authority: bondAuthority,
+      })
+
+    let configData = await setPauseFlags(PAUSE_FLAGS.bond)
+    expect(configData.pauseFlags).toEqual(PAUSE_FLAGS.bond)
+    expect(configData.paused).toEqual(true)
+    await verifyIsPaused([bondAuthority], configBondIx)
+    // withdraw instruction family is not paused
+    await provider.sendIx([bondAuthority], initWithdrawIx)
+
+    try {
+      await setPauseFlags(PAUSE_FLAGS.bond)
+      throw new Error('Failure expected; pause flags are not changed')
+    } catch (e) {
+      verifyError(e, Errors, 6089, 'Pause flags are already set')
+    }
+    try {
+      await setPauseFlags(PAUSE_FLAGS_ALL + 1)
+      throw new Error('Failure expected; pause flags are invalid')
+    } catch (e) {
+      verifyError(e, Errors, 6067, 'Invalid pause flags')
+    }
+
+    configData = await setPauseFlags(0)
+    expect(configData.pauseFlags).toEqual(0)
+    expect(configData.paused).toEqual(false)
+    await provider.sendIx([bondAuthority], configBondIx)
+
+    // emergency pause is the same as pausing all instruction families
+    configData = await pause()
+    expect(configData.pauseFlags).toEqual(PAUSE_FLAGS_ALL)
+    try {
+      await setPauseFlags(PAUSE_FLAGS_ALL)
+      throw new Error('Failure expected; pause flags are not changed')
+    } catch (e) {
+      verifyError(e, Errors, 6089, 'Pause flags are already set')
+    }
+    await resume()
+  })
+
+  async function setPauseFlags(pauseFlags: number): Promise<Config> {
+    const { instruction } = await setPauseFlagsInstruction({
+      program,
+      configAccount,
+      pauseAuthority: pauseAuthority.publicKey,
+      pauseFlags,
+    })
+    await warpToNextEpoch(provider)
+    await provider.sendIx([pauseAuthority], instruction)
+    return await getConfig(program, configAccount)
+  }
+
   async function pause(isWarp = true): Promise<Config> {
     const { instruction } = await emergencyPauseInstruction({
       program,
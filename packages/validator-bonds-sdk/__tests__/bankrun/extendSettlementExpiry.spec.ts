@@ -0,0 +1,154 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  closeSettlementInstruction,
+  configureConfigInstruction,
+  extendSettlementExpiryInstruction,
+  getSettlement,
+} from '../../src'
+import {
+  BankrunExtendedProvider,
+  assertNotExist,
+  warpOffsetEpoch,
+} from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+  executeInitSettlement,
+} from '../utils/testTransactions'
+import { Keypair, PublicKey } from '@solana/web3.js'
+import { createVoteAccount } from '../utils/staking'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds extend settlement expiry', () => {
+  const epochsToClaimSettlement = 1
+  const maxClaimExpiryExtension = 3
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let adminAuthority: Keypair
+  let operatorAuthority: Keypair
+  let settlementAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount, adminAuthority, operatorAuthority } =
+      await executeInitConfigInstruction({
+        program,
+        provider,
+        epochsToClaimSettlement,
+      }))
+    const { instruction: configureIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newMaxClaimExpiryExtension: maxClaimExpiryExtension,
+    })
+    await provider.sendIx([adminAuthority], configureIx)
+
+    const { voteAccount, validatorIdentity } = await createVoteAccount({
+      provider,
+    })
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    })
+    ;({ settlementAccount } = await executeInitSettlement({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      operatorAuthority,
+    }))
+  })
+
+  it('extend settlement expiry postpones the settlement close', async () => {
+    const { instruction } = await extendSettlementExpiryInstruction({
+      program,
+      settlementAccount,
+      epochs: 2,
+    })
+    await provider.sendIx([operatorAuthority], instruction)
+    expect(
+      (await getSettlement(program, settlementAccount)).claimExpiryExtension
+    ).toEqual(2)
+
+    const { instruction: closeIx } = await closeSettlementInstruction({
+      program,
+      settlementAccount,
+    })
+    await warpOffsetEpoch(provider, epochsToClaimSettlement + 1)
+    try {
+      await provider.sendIx([], closeIx)
+      throw new Error('failure expected; settlement expiry extended')
+    } catch (e) {
+      verifyError(e, Errors, 6022, 'has not expired yet')
+    }
+
+    await warpOffsetEpoch(provider, 2)
+    await provider.sendIx([], closeIx)
+    assertNotExist(provider, settlementAccount)
+  })
+
+  it('extend settlement expiry by admin up to the config maximum', async () => {
+    const { instruction } = await extendSettlementExpiryInstruction({
+      program,
+      settlementAccount,
+      epochs: maxClaimExpiryExtension,
+      authority: adminAuthority,
+    })
+    await provider.sendIx([adminAuthority], instruction)
+    expect(
+      (await getSettlement(program, settlementAccount)).claimExpiryExtension
+    ).toEqual(maxClaimExpiryExtension)
+
+    const { instruction: extendAgainIx } =
+      await extendSettlementExpiryInstruction({
+        program,
+        settlementAccount,
+        epochs: 1,
+      })
+    try {
+      await provider.sendIx([operatorAuthority], extendAgainIx)
+      throw new Error('failure expected; config maximum exceeded')
+    } catch (e) {
+      verifyError(e, Errors, 6094, 'exceeds the config maximum')
+    }
+  })
+
+  it('cannot extend settlement expiry by zero epochs', async () => {
+    const { instruction } = await extendSettlementExpiryInstruction({
+      program,
+      settlementAccount,
+      epochs: 0,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], instruction)
+      throw new Error('failure expected; zero epochs')
+    } catch (e) {
+      verifyError(e, Errors, 6085, 'positive number of epochs')
+    }
+  })
+
+  it('cannot extend settlement expiry with wrong authority', async () => {
+    const wrongAuthority = Keypair.generate()
+    const { instruction } = await extendSettlementExpiryInstruction({
+      program,
+      settlementAccount,
+      epochs: 1,
+      authority: wrongAuthority,
+    })
+    try {
+      await provider.sendIx([wrongAuthority], instruction)
+      throw new Error('failure expected; wrong authority')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+  })
+})
@@ -2,6 +2,7 @@ import {
   Errors,
   ValidatorBondsProgram,
   closeSettlementInstruction,
+  configureConfigInstruction,
   fundSettlementInstruction,
   getConfig,
   getSettlement,
@@ -53,6 +54,7 @@ describe('Validator Bonds fund settlement', () => {
   let program: ValidatorBondsProgram
   let configAccount: PublicKey
   let operatorAuthority: Keypair
+  let adminAuthority: Keypair
   let validatorIdentity: Keypair
   let bondAccount: PublicKey
   let voteAccount: PublicKey
@@ -67,13 +69,12 @@ describe('Validator Bonds fund settlement', () => {
   })
 
   beforeEach(async () => {
-    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
-      {
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
         program,
         provider,
         epochsToClaimSettlement,
-      }
-    ))
+      }))
     const config = await getConfig(program, configAccount)
 
     ;({ voteAccount, validatorIdentity } = await createVoteAccount({
@@ -539,6 +540,60 @@ describe('Validator Bonds fund settlement', () => {
     ).toEqual(0)
   })
 
+  it('fund settlement with settlement funder role', async () => {
+    const { settlementAccount } = await executeInitSettlement({
+      configAccount,
+      program,
+      provider,
+      voteAccount,
+      operatorAuthority,
+      currentEpoch: await currentEpoch(provider),
+      rentCollector: rentCollector.publicKey,
+      maxTotalClaim: 3 * LAMPORTS_PER_SOL,
+    })
+    const settlementFunder = Keypair.generate()
+    const { instruction: configIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newSettlementFunder: settlementFunder.publicKey,
+    })
+    await provider.sendIx([adminAuthority], configIx)
+    const stakeAccount = await createBondsFundedStakeAccountActivated(
+      5 * LAMPORTS_PER_SOL
+    )
+
+    const { instruction: operatorIx, splitStakeAccount: operatorSplit } =
+      await fundSettlementInstruction({
+        program,
+        settlementAccount,
+        stakeAccount,
+        operatorAuthority,
+      })
+    try {
+      await provider.sendIx(
+        [operatorAuthority, signer(operatorSplit)],
+        operatorIx
+      )
+      throw new Error('cannot fund as operator not holding the role')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+
+    // the role authority is loaded from config by default
+    const { instruction, splitStakeAccount } = await fundSettlementInstruction({
+      program,
+      settlementAccount,
+      stakeAccount,
+    })
+    await provider.sendIx(
+      [settlementFunder, signer(splitStakeAccount)],
+      instruction
+    )
+    expect(
+      (await getSettlement(program, settlementAccount)).lamportsFunded
+    ).toBeGreaterThan(0)
+  })
+
   it('cannot fund already funded', async () => {
     const maxTotalClaim = 3 * LAMPORTS_PER_SOL
     const { settlementAccount } = await executeInitSettlement({
@@ -2,6 +2,7 @@ import {
   Bond,
   Errors,
   ValidatorBondsProgram,
+  configureConfigInstruction,
   getBond,
   getSettlement,
   initSettlementInstruction,
@@ -29,6 +30,7 @@ describe('Validator Bonds init settlement', () => {
   let configAccount: PublicKey
   let bond: ProgramAccount<Bond>
   let operatorAuthority: Keypair
+  let adminAuthority: Keypair
   let validatorIdentity: Keypair
   let voteAccount: PublicKey
 
@@ -37,12 +39,11 @@ describe('Validator Bonds init settlement', () => {
   })
 
   beforeEach(async () => {
-    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
-      {
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
         program,
         provider,
-      }
-    ))
+      }))
     ;({ voteAccount, validatorIdentity } = await createVoteAccount({
       provider,
     }))
@@ -188,4 +189,51 @@ describe('Validator Bonds init settlement', () => {
     }
     assertNotExist(provider, settlementAccount)
   })
+
+  it('init settlement with settlement creator role', async () => {
+    const settlementCreator = Keypair.generate()
+    const { instruction: configIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newSettlementCreator: settlementCreator.publicKey,
+    })
+    await provider.sendIx([adminAuthority], configIx)
+
+    const merkleRoot = Buffer.alloc(32, 1)
+    const epoch = await currentEpoch(provider)
+    const { instruction: operatorIx, settlementAccount } =
+      await initSettlementInstruction({
+        program,
+        bondAccount: bond.publicKey,
+        operatorAuthority,
+        merkleRoot,
+        maxMerkleNodes: 1,
+        maxTotalClaim: 3,
+        voteAccount,
+        epoch,
+        configAccount,
+      })
+    try {
+      await provider.sendIx([operatorAuthority], operatorIx)
+      throw new Error('failure; expected operator not holding the role')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+    assertNotExist(provider, settlementAccount)
+
+    // the role authority is loaded from config by default
+    const { instruction } = await initSettlementInstruction({
+      program,
+      bondAccount: bond.publicKey,
+      merkleRoot,
+      maxMerkleNodes: 1,
+      maxTotalClaim: 3,
+      voteAccount,
+      epoch,
+      configAccount,
+    })
+    await provider.sendIx([settlementCreator], instruction)
+    const settlementData = await getSettlement(program, settlementAccount)
+    expect(settlementData.bond).toEqual(bond.publicKey)
+  })
 })
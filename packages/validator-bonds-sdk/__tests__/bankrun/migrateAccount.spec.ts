@@ -0,0 +1,122 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  getBond,
+  migrateAccountInstruction,
+} from '../../src'
+import { BankrunExtendedProvider } from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
+import { LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import {
+  createUserAndFund,
+  pubkey,
+  signer,
+} from '@marinade.finance/web3js-common'
+import { verifyError } from '@marinade.finance/anchor-common'
+import assert from 'assert'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds migrate account', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let bondAccount: PublicKey
+  let voteAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+    }))
+    ;({ bondAccount, voteAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+    }))
+  })
+
+  it('migrate bond account of the previous version', async () => {
+    await setBondAccountVersion(0)
+    expect((await getBond(program, bondAccount)).accountVersion).toEqual(0)
+    const bondDataBefore = await getBond(program, bondAccount)
+
+    const rentPayer = await createUserAndFund({
+      provider,
+      lamports: LAMPORTS_PER_SOL,
+    })
+    const { instruction } = await migrateAccountInstruction({
+      program,
+      account: bondAccount,
+      rentPayer,
+    })
+    await provider.sendIx([signer(rentPayer)], instruction)
+
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.accountVersion).toEqual(1)
+    expect(bondData.voteAccount).toEqual(bondDataBefore.voteAccount)
+    expect(bondData.authority).toEqual(bondDataBefore.authority)
+    expect(bondData.cpmpe).toEqual(bondDataBefore.cpmpe)
+
+    // the same layout, nothing to be paid by the rent payer
+    expect(
+      (await provider.connection.getAccountInfo(pubkey(rentPayer)))?.lamports
+    ).toEqual(LAMPORTS_PER_SOL)
+
+    const { instruction: migrateAgainIx } = await migrateAccountInstruction({
+      program,
+      account: bondAccount,
+    })
+    try {
+      await provider.sendIx([], migrateAgainIx)
+      throw new Error('failure expected; account migrated twice')
+    } catch (e) {
+      verifyError(e, Errors, 6088, 'already migrated')
+    }
+  })
+
+  it('cannot migrate already migrated account', async () => {
+    const { instruction } = await migrateAccountInstruction({
+      program,
+      account: bondAccount,
+    })
+    try {
+      await provider.sendIx([], instruction)
+      throw new Error('failure expected; account already migrated')
+    } catch (e) {
+      verifyError(e, Errors, 6088, 'already migrated')
+    }
+  })
+
+  it('cannot migrate account not owned by the program', async () => {
+    const { instruction } = await migrateAccountInstruction({
+      program,
+      account: voteAccount,
+    })
+    try {
+      await provider.sendIx([], instruction)
+      throw new Error('failure expected; vote account cannot be migrated')
+    } catch (e) {
+      verifyError(e, Errors, 6087, 'cannot be migrated')
+    }
+  })
+
+  async function setBondAccountVersion(accountVersion: number) {
+    const accountInfo = await provider.connection.getAccountInfo(bondAccount)
+    assert(accountInfo !== null, 'bond account not found')
+    const bondData = await getBond(program, bondAccount)
+    const encoded = await program.coder.accounts.encode('bond', {
+      ...bondData,
+      accountVersion,
+    })
+    const data = Buffer.alloc(accountInfo.data.length)
+    encoded.copy(data)
+    provider.context.setAccount(bondAccount, { ...accountInfo, data })
+  }
+})
@@ -0,0 +1,353 @@
+import {
+  Errors,
+  ValidatorBondsProgram,
+  bondAliasAddress,
+  bondsWithdrawerAuthority,
+  getBond,
+  initBondInstruction,
+  migrateBondInstruction,
+  redelegateStakeInstruction,
+} from '../../src'
+import {
+  BankrunExtendedProvider,
+  assertNotExist,
+  currentEpoch,
+  warpToNextEpoch,
+} from '@marinade.finance/bankrun-utils'
+import {
+  executeInitBondInstruction,
+  executeInitConfigInstruction,
+} from '../utils/testTransactions'
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js'
+import {
+  StakeStates,
+  createBondsFundedStakeAccount,
+  createVoteAccount,
+  getAndCheckStakeAccount,
+} from '../utils/staking'
+import { verifyError } from '@marinade.finance/anchor-common'
+import { U64_MAX } from '@marinade.finance/web3js-common'
+import { initBankrunTest } from './bankrun'
+
+describe('Validator Bonds migrate bond', () => {
+  let provider: BankrunExtendedProvider
+  let program: ValidatorBondsProgram
+  let configAccount: PublicKey
+  let validatorIdentity: Keypair
+  let voteAccount: PublicKey
+  let newVoteAccount: PublicKey
+  let bondAccount: PublicKey
+
+  beforeAll(async () => {
+    ;({ provider, program } = await initBankrunTest())
+  })
+
+  beforeEach(async () => {
+    ;({ configAccount } = await executeInitConfigInstruction({
+      program,
+      provider,
+    }))
+    validatorIdentity = Keypair.generate()
+    ;({ voteAccount } = await createVoteAccount({
+      provider,
+      validatorIdentity,
+    }))
+    ;({ voteAccount: newVoteAccount } = await createVoteAccount({
+      provider,
+      validatorIdentity,
+    }))
+    ;({ bondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      validatorIdentity,
+    }))
+  })
+
+  it('migrate bond and redelegate stake', async () => {
+    const stakeAccount = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: 3 * LAMPORTS_PER_SOL,
+    })
+    await warpToNextEpoch(provider)
+
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    const bondData = await getBond(program, bondAccount)
+    expect(bondData.voteAccount).toEqual(voteAccount)
+    expect(bondData.migratedVoteAccount).toEqual(newVoteAccount)
+
+    // first call deactivates the stake account
+    const { instruction: deactivateIx } = await redelegateStakeInstruction({
+      program,
+      stakeAccount,
+      bondAccount,
+    })
+    await provider.sendIx([], deactivateIx)
+    let [stakeAccountData] = await getAndCheckStakeAccount(
+      provider,
+      stakeAccount,
+      StakeStates.Delegated
+    )
+    expect(stakeAccountData.Stake?.stake.delegation.voterPubkey).toEqual(
+      voteAccount
+    )
+    expect(stakeAccountData.Stake?.stake.delegation.deactivationEpoch).toEqual(
+      await currentEpoch(provider)
+    )
+
+    // second call delegates the deactivated stake account to the new vote account
+    await warpToNextEpoch(provider)
+    const { instruction: delegateIx } = await redelegateStakeInstruction({
+      program,
+      stakeAccount,
+      bondAccount,
+    })
+    await provider.sendIx([], delegateIx)
+    ;[stakeAccountData] = await getAndCheckStakeAccount(
+      provider,
+      stakeAccount,
+      StakeStates.Delegated
+    )
+    expect(stakeAccountData.Stake?.stake.delegation.voterPubkey).toEqual(
+      newVoteAccount
+    )
+    expect(stakeAccountData.Stake?.stake.delegation.deactivationEpoch).toEqual(
+      U64_MAX
+    )
+    const [bondsAuth] = bondsWithdrawerAuthority(
+      configAccount,
+      program.programId
+    )
+    expect(stakeAccountData.Stake?.meta.authorized.staker).toEqual(bondsAuth)
+    expect(stakeAccountData.Stake?.meta.authorized.withdrawer).toEqual(
+      bondsAuth
+    )
+  })
+
+  it('migrate bond back to the original vote account', async () => {
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    expect((await getBond(program, bondAccount)).migratedVoteAccount).toEqual(
+      newVoteAccount
+    )
+
+    const { instruction: backIx } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount: voteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], backIx)
+    expect((await getBond(program, bondAccount)).migratedVoteAccount).toEqual(
+      PublicKey.default
+    )
+    await assertNotExist(
+      provider,
+      bondAliasAddress(configAccount, newVoteAccount, program.programId)[0]
+    )
+
+    // the vote account is free to be bonded again
+    const { bondAccount: newBondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount: newVoteAccount,
+    })
+    expect((await getBond(program, newBondAccount)).voteAccount).toEqual(
+      newVoteAccount
+    )
+  })
+
+  it('cannot init bond for vote account the bond was migrated to', async () => {
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+    const [bondAlias] = bondAliasAddress(
+      configAccount,
+      newVoteAccount,
+      program.programId
+    )
+    const bondAliasData = await program.account.bondAlias.fetch(bondAlias)
+    expect(bondAliasData.bond).toEqual(bondAccount)
+    expect(bondAliasData.voteAccount).toEqual(newVoteAccount)
+
+    // permission-less init bond
+    const { instruction: initBondIx } = await initBondInstruction({
+      program,
+      configAccount,
+      voteAccount: newVoteAccount,
+    })
+    try {
+      await provider.sendIx([], initBondIx)
+      throw new Error('failure expected; bond migrated to the vote account')
+    } catch (e) {
+      verifyError(e, Errors, 6093, 'Bond already exists')
+    }
+  })
+
+  it('migrate bond further closes the previous alias', async () => {
+    const { voteAccount: thirdVoteAccount } = await createVoteAccount({
+      provider,
+      validatorIdentity,
+    })
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+
+    const { instruction: furtherIx } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount: thirdVoteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], furtherIx)
+    expect((await getBond(program, bondAccount)).migratedVoteAccount).toEqual(
+      thirdVoteAccount
+    )
+    await assertNotExist(
+      provider,
+      bondAliasAddress(configAccount, newVoteAccount, program.programId)[0]
+    )
+    const bondAliasData = await program.account.bondAlias.fetch(
+      bondAliasAddress(configAccount, thirdVoteAccount, program.programId)[0]
+    )
+    expect(bondAliasData.bond).toEqual(bondAccount)
+
+    try {
+      await provider.sendIx([validatorIdentity], furtherIx)
+      throw new Error('failure expected; already migrated to the vote account')
+    } catch (e) {
+      verifyError(e, Errors, 6096, 'already delegated to the vote account')
+    }
+  })
+
+  it('cannot migrate bond to vote account other bond was migrated to', async () => {
+    const { voteAccount: otherVoteAccount } = await createVoteAccount({
+      provider,
+      validatorIdentity,
+    })
+    const { bondAccount: otherBondAccount } = await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount: otherVoteAccount,
+      validatorIdentity,
+    })
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount,
+      authority: validatorIdentity,
+    })
+    await provider.sendIx([validatorIdentity], instruction)
+
+    const { instruction: otherIx } = await migrateBondInstruction({
+      program,
+      bondAccount: otherBondAccount,
+      newVoteAccount,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], otherIx)
+      throw new Error('failure expected; bond alias exists for vote account')
+    } catch (e) {
+      // the bond alias account already exists
+      expect((e as Error).message).toMatch('custom program error: 0x0')
+    }
+    expect(
+      (await getBond(program, otherBondAccount)).migratedVoteAccount
+    ).toEqual(PublicKey.default)
+  })
+
+  it('cannot migrate bond to vote account with existing bond', async () => {
+    const { voteAccount: bondedVoteAccount } = await createVoteAccount({
+      provider,
+      validatorIdentity,
+    })
+    await executeInitBondInstruction({
+      program,
+      provider,
+      configAccount,
+      voteAccount: bondedVoteAccount,
+      validatorIdentity,
+    })
+
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount: bondedVoteAccount,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], instruction)
+      throw new Error('failure expected; bond exists for the vote account')
+    } catch (e) {
+      verifyError(e, Errors, 6093, 'Bond already exists')
+    }
+    expect((await getBond(program, bondAccount)).migratedVoteAccount).toEqual(
+      PublicKey.default
+    )
+  })
+
+  it('cannot migrate bond to vote account of other validator', async () => {
+    const { voteAccount: otherVoteAccount } = await createVoteAccount({
+      provider,
+    })
+    const { instruction } = await migrateBondInstruction({
+      program,
+      bondAccount,
+      newVoteAccount: otherVoteAccount,
+      authority: validatorIdentity,
+    })
+    try {
+      await provider.sendIx([validatorIdentity], instruction)
+      throw new Error('failure expected; not the same validator')
+    } catch (e) {
+      verifyError(e, Errors, 6071, 'Bond migration requires')
+    }
+  })
+
+  it('cannot redelegate stake of not migrated bond', async () => {
+    const stakeAccount = await createBondsFundedStakeAccount({
+      program,
+      provider,
+      configAccount,
+      voteAccount,
+      lamports: 3 * LAMPORTS_PER_SOL,
+    })
+    const { instruction } = await redelegateStakeInstruction({
+      program,
+      stakeAccount,
+      bondAccount,
+    })
+    try {
+      await provider.sendIx([], instruction)
+      throw new Error('failure expected; nothing to redelegate')
+    } catch (e) {
+      verifyError(e, Errors, 6072, 'already delegated')
+    }
+  })
+})
@@ -1,6 +1,7 @@
 import {
   Errors,
   ValidatorBondsProgram,
+  configureConfigInstruction,
   resetStakeInstruction,
   bondsWithdrawerAuthority,
 } from '../../src'
@@ -31,6 +32,7 @@ describe('Validator Bonds reset stake', () => {
   let program: ValidatorBondsProgram
   let configAccount: PublicKey
   let operatorAuthority: Keypair
+  let adminAuthority: Keypair
   let validatorIdentity: Keypair
   let voteAccount: PublicKey
 
@@ -39,12 +41,11 @@ describe('Validator Bonds reset stake', () => {
   })
 
   beforeEach(async () => {
-    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
-      {
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
         program,
         provider,
-      }
-    ))
+      }))
     ;({ voteAccount, validatorIdentity } = await createVoteAccount({
       provider,
     }))
@@ -102,6 +103,74 @@ describe('Validator Bonds reset stake', () => {
     )
   })
 
+  it('reset stake with settlement closer role', async () => {
+    const settlementCloser = Keypair.generate()
+    const { instruction: configIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newSettlementCloser: settlementCloser.publicKey,
+    })
+    await provider.sendIx([adminAuthority], configIx)
+
+    const fakeSettlement = Keypair.generate().publicKey
+    const stakeAccount = await createSettlementFundedDelegatedStake({
+      program,
+      provider,
+      configAccount,
+      settlementAccount: fakeSettlement,
+      voteAccount,
+      lamports: LAMPORTS_PER_SOL * 5,
+    })
+
+    const { instruction: noAuthorityIx } = await resetStakeInstruction({
+      program,
+      configAccount,
+      stakeAccount,
+      voteAccount,
+      settlementAccount: fakeSettlement,
+    })
+    try {
+      await provider.sendIx([], noAuthorityIx)
+      throw new Error('Expected error; settlement closer signature missing')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+    const { instruction: operatorIx } = await resetStakeInstruction({
+      program,
+      configAccount,
+      stakeAccount,
+      voteAccount,
+      settlementAccount: fakeSettlement,
+      operatorAuthority,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], operatorIx)
+      throw new Error('Expected error; operator does not hold the role')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+
+    const { instruction } = await resetStakeInstruction({
+      program,
+      configAccount,
+      stakeAccount,
+      voteAccount,
+      settlementAccount: fakeSettlement,
+      operatorAuthority: settlementCloser,
+    })
+    await provider.sendIx([settlementCloser], instruction)
+    const [bondsAuth] = bondsWithdrawerAuthority(
+      configAccount,
+      program.programId
+    )
+    const [stakeAccountData] = await getAndCheckStakeAccount(
+      provider,
+      stakeAccount,
+      StakeStates.Delegated
+    )
+    expect(stakeAccountData.Stake?.meta.authorized.staker).toEqual(bondsAuth)
+  })
+
   it('cannot reset stake when not delegated', async () => {
     const fakeSettlement = Keypair.generate().publicKey
     const stakeAccount = await createSettlementFundedInitializedStake({
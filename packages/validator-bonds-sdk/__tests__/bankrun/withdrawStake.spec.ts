@@ -1,6 +1,7 @@
 import {
   Errors,
   ValidatorBondsProgram,
+  configureConfigInstruction,
   resetStakeInstruction,
   withdrawStakeInstruction,
 } from '../../src'
@@ -34,6 +35,7 @@ describe('Validator Bonds withdraw stake', () => {
   let program: ValidatorBondsProgram
   let configAccount: PublicKey
   let operatorAuthority: Keypair
+  let adminAuthority: Keypair
   let validatorIdentity: Keypair
   let voteAccount: PublicKey
   let user: SignerType
@@ -43,12 +45,11 @@ describe('Validator Bonds withdraw stake', () => {
   })
 
   beforeEach(async () => {
-    ;({ configAccount, operatorAuthority } = await executeInitConfigInstruction(
-      {
+    ;({ configAccount, operatorAuthority, adminAuthority } =
+      await executeInitConfigInstruction({
         program,
         provider,
-      }
-    ))
+      }))
     ;({ voteAccount, validatorIdentity } = await createVoteAccount({
       provider,
     }))
@@ -88,6 +89,50 @@ describe('Validator Bonds withdraw stake', () => {
     ).toEqual(2 * LAMPORTS_PER_SOL)
   })
 
+  it('withdraw stake with settlement closer role', async () => {
+    const settlementCloser = Keypair.generate()
+    const { instruction: configIx } = await configureConfigInstruction({
+      program,
+      configAccount,
+      newSettlementCloser: settlementCloser.publicKey,
+    })
+    await provider.sendIx([adminAuthority], configIx)
+
+    const fakeSettlement = Keypair.generate().publicKey
+    const stakeAccount = await createSettlementFundedInitializedStake({
+      program,
+      provider,
+      configAccount,
+      settlementAccount: fakeSettlement,
+      lamports: LAMPORTS_PER_SOL,
+    })
+    const { instruction: operatorIx } = await withdrawStakeInstruction({
+      program,
+      configAccount,
+      stakeAccount,
+      operatorAuthority: operatorAuthority.publicKey,
+      settlementAccount: fakeSettlement,
+      withdrawTo: user.publicKey,
+    })
+    try {
+      await provider.sendIx([operatorAuthority], operatorIx)
+      throw new Error('failure expected; operator does not hold the role')
+    } catch (e) {
+      verifyError(e, Errors, 6003, 'operator authority signature')
+    }
+
+    // the role authority is loaded from config by default
+    const { instruction } = await withdrawStakeInstruction({
+      program,
+      configAccount,
+      stakeAccount,
+      settlementAccount: fakeSettlement,
+      withdrawTo: user.publicKey,
+    })
+    await provider.sendIx([settlementCloser], instruction)
+    await assertNotExist(provider, stakeAccount)
+  })
+
   it('cannot withdraw settlement operator stake when delegated', async () => {
     const fakeSettlement = Keypair.generate().publicKey
     const stakeAccount = await createSettlementFundedDelegatedStake({
@@ -334,7 +334,19 @@ describe('Validator Bonds claim settlement', () => {
     const e = assertEvent(events, CLOSE_SETTLEMENT_CLAIM_EVENT)
     // Ensure the event was emitted
     assert(e !== undefined)
+    expect(e.version).toEqual(1)
     expect(e.settlement).toEqual(settlementAccount)
+    expect(e.settlementClaim).toEqual(settlementClaimAccount)
+    expect(e.stakeAccountStaker).toEqual(
+      treeNodeVoteAccount1Withdrawer1.treeNode.stakeAuthority
+    )
+    expect(e.stakeAccountWithdrawer).toEqual(
+      treeNodeVoteAccount1Withdrawer1.treeNode.withdrawAuthority
+    )
+    expect(e.amount).toEqual(
+      treeNodeVoteAccount1Withdrawer1.treeNode.data.claim
+    )
     expect(e.rentCollector).toEqual(fundSettlementRentPayer.publicKey)
+    expect(e.rentRefund.toNumber()).toBeGreaterThan(0)
   })
 })
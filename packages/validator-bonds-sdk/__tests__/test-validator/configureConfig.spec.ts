@@ -3,6 +3,7 @@ import {
   CONFIGURE_CONFIG_EVENT,
   Config,
   ValidatorBondsProgram,
+  acceptConfigAuthorityInstruction,
   assertEvent,
   configureConfigInstruction,
   getConfig,
@@ -47,17 +48,17 @@ describe('Validator Bonds configure config', () => {
 
   it('configure config', async () => {
     const newAdminAuthority = Keypair.generate()
-    const newOperatorAuthority = PublicKey.unique()
-    const newPauseAuthority = PublicKey.unique()
+    const newOperatorAuthority = Keypair.generate()
+    const newPauseAuthority = Keypair.generate()
 
     const tx = await transaction(provider)
     const { instruction } = await configureConfigInstruction({
       program,
       configAccount: configInitialized.publicKey,
       adminAuthority,
-      newOperator: newOperatorAuthority,
+      newOperator: newOperatorAuthority.publicKey,
       newAdmin: newAdminAuthority.publicKey,
-      newPauseAuthority: newPauseAuthority,
+      newPauseAuthority: newPauseAuthority.publicKey,
       newEpochsToClaimSettlement: 100,
       newWithdrawLockupEpochs: 103,
       newMinimumStakeLamports: 1001,
@@ -70,9 +71,23 @@ describe('Validator Bonds configure config', () => {
     ])
 
     const configData = await getConfig(program, configInitialized.publicKey)
-    expect(configData.adminAuthority).toEqual(newAdminAuthority.publicKey)
-    expect(configData.operatorAuthority).toEqual(newOperatorAuthority)
-    expect(configData.pauseAuthority).toEqual(newPauseAuthority)
+    // authorities are only proposed
+    expect(configData.adminAuthority).toEqual(adminAuthority.publicKey)
+    expect(configData.pendingAdminAuthority).toEqual(
+      newAdminAuthority.publicKey
+    )
+    expect(configData.operatorAuthority).toEqual(
+      configInitialized.account.operatorAuthority
+    )
+    expect(configData.pendingOperatorAuthority).toEqual(
+      newOperatorAuthority.publicKey
+    )
+    expect(configData.pauseAuthority).toEqual(
+      configInitialized.account.pauseAuthority
+    )
+    expect(configData.pendingPauseAuthority).toEqual(
+      newPauseAuthority.publicKey
+    )
     expect(configData.paused).toBeFalsy()
     expect(configData.epochsToClaimSettlement).toEqual(100)
     expect(configData.withdrawLockupEpochs).toEqual(103)
@@ -83,18 +98,10 @@ describe('Validator Bonds configure config', () => {
     const e = assertEvent(events, CONFIGURE_CONFIG_EVENT)
     // Ensure the event was emitted
     assert(e !== undefined)
-    expect(e.adminAuthority).toEqual({
-      old: adminAuthority.publicKey,
-      new: newAdminAuthority.publicKey,
-    })
-    expect(e.operatorAuthority).toEqual({
-      old: configInitialized.account.operatorAuthority,
-      new: newOperatorAuthority,
-    })
-    expect(e.pauseAuthority).toEqual({
-      old: configInitialized.account.pauseAuthority,
-      new: newPauseAuthority,
-    })
+    expect(e.adminAuthority).toBeNull()
+    expect(e.pendingAdminAuthority).toEqual(newAdminAuthority.publicKey)
+    expect(e.pendingOperatorAuthority).toEqual(newOperatorAuthority.publicKey)
+    expect(e.pendingPauseAuthority).toEqual(newPauseAuthority.publicKey)
     expect(e.epochsToClaimSettlement).toEqual({
       old: configInitialized.account.epochsToClaimSettlement,
       new: 100,
@@ -111,5 +118,61 @@ describe('Validator Bonds configure config', () => {
       old: 0,
       new: 1002,
     })
+
+    const acceptTx = await transaction(provider)
+    for (const newAuthority of [
+      newAdminAuthority,
+      newOperatorAuthority,
+      newPauseAuthority,
+    ]) {
+      const { instruction: acceptIx } = await acceptConfigAuthorityInstruction(
+        {
+          program,
+          configAccount: configInitialized.publicKey,
+          newAuthority,
+        }
+      )
+      acceptTx.add(acceptIx)
+    }
+    const acceptExecutionReturn = await executeTxSimple(
+      provider.connection,
+      acceptTx,
+      [
+        provider.wallet,
+        newAdminAuthority,
+        newOperatorAuthority,
+        newPauseAuthority,
+      ]
+    )
+
+    const acceptedConfigData = await getConfig(
+      program,
+      configInitialized.publicKey
+    )
+    expect(acceptedConfigData.adminAuthority).toEqual(
+      newAdminAuthority.publicKey
+    )
+    expect(acceptedConfigData.operatorAuthority).toEqual(
+      newOperatorAuthority.publicKey
+    )
+    expect(acceptedConfigData.pauseAuthority).toEqual(
+      newPauseAuthority.publicKey
+    )
+    expect(acceptedConfigData.pendingAdminAuthority).toEqual(PublicKey.default)
+    expect(acceptedConfigData.pendingOperatorAuthority).toEqual(
+      PublicKey.default
+    )
+    expect(acceptedConfigData.pendingPauseAuthority).toEqual(PublicKey.default)
+
+    const acceptEvents = parseCpiEvents(
+      program,
+      acceptExecutionReturn?.response
+    )
+    const acceptEvent = assertEvent(acceptEvents, CONFIGURE_CONFIG_EVENT)
+    assert(acceptEvent !== undefined)
+    expect(acceptEvent.adminAuthority).toEqual({
+      old: adminAuthority.publicKey,
+      new: newAdminAuthority.publicKey,
+    })
   })
 })
@@ -4,6 +4,7 @@ import {
   EMERGENCY_PAUSE_EVENT,
   EMERGENCY_RESUME_EVENT,
   ValidatorBondsProgram,
+  acceptConfigAuthorityInstruction,
   assertEvent,
   configureConfigInstruction,
   emergencyPauseInstruction,
@@ -48,7 +49,16 @@ describe('Validator Bonds pause and resume', () => {
         newPauseAuthority: pauseAuthority.publicKey,
       }
     )
-    await provider.sendIx([adminAuth], configureConfigIx)
+    const { instruction: acceptIx } = await acceptConfigAuthorityInstruction({
+      program,
+      configAccount: config.publicKey,
+      newAuthority: pauseAuthority,
+    })
+    await provider.sendIx(
+      [adminAuth, pauseAuthority],
+      configureConfigIx,
+      acceptIx
+    )
   })
 
   it('pause and resume', async () => {
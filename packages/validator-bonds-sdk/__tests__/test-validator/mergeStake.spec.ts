@@ -107,5 +107,12 @@ describe('Validator Bonds fund bond', () => {
     expect(e.sourceStake).toEqual(stakeAccount2)
     expect(e.sourceDelegation?.voterPubkey).toEqual(voteAccount)
     expect(e.stakerAuthority).toEqual(bondWithdrawer)
+    expect(e.version).toEqual(1)
+    expect(e.destinationLamports.old.toNumber()).toEqual(lamports1)
+    expect(e.destinationLamports.new.toNumber()).toEqual(lamports1 + lamports2)
+    expect(e.sourceLamports.toNumber()).toEqual(lamports2)
+    expect(e.epoch.toNumber()).toEqual(
+      (await provider.connection.getEpochInfo()).epoch
+    )
   })
 })
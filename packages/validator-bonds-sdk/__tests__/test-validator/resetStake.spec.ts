@@ -118,5 +118,11 @@ describe('Validator Bonds reset settlement stake account', () => {
     expect(e.settlement).toEqual(fakeSettlement)
     expect(e.settlementStakerAuthority).toEqual(settlementAuth)
     expect(e.voteAccount).toEqual(voteAccount)
+    expect(e.version).toEqual(1)
+    expect(e.stakeAmount).toEqual(stakeAccountData.balanceLamports)
+    expect(e.operatorAuthority).toBeNull()
+    expect(e.epoch.toNumber()).toEqual(
+      (await provider.connection.getEpochInfo()).epoch
+    )
   })
 })
@@ -1,12 +1,22 @@
 import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 import { ExtendedProvider } from '@marinade.finance/web3js-common'
 import {
+  Keypair,
   PublicKey,
   Signer,
+  SystemProgram,
   Transaction,
   TransactionInstruction,
   TransactionInstructionCtorFields,
 } from '@solana/web3.js'
+import {
+  MINT_SIZE,
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createInitializeMint2Instruction,
+  createMintToInstruction,
+  getAssociatedTokenAddressSync,
+} from 'solana-spl-token-modern'
 import { checkErrorMessage } from '@marinade.finance/ts-common'
 import assert from 'assert'
 
@@ -50,3 +60,59 @@ export async function getRentExempt(
     accountInfo.data.length
   )
 }
+
+/**
+ * Creates a token mint (mint authority is the provider wallet)
+ * and mints the amount to the associated token account of the owner.
+ */
+export async function createTokenMintAndFund({
+  provider,
+  owner,
+  amount,
+  decimals = 9,
+}: {
+  provider: ExtendedProvider
+  owner: PublicKey
+  amount: number | bigint
+  decimals?: number
+}): Promise<{
+  mint: PublicKey
+  tokenAccount: PublicKey
+}> {
+  const mint = Keypair.generate()
+  const tokenAccount = getAssociatedTokenAddressSync(
+    mint.publicKey,
+    owner,
+    true
+  )
+  await provider.sendIx(
+    [mint],
+    SystemProgram.createAccount({
+      fromPubkey: provider.walletPubkey,
+      newAccountPubkey: mint.publicKey,
+      lamports:
+        await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE),
+      space: MINT_SIZE,
+      programId: TOKEN_PROGRAM_ID,
+    }),
+    createInitializeMint2Instruction(
+      mint.publicKey,
+      decimals,
+      provider.walletPubkey,
+      null
+    ),
+    createAssociatedTokenAccountIdempotentInstruction(
+      provider.walletPubkey,
+      tokenAccount,
+      owner,
+      mint.publicKey
+    ),
+    createMintToInstruction(
+      mint.publicKey,
+      tokenAccount,
+      provider.walletPubkey,
+      amount
+    )
+  )
+  return { mint: mint.publicKey, tokenAccount }
+}
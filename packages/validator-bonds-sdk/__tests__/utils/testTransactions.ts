@@ -128,6 +128,10 @@ export async function executeConfigureConfigInstruction({
   newWithdrawLockupEpochs,
   newMinimumStakeLamports,
   newMinBondMaxStakeWanted,
+  newBidMaxDelegatedLamports,
+  newMaxClaimExpiryExtension,
+  newConfigChangeDelayEpochs,
+  cancelPendingChanges,
 }: {
   program: ValidatorBondsProgram
   provider: ExtendedProvider
@@ -141,6 +145,10 @@ export async function executeConfigureConfigInstruction({
   newWithdrawLockupEpochs?: BN | number
   newMinimumStakeLamports?: BN | number
   newMinBondMaxStakeWanted?: BN | number
+  newBidMaxDelegatedLamports?: BN | number
+  newMaxClaimExpiryExtension?: number
+  newConfigChangeDelayEpochs?: BN | number
+  cancelPendingChanges?: boolean
 }): Promise<{
   configAccount: PublicKey
 }> {
@@ -156,6 +164,10 @@ export async function executeConfigureConfigInstruction({
     newWithdrawLockupEpochs,
     newMinimumStakeLamports,
     newMinBondMaxStakeWanted,
+    newBidMaxDelegatedLamports,
+    newMaxClaimExpiryExtension,
+    newConfigChangeDelayEpochs,
+    cancelPendingChanges,
   })
   try {
     await provider.sendIx([adminAuthority], instruction)
@@ -12,6 +12,11 @@ export type ValidatorBonds = {
       "type": "bytes",
       "value": "[98, 111, 110, 100, 95, 97, 99, 99, 111, 117, 110, 116]"
     },
+    {
+      "name": "BOND_ALIAS_SEED",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 95, 97, 108, 105, 97, 115]"
+    },
     {
       "name": "BOND_MINT_SEED",
       "type": "bytes",
@@ -32,6 +37,21 @@ export type ValidatorBonds = {
       "type": "bytes",
       "value": "[99, 108, 97, 105, 109, 95, 97, 99, 99, 111, 117, 110, 116]"
     },
+    {
+      "name": "COLLATERAL_MINT_SEED",
+      "type": "bytes",
+      "value": "[99, 111, 108, 108, 97, 116, 101, 114, 97, 108, 95, 109, 105, 110, 116]"
+    },
+    {
+      "name": "COLLATERAL_VAULT_SEED",
+      "type": "bytes",
+      "value": "[99, 111, 108, 108, 97, 116, 101, 114, 97, 108, 95, 118, 97, 117, 108, 116]"
+    },
+    {
+      "name": "SETTLEMENT_COLLATERAL_SEED",
+      "type": "bytes",
+      "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 99, 111, 108, 108, 97, 116, 101, 114, 97, 108]"
+    },
     {
       "name": "BONDS_WITHDRAWER_AUTHORITY_SEED",
       "type": "bytes",
@@ -41,6 +61,16 @@ export type ValidatorBonds = {
       "name": "SETTLEMENT_STAKER_AUTHORITY_SEED",
       "type": "bytes",
       "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
+    },
+    {
+      "name": "EVENT_VERSION",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "ACCOUNT_VERSION",
+      "type": "u8",
+      "value": "1"
     }
   ],
   "instructions": [
@@ -94,6 +124,49 @@ export type ValidatorBonds = {
         }
       ]
     },
+    {
+      "name": "migrateAccount",
+      "accounts": [
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the account reallocation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "configureConfig",
       "accounts": [
@@ -142,6 +215,74 @@ export type ValidatorBonds = {
         }
       ]
     },
+    {
+      "name": "acceptConfigAuthority",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "the authority proposed by admin with the configure config instruction"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "applyConfigChanges",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
     {
       "name": "initBond",
       "accounts": [
@@ -193,6 +334,35 @@ export type ValidatorBonds = {
             ]
           }
         },
+        {
+          "name": "bondAlias",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "alias of the vote account created on bond migration, no bond may be created",
+            "for the vote account another bond was migrated to"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_alias"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          }
+        },
         {
           "name": "rentPayer",
           "isMut": true,
@@ -388,7 +558,7 @@ export type ValidatorBonds = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "token account to burn bond mint configuration tokens from"
+            "token account to burn bond mint configuration tokens from (or holding the bond authority mint token)"
           ]
         },
         {
@@ -430,6 +600,85 @@ export type ValidatorBonds = {
         }
       ]
     },
+    {
+      "name": "configureBondAuthorityMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "vote_account",
+            "config"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account validator identity or bond authority may change the account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ConfigureBondAuthorityMintArgs"
+          }
+        }
+      ]
+    },
     {
       "name": "mintBond",
       "accounts": [
@@ -684,19 +933,19 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "initWithdrawRequest",
+      "name": "chargeBid",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
+          "relations": [
+            "operator_authority"
           ]
         },
         {
           "name": "bond",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -714,54 +963,76 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
           },
           "relations": [
-            "config",
-            "vote_account"
+            "config"
           ]
         },
         {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
+          "name": "operatorAuthority",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "validator vote account node identity or bond authority may ask for the withdrawal"
+            "operator signer authority is allowed to charge the bid"
           ]
         },
         {
-          "name": "withdrawRequest",
+          "name": "stakeAccount",
           "isMut": true,
           "isSigner": false,
+          "docs": [
+            "bond stake account the bid is charged from"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "authority that manages (owns) all stakes account under the bonds program"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "withdraw_account"
+                "value": "bonds_authority"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
+                "account": "Config",
+                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "rentPayer",
+          "name": "bidFeeCollector",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "new staker and withdrawer authority of the charged split stake account"
+          ]
+        },
+        {
+          "name": "splitStakeAccount",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "rent exempt payer of withdraw request account creation"
+            "a new stake account where the charged bid amount is split into"
+          ]
+        },
+        {
+          "name": "splitStakeRentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "the rent exempt payer of the split_stake_account creation"
           ]
         },
         {
@@ -769,6 +1040,21 @@ export type ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -791,15 +1077,15 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "createWithdrawRequestArgs",
+          "name": "chargeBidArgs",
           "type": {
-            "defined": "InitWithdrawRequestArgs"
+            "defined": "ChargeBidArgs"
           }
         }
       ]
     },
     {
-      "name": "cancelWithdrawRequest",
+      "name": "migrateBond",
       "accounts": [
         {
           "name": "config",
@@ -808,7 +1094,7 @@ export type ValidatorBonds = {
         },
         {
           "name": "bond",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -826,93 +1112,38 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
           },
           "relations": [
-            "vote_account",
             "config"
           ]
         },
         {
           "name": "voteAccount",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "validator vote account validator identity or bond authority may ask for cancelling"
-          ]
-        },
-        {
-          "name": "withdrawRequest",
-          "isMut": true,
           "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "withdraw_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
-              }
-            ]
-          },
-          "relations": [
-            "bond"
+          "docs": [
+            "the vote account the bond stake accounts are currently delegated to"
           ]
         },
         {
-          "name": "rentCollector",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
-          }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "claimWithdrawRequest",
-      "accounts": [
-        {
-          "name": "config",
+          "name": "newVoteAccount",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "the config root configuration account"
+            "the vote account the bond is migrated to"
           ]
         },
         {
-          "name": "bond",
+          "name": "newVoteAccountBond",
           "isMut": false,
           "isSigner": false,
+          "docs": [
+            "bond address of the new vote account, no bond may exist for the vote account the bond is migrated to"
+          ],
           "pda": {
             "seeds": [
               {
@@ -929,127 +1160,96 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "path": "new_vote_account"
               }
             ]
-          },
-          "relations": [
-            "config",
-            "vote_account"
-          ]
-        },
-        {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "validator vote account node identity or bond authority may claim"
-          ]
+          }
         },
         {
-          "name": "withdrawRequest",
+          "name": "oldBondAlias",
           "isMut": true,
           "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "alias of the vote account the bond is currently migrated to, closed on the migration",
+            "required when the bond has been already migrated"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "withdraw_account"
+                "value": "bond_alias"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Bond",
-                "path": "bond"
+                "path": "bond.migrated_vote_account"
               }
             ]
           },
           "relations": [
-            "vote_account",
             "bond"
           ]
         },
         {
-          "name": "bondsWithdrawerAuthority",
-          "isMut": false,
+          "name": "newBondAlias",
+          "isMut": true,
           "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "alias of the vote account the bond is migrated to",
+            "required when the bond is not migrated back to the original vote account"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bonds_authority"
+                "value": "bond_alias"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Config",
                 "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "new_vote_account"
               }
             ]
           }
         },
         {
-          "name": "stakeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "stake account to be used to withdraw the funds",
-            "this stake account has to be delegated to the validator vote account associated to the bond"
-          ]
-        },
-        {
-          "name": "withdrawer",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "New owner of the stake account, it will be accounted to the withdrawer authority"
-          ]
-        },
-        {
-          "name": "splitStakeAccount",
-          "isMut": true,
           "isSigner": true,
           "docs": [
-            "this is a whatever address that does not exist",
-            "when withdrawing needs to split the provided account this will be used as a new stake account"
+            "validator identity or authorized withdrawer of both vote accounts"
           ]
         },
         {
-          "name": "splitStakeRentPayer",
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "when the split_stake_account is created the rent for creation is taken from here",
-            "when the split_stake_account is not created then no rent is paid"
+            "rent exempt payer of the bond alias account creation, refunded on the alias close"
           ]
         },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1073,14 +1273,14 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "initSettlement",
+      "name": "initWithdrawRequest",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
-          "relations": [
-            "operator_authority"
+          "docs": [
+            "the config account under which the bond was created"
           ]
         },
         {
@@ -1103,17 +1303,30 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond.vote_account"
+                "path": "vote_account"
               }
             ]
           },
           "relations": [
-            "config"
+            "config",
+            "vote_account"
           ]
         },
         {
-          "name": "settlement",
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account node identity or bond authority may ask for the withdrawal"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -1121,45 +1334,23 @@ export type ValidatorBonds = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "settlement_account"
+                "value": "withdraw_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Bond",
                 "path": "bond"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "InitSettlementArgs"
-                },
-                "path": "params.merkle_root"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "InitSettlementArgs"
-                },
-                "path": "params.epoch"
               }
             ]
           }
         },
-        {
-          "name": "operatorAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "operator signer authority that is allowed to create the settlement account"
-          ]
-        },
         {
           "name": "rentPayer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "rent exempt payer of account creation"
+            "rent exempt payer of withdraw request account creation"
           ]
         },
         {
@@ -1189,15 +1380,15 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "initSettlementArgs",
+          "name": "createWithdrawRequestArgs",
           "type": {
-            "defined": "InitSettlementArgs"
+            "defined": "InitWithdrawRequestArgs"
           }
         }
       ]
     },
     {
-      "name": "closeSettlement",
+      "name": "cancelWithdrawRequest",
       "accounts": [
         {
           "name": "config",
@@ -1224,113 +1415,56 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond.vote_account"
+                "path": "vote_account"
               }
             ]
           },
           "relations": [
+            "vote_account",
             "config"
           ]
         },
         {
-          "name": "settlement",
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account validator identity or bond authority may ask for cancelling"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "settlement to close when expired"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "settlement_account"
+                "value": "withdraw_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Bond",
                 "path": "bond"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "Settlement",
-                "path": "settlement.merkle_root"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "Settlement",
-                "path": "settlement.epoch_created_for"
               }
             ]
           },
           "relations": [
-            "bond",
-            "rent_collector"
+            "bond"
           ]
         },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bonds_authority"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              }
-            ]
-          }
-        },
         {
           "name": "rentCollector",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "splitRentCollector",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "splitRentRefundAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
-            "The lamports are utilized to pay back the rent exemption of the split_stake_account, which can be created upon funding the settlement."
-          ]
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1354,12 +1488,18 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "cancelSettlement",
+      "name": "claimWithdrawRequest",
+      "docs": [
+        "remaining accounts are the bond stake accounts merged to the claimed stake account"
+      ],
       "accounts": [
         {
           "name": "config",
-          "isMut": true,
-          "isSigner": false
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config root configuration account"
+          ]
         },
         {
           "name": "bond",
@@ -1381,65 +1521,50 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond.vote_account"
+                "path": "vote_account"
               }
             ]
           },
           "relations": [
-            "config"
+            "config",
+            "vote_account"
           ]
         },
         {
-          "name": "settlement",
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account node identity or bond authority may claim"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "settlement to close when expired"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "settlement_account"
+                "value": "withdraw_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Bond",
                 "path": "bond"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "Settlement",
-                "path": "settlement.merkle_root"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "Settlement",
-                "path": "settlement.epoch_created_for"
               }
             ]
           },
           "relations": [
-            "bond",
-            "rent_collector"
-          ]
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Cancelling is permitted only to emergency or operator authority"
+            "vote_account",
+            "bond"
           ]
         },
         {
@@ -1463,31 +1588,47 @@ export type ValidatorBonds = {
           }
         },
         {
-          "name": "rentCollector",
+          "name": "stakeAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "stake account to be used to withdraw the funds",
+            "this stake account has to be delegated to the validator vote account associated to the bond"
+          ]
         },
         {
-          "name": "splitRentCollector",
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "New owner (staker and withdrawer) of the stake account, an explicit destination chosen by the claiming authority"
+          ]
+        },
+        {
+          "name": "splitStakeAccount",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "this is a whatever address that does not exist",
+            "when withdrawing needs to split the provided account this will be used as a new stake account"
+          ]
         },
         {
-          "name": "splitRentRefundAccount",
+          "name": "splitStakeRentPayer",
           "isMut": true,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
-            "The lamports are utilized to pay back the rent exemption of the split_stake_account"
+            "when the split_stake_account is created the rent for creation is taken from here",
+            "when the split_stake_account is not created then no rent is paid"
           ]
         },
         {
-          "name": "clock",
+          "name": "stakeProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakeProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -1496,6 +1637,11 @@ export type ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1519,15 +1665,12 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "fundSettlement",
+      "name": "initSettlement",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false,
-          "relations": [
-            "operator_authority"
-          ]
+          "isSigner": false
         },
         {
           "name": "bond",
@@ -1576,181 +1719,156 @@ export type ValidatorBonds = {
                 "path": "bond"
               },
               {
-                "kind": "account",
+                "kind": "arg",
                 "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
+                  "defined": "InitSettlementArgs"
                 },
-                "account": "Settlement",
-                "path": "settlement.merkle_root"
+                "path": "params.merkle_root"
               },
               {
-                "kind": "account",
-                "type": "u64",
-                "account": "Settlement",
-                "path": "settlement.epoch_created_for"
+                "kind": "arg",
+                "type": {
+                  "defined": "InitSettlementArgs"
+                },
+                "path": "params.epoch"
               }
             ]
-          },
-          "relations": [
-            "bond"
-          ]
+          }
         },
         {
           "name": "operatorAuthority",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "operator signer authority is allowed to fund the settlement account"
+            "operator signer authority holding the settlement creator role"
           ]
         },
         {
-          "name": "stakeAccount",
+          "name": "rentPayer",
           "isMut": true,
-          "isSigner": false,
+          "isSigner": true,
           "docs": [
-            "stake account to be funded into the settlement"
+            "rent exempt payer of account creation"
           ]
         },
         {
-          "name": "settlementStakerAuthority",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "the settlement stake authority differentiates between deposited and funded stake accounts",
-            "deposited accounts have the bonds_withdrawer_authority, while funded accounts have the settlement_staker_authority"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "settlement_authority"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Settlement",
-                "path": "settlement"
+                "value": "__event_authority"
               }
             ]
           }
         },
         {
-          "name": "bondsWithdrawerAuthority",
+          "name": "program",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "authority that manages (owns) all stakes account under the bonds program"
-          ],
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initSettlementArgs",
+          "type": {
+            "defined": "InitSettlementArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "amendSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bonds_authority"
+                "value": "bond_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Config",
                 "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
-          }
-        },
-        {
-          "name": "splitStakeAccount",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "if an account that does not exist is provided, it will be initialized as a stake account (with the necessary signature)",
-            "the split_stake_account is required when the provided stake_account contains more lamports than necessary to fund the settlement",
-            "in this case, the excess lamports from the stake account are split into the new split_stake_account,",
-            "if the split_stake_account is not needed, the rent payer is refunded back within tx"
+          },
+          "relations": [
+            "config"
           ]
         },
         {
-          "name": "splitStakeRentPayer",
+          "name": "settlement",
           "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "the rent exempt payer of the split_stake_account creation",
-            "if the split_stake_account is not needed (no leftover lamports on funding), then the rent payer is refunded",
-            "if the split_stake_account is needed to spill out over funding of the settlement,",
-            "then the rent payer is refunded when the settlement is closed"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "__event_authority"
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
               }
             ]
-          }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "closeSettlementClaim",
-      "accounts": [
-        {
-          "name": "settlement",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "settlementClaim",
-          "isMut": true,
-          "isSigner": false,
+          },
           "relations": [
-            "rent_collector",
-            "settlement"
+            "bond"
           ]
         },
         {
-          "name": "rentCollector",
-          "isMut": true,
-          "isSigner": false
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority holding the settlement creator role"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -1772,17 +1890,24 @@ export type ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amendSettlementArgs",
+          "type": {
+            "defined": "AmendSettlementArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "claimSettlement",
+      "name": "disputeSettlement",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "the config account under which the settlement was created"
+            "the config account under which the bond was created"
           ]
         },
         {
@@ -1805,15 +1930,20 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond.vote_account"
+                "path": "vote_account"
               }
             ]
           },
           "relations": [
-            "config"
+            "config",
+            "vote_account"
           ]
         },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "settlement",
           "isMut": true,
@@ -1855,103 +1985,18 @@ export type ValidatorBonds = {
           ]
         },
         {
-          "name": "settlementClaim",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "deduplication, merkle tree record cannot be claimed twice"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "claim_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Settlement",
-                "path": "settlement"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "ClaimSettlementArgs"
-                },
-                "path": "params.tree_node_hash"
-              }
-            ]
-          }
-        },
-        {
-          "name": "stakeAccountFrom",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "a stake account that will be withdrawn"
-          ]
-        },
-        {
-          "name": "stakeAccountTo",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "a stake account that will receive the funds"
-          ]
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bonds_authority"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              }
-            ]
-          }
-        },
-        {
-          "name": "rentPayer",
-          "isMut": true,
           "isSigner": true,
           "docs": [
-            "upon claiming, a claim account is created to confirm the occurrence of the claim",
-            "when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here"
+            "validator vote account node identity or bond authority may dispute the settlement"
           ]
         },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "clock",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -1974,56 +2019,100 @@ export type ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "claimSettlementArgs",
+          "name": "disputeSettlementArgs",
           "type": {
-            "defined": "ClaimSettlementArgs"
+            "defined": "DisputeSettlementArgs"
           }
         }
       ]
     },
     {
-      "name": "mergeStake",
+      "name": "resolveDispute",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
+          "relations": [
+            "admin_authority"
           ]
         },
         {
-          "name": "sourceStake",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destinationStake",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "stakerAuthority",
+          "name": "bond",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "bonds program authority PDA address: settlement staker or bonds withdrawer"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
           ]
         },
         {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
         },
         {
-          "name": "stakeProgram",
+          "name": "adminAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "only the admin authority can resolve the dispute"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -2045,25 +2134,15 @@ export type ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "mergeArgs",
-          "type": {
-            "defined": "MergeStakeArgs"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "resetStake",
+      "name": "extendSettlementExpiry",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
-          ]
+          "isSigner": false
         },
         {
           "name": "bond",
@@ -2085,79 +2164,62 @@ export type ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
           },
           "relations": [
-            "config",
-            "vote_account"
+            "config"
           ]
         },
         {
           "name": "settlement",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "cannot exist; used to derive settlement authority"
-          ]
-        },
-        {
-          "name": "stakeAccount",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "stake account belonging under the settlement by staker authority"
-          ]
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "bonds withdrawer authority",
-            "to cancel settlement funding of the stake account changing staker authority to address"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bonds_authority"
+                "value": "settlement_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Config",
-                "path": "config"
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
               }
             ]
-          }
-        },
-        {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeConfig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
+          },
+          "relations": [
+            "bond"
+          ]
         },
         {
-          "name": "stakeProgram",
+          "name": "authority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "operator signer authority holding the settlement creator role or the admin authority"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -2179,79 +2241,135 @@ export type ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExtendSettlementExpiryArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "withdrawStake",
+      "name": "closeSettlement",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
-          ],
-          "relations": [
-            "operator_authority"
-          ]
-        },
-        {
-          "name": "operatorAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "operator authority is allowed to reset the non-delegated stake accounts"
-          ]
-        },
-        {
-          "name": "settlement",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "cannot exist; used to derive settlement authority"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "stake account where staker authority is derived from settlement"
-          ]
+          "isSigner": false
         },
         {
-          "name": "bondsWithdrawerAuthority",
+          "name": "bond",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "bonds authority to withdraw the stake account"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bonds_authority"
+                "value": "bond_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Config",
                 "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
-          }
+          },
+          "relations": [
+            "config"
+          ]
         },
         {
-          "name": "withdrawTo",
+          "name": "settlement",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
+          "isSigner": false,
+          "docs": [
+            "settlement to close when expired"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond",
+            "rent_collector"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentRefundAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
+            "The lamports are utilized to pay back the rent exemption of the split_stake_account, which can be created upon funding the settlement."
+          ]
+        },
         {
           "name": "clock",
           "isMut": false,
@@ -2262,6 +2380,11 @@ export type ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -2285,23 +2408,96 @@ export type ValidatorBonds = {
       "args": []
     },
     {
-      "name": "emergencyPause",
+      "name": "cancelSettlement",
       "accounts": [
         {
           "name": "config",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
           "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
           "relations": [
-            "pause_authority"
+            "config"
           ]
         },
         {
-          "name": "pauseAuthority",
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "settlement to close when expired"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond",
+            "rent_collector"
+          ]
+        },
+        {
+          "name": "authority",
           "isMut": false,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "Cancelling is permitted only to emergency or operator authority"
+          ]
         },
         {
-          "name": "eventAuthority",
+          "name": "bondsWithdrawerAuthority",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -2309,34 +2505,50 @@ export type ValidatorBonds = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "__event_authority"
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
               }
             ]
           }
         },
         {
-          "name": "program",
-          "isMut": false,
+          "name": "rentCollector",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "emergencyResume",
-      "accounts": [
+        },
         {
-          "name": "config",
+          "name": "splitRentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentRefundAccount",
           "isMut": true,
           "isSigner": false,
-          "relations": [
-            "pause_authority"
+          "docs": [
+            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
+            "The lamports are utilized to pay back the rent exemption of the split_stake_account"
           ]
         },
         {
-          "name": "pauseAuthority",
+          "name": "clock",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "eventAuthority",
@@ -2359,916 +2571,5008 @@ export type ValidatorBonds = {
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "bond",
-      "docs": [
-        "Bond account for a validator vote address"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "config",
-            "docs": [
-              "Program root config address. Validator bond is created for this config as PDA"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "voteAccount",
-            "docs": [
-              "Validator vote address that this bond account is crated for",
-              "INVARIANTS:",
-              "- one bond account per validator vote address",
-              "- this program does NOT change stake account delegation voter_pubkey to any other validator vote account"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "docs": [
-              "Authority that may close the bond or withdraw stake accounts associated with the bond",
-              "The same powers has got the owner of the validator vote account"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "cpmpe",
-            "docs": [
-              "Cost per mille per epoch.",
-              "This field represents the bid the bond (vote) account owner is willing to pay",
-              "for up to the `max_stake_wanted` being delegated.",
-              "The bid is in cost per mille per epoch similar to Google ads cpm system.",
-              "---",
-              "The actual amount of lamports deducted from the bond account for the processed bid",
-              "is based on the actual delegated lamports during the epoch."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "docs": [
-              "PDA Bond address bump seed"
-            ],
-            "type": "u8"
+      "name": "fundSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
           },
-          {
-            "name": "maxStakeWanted",
-            "docs": [
-              "Maximum stake (in lamports) that the bond (vote) account owner requests.",
-              "This is the maximum stake that will be distributed to the vote account",
-              "when all other constraints are fulfilled (managed off-chain).",
-              "The vote account owner then goes to auction to obtain up to that maximum.",
-              "Use the `cpmpe` field to define the bid for this purpose."
-            ],
-            "type": "u64"
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
           },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserve space for future extensions"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                134
-              ]
-            }
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority is allowed to fund the settlement account"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account to be funded into the settlement"
+          ]
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the settlement stake authority differentiates between deposited and funded stake accounts",
+            "deposited accounts have the bonds_withdrawer_authority, while funded accounts have the settlement_staker_authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "authority that manages (owns) all stakes account under the bonds program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "splitStakeAccount",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "if an account that does not exist is provided, it will be initialized as a stake account (with the necessary signature)",
+            "the split_stake_account is required when the provided stake_account contains more lamports than necessary to fund the settlement",
+            "in this case, the excess lamports from the stake account are split into the new split_stake_account,",
+            "if the split_stake_account is not needed, the rent payer is refunded back within tx"
+          ]
+        },
+        {
+          "name": "splitStakeRentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "the rent exempt payer of the split_stake_account creation",
+            "if the split_stake_account is not needed (no leftover lamports on funding), then the rent payer is refunded",
+            "if the split_stake_account is needed to spill out over funding of the settlement,",
+            "then the rent payer is refunded when the settlement is closed"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "config",
-      "docs": [
-        "Root account that configures the validator bonds program"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "adminAuthority",
-            "docs": [
-              "Admin authority that can update the config"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "operatorAuthority",
-            "docs": [
-              "Operator authority (bot hot wallet)"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "epochsToClaimSettlement",
-            "docs": [
-              "How many epochs permitting to claim the settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "docs": [
-              "How many epochs before withdraw is allowed"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "minimumStakeLamports",
-            "docs": [
-              "Minimum amount of lamports to be considered for a stake account operations (e.g., split)"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "bondsWithdrawerAuthorityBump",
-            "docs": [
-              "PDA bonds stake accounts authority bump seed"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "pauseAuthority",
-            "docs": [
-              "Authority that can pause the program in case of emergency"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "paused",
-            "type": "bool"
-          },
-          {
-            "name": "slotsToStartSettlementClaiming",
-            "docs": [
-              "How many slots to wait before settlement is permitted to be claimed"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "minBondMaxStakeWanted",
-            "docs": [
-              "Minimum value of max_stake_wanted to be configured by vote account owners at bond."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserved space for future changes"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                463
-              ]
-            }
+      "name": "closeSettlementClaim",
+      "accounts": [
+        {
+          "name": "settlement",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementClaim",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "rent_collector",
+            "settlement"
+          ]
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "settlementClaim",
-      "docs": [
-        "The settlement claim serves for deduplication purposes,",
-        "preventing the same settlement from being claimed multiple times with the same claiming data"
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "settlement",
-            "docs": [
-              "settlement account this claim belongs under"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakeAccountTo",
-            "docs": [
-              "stake account to which the claim has been withdrawn to"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakeAccountStaker",
-            "docs": [
-              "staker authority as part of the merkle proof for this claim"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakeAccountWithdrawer",
-            "docs": [
-              "withdrawer authority as part of the merkle proof for this claim"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "claim amount"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "bump",
-            "docs": [
-              "PDA account bump, one claim per settlement"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "rentCollector",
-            "docs": [
-              "rent collector account to get the rent back for claim account creation"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserve space for future extensions"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                93
-              ]
-            }
-          }
-        ]
-      }
+      "args": []
     },
     {
-      "name": "settlement",
-      "docs": [
-        "Settlement account for a particular config and merkle root",
-        "Settlement defines that a protected event happened and it will be settled"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bond",
-            "docs": [
-              "the settlement belongs under this bond, i.e., under a particular validator vote account"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakerAuthority",
-            "docs": [
-              "settlement authority used as the 'staker' stake account authority",
-              "of stake accounts funded to this settlement"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "merkleRoot",
-            "docs": [
-              "256-bit merkle root to check the claims against"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "maxTotalClaim",
-            "docs": [
-              "maximum number of funds that can ever be claimed"
-            ],
-            "type": "u64"
+      "name": "claimSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the settlement was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
           },
-          {
-            "name": "maxMerkleNodes",
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "settlementClaim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "deduplication, merkle tree record cannot be claimed twice"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ClaimSettlementArgs"
+                },
+                "path": "params.tree_node_hash"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccountFrom",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "a stake account that will be withdrawn"
+          ]
+        },
+        {
+          "name": "stakeAccountTo",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "a stake account that will receive the funds"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "upon claiming, a claim account is created to confirm the occurrence of the claim",
+            "when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here",
+            "the claimer fee is paid here"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "claimSettlementArgs",
+          "type": {
+            "defined": "ClaimSettlementArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mergeStake",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "sourceStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationStake",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bonds program authority PDA address: settlement staker or bonds withdrawer"
+          ]
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mergeArgs",
+          "type": {
+            "defined": "MergeStakeArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resetStake",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "cannot exist; used to derive settlement authority"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account belonging under the settlement by staker authority"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bonds withdrawer authority",
+            "to cancel settlement funding of the stake account changing staker authority to address"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "for a migrated bond it is the vote account the bond was migrated to"
+          ]
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "operator authority holding the settlement closer role, required only when the role is configured"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "redelegateStake",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "bond stake account delegated to the original bond vote account"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bonds withdrawer authority is the staker of the bond stake accounts"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawStake",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator authority holding the settlement closer role is allowed to withdraw the non-delegated stake accounts"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "cannot exist; used to derive settlement authority"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account where staker authority is derived from settlement"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bonds authority to withdraw the stake account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "withdrawTo",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initCollateralMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin_authority"
+          ]
+        },
+        {
+          "name": "adminAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "only the admin authority can whitelist the collateral mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the token mint to be whitelisted"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the collateral mint account creation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initCollateralMintArgs",
+          "type": {
+            "defined": "InitCollateralMintArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "configureCollateralMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin_authority"
+          ]
+        },
+        {
+          "name": "adminAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "only the admin authority can change the collateral mint params"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "CollateralMint",
+                "path": "collateral_mint.mint"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "configureCollateralMintArgs",
+          "type": {
+            "defined": "ConfigureCollateralMintArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundBondCollateral",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bond account to be deposited to with the provided tokens"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "owner of the collateral vault, it's the bonds withdrawer authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "bond collateral vault for the mint, created on the first deposit"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "token account the tokens are deposited from"
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "authority signature permitting to transfer the tokens from the token account"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the collateral vault creation"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "fundBondCollateralArgs",
+          "type": {
+            "defined": "FundBondCollateralArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "claimWithdrawCollateral",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config root configuration account"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "vote_account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account node identity or bond authority may claim"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "withdraw_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              }
+            ]
+          },
+          "relations": [
+            "vote_account",
+            "bond"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "bond collateral vault the tokens are withdrawn from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "withdrawerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "whatever token account of the mint, authority signature states his intention to withdraw the tokens there"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fundSettlementCollateral",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority is allowed to fund the settlement account"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "authority that manages (owns) the bond collateral vaults"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "bond collateral vault the settlement is funded from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "owner of the settlement collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "settlement collateral vault the claims are paid from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateral",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "tokens and their lamports value funded to the settlement, the claims are paid pro rata from them"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_collateral"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the settlement collateral vault and the settlement collateral creation,",
+            "the rent is returned to the settlement funder when the collateral is reset after the settlement is closed"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimSettlementCollateral",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the settlement was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "settlementClaim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "deduplication, merkle tree record cannot be claimed twice"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ClaimSettlementArgs"
+                },
+                "path": "params.tree_node_hash"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateral",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "tokens funded to the settlement, the claim is paid pro rata from them"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_collateral"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "settlement",
+            "mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "owner of the settlement collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "settlement collateral vault the claim is paid from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claimerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "a token account that will receive the tokens"
+          ]
+        },
+        {
+          "name": "rentPayerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "a token account of the rent payer that receives the claimer fee, no fee is paid when not provided"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "upon claiming, a claim account is created to confirm the occurrence of the claim",
+            "when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "claimSettlementArgs",
+          "type": {
+            "defined": "ClaimSettlementArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resetSettlementCollateral",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "cannot exist; the PDA binds the settlement to the bond"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ResetSettlementCollateralArgs"
+                },
+                "path": "params.merkle_root"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ResetSettlementCollateralArgs"
+                },
+                "path": "params.epoch_created_for"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "mint"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "owner of the settlement collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "settlement collateral vault to be emptied and closed"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateral",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "tokens funded to the closed settlement, closed together with the settlement collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_collateral"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "settlement",
+            "mint"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "owner of the bond collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "bond collateral vault the unclaimed tokens are returned to"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "resetSettlementCollateralArgs",
+          "type": {
+            "defined": "ResetSettlementCollateralArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "emergencyPause",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "pause_authority"
+          ]
+        },
+        {
+          "name": "pauseAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergencyResume",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "pause_authority"
+          ]
+        },
+        {
+          "name": "pauseAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPauseFlags",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "pause_authority"
+          ]
+        },
+        {
+          "name": "pauseAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "setPauseFlagsArgs",
+          "type": {
+            "defined": "SetPauseFlagsArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bondAlias",
+      "docs": [
+        "Marker of the vote account a bond was migrated to.",
+        "The bond stake accounts are delegated to the vote account, no other bond may be created for it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "docs": [
+              "Bond account that was migrated to the vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Vote account the bond was migrated to, the alias PDA address is derived from it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bond",
+      "docs": [
+        "Bond account for a validator vote address"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Program root config address. Validator bond is created for this config as PDA"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Validator vote address that this bond account is crated for",
+              "INVARIANTS:",
+              "- one bond account per validator vote address",
+              "- this program does NOT change stake account delegation voter_pubkey to any other validator vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority that may close the bond or withdraw stake accounts associated with the bond",
+              "The same powers has got the owner of the validator vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "Cost per mille per epoch.",
+              "This field represents the bid the bond (vote) account owner is willing to pay",
+              "for up to the `max_stake_wanted` being delegated.",
+              "The bid is in cost per mille per epoch similar to Google ads cpm system.",
+              "---",
+              "The actual amount of lamports deducted from the bond account for the processed bid",
+              "is based on the actual delegated lamports during the epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA Bond address bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxStakeWanted",
+            "docs": [
+              "Maximum stake (in lamports) that the bond (vote) account owner requests.",
+              "This is the maximum stake that will be distributed to the vote account",
+              "when all other constraints are fulfilled (managed off-chain).",
+              "The vote account owner then goes to auction to obtain up to that maximum.",
+              "Use the `cpmpe` field to define the bid for this purpose."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bidChargedEpoch",
+            "docs": [
+              "The last epoch the bid was charged for by the charge bid instruction"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bidChargedAmount",
+            "docs": [
+              "Lamports charged as the bid for the `bid_charged_epoch`,",
+              "lower than the bid amount when the bid was charged partially"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "migratedVoteAccount",
+            "docs": [
+              "Vote account the bond was migrated to, the bonded stake accounts are redelegated to this vote account.",
+              "Default pubkey means the bond has not been migrated and stakes are delegated to `vote_account`.",
+              "The bond PDA address is still derived from the original `vote_account`."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityMint",
+            "docs": [
+              "Bond mint that works as a transferable bond authority credential.",
+              "Holder of a token of the mint may configure the bond without burning the token.",
+              "Default pubkey means the holding mode is disabled and the bond mint token is burnt on configuration."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdEpoch",
+            "docs": [
+              "Epoch the bond was created at, the bid cannot be charged for an earlier epoch.",
+              "Zero for bonds created before the field was introduced."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                45
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collateralMint",
+      "docs": [
+        "SPL token mint (e.g., a liquid staking token) whitelisted to be used as a bond collateral.",
+        "Tokens are deposited to a collateral vault, a PDA token account per bond and mint",
+        "owned by the bonds withdrawer authority."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Config the mint is whitelisted for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The whitelisted SPL token mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the mint, one token is 10^decimals of token base units"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "lamportsPerToken",
+            "docs": [
+              "Price of one token in lamports, maintained by the admin authority"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "haircutBps",
+            "docs": [
+              "Haircut in basis points deducted from the token price when the collateral is valued as the bond funds"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "config",
+      "docs": [
+        "Root account that configures the validator bonds program"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "docs": [
+              "Admin authority that can update the config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "operatorAuthority",
+            "docs": [
+              "Operator authority (bot hot wallet)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "docs": [
+              "How many epochs permitting to claim the settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "docs": [
+              "How many epochs before withdraw is allowed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
+            "docs": [
+              "Minimum amount of lamports to be considered for a stake account operations (e.g., split)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bondsWithdrawerAuthorityBump",
+            "docs": [
+              "PDA bonds stake accounts authority bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pauseAuthority",
+            "docs": [
+              "Authority that can pause the program in case of emergency"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "docs": [
+              "How many slots to wait before settlement is permitted to be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "docs": [
+              "Minimum value of max_stake_wanted to be configured by vote account owners at bond."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAdminAuthority",
+            "docs": [
+              "Admin authority proposed by the current admin, waiting to be accepted by the new key,",
+              "default pubkey means no change is proposed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOperatorAuthority",
+            "docs": [
+              "Operator authority proposed by the admin, waiting to be accepted by the new key,",
+              "default pubkey means no change is proposed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingPauseAuthority",
+            "docs": [
+              "Pause authority proposed by the admin, waiting to be accepted by the new key,",
+              "default pubkey means no change is proposed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "docs": [
+              "How many epochs a config parameter change is staged before it can be applied.",
+              "When 0, the parameter changes are applied immediately."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingConfigChanges",
+            "docs": [
+              "Parameter changes staged by the admin, to be applied from the effective epoch"
+            ],
+            "type": {
+              "defined": "PendingConfigChanges"
+            }
+          },
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "Bitflag set of the paused instruction families, see `pause_flags` module"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "settlementCreatorAuthority",
+            "docs": [
+              "Operator role permitted to create settlements, default pubkey means the role is held by the operator authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "settlementFunderAuthority",
+            "docs": [
+              "Operator role permitted to fund settlements, default pubkey means the role is held by the operator authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "settlementCloserAuthority",
+            "docs": [
+              "Operator role permitted to reset and withdraw stake accounts of closed settlements,",
+              "default pubkey means the role is held by the operator authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bidFeeCollector",
+            "docs": [
+              "Authority of stake accounts split from bonds when the bid is charged"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "claimerFeeLamports",
+            "docs": [
+              "Lamports paid from the claimed amount to the claimer (rent payer) of a settlement claim",
+              "to cover the transaction costs of the permission-less claiming"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "docs": [
+              "Maximum claimer fee as basis points of the claimed amount, the fee is capped for small claims"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
+            "docs": [
+              "Upper bound of the delegated lamports the operator may charge the bid for,",
+              "the bid cannot be charged until it is configured"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "docs": [
+              "Maximum number of epochs a settlement claiming period can be extended in total,",
+              "see `Settlement::claim_expiry_extension`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved space for future changes"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                127
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "settlementClaim",
+      "docs": [
+        "The settlement claim serves for deduplication purposes,",
+        "preventing the same settlement from being claimed multiple times with the same claiming data"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "docs": [
+              "settlement account this claim belongs under"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountTo",
+            "docs": [
+              "stake account to which the claim has been withdrawn to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountStaker",
+            "docs": [
+              "staker authority as part of the merkle proof for this claim"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "docs": [
+              "withdrawer authority as part of the merkle proof for this claim"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "claim amount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump, one claim per settlement"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rentCollector",
+            "docs": [
+              "rent collector account to get the rent back for claim account creation"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                92
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "settlementCollateral",
+      "docs": [
+        "Token collateral of a particular mint funded to the settlement.",
+        "The claims are paid pro rata of the funded tokens to their funded lamports value,",
+        "i.e., at the valuation the settlement was funded with regardless of later changes of the collateral mint price."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "docs": [
+              "Settlement the collateral was funded to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the funded collateral tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokensFunded",
+            "docs": [
+              "Tokens moved to the settlement collateral vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsFunded",
+            "docs": [
+              "Lamports value the tokens were funded with, part of the settlement `collateral_funded`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokensClaimed",
+            "docs": [
+              "Tokens paid to the claimers"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsClaimed",
+            "docs": [
+              "Lamports of the claims paid with the tokens, part of the settlement `collateral_claimed`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "settlement",
+      "docs": [
+        "Settlement account for a particular config and merkle root",
+        "Settlement defines that a protected event happened and it will be settled"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "docs": [
+              "the settlement belongs under this bond, i.e., under a particular validator vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakerAuthority",
+            "docs": [
+              "settlement authority used as the 'staker' stake account authority",
+              "of stake accounts funded to this settlement"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "256-bit merkle root the settlement was created with, used as the PDA seed",
+              "and to check the claims against when the settlement has not been amended"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxTotalClaim",
+            "docs": [
+              "maximum number of funds that can ever be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxMerkleNodes",
+            "docs": [
+              "maximum number of merkle tree nodes that can ever be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsFunded",
+            "docs": [
+              "total lamports funded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsClaimed",
+            "docs": [
+              "total lamports that have been claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "merkleNodesClaimed",
+            "docs": [
+              "number of nodes that have been claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "epochCreatedFor",
+            "docs": [
+              "what epoch the Settlement has been created for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "slotCreatedAt",
+            "docs": [
+              "when the Settlement was created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentCollector",
+            "docs": [
+              "address that collects the rent exempt from the Settlement account when closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "splitRentCollector",
+            "docs": [
+              "address that collects rent exempt for \"split stake account\" possibly created on funding settlement"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "splitRentAmount",
+            "docs": [
+              "amount of lamports that are collected for rent exempt for \"split stake account\""
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bumps",
+            "docs": [
+              "PDA bumps"
+            ],
+            "type": {
+              "defined": "Bumps"
+            }
+          },
+          {
+            "name": "collateralFunded",
+            "docs": [
+              "lamports value of token collateral funded to the settlement, part of `lamports_funded`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "collateralClaimed",
+            "docs": [
+              "lamports claimed from the token collateral, part of `lamports_claimed`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amended",
+            "docs": [
+              "the settlement was amended before any claim, the claims are verified against `amended_merkle_root`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "amendedMerkleRoot",
+            "docs": [
+              "merkle root replacing the original one when the settlement was amended, zeroed when not amended"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "disputed",
+            "docs": [
+              "the settlement was disputed by the bond authority, claiming is blocked until the admin resolves it"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "disputeReasonHash",
+            "docs": [
+              "hash of the dispute reason provided by the bond authority, kept when the dispute is resolved",
+              "and cleared when the settlement is amended"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "claimExpiryExtension",
+            "docs": [
+              "number of epochs the settlement claiming is extended beyond the config `epochs_to_claim_settlement`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawRequest",
+      "docs": [
+        "Request from a validator to withdraw the bond"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Validator vote account that requested the withdrawal"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bond",
+            "docs": [
+              "Bond account that the withdraw request is for (has to match with vote_account)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "Epoch when the withdrawal was requested, i.e., when this \"ticket\" is created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "requestedAmount",
+            "docs": [
+              "Amount of lamports to withdraw"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawnAmount",
+            "docs": [
+              "Amount of lamports withdrawn so far"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                92
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "PubkeyValueChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old",
+            "type": "publicKey"
+          },
+          {
+            "name": "new",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "U64ValueChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old",
+            "type": "u64"
+          },
+          {
+            "name": "new",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DelegationInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voterPubkey",
+            "docs": [
+              "to whom the stake is delegated"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "activated stake amount, set at delegate() time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "activationEpoch",
+            "docs": [
+              "epoch at which this stake was activated, std::Epoch::MAX if is a bootstrap stake"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deactivationEpoch",
+            "docs": [
+              "epoch the stake was deactivated, std::Epoch::MAX if not deactivated"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SplitStakeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChargeBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
+            "docs": [
+              "epoch the bid is charged for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "delegatedLamports",
+            "docs": [
+              "lamports delegated to the bond vote account in the epoch, the bid is calculated from,",
+              "bounded by the config `bid_max_delegated_lamports`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureBondAuthorityMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "docs": [
+              "When true the bond mint of the current validator identity becomes a persistent bond authority credential,",
+              "its holder may configure the bond without burning the token.",
+              "When false the holding mode is switched off and the bond mint token is burnt on configuration."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureBondWithMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "validatorIdentity",
+            "docs": [
+              "Validator identity configured within the vote account.",
+              "For the bond authority mint it is the identity the mint was created for."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bondAuthority",
+            "docs": [
+              "New bond authority that can manage the bond account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "New `cpmpe` value (cost per mille per epoch).",
+              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxStakeWanted",
+            "docs": [
+              "new max_stake_wanted value that vote account owner declares",
+              "as the maximum delegated stake wanted"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureBondArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondAuthority",
+            "docs": [
+              "New bond authority that can manage the bond account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "New `cpmpe` value (cost per mille per epoch).",
+              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxStakeWanted",
+            "docs": [
+              "New `max_stake_wanted` value that the vote account owner declares",
+              "as the maximum delegated stake desired."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitBondArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "cpmpe",
+            "type": "u64"
+          },
+          {
+            "name": "maxStakeWanted",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureCollateralMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lamportsPerToken",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "haircutBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundBondCollateralArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "amount of tokens (in base units) to be deposited"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitCollateralMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lamportsPerToken",
+            "docs": [
+              "price of one token in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "haircutBps",
+            "docs": [
+              "haircut in basis points deducted from the price when valuing the collateral"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ResetSettlementCollateralArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "merkle root of the closed settlement; settlement PDA seed"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "epochCreatedFor",
+            "docs": [
+              "epoch the closed settlement was created for; settlement PDA seed"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "operator",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "pauseAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "settlementCreator",
             "docs": [
-              "maximum number of merkle tree nodes that can ever be claimed"
+              "operator roles, default pubkey means the role falls back to the operator authority"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "settlementFunder",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "settlementCloser",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "bidFeeCollector",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
+            "docs": [
+              "upper bound of the delegated lamports the bid is charged for, see `Config::bid_max_delegated_lamports`"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "docs": [
+              "maximum total extension of a settlement claiming period, see `Config::max_claim_expiry_extension`"
+            ],
+            "type": {
+              "option": "u32"
+            }
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeLamports",
+            "docs": [
+              "fee for permission-less settlement claiming, see `Config::claimer_fee`"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "cancelPendingChanges",
+            "docs": [
+              "drops all pending authority and parameter changes before the new ones are processed"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPauseFlagsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "bitflag set of the paused instruction families, see `pause_flags`, 0 resumes all of them"
             ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "operatorAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "epochsToClaimSettlement",
             "type": "u64"
           },
           {
-            "name": "lamportsFunded",
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmendSettlementArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
             "docs": [
-              "total lamports funded"
+              "new merkle root the claims are verified against,",
+              "the settlement address stays derived from the original merkle root"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxTotalClaim",
+            "docs": [
+              "new maximal number of lamports that can be claimed from this settlement"
             ],
             "type": "u64"
           },
           {
-            "name": "lamportsClaimed",
+            "name": "maxMerkleNodes",
             "docs": [
-              "total lamports that have been claimed"
+              "new maximal number of merkle tree nodes that can be claimed from this settlement"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimSettlementArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proof",
+            "docs": [
+              "proof that the claim is appropriate"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "treeNodeHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "stakeAccountStaker",
+            "docs": [
+              "staker authority of the stake_account_to; merkle root verification"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "docs": [
+              "withdrawer authority of the stake_account_to; merkle root verification"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "claim",
+            "docs": [
+              "claim amount; merkle root verification"
             ],
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DisputeSettlementArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reasonHash",
+            "docs": [
+              "hash of the dispute reason published off-chain by the validator"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExtendSettlementExpiryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epochs",
+            "docs": [
+              "number of epochs added to the settlement claiming period"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitSettlementArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "merkle root for this settlement, multiple settlements can be created with the same merkle root,",
+              "settlements will be distinguished by the vote_account"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "merkleNodesClaimed",
+            "name": "maxTotalClaim",
             "docs": [
-              "number of nodes that have been claimed"
+              "maximal number of lamports that can be claimed from this settlement"
             ],
             "type": "u64"
           },
           {
-            "name": "epochCreatedFor",
+            "name": "maxMerkleNodes",
             "docs": [
-              "what epoch the Settlement has been created for"
+              "maximal number of merkle tree nodes that can be claimed from this settlement"
             ],
             "type": "u64"
           },
           {
-            "name": "slotCreatedAt",
+            "name": "rentCollector",
             "docs": [
-              "when the Settlement was created"
+              "collects the rent exempt from the settlement account when closed"
             ],
-            "type": "u64"
+            "type": "publicKey"
           },
           {
-            "name": "rentCollector",
+            "name": "epoch",
             "docs": [
-              "address that collects the rent exempt from the Settlement account when closed"
+              "epoch that the settlement is created for"
             ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MergeStakeArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
             "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitWithdrawRequestArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigParamChanges",
+      "docs": [
+        "Config parameter changes, `None` means the parameter is not changed"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "splitRentCollector",
-            "docs": [
-              "address that collects rent exempt for \"split stake account\" possibly created on funding settlement"
-            ],
+            "name": "withdrawLockupEpochs",
             "type": {
-              "option": "publicKey"
+              "option": "u64"
             }
           },
           {
-            "name": "splitRentAmount",
-            "docs": [
-              "amount of lamports that are collected for rent exempt for \"split stake account\""
-            ],
-            "type": "u64"
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "bumps",
-            "docs": [
-              "PDA bumps"
-            ],
+            "name": "slotsToStartSettlementClaiming",
             "type": {
-              "defined": "Bumps"
+              "option": "u64"
             }
           },
           {
-            "name": "reserved",
-            "docs": [
-              "reserve space for future extensions"
-            ],
+            "name": "minBondMaxStakeWanted",
             "type": {
-              "array": [
-                "u8",
-                91
-              ]
+              "option": "u64"
+            }
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "type": {
+              "option": "u32"
             }
           }
         ]
       }
     },
     {
-      "name": "withdrawRequest",
+      "name": "PendingConfigChanges",
       "docs": [
-        "Request from a validator to withdraw the bond"
+        "Config parameters staged by the admin authority.",
+        "Any party may apply them once the `effective_epoch` is reached.",
+        "Fixed-size encoded to keep the offsets of the following Config fields stable,",
+        "a parameter value is considered only when its bit is set in `staged_params`."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "voteAccount",
+            "name": "effectiveEpoch",
             "docs": [
-              "Validator vote account that requested the withdrawal"
+              "epoch from which the changes may be applied"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "bond",
+            "name": "stagedParams",
             "docs": [
-              "Bond account that the withdraw request is for (has to match with vote_account)"
+              "bitflag set of the staged parameters, see `staged_config_params` module, 0 means nothing is staged"
             ],
-            "type": "publicKey"
+            "type": "u16"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": "u64"
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": "u64"
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "claimerFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "type": "u16"
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Bumps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pda",
+            "type": "u8"
+          },
+          {
+            "name": "stakerAuthority",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "MigrateAccountEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "account",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "accountType",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "accountVersion",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
+        {
+          "name": "dataSize",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "validatorIdentity",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigureBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bondAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
           },
-          {
-            "name": "epoch",
-            "docs": [
-              "Epoch when the withdrawal was requested, i.e., when this \"ticket\" is created"
-            ],
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
           },
-          {
-            "name": "requestedAmount",
-            "docs": [
-              "Amount of lamports to withdraw"
-            ],
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
           },
-          {
-            "name": "withdrawnAmount",
-            "docs": [
-              "Amount of lamports withdrawn so far"
-            ],
-            "type": "u64"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigureBondWithMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "validatorIdentity",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenBurned",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "bondAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
           },
-          {
-            "name": "bump",
-            "docs": [
-              "PDA account bump"
-            ],
-            "type": "u8"
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
           },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserve space for future extensions"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                93
-              ]
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
             }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "PubkeyValueChange",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "old",
-            "type": "publicKey"
           },
-          {
-            "name": "new",
-            "type": "publicKey"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "U64ValueChange",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "old",
-            "type": "u64"
+      "name": "ConfigureBondAuthorityMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityMint",
+          "type": {
+            "defined": "PubkeyValueChange"
           },
-          {
-            "name": "new",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FundBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAuthoritySigner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositedAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MintBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "validatorIdentity",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "validatorIdentityTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenMetadata",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChargeBidEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "delegatedLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "chargedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidChargedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "splitStakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bidFeeCollector",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "DelegationInfo",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "voterPubkey",
-            "docs": [
-              "to whom the stake is delegated"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stake",
-            "docs": [
-              "activated stake amount, set at delegate() time"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "activationEpoch",
-            "docs": [
-              "epoch at which this stake was activated, std::Epoch::MAX if is a bootstrap stake"
-            ],
-            "type": "u64"
+      "name": "MigrateBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bondVoteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": {
+            "defined": "PubkeyValueChange"
           },
-          {
-            "name": "deactivationEpoch",
-            "docs": [
-              "epoch the stake was deactivated, std::Epoch::MAX if not deactivated"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "SplitStakeData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "publicKey"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "InitCollateralMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "decimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "lamportsPerToken",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "haircutBps",
+          "type": "u16",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ConfigureBondWithMintArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "validatorIdentity",
-            "docs": [
-              "Validator identity configured within the vote account."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "bondAuthority",
-            "docs": [
-              "New bond authority that can manage the bond account."
-            ],
-            "type": {
-              "option": "publicKey"
+      "name": "ConfigureCollateralMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "collateralMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamportsPerToken",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
             }
           },
-          {
-            "name": "cpmpe",
-            "docs": [
-              "New `cpmpe` value (cost per mille per epoch).",
-              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
-            ],
-            "type": {
-              "option": "u64"
+          "index": false
+        },
+        {
+          "name": "haircutBps",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
             }
           },
-          {
-            "name": "maxStakeWanted",
-            "docs": [
-              "new max_stake_wanted value that vote account owner declares",
-              "as the maximum delegated stake wanted"
-            ],
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FundBondCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "vaultAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "collateralValue",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimWithdrawCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "withdrawRequest",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawerTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawingTokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "withdrawingAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "withdrawnAmount",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ConfigureBondArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bondAuthority",
-            "docs": [
-              "New bond authority that can manage the bond account."
-            ],
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "cpmpe",
-            "docs": [
-              "New `cpmpe` value (cost per mille per epoch).",
-              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
-            ],
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "maxStakeWanted",
-            "docs": [
-              "New `max_stake_wanted` value that the vote account owner declares",
-              "as the maximum delegated stake desired."
-            ],
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+      "name": "FundSettlementCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlementCollateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fundingTokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fundingAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lamportsFunded",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "collateralFunded",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "InitBondArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bondAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "cpmpe",
-            "type": "u64"
+      "name": "ClaimSettlementCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlementClaim",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimerTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountStaker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountWithdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "settlementLamportsClaimed",
+          "type": {
+            "defined": "U64ValueChange"
           },
-          {
-            "name": "maxStakeWanted",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "settlementMerkleNodesClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimerFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "claimerFeeTokens",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ConfigureConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "operator",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "pauseAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "epochsToClaimSettlement",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "minimumStakeLamports",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "slotsToStartSettlementClaiming",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "minBondMaxStakeWanted",
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+      "name": "ResetSettlementCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlementCollateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "returnedTokenAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "InitConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "adminAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "operatorAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "epochsToClaimSettlement",
-            "type": "u64"
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "type": "u64"
-          },
-          {
-            "name": "slotsToStartSettlementClaiming",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "InitConfigEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "adminAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "operatorAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawLockupEpochs",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "epochsToClaimSettlement",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "minimumStakeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slotsToStartSettlementClaiming",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ClaimSettlementArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "proof",
-            "docs": [
-              "proof that the claim is appropriate"
-            ],
-            "type": {
-              "vec": {
-                "array": [
-                  "u8",
-                  32
-                ]
-              }
+      "name": "ConfigureConfigEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "adminAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
             }
           },
-          {
-            "name": "treeNodeHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+          "index": false
+        },
+        {
+          "name": "operatorAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
             }
           },
-          {
-            "name": "stakeAccountStaker",
-            "docs": [
-              "staker authority of the stake_account_to; merkle root verification"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakeAccountWithdrawer",
-            "docs": [
-              "withdrawer authority of the stake_account_to; merkle root verification"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "claim",
-            "docs": [
-              "claim amount; merkle root verification"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "InitSettlementArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "merkleRoot",
-            "docs": [
-              "merkle root for this settlement, multiple settlements can be created with the same merkle root,",
-              "settlements will be distinguished by the vote_account"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+          "index": false
+        },
+        {
+          "name": "pauseAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
             }
           },
-          {
-            "name": "maxTotalClaim",
-            "docs": [
-              "maximal number of lamports that can be claimed from this settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "maxMerkleNodes",
-            "docs": [
-              "maximal number of merkle tree nodes that can be claimed from this settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rentCollector",
-            "docs": [
-              "collects the rent exempt from the settlement account when closed"
-            ],
-            "type": "publicKey"
+          "index": false
+        },
+        {
+          "name": "settlementCreatorAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
           },
-          {
-            "name": "epoch",
-            "docs": [
-              "epoch that the settlement is created for"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "MergeStakeArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "settlement",
-            "type": "publicKey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "InitWithdrawRequestArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Bumps",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pda",
-            "type": "u8"
+          "index": false
+        },
+        {
+          "name": "settlementFunderAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
           },
-          {
-            "name": "stakerAuthority",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "InitBondEvent",
-      "fields": [
+          "index": false
+        },
         {
-          "name": "bond",
-          "type": "publicKey",
+          "name": "settlementCloserAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "config",
-          "type": "publicKey",
+          "name": "bidFeeCollector",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "voteAccount",
-          "type": "publicKey",
+          "name": "bidMaxDelegatedLamports",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "validatorIdentity",
-          "type": "publicKey",
+          "name": "maxClaimExpiryExtension",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "authority",
-          "type": "publicKey",
+          "name": "epochsToClaimSettlement",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "cpmpe",
-          "type": "u64",
+          "name": "minimumStakeLamports",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "maxStakeWanted",
-          "type": "u64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "ConfigureBondEvent",
-      "fields": [
-        {
-          "name": "bondAuthority",
+          "name": "withdrawLockupEpochs",
           "type": {
             "option": {
-              "defined": "PubkeyValueChange"
+              "defined": "U64ValueChange"
             }
           },
           "index": false
         },
         {
-          "name": "cpmpe",
+          "name": "slotsToStartSettlementClaiming",
           "type": {
             "option": {
               "defined": "U64ValueChange"
@@ -3277,35 +7581,34 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "maxStakeWanted",
+          "name": "minBondMaxStakeWanted",
           "type": {
             "option": {
               "defined": "U64ValueChange"
             }
           },
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "ConfigureBondWithMintEvent",
-      "fields": [
+        },
         {
-          "name": "validatorIdentity",
-          "type": "publicKey",
+          "name": "configChangeDelayEpochs",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "bondAuthority",
+          "name": "claimerFeeLamports",
           "type": {
             "option": {
-              "defined": "PubkeyValueChange"
+              "defined": "U64ValueChange"
             }
           },
           "index": false
         },
         {
-          "name": "cpmpe",
+          "name": "claimerFeeMaxBps",
           "type": {
             "option": {
               "defined": "U64ValueChange"
@@ -3314,10 +7617,31 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "maxStakeWanted",
+          "name": "pendingAdminAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "pendingOperatorAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "pendingPauseAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "pendingConfigChanges",
           "type": {
             "option": {
-              "defined": "U64ValueChange"
+              "defined": "PendingConfigChanges"
             }
           },
           "index": false
@@ -3325,63 +7649,65 @@ export type ValidatorBonds = {
       ]
     },
     {
-      "name": "FundBondEvent",
+      "name": "EmergencyPauseEvent",
       "fields": [
         {
-          "name": "bond",
-          "type": "publicKey",
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "voteAccount",
+          "name": "config",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakeAccount",
+          "name": "adminAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakeAuthoritySigner",
+          "name": "operatorAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "depositedAmount",
+          "name": "epochsToClaimSettlement",
           "type": "u64",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "MintBondEvent",
-      "fields": [
+        },
         {
-          "name": "bond",
-          "type": "publicKey",
+          "name": "withdrawLockupEpochs",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "validatorIdentity",
-          "type": "publicKey",
+          "name": "minimumStakeLamports",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "validatorIdentityTokenAccount",
+          "name": "pauseAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenMetadata",
-          "type": "publicKey",
+          "name": "pauseFlags",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "InitConfigEvent",
+      "name": "EmergencyResumeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
@@ -3398,12 +7724,12 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
+          "name": "epochsToClaimSettlement",
           "type": "u64",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "withdrawLockupEpochs",
           "type": "u64",
           "index": false
         },
@@ -3413,179 +7739,238 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "bondsWithdrawerAuthority",
+          "name": "pauseAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "slotsToStartSettlementClaiming",
-          "type": "u64",
+          "name": "pauseFlags",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "ConfigureConfigEvent",
+      "name": "SetPauseFlagsEvent",
       "fields": [
         {
-          "name": "adminAuthority",
-          "type": {
-            "option": {
-              "defined": "PubkeyValueChange"
-            }
-          },
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "operatorAuthority",
-          "type": {
-            "option": {
-              "defined": "PubkeyValueChange"
-            }
-          },
+          "name": "config",
+          "type": "publicKey",
           "index": false
         },
         {
           "name": "pauseAuthority",
-          "type": {
-            "option": {
-              "defined": "PubkeyValueChange"
-            }
-          },
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "pauseFlags",
           "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
+            "defined": "U64ValueChange"
           },
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimSettlementEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "minimumStakeLamports",
-          "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
-          },
+          "name": "settlementClaim",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
-          "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
-          },
+          "name": "settlement",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "slotsToStartSettlementClaiming",
+          "name": "settlementLamportsClaimed",
           "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
+            "defined": "U64ValueChange"
           },
           "index": false
         },
         {
-          "name": "minBondMaxStakeWanted",
-          "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
-          },
+          "name": "settlementMerkleNodesClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stakeAccountTo",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountWithdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountStaker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimerFee",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "EmergencyPauseEvent",
+      "name": "CloseSettlementClaimEvent",
       "fields": [
         {
-          "name": "config",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "adminAuthority",
+          "name": "settlementClaim",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "operatorAuthority",
+          "name": "stakeAccountTo",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountWithdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountStaker",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "amount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
-          "type": "u64",
+          "name": "rentCollector",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "minimumStakeLamports",
+          "name": "rentRefund",
           "type": "u64",
           "index": false
         },
         {
-          "name": "pauseAuthority",
-          "type": "publicKey",
+          "name": "epoch",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "EmergencyResumeEvent",
+      "name": "InitSettlementEvent",
       "fields": [
         {
-          "name": "config",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "adminAuthority",
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "operatorAuthority",
+          "name": "voteAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "stakerAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "maxTotalClaim",
           "type": "u64",
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
+          "name": "maxMerkleNodes",
           "type": "u64",
           "index": false
         },
         {
-          "name": "minimumStakeLamports",
+          "name": "epochCreatedFor",
           "type": "u64",
           "index": false
         },
         {
-          "name": "pauseAuthority",
+          "name": "slotCreatedAt",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "ClaimSettlementEvent",
+      "name": "AmendSettlementEvent",
       "fields": [
         {
-          "name": "settlementClaim",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
@@ -3595,84 +7980,113 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "settlementLamportsClaimed",
+          "name": "oldMerkleRoot",
           "type": {
-            "defined": "U64ValueChange"
+            "array": [
+              "u8",
+              32
+            ]
           },
           "index": false
         },
         {
-          "name": "settlementMerkleNodesClaimed",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "stakeAccountTo",
-          "type": "publicKey",
+          "name": "newMerkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
           "index": false
         },
         {
-          "name": "stakeAccountWithdrawer",
-          "type": "publicKey",
+          "name": "maxTotalClaim",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         },
         {
-          "name": "stakeAccountStaker",
-          "type": "publicKey",
+          "name": "maxMerkleNodes",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         },
         {
-          "name": "amount",
-          "type": "u64",
+          "name": "slotCreatedAt",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         },
         {
-          "name": "rentCollector",
+          "name": "authority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "CloseSettlementClaimEvent",
+      "name": "DisputeSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "rentCollector",
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reasonHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "authority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "InitSettlementEvent",
+      "name": "ResolveDisputeEvent",
       "fields": [
         {
-          "name": "bond",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "settlement",
-          "type": "publicKey",
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "voteAccount",
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakerAuthority",
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "merkleRoot",
+          "name": "reasonHash",
           "type": {
             "array": [
               "u8",
@@ -3682,27 +8096,46 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "maxTotalClaim",
-          "type": "u64",
+          "name": "adminAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ExtendSettlementExpiryEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "maxMerkleNodes",
-          "type": "u64",
+          "name": "bond",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochCreatedFor",
-          "type": "u64",
+          "name": "settlement",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "slotCreatedAt",
-          "type": "u64",
+          "name": "claimExpiryExtension",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         },
         {
-          "name": "rentCollector",
+          "name": "expirationEpoch",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
+        {
+          "name": "authority",
           "type": "publicKey",
           "index": false
         }
@@ -3711,6 +8144,11 @@ export type ValidatorBonds = {
     {
       "name": "CloseSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
@@ -3790,6 +8228,11 @@ export type ValidatorBonds = {
     {
       "name": "CancelSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
@@ -3864,6 +8307,11 @@ export type ValidatorBonds = {
     {
       "name": "FundSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
@@ -3925,11 +8373,21 @@ export type ValidatorBonds = {
     {
       "name": "MergeStakeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "stakerAuthority",
           "type": "publicKey",
@@ -3949,6 +8407,13 @@ export type ValidatorBonds = {
           },
           "index": false
         },
+        {
+          "name": "destinationLamports",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
         {
           "name": "sourceStake",
           "type": "publicKey",
@@ -3962,12 +8427,27 @@ export type ValidatorBonds = {
             }
           },
           "index": false
+        },
+        {
+          "name": "sourceLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
       "name": "ResetStakeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
@@ -3989,7 +8469,64 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "voteAccount",
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "operatorAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "WithdrawStakeEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "operatorAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawTo",
           "type": "publicKey",
           "index": false
         },
@@ -3997,24 +8534,29 @@ export type ValidatorBonds = {
           "name": "settlementStakerAuthority",
           "type": "publicKey",
           "index": false
+        },
+        {
+          "name": "withdrawnAmount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "WithdrawStakeEvent",
+      "name": "RedelegateStakeEvent",
       "fields": [
         {
-          "name": "config",
-          "type": "publicKey",
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "operatorAuthority",
+          "name": "config",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "settlement",
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
@@ -4024,18 +8566,18 @@ export type ValidatorBonds = {
           "index": false
         },
         {
-          "name": "withdrawTo",
+          "name": "oldVoteAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "settlementStakerAuthority",
+          "name": "newVoteAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "withdrawnAmount",
-          "type": "u64",
+          "name": "deactivated",
+          "type": "bool",
           "index": false
         }
       ]
@@ -4043,6 +8585,11 @@ export type ValidatorBonds = {
     {
       "name": "InitWithdrawRequestEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "withdrawRequest",
           "type": "publicKey",
@@ -4073,6 +8620,11 @@ export type ValidatorBonds = {
     {
       "name": "CancelWithdrawRequestEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "withdrawRequest",
           "type": "publicKey",
@@ -4103,6 +8655,11 @@ export type ValidatorBonds = {
     {
       "name": "ClaimWithdrawRequestEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "withdrawRequest",
           "type": "publicKey",
@@ -4123,6 +8680,13 @@ export type ValidatorBonds = {
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "mergedStakeAccounts",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
         {
           "name": "splitStake",
           "type": {
@@ -4339,203 +8903,3052 @@ export type ValidatorBonds = {
       "msg": "Provided stake account is not funded under the settlement"
     },
     {
-      "code": 6037,
-      "name": "VoteAccountValidatorIdentityMismatch",
-      "msg": "Validator vote account does not match to provided validator identity signature"
+      "code": 6037,
+      "name": "VoteAccountValidatorIdentityMismatch",
+      "msg": "Validator vote account does not match to provided validator identity signature"
+    },
+    {
+      "code": 6038,
+      "name": "VoteAccountMismatch",
+      "msg": "Bond vote account address does not match with the provided validator vote account"
+    },
+    {
+      "code": 6039,
+      "name": "ConfigAccountMismatch",
+      "msg": "Bond config address does not match with the provided config account"
+    },
+    {
+      "code": 6040,
+      "name": "WithdrawRequestVoteAccountMismatch",
+      "msg": "Withdraw request vote account address does not match with the provided validator vote account"
+    },
+    {
+      "code": 6041,
+      "name": "BondAccountMismatch",
+      "msg": "Bond account address does not match with the stored one"
+    },
+    {
+      "code": 6042,
+      "name": "SettlementAccountMismatch",
+      "msg": "Settlement account address does not match with the stored one"
+    },
+    {
+      "code": 6043,
+      "name": "RentCollectorMismatch",
+      "msg": "Rent collector address does not match permitted rent collector"
+    },
+    {
+      "code": 6044,
+      "name": "StakerAuthorityMismatch",
+      "msg": "Stake account's staker does not match with the provided authority"
+    },
+    {
+      "code": 6045,
+      "name": "NonBondStakeAuthorities",
+      "msg": "One or both stake authorities does not belong to bonds program"
+    },
+    {
+      "code": 6046,
+      "name": "SettlementAuthorityMismatch",
+      "msg": "Stake account staker authority mismatches with the settlement authority"
+    },
+    {
+      "code": 6047,
+      "name": "StakeDelegationMismatch",
+      "msg": "Delegation of provided stake account mismatches"
+    },
+    {
+      "code": 6048,
+      "name": "WithdrawRequestAmountTooSmall",
+      "msg": "Too small non-withdrawn withdraw request amount, cancel and init new one"
+    },
+    {
+      "code": 6049,
+      "name": "WithdrawRequestAlreadyFulfilled",
+      "msg": "Withdraw request has been already fulfilled"
+    },
+    {
+      "code": 6050,
+      "name": "ClaimSettlementMerkleTreeNodeMismatch",
+      "msg": "Claim settlement merkle tree node mismatch"
+    },
+    {
+      "code": 6051,
+      "name": "WrongStakeAccountStaker",
+      "msg": "Wrong staker authority of the stake account"
+    },
+    {
+      "code": 6052,
+      "name": "AlreadyPaused",
+      "msg": "Requested pause and already Paused"
+    },
+    {
+      "code": 6053,
+      "name": "NotPaused",
+      "msg": "Requested resume, but not Paused"
+    },
+    {
+      "code": 6054,
+      "name": "ProgramIsPaused",
+      "msg": "Emergency Pause is Active"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidPauseAuthority",
+      "msg": "Invalid pause authority"
+    },
+    {
+      "code": 6056,
+      "name": "MergeMismatchSameSourceDestination",
+      "msg": "Source and destination cannot be the same for merge operation"
+    },
+    {
+      "code": 6057,
+      "name": "WrongStakeAccountState",
+      "msg": "Wrong state of the stake account"
+    },
+    {
+      "code": 6058,
+      "name": "ValidatorIdentityBondMintMismatch",
+      "msg": "Validator identity mismatch for bond mint"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidBondMintSupply",
+      "msg": "Bond mint permits only a single token to exist"
+    },
+    {
+      "code": 6060,
+      "name": "OperatorAndPauseAuthorityMismatch",
+      "msg": "Operation permitted only to operator or pause authority"
+    },
+    {
+      "code": 6061,
+      "name": "SettlementNotReadyForClaiming",
+      "msg": "Settlement slots to start claiming not expired yet"
+    },
+    {
+      "code": 6062,
+      "name": "InvalidVoteAccountType",
+      "msg": "Unsupported vote account type to deserialize"
+    },
+    {
+      "code": 6063,
+      "name": "MaxStakeWantedTooLow",
+      "msg": "Max stake wanted value is lower to minimum configured value"
+    },
+    {
+      "code": 6064,
+      "name": "NoPendingAuthorityChange",
+      "msg": "No pending authority change for the signer to accept"
+    },
+    {
+      "code": 6065,
+      "name": "NoPendingConfigChanges",
+      "msg": "No pending config changes to apply"
+    },
+    {
+      "code": 6066,
+      "name": "PendingConfigChangesNotEffective",
+      "msg": "Pending config changes are not effective yet"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6068,
+      "name": "BidAlreadyCharged",
+      "msg": "Bid for the epoch has been already charged"
+    },
+    {
+      "code": 6069,
+      "name": "BidEpochNotFinished",
+      "msg": "Bid can be charged only for already finished epoch"
+    },
+    {
+      "code": 6070,
+      "name": "BidFeeCollectorNotConfigured",
+      "msg": "Bid fee collector is not configured"
+    },
+    {
+      "code": 6071,
+      "name": "BondMigrationNotPermitted",
+      "msg": "Bond migration requires validator identity or withdrawer of both vote accounts"
+    },
+    {
+      "code": 6072,
+      "name": "NothingToRedelegate",
+      "msg": "Stake account is already delegated to the bond vote account"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidCollateralMintAddress",
+      "msg": "Invalid collateral mint account address"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidCollateralHaircut",
+      "msg": "Collateral haircut basis points exceeds 10000"
+    },
+    {
+      "code": 6075,
+      "name": "CollateralAmountTooSmall",
+      "msg": "Collateral token amount has got no value"
+    },
+    {
+      "code": 6076,
+      "name": "CollateralInsufficient",
+      "msg": "Not enough token collateral in the vault"
+    },
+    {
+      "code": 6077,
+      "name": "CollateralClaimExceedsFunded",
+      "msg": "Claim exceeds token collateral funded to the settlement"
+    },
+    {
+      "code": 6078,
+      "name": "SettlementAlreadyClaimed",
+      "msg": "Settlement cannot be amended as it has been already claimed"
+    },
+    {
+      "code": 6079,
+      "name": "SettlementDisputeWindowClosed",
+      "msg": "Settlement dispute window has already passed"
+    },
+    {
+      "code": 6080,
+      "name": "SettlementDisputed",
+      "msg": "Settlement is disputed, waiting for the admin resolution"
+    },
+    {
+      "code": 6081,
+      "name": "SettlementAlreadyDisputed",
+      "msg": "Settlement has been already disputed"
+    },
+    {
+      "code": 6082,
+      "name": "SettlementNotDisputed",
+      "msg": "Settlement is not disputed"
+    },
+    {
+      "code": 6083,
+      "name": "InvalidBondAuthorityMint",
+      "msg": "Bond authority mint is not the bond mint of the validator identity"
+    },
+    {
+      "code": 6084,
+      "name": "BondAuthorityMintNotHeld",
+      "msg": "Token account does not hold the bond authority mint token"
+    },
+    {
+      "code": 6085,
+      "name": "InvalidSettlementExpiryExtension",
+      "msg": "Settlement expiry extension has to be a positive number of epochs"
+    },
+    {
+      "code": 6086,
+      "name": "InvalidClaimerFeeBps",
+      "msg": "Claimer fee basis points exceeds the maximum"
+    },
+    {
+      "code": 6087,
+      "name": "MigrateAccountNotSupported",
+      "msg": "Account type cannot be migrated"
+    },
+    {
+      "code": 6088,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account has been already migrated to the current version"
+    },
+    {
+      "code": 6089,
+      "name": "PauseFlagsNotChanged",
+      "msg": "Pause flags are already set to the requested value"
+    },
+    {
+      "code": 6090,
+      "name": "BidEpochBeforeBondCreation",
+      "msg": "Bid cannot be charged for an epoch before the bond was created"
+    },
+    {
+      "code": 6091,
+      "name": "BidDelegatedLamportsExceeded",
+      "msg": "Delegated lamports exceed the config maximum for charging the bid"
+    },
+    {
+      "code": 6092,
+      "name": "BidAmountIsZero",
+      "msg": "Bid amount for the epoch is zero"
+    },
+    {
+      "code": 6093,
+      "name": "BondAlreadyExists",
+      "msg": "Bond already exists for the vote account"
+    },
+    {
+      "code": 6094,
+      "name": "SettlementExpiryExtensionExceeded",
+      "msg": "Settlement expiry extension exceeds the config maximum"
+    },
+    {
+      "code": 6095,
+      "name": "BondAliasMismatch",
+      "msg": "Bond alias account does not match the bond migration"
+    },
+    {
+      "code": 6096,
+      "name": "BondAlreadyMigrated",
+      "msg": "Bond stake accounts are already delegated to the vote account"
+    },
+    {
+      "code": 6097,
+      "name": "CollateralClaimTooSmall",
+      "msg": "Settlement claim is too small to be paid with the collateral tokens"
+    },
+    {
+      "code": 6098,
+      "name": "EmptyDisputeReasonHash",
+      "msg": "Settlement dispute reason hash cannot be empty"
+    }
+  ]
+};
+
+export const IDL: ValidatorBonds = {
+  "version": "1.5.0",
+  "name": "validator_bonds",
+  "constants": [
+    {
+      "name": "PROGRAM_ID",
+      "type": "string",
+      "value": "\"vBoNdEvzMrSai7is21XgVYik65mqtaKXuSdMBJ1xkW4\""
+    },
+    {
+      "name": "BOND_SEED",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 95, 97, 99, 99, 111, 117, 110, 116]"
+    },
+    {
+      "name": "BOND_ALIAS_SEED",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 95, 97, 108, 105, 97, 115]"
+    },
+    {
+      "name": "BOND_MINT_SEED",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 95, 109, 105, 110, 116]"
     },
     {
-      "code": 6038,
-      "name": "VoteAccountMismatch",
-      "msg": "Bond vote account address does not match with the provided validator vote account"
+      "name": "SETTLEMENT_SEED",
+      "type": "bytes",
+      "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 97, 99, 99, 111, 117, 110, 116]"
     },
     {
-      "code": 6039,
-      "name": "ConfigAccountMismatch",
-      "msg": "Bond config address does not match with the provided config account"
+      "name": "WITHDRAW_REQUEST_SEED",
+      "type": "bytes",
+      "value": "[119, 105, 116, 104, 100, 114, 97, 119, 95, 97, 99, 99, 111, 117, 110, 116]"
     },
     {
-      "code": 6040,
-      "name": "WithdrawRequestVoteAccountMismatch",
-      "msg": "Withdraw request vote account address does not match with the provided validator vote account"
+      "name": "SETTLEMENT_CLAIM_SEED",
+      "type": "bytes",
+      "value": "[99, 108, 97, 105, 109, 95, 97, 99, 99, 111, 117, 110, 116]"
     },
     {
-      "code": 6041,
-      "name": "BondAccountMismatch",
-      "msg": "Bond account address does not match with the stored one"
+      "name": "COLLATERAL_MINT_SEED",
+      "type": "bytes",
+      "value": "[99, 111, 108, 108, 97, 116, 101, 114, 97, 108, 95, 109, 105, 110, 116]"
     },
     {
-      "code": 6042,
-      "name": "SettlementAccountMismatch",
-      "msg": "Settlement account address does not match with the stored one"
+      "name": "COLLATERAL_VAULT_SEED",
+      "type": "bytes",
+      "value": "[99, 111, 108, 108, 97, 116, 101, 114, 97, 108, 95, 118, 97, 117, 108, 116]"
     },
     {
-      "code": 6043,
-      "name": "RentCollectorMismatch",
-      "msg": "Rent collector address does not match permitted rent collector"
+      "name": "SETTLEMENT_COLLATERAL_SEED",
+      "type": "bytes",
+      "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 99, 111, 108, 108, 97, 116, 101, 114, 97, 108]"
     },
     {
-      "code": 6044,
-      "name": "StakerAuthorityMismatch",
-      "msg": "Stake account's staker does not match with the provided authority"
+      "name": "BONDS_WITHDRAWER_AUTHORITY_SEED",
+      "type": "bytes",
+      "value": "[98, 111, 110, 100, 115, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
     },
     {
-      "code": 6045,
-      "name": "NonBondStakeAuthorities",
-      "msg": "One or both stake authorities does not belong to bonds program"
+      "name": "SETTLEMENT_STAKER_AUTHORITY_SEED",
+      "type": "bytes",
+      "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
     },
     {
-      "code": 6046,
-      "name": "SettlementAuthorityMismatch",
-      "msg": "Stake account staker authority mismatches with the settlement authority"
+      "name": "EVENT_VERSION",
+      "type": "u8",
+      "value": "1"
     },
     {
-      "code": 6047,
-      "name": "StakeDelegationMismatch",
-      "msg": "Delegation of provided stake account mismatches"
+      "name": "ACCOUNT_VERSION",
+      "type": "u8",
+      "value": "1"
+    }
+  ],
+  "instructions": [
+    {
+      "name": "initConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer for the config account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initConfigArgs",
+          "type": {
+            "defined": "InitConfigArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6048,
-      "name": "WithdrawRequestAmountTooSmall",
-      "msg": "Too small non-withdrawn withdraw request amount, cancel and init new one"
+      "name": "migrateAccount",
+      "accounts": [
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the account reallocation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6049,
-      "name": "WithdrawRequestAlreadyFulfilled",
-      "msg": "Withdraw request has been already fulfilled"
+      "name": "configureConfig",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "admin_authority"
+          ]
+        },
+        {
+          "name": "adminAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "only the admin authority can change the config params"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "configureConfigArgs",
+          "type": {
+            "defined": "ConfigureConfigArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6050,
-      "name": "ClaimSettlementMerkleTreeNodeMismatch",
-      "msg": "Claim settlement merkle tree node mismatch"
+      "name": "acceptConfigAuthority",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "the authority proposed by admin with the configure config instruction"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6051,
-      "name": "WrongStakeAccountStaker",
-      "msg": "Wrong staker authority of the stake account"
+      "name": "applyConfigChanges",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6052,
-      "name": "AlreadyPaused",
-      "msg": "Requested pause and already Paused"
+      "name": "initBond",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond is created"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "validatorIdentity",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "permission-ed: the validator identity signs the instruction, InitBondArgs applied",
+            "permission-less: no signature, default init bond configuration"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondAlias",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "alias of the vote account created on bond migration, no bond may be created",
+            "for the vote account another bond was migrated to"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_alias"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of validator bond account creation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initBondArgs",
+          "type": {
+            "defined": "InitBondArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6053,
-      "name": "NotPaused",
-      "msg": "Requested resume, but not Paused"
+      "name": "configureBond",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "vote_account",
+            "config"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account validator identity or bond authority may change the account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "configureBondArgs",
+          "type": {
+            "defined": "ConfigureBondArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6054,
-      "name": "ProgramIsPaused",
-      "msg": "Emergency Pause is Active"
+      "name": "configureBondWithMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "vote_account"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ConfigureBondWithMintArgs"
+                },
+                "path": "params.validator_identity"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "token account to burn bond mint configuration tokens from (or holding the bond authority mint token)"
+          ]
+        },
+        {
+          "name": "tokenAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ConfigureBondWithMintArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6055,
-      "name": "InvalidPauseAuthority",
-      "msg": "Invalid pause authority"
+      "name": "configureBondAuthorityMint",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "vote_account",
+            "config"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account validator identity or bond authority may change the account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ConfigureBondAuthorityMintArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6056,
-      "name": "MergeMismatchSameSourceDestination",
-      "msg": "Source and destination cannot be the same for merge operation"
+      "name": "mintBond",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "vote_account"
+          ]
+        },
+        {
+          "name": "mint",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "validator_identity"
+              }
+            ]
+          }
+        },
+        {
+          "name": "validatorIdentity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "validatorIdentityTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of account creation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "metadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6057,
-      "name": "WrongStakeAccountState",
-      "msg": "Wrong state of the stake account"
+      "name": "fundBond",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bond account to be deposited to with the provided stake account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "new owner of the stake_account, it's the bonds withdrawer authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account to be deposited"
+          ]
+        },
+        {
+          "name": "stakeAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "authority signature permitting to change the stake_account authorities"
+          ]
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6058,
-      "name": "ValidatorIdentityBondMintMismatch",
-      "msg": "Validator identity mismatch for bond mint"
+      "name": "chargeBid",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "operator_authority"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority is allowed to charge the bid"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "bond stake account the bid is charged from"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "authority that manages (owns) all stakes account under the bonds program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidFeeCollector",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "new staker and withdrawer authority of the charged split stake account"
+          ]
+        },
+        {
+          "name": "splitStakeAccount",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "a new stake account where the charged bid amount is split into"
+          ]
+        },
+        {
+          "name": "splitStakeRentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "the rent exempt payer of the split_stake_account creation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "chargeBidArgs",
+          "type": {
+            "defined": "ChargeBidArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6059,
-      "name": "InvalidBondMintSupply",
-      "msg": "Bond mint permits only a single token to exist"
+      "name": "migrateBond",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the vote account the bond stake accounts are currently delegated to"
+          ]
+        },
+        {
+          "name": "newVoteAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the vote account the bond is migrated to"
+          ]
+        },
+        {
+          "name": "newVoteAccountBond",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bond address of the new vote account, no bond may exist for the vote account the bond is migrated to"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "new_vote_account"
+              }
+            ]
+          }
+        },
+        {
+          "name": "oldBondAlias",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "alias of the vote account the bond is currently migrated to, closed on the migration",
+            "required when the bond has been already migrated"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_alias"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.migrated_vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "newBondAlias",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "alias of the vote account the bond is migrated to",
+            "required when the bond is not migrated back to the original vote account"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_alias"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "new_vote_account"
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator identity or authorized withdrawer of both vote accounts"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the bond alias account creation, refunded on the alias close"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6060,
-      "name": "OperatorAndPauseAuthorityMismatch",
-      "msg": "Operation permitted only to operator or pause authority"
+      "name": "initWithdrawRequest",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "vote_account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account node identity or bond authority may ask for the withdrawal"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "withdraw_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of withdraw request account creation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "createWithdrawRequestArgs",
+          "type": {
+            "defined": "InitWithdrawRequestArgs"
+          }
+        }
+      ]
     },
     {
-      "code": 6061,
-      "name": "SettlementNotReadyForClaiming",
-      "msg": "Settlement slots to start claiming not expired yet"
+      "name": "cancelWithdrawRequest",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "vote_account",
+            "config"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account validator identity or bond authority may ask for cancelling"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "withdraw_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6062,
-      "name": "InvalidVoteAccountType",
-      "msg": "Unsupported vote account type to deserialize"
+      "name": "claimWithdrawRequest",
+      "docs": [
+        "remaining accounts are the bond stake accounts merged to the claimed stake account"
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config root configuration account"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "vote_account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account node identity or bond authority may claim"
+          ]
+        },
+        {
+          "name": "withdrawRequest",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "withdraw_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              }
+            ]
+          },
+          "relations": [
+            "vote_account",
+            "bond"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account to be used to withdraw the funds",
+            "this stake account has to be delegated to the validator vote account associated to the bond"
+          ]
+        },
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "New owner (staker and withdrawer) of the stake account, an explicit destination chosen by the claiming authority"
+          ]
+        },
+        {
+          "name": "splitStakeAccount",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "this is a whatever address that does not exist",
+            "when withdrawing needs to split the provided account this will be used as a new stake account"
+          ]
+        },
+        {
+          "name": "splitStakeRentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "when the split_stake_account is created the rent for creation is taken from here",
+            "when the split_stake_account is not created then no rent is paid"
+          ]
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6063,
-      "name": "MaxStakeWantedTooLow",
-      "msg": "Max stake wanted value is lower to minimum configured value"
-    }
-  ]
-};
-
-export const IDL: ValidatorBonds = {
-  "version": "1.5.0",
-  "name": "validator_bonds",
-  "constants": [
-    {
-      "name": "PROGRAM_ID",
-      "type": "string",
-      "value": "\"vBoNdEvzMrSai7is21XgVYik65mqtaKXuSdMBJ1xkW4\""
+      "name": "initSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "InitSettlementArgs"
+                },
+                "path": "params.merkle_root"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "InitSettlementArgs"
+                },
+                "path": "params.epoch"
+              }
+            ]
+          }
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority holding the settlement creator role"
+          ]
+        },
+        {
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of account creation"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "initSettlementArgs",
+          "type": {
+            "defined": "InitSettlementArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "BOND_SEED",
-      "type": "bytes",
-      "value": "[98, 111, 110, 100, 95, 97, 99, 99, 111, 117, 110, 116]"
+      "name": "amendSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority holding the settlement creator role"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amendSettlementArgs",
+          "type": {
+            "defined": "AmendSettlementArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "BOND_MINT_SEED",
-      "type": "bytes",
-      "value": "[98, 111, 110, 100, 95, 109, 105, 110, 116]"
+      "name": "disputeSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "vote_account"
+          ]
+        },
+        {
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "validator vote account node identity or bond authority may dispute the settlement"
+          ]
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "disputeSettlementArgs",
+          "type": {
+            "defined": "DisputeSettlementArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "SETTLEMENT_SEED",
-      "type": "bytes",
-      "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 97, 99, 99, 111, 117, 110, 116]"
+      "name": "resolveDispute",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "relations": [
+            "admin_authority"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "adminAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "only the admin authority can resolve the dispute"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "WITHDRAW_REQUEST_SEED",
-      "type": "bytes",
-      "value": "[119, 105, 116, 104, 100, 114, 97, 119, 95, 97, 99, 99, 111, 117, 110, 116]"
+      "name": "extendSettlementExpiry",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority holding the settlement creator role or the admin authority"
+          ]
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExtendSettlementExpiryArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "SETTLEMENT_CLAIM_SEED",
-      "type": "bytes",
-      "value": "[99, 108, 97, 105, 109, 95, 97, 99, 99, 111, 117, 110, 116]"
+      "name": "closeSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "settlement to close when expired"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond",
+            "rent_collector"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentRefundAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
+            "The lamports are utilized to pay back the rent exemption of the split_stake_account, which can be created upon funding the settlement."
+          ]
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "BONDS_WITHDRAWER_AUTHORITY_SEED",
-      "type": "bytes",
-      "value": "[98, 111, 110, 100, 115, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
+      "name": "cancelSettlement",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "settlement to close when expired"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond",
+            "rent_collector"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Cancelling is permitted only to emergency or operator authority"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "splitRentRefundAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
+            "The lamports are utilized to pay back the rent exemption of the split_stake_account"
+          ]
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "name": "SETTLEMENT_STAKER_AUTHORITY_SEED",
-      "type": "bytes",
-      "value": "[115, 101, 116, 116, 108, 101, 109, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
-    }
-  ],
-  "instructions": [
-    {
-      "name": "initConfig",
+      "name": "fundSettlement",
       "accounts": [
         {
-          "name": "config",
+          "name": "config",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
+          "relations": [
+            "bond"
+          ]
         },
         {
-          "name": "rentPayer",
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator signer authority is allowed to fund the settlement account"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account to be funded into the settlement"
+          ]
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the settlement stake authority differentiates between deposited and funded stake accounts",
+            "deposited accounts have the bonds_withdrawer_authority, while funded accounts have the settlement_staker_authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "authority that manages (owns) all stakes account under the bonds program"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bonds_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              }
+            ]
+          }
+        },
+        {
+          "name": "splitStakeAccount",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "rent exempt payer for the config account"
+            "if an account that does not exist is provided, it will be initialized as a stake account (with the necessary signature)",
+            "the split_stake_account is required when the provided stake_account contains more lamports than necessary to fund the settlement",
+            "in this case, the excess lamports from the stake account are split into the new split_stake_account,",
+            "if the split_stake_account is not needed, the rent payer is refunded back within tx"
+          ]
+        },
+        {
+          "name": "splitStakeRentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "the rent exempt payer of the split_stake_account creation",
+            "if the split_stake_account is not needed (no leftover lamports on funding), then the rent payer is refunded",
+            "if the split_stake_account is needed to spill out over funding of the settlement,",
+            "then the rent payer is refunded when the settlement is closed"
           ]
         },
         {
@@ -4543,6 +11956,26 @@ export const IDL: ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -4563,110 +11996,197 @@ export const IDL: ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "closeSettlementClaim",
+      "accounts": [
         {
-          "name": "initConfigArgs",
-          "type": {
-            "defined": "InitConfigArgs"
+          "name": "settlement",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementClaim",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "rent_collector",
+            "settlement"
+          ]
+        },
+        {
+          "name": "rentCollector",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
           }
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "configureConfig",
+      "name": "claimSettlement",
       "accounts": [
         {
           "name": "config",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the settlement was created"
+          ]
+        },
+        {
+          "name": "bond",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "bond_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond.vote_account"
+              }
+            ]
+          },
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "settlement",
           "isMut": true,
           "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "Settlement",
+                "path": "settlement.merkle_root"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "Settlement",
+                "path": "settlement.epoch_created_for"
+              }
+            ]
+          },
           "relations": [
-            "admin_authority"
-          ]
-        },
-        {
-          "name": "adminAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "only the admin authority can change the config params"
+            "bond"
           ]
         },
         {
-          "name": "eventAuthority",
-          "isMut": false,
+          "name": "settlementClaim",
+          "isMut": true,
           "isSigner": false,
+          "docs": [
+            "deduplication, merkle tree record cannot be claimed twice"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "__event_authority"
+                "value": "claim_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ClaimSettlementArgs"
+                },
+                "path": "params.tree_node_hash"
               }
             ]
           }
         },
         {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "configureConfigArgs",
-          "type": {
-            "defined": "ConfigureConfigArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "initBond",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
+          "name": "stakeAccountFrom",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "the config account under which the bond is created"
+            "a stake account that will be withdrawn"
           ]
         },
         {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "validatorIdentity",
-          "isMut": false,
-          "isSigner": true,
-          "isOptional": true,
+          "name": "stakeAccountTo",
+          "isMut": true,
+          "isSigner": false,
           "docs": [
-            "permission-ed: the validator identity signs the instruction, InitBondArgs applied",
-            "permission-less: no signature, default init bond configuration"
+            "a stake account that will receive the funds"
           ]
         },
         {
-          "name": "bond",
-          "isMut": true,
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
           "isSigner": false,
+          "docs": [
+            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bond_account"
+                "value": "bonds_authority"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Config",
                 "path": "config"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "vote_account"
               }
             ]
           }
@@ -4676,7 +12196,9 @@ export const IDL: ValidatorBonds = {
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "rent exempt payer of validator bond account creation"
+            "upon claiming, a claim account is created to confirm the occurrence of the claim",
+            "when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here",
+            "the claimer fee is paid here"
           ]
         },
         {
@@ -4684,6 +12206,21 @@ export const IDL: ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -4706,60 +12243,54 @@ export const IDL: ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "initBondArgs",
+          "name": "claimSettlementArgs",
           "type": {
-            "defined": "InitBondArgs"
+            "defined": "ClaimSettlementArgs"
           }
         }
       ]
     },
     {
-      "name": "configureBond",
+      "name": "mergeStake",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
+        },
+        {
+          "name": "sourceStake",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bond",
+          "name": "destinationStake",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bond_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "vote_account"
-              }
-            ]
-          },
-          "relations": [
-            "vote_account",
-            "config"
-          ]
+          "isSigner": false
         },
         {
-          "name": "authority",
+          "name": "stakerAuthority",
           "isMut": false,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "validator vote account validator identity or bond authority may change the account"
+            "bonds program authority PDA address: settlement staker or bonds withdrawer"
           ]
         },
         {
-          "name": "voteAccount",
+          "name": "stakeHistory",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -4785,24 +12316,27 @@ export const IDL: ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "configureBondArgs",
+          "name": "mergeArgs",
           "type": {
-            "defined": "ConfigureBondArgs"
+            "defined": "MergeStakeArgs"
           }
         }
       ]
     },
     {
-      "name": "configureBondWithMint",
+      "name": "resetStake",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
         },
         {
           "name": "bond",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -4820,38 +12354,51 @@ export const IDL: ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
           },
           "relations": [
-            "config",
-            "vote_account"
+            "config"
           ]
         },
         {
-          "name": "mint",
+          "name": "settlement",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "cannot exist; used to derive settlement authority"
+          ]
+        },
+        {
+          "name": "stakeAccount",
           "isMut": true,
           "isSigner": false,
+          "docs": [
+            "stake account belonging under the settlement by staker authority"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bonds withdrawer authority",
+            "to cancel settlement funding of the stake account changing staker authority to address"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bond_mint"
+                "value": "bonds_authority"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "ConfigureBondWithMintArgs"
-                },
-                "path": "params.validator_identity"
+                "account": "Config",
+                "path": "config"
               }
             ]
           }
@@ -4859,25 +12406,39 @@ export const IDL: ValidatorBonds = {
         {
           "name": "voteAccount",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenAccount",
-          "isMut": true,
           "isSigner": false,
           "docs": [
-            "token account to burn bond mint configuration tokens from"
+            "for a migrated bond it is the vote account the bond was migrated to"
           ]
         },
         {
-          "name": "tokenAuthority",
+          "name": "stakeHistory",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "stakeConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "stakeProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "operatorAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "operator authority holding the settlement closer role, required only when the role is configured"
+          ]
         },
         {
           "name": "eventAuthority",
@@ -4899,17 +12460,10 @@ export const IDL: ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "ConfigureBondWithMintArgs"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "mintBond",
+      "name": "redelegateStake",
       "accounts": [
         {
           "name": "config",
@@ -4936,90 +12490,68 @@ export const IDL: ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
           },
           "relations": [
-            "config",
-            "vote_account"
+            "config"
           ]
         },
         {
-          "name": "mint",
+          "name": "stakeAccount",
           "isMut": true,
           "isSigner": false,
+          "docs": [
+            "bond stake account delegated to the original bond vote account"
+          ]
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "bonds withdrawer authority is the staker of the bond stake accounts"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bond_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
+                "value": "bonds_authority"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "validator_identity"
+                "account": "Config",
+                "path": "config"
               }
             ]
           }
         },
-        {
-          "name": "validatorIdentity",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "validatorIdentityTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "voteAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rentPayer",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "rent exempt payer of account creation"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "stakeHistory",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "stakeConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "metadataProgram",
+          "name": "clock",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "stakeProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -5046,43 +12578,38 @@ export const IDL: ValidatorBonds = {
       "args": []
     },
     {
-      "name": "fundBond",
+      "name": "withdrawStake",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "the config account under which the bond was created"
+          ]
         },
         {
-          "name": "bond",
+          "name": "operatorAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "operator authority holding the settlement closer role is allowed to withdraw the non-delegated stake accounts"
+          ]
+        },
+        {
+          "name": "settlement",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "bond account to be deposited to with the provided stake account"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bond_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Bond",
-                "path": "bond.vote_account"
-              }
-            ]
-          },
-          "relations": [
-            "config"
+            "cannot exist; used to derive settlement authority"
+          ]
+        },
+        {
+          "name": "stakeAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "stake account where staker authority is derived from settlement"
           ]
         },
         {
@@ -5090,7 +12617,7 @@ export const IDL: ValidatorBonds = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "new owner of the stake_account, it's the bonds withdrawer authority"
+            "bonds authority to withdraw the stake account"
           ],
           "pda": {
             "seeds": [
@@ -5109,28 +12636,17 @@ export const IDL: ValidatorBonds = {
           }
         },
         {
-          "name": "stakeAccount",
+          "name": "withdrawTo",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "stake account to be deposited"
-          ]
-        },
-        {
-          "name": "stakeAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "authority signature permitting to change the stake_account authorities"
-          ]
+          "isSigner": false
         },
         {
-          "name": "clock",
+          "name": "stakeHistory",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakeHistory",
+          "name": "clock",
           "isMut": false,
           "isSigner": false
         },
@@ -5162,60 +12678,34 @@ export const IDL: ValidatorBonds = {
       "args": []
     },
     {
-      "name": "initWithdrawRequest",
+      "name": "initCollateralMint",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
-          ]
-        },
-        {
-          "name": "bond",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bond_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "vote_account"
-              }
-            ]
-          },
           "relations": [
-            "config",
-            "vote_account"
+            "admin_authority"
           ]
         },
         {
-          "name": "voteAccount",
+          "name": "adminAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "only the admin authority can whitelist the collateral mint"
+          ]
         },
         {
-          "name": "authority",
+          "name": "mint",
           "isMut": false,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "validator vote account node identity or bond authority may ask for the withdrawal"
+            "the token mint to be whitelisted"
           ]
         },
         {
-          "name": "withdrawRequest",
+          "name": "collateralMint",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -5223,13 +12713,19 @@ export const IDL: ValidatorBonds = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "withdraw_account"
+                "value": "collateral_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
               }
             ]
           }
@@ -5239,7 +12735,7 @@ export const IDL: ValidatorBonds = {
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "rent exempt payer of withdraw request account creation"
+            "rent exempt payer of the collateral mint account creation"
           ]
         },
         {
@@ -5269,65 +12765,34 @@ export const IDL: ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "createWithdrawRequestArgs",
+          "name": "initCollateralMintArgs",
           "type": {
-            "defined": "InitWithdrawRequestArgs"
+            "defined": "InitCollateralMintArgs"
           }
         }
       ]
     },
     {
-      "name": "cancelWithdrawRequest",
+      "name": "configureCollateralMint",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bond",
-          "isMut": false,
           "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bond_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "vote_account"
-              }
-            ]
-          },
           "relations": [
-            "vote_account",
-            "config"
+            "admin_authority"
           ]
         },
         {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
+          "name": "adminAuthority",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "validator vote account validator identity or bond authority may ask for cancelling"
+            "only the admin authority can change the collateral mint params"
           ]
         },
         {
-          "name": "withdrawRequest",
+          "name": "collateralMint",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -5335,25 +12800,26 @@ export const IDL: ValidatorBonds = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "withdraw_account"
+                "value": "collateral_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "CollateralMint",
+                "path": "collateral_mint.mint"
               }
             ]
           },
           "relations": [
-            "bond"
+            "config"
           ]
         },
-        {
-          "name": "rentCollector",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -5374,23 +12840,30 @@ export const IDL: ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "configureCollateralMintArgs",
+          "type": {
+            "defined": "ConfigureCollateralMintArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "claimWithdrawRequest",
+      "name": "fundBondCollateral",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "the config root configuration account"
-          ]
+          "isSigner": false
         },
         {
           "name": "bond",
           "isMut": false,
           "isSigner": false,
+          "docs": [
+            "bond account to be deposited to with the provided tokens"
+          ],
           "pda": {
             "seeds": [
               {
@@ -5407,56 +12880,57 @@ export const IDL: ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "vote_account"
+                "account": "Bond",
+                "path": "bond.vote_account"
               }
             ]
           },
           "relations": [
-            "config",
-            "vote_account"
+            "config"
           ]
         },
         {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authority",
+          "name": "collateralMint",
           "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "validator vote account node identity or bond authority may claim"
-          ]
-        },
-        {
-          "name": "withdrawRequest",
-          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "withdraw_account"
+                "value": "collateral_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
               }
             ]
           },
           "relations": [
-            "vote_account",
-            "bond"
+            "config",
+            "mint"
           ]
         },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "bondsWithdrawerAuthority",
           "isMut": false,
           "isSigner": false,
+          "docs": [
+            "owner of the collateral vault, it's the bonds withdrawer authority"
+          ],
           "pda": {
             "seeds": [
               {
@@ -5474,42 +12948,60 @@ export const IDL: ValidatorBonds = {
           }
         },
         {
-          "name": "stakeAccount",
+          "name": "collateralVault",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "stake account to be used to withdraw the funds",
-            "this stake account has to be delegated to the validator vote account associated to the bond"
-          ]
+            "bond collateral vault for the mint, created on the first deposit"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "withdrawer",
-          "isMut": false,
+          "name": "tokenAccount",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "New owner of the stake account, it will be accounted to the withdrawer authority"
+            "token account the tokens are deposited from"
           ]
         },
         {
-          "name": "splitStakeAccount",
-          "isMut": true,
+          "name": "tokenAuthority",
+          "isMut": false,
           "isSigner": true,
           "docs": [
-            "this is a whatever address that does not exist",
-            "when withdrawing needs to split the provided account this will be used as a new stake account"
+            "authority signature permitting to transfer the tokens from the token account"
           ]
         },
         {
-          "name": "splitStakeRentPayer",
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "when the split_stake_account is created the rent for creation is taken from here",
-            "when the split_stake_account is not created then no rent is paid"
+            "rent exempt payer of the collateral vault creation"
           ]
         },
         {
-          "name": "stakeProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -5518,16 +13010,6 @@ export const IDL: ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -5548,17 +13030,24 @@ export const IDL: ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "fundBondCollateralArgs",
+          "type": {
+            "defined": "FundBondCollateralArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "initSettlement",
+      "name": "claimWithdrawCollateral",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
-          "relations": [
-            "operator_authority"
+          "docs": [
+            "the config root configuration account"
           ]
         },
         {
@@ -5581,180 +13070,87 @@ export const IDL: ValidatorBonds = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond.vote_account"
+                "path": "vote_account"
               }
             ]
           },
           "relations": [
-            "config"
+            "config",
+            "vote_account"
           ]
         },
         {
-          "name": "settlement",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "settlement_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "InitSettlementArgs"
-                },
-                "path": "params.merkle_root"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "InitSettlementArgs"
-                },
-                "path": "params.epoch"
-              }
-            ]
-          }
+          "name": "voteAccount",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "operatorAuthority",
+          "name": "authority",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "operator signer authority that is allowed to create the settlement account"
+            "validator vote account node identity or bond authority may claim"
           ]
         },
         {
-          "name": "rentPayer",
+          "name": "withdrawRequest",
           "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "rent exempt payer of account creation"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
-          }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "initSettlementArgs",
-          "type": {
-            "defined": "InitSettlementArgs"
-          }
-        }
-      ]
-    },
-    {
-      "name": "closeSettlement",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bond",
-          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bond_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
+                "value": "withdraw_account"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Bond",
-                "path": "bond.vote_account"
+                "path": "bond"
               }
             ]
           },
           "relations": [
-            "config"
+            "vote_account",
+            "bond"
           ]
         },
         {
-          "name": "settlement",
-          "isMut": true,
+          "name": "collateralMint",
+          "isMut": false,
           "isSigner": false,
-          "docs": [
-            "settlement to close when expired"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "settlement_account"
+                "value": "collateral_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Bond",
-                "path": "bond"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "Settlement",
-                "path": "settlement.merkle_root"
+                "account": "Config",
+                "path": "config"
               },
               {
                 "kind": "account",
-                "type": "u64",
-                "account": "Settlement",
-                "path": "settlement.epoch_created_for"
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
               }
             ]
           },
           "relations": [
-            "bond",
-            "rent_collector"
+            "config",
+            "mint"
           ]
         },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "bondsWithdrawerAuthority",
           "isMut": false,
@@ -5776,36 +13172,49 @@ export const IDL: ValidatorBonds = {
           }
         },
         {
-          "name": "rentCollector",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "splitRentCollector",
+          "name": "collateralVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "bond collateral vault the tokens are withdrawn from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "splitRentRefundAccount",
+          "name": "withdrawerTokenAccount",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
-            "The lamports are utilized to pay back the rent exemption of the split_stake_account, which can be created upon funding the settlement."
+            "whatever token account of the mint, authority signature states his intention to withdraw the tokens there"
           ]
         },
         {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakeHistory",
+          "name": "clock",
           "isMut": false,
           "isSigner": false
         },
@@ -5832,11 +13241,11 @@ export const IDL: ValidatorBonds = {
       "args": []
     },
     {
-      "name": "cancelSettlement",
+      "name": "fundSettlementCollateral",
       "accounts": [
         {
           "name": "config",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -5872,9 +13281,6 @@ export const IDL: ValidatorBonds = {
           "name": "settlement",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "settlement to close when expired"
-          ],
           "pda": {
             "seeds": [
               {
@@ -5908,22 +13314,59 @@ export const IDL: ValidatorBonds = {
             ]
           },
           "relations": [
-            "bond",
-            "rent_collector"
+            "bond"
           ]
         },
         {
-          "name": "authority",
+          "name": "operatorAuthority",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "Cancelling is permitted only to emergency or operator authority"
+            "operator signer authority is allowed to fund the settlement account"
+          ]
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "config",
+            "mint"
           ]
         },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "bondsWithdrawerAuthority",
           "isMut": false,
           "isSigner": false,
+          "docs": [
+            "authority that manages (owns) the bond collateral vaults"
+          ],
           "pda": {
             "seeds": [
               {
@@ -5941,36 +13384,131 @@ export const IDL: ValidatorBonds = {
           }
         },
         {
-          "name": "rentCollector",
+          "name": "collateralVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "bond collateral vault the settlement is funded from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "splitRentCollector",
+          "name": "settlementStakerAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "owner of the settlement collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_authority"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateralVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "settlement collateral vault the claims are paid from"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "splitRentRefundAccount",
+          "name": "settlementCollateral",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "The stake account is funded to the settlement and credited to the bond's validator vote account.",
-            "The lamports are utilized to pay back the rent exemption of the split_stake_account"
-          ]
+            "tokens and their lamports value funded to the settlement, the claims are paid pro rata from them"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_collateral"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
+          "name": "rentPayer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "rent exempt payer of the settlement collateral vault and the settlement collateral creation,",
+            "the rent is returned to the settlement funder when the collateral is reset after the settlement is closed"
+          ]
         },
         {
-          "name": "stakeProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "stakeHistory",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -5997,14 +13535,14 @@ export const IDL: ValidatorBonds = {
       "args": []
     },
     {
-      "name": "fundSettlement",
+      "name": "claimSettlementCollateral",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
-          "relations": [
-            "operator_authority"
+          "docs": [
+            "the config account under which the settlement was created"
           ]
         },
         {
@@ -6073,32 +13611,83 @@ export const IDL: ValidatorBonds = {
             ]
           },
           "relations": [
-            "bond"
+            "bond"
+          ]
+        },
+        {
+          "name": "settlementClaim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "deduplication, merkle tree record cannot be claimed twice"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim_account"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ClaimSettlementArgs"
+                },
+                "path": "params.tree_node_hash"
+              }
+            ]
+          }
+        },
+        {
+          "name": "settlementCollateral",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "tokens funded to the settlement, the claim is paid pro rata from them"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_collateral"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "settlement",
+            "mint"
           ]
         },
         {
-          "name": "operatorAuthority",
+          "name": "mint",
           "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "operator signer authority is allowed to fund the settlement account"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "stake account to be funded into the settlement"
-          ]
+          "isSigner": false
         },
         {
           "name": "settlementStakerAuthority",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "the settlement stake authority differentiates between deposited and funded stake accounts",
-            "deposited accounts have the bonds_withdrawer_authority, while funded accounts have the settlement_staker_authority"
+            "owner of the settlement collateral vault"
           ],
           "pda": {
             "seeds": [
@@ -6117,48 +13706,58 @@ export const IDL: ValidatorBonds = {
           }
         },
         {
-          "name": "bondsWithdrawerAuthority",
-          "isMut": false,
+          "name": "settlementCollateralVault",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "authority that manages (owns) all stakes account under the bonds program"
+            "settlement collateral vault the claim is paid from"
           ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "bonds_authority"
+                "value": "collateral_vault"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Config",
-                "path": "config"
+                "account": "Settlement",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
               }
             ]
           }
         },
         {
-          "name": "splitStakeAccount",
+          "name": "claimerTokenAccount",
           "isMut": true,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "if an account that does not exist is provided, it will be initialized as a stake account (with the necessary signature)",
-            "the split_stake_account is required when the provided stake_account contains more lamports than necessary to fund the settlement",
-            "in this case, the excess lamports from the stake account are split into the new split_stake_account,",
-            "if the split_stake_account is not needed, the rent payer is refunded back within tx"
+            "a token account that will receive the tokens"
           ]
         },
         {
-          "name": "splitStakeRentPayer",
+          "name": "rentPayerTokenAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "a token account of the rent payer that receives the claimer fee, no fee is paid when not provided"
+          ]
+        },
+        {
+          "name": "rentPayer",
           "isMut": true,
           "isSigner": true,
           "docs": [
-            "the rent exempt payer of the split_stake_account creation",
-            "if the split_stake_account is not needed (no leftover lamports on funding), then the rent payer is refunded",
-            "if the split_stake_account is needed to spill out over funding of the settlement,",
-            "then the rent payer is refunded when the settlement is closed"
+            "upon claiming, a claim account is created to confirm the occurrence of the claim",
+            "when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here"
           ]
         },
         {
@@ -6167,7 +13766,7 @@ export const IDL: ValidatorBonds = {
           "isSigner": false
         },
         {
-          "name": "stakeHistory",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -6176,16 +13775,6 @@ export const IDL: ValidatorBonds = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "eventAuthority",
           "isMut": false,
@@ -6206,61 +13795,24 @@ export const IDL: ValidatorBonds = {
           "isSigner": false
         }
       ],
-      "args": []
-    },
-    {
-      "name": "closeSettlementClaim",
-      "accounts": [
-        {
-          "name": "settlement",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "settlementClaim",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "rent_collector",
-            "settlement"
-          ]
-        },
-        {
-          "name": "rentCollector",
-          "isMut": true,
-          "isSigner": false
-        },
+      "args": [
         {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
+          "name": "claimSettlementArgs",
+          "type": {
+            "defined": "ClaimSettlementArgs"
           }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "claimSettlement",
+      "name": "resetSettlementCollateral",
       "accounts": [
         {
           "name": "config",
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "the config account under which the settlement was created"
+            "the config account under which the bond was created"
           ]
         },
         {
@@ -6294,8 +13846,11 @@ export const IDL: ValidatorBonds = {
         },
         {
           "name": "settlement",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
+          "docs": [
+            "cannot exist; the PDA binds the settlement to the bond"
+          ],
           "pda": {
             "seeds": [
               {
@@ -6310,72 +13865,137 @@ export const IDL: ValidatorBonds = {
                 "path": "bond"
               },
               {
-                "kind": "account",
+                "kind": "arg",
                 "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
+                  "defined": "ResetSettlementCollateralArgs"
                 },
-                "account": "Settlement",
-                "path": "settlement.merkle_root"
+                "path": "params.merkle_root"
+              },
+              {
+                "kind": "arg",
+                "type": {
+                  "defined": "ResetSettlementCollateralArgs"
+                },
+                "path": "params.epoch_created_for"
+              }
+            ]
+          }
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_mint"
               },
               {
                 "kind": "account",
-                "type": "u64",
-                "account": "Settlement",
-                "path": "settlement.epoch_created_for"
+                "type": "publicKey",
+                "account": "Config",
+                "path": "config"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
               }
             ]
           },
           "relations": [
-            "bond"
+            "config",
+            "mint"
           ]
         },
         {
-          "name": "settlementClaim",
-          "isMut": true,
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "settlementStakerAuthority",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "deduplication, merkle tree record cannot be claimed twice"
+            "owner of the settlement collateral vault"
           ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "claim_account"
+                "value": "settlement_authority"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Settlement",
                 "path": "settlement"
-              },
-              {
-                "kind": "arg",
-                "type": {
-                  "defined": "ClaimSettlementArgs"
-                },
-                "path": "params.tree_node_hash"
               }
             ]
           }
         },
         {
-          "name": "stakeAccountFrom",
+          "name": "settlementCollateralVault",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "a stake account that will be withdrawn"
-          ]
+            "settlement collateral vault to be emptied and closed"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "stakeAccountTo",
+          "name": "settlementCollateral",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "a stake account that will receive the funds"
+            "tokens funded to the closed settlement, closed together with the settlement collateral vault"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "settlement_collateral"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "settlement"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          },
+          "relations": [
+            "settlement",
+            "mint"
           ]
         },
         {
@@ -6383,7 +14003,7 @@ export const IDL: ValidatorBonds = {
           "isMut": false,
           "isSigner": false,
           "docs": [
-            "authority that manages (owns == by being withdrawer authority) all stakes account under the bonds program"
+            "owner of the bond collateral vault"
           ],
           "pda": {
             "seeds": [
@@ -6402,31 +14022,41 @@ export const IDL: ValidatorBonds = {
           }
         },
         {
-          "name": "rentPayer",
+          "name": "collateralVault",
           "isMut": true,
-          "isSigner": true,
+          "isSigner": false,
           "docs": [
-            "upon claiming, a claim account is created to confirm the occurrence of the claim",
-            "when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
+            "bond collateral vault the unclaimed tokens are returned to"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "collateral_vault"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Bond",
+                "path": "bond"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "mint"
+              }
+            ]
+          }
         },
         {
-          "name": "clock",
-          "isMut": false,
+          "name": "rentCollector",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "stakeProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -6452,56 +14082,104 @@ export const IDL: ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "claimSettlementArgs",
+          "name": "resetSettlementCollateralArgs",
           "type": {
-            "defined": "ClaimSettlementArgs"
+            "defined": "ResetSettlementCollateralArgs"
           }
         }
       ]
     },
     {
-      "name": "mergeStake",
+      "name": "emergencyPause",
       "accounts": [
         {
           "name": "config",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
+          "relations": [
+            "pause_authority"
           ]
         },
         {
-          "name": "sourceStake",
-          "isMut": true,
-          "isSigner": false
+          "name": "pauseAuthority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "destinationStake",
-          "isMut": true,
-          "isSigner": false
+          "name": "eventAuthority",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
         },
         {
-          "name": "stakerAuthority",
+          "name": "program",
           "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergencyResume",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "bonds program authority PDA address: settlement staker or bonds withdrawer"
+          "relations": [
+            "pause_authority"
           ]
         },
         {
-          "name": "stakeHistory",
+          "name": "pauseAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "clock",
+          "name": "eventAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "__event_authority"
+              }
+            ]
+          }
         },
         {
-          "name": "stakeProgram",
+          "name": "program",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPauseFlags",
+      "accounts": [
+        {
+          "name": "config",
+          "isMut": true,
+          "isSigner": false,
+          "relations": [
+            "pause_authority"
+          ]
+        },
+        {
+          "name": "pauseAuthority",
+          "isMut": false,
+          "isSigner": true
         },
         {
           "name": "eventAuthority",
@@ -6525,384 +14203,869 @@ export const IDL: ValidatorBonds = {
       ],
       "args": [
         {
-          "name": "mergeArgs",
+          "name": "setPauseFlagsArgs",
           "type": {
-            "defined": "MergeStakeArgs"
+            "defined": "SetPauseFlagsArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "bondAlias",
+      "docs": [
+        "Marker of the vote account a bond was migrated to.",
+        "The bond stake accounts are delegated to the vote account, no other bond may be created for it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "docs": [
+              "Bond account that was migrated to the vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Vote account the bond was migrated to, the alias PDA address is derived from it"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bond",
+      "docs": [
+        "Bond account for a validator vote address"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Program root config address. Validator bond is created for this config as PDA"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteAccount",
+            "docs": [
+              "Validator vote address that this bond account is crated for",
+              "INVARIANTS:",
+              "- one bond account per validator vote address",
+              "- this program does NOT change stake account delegation voter_pubkey to any other validator vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority that may close the bond or withdraw stake accounts associated with the bond",
+              "The same powers has got the owner of the validator vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "Cost per mille per epoch.",
+              "This field represents the bid the bond (vote) account owner is willing to pay",
+              "for up to the `max_stake_wanted` being delegated.",
+              "The bid is in cost per mille per epoch similar to Google ads cpm system.",
+              "---",
+              "The actual amount of lamports deducted from the bond account for the processed bid",
+              "is based on the actual delegated lamports during the epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA Bond address bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "maxStakeWanted",
+            "docs": [
+              "Maximum stake (in lamports) that the bond (vote) account owner requests.",
+              "This is the maximum stake that will be distributed to the vote account",
+              "when all other constraints are fulfilled (managed off-chain).",
+              "The vote account owner then goes to auction to obtain up to that maximum.",
+              "Use the `cpmpe` field to define the bid for this purpose."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bidChargedEpoch",
+            "docs": [
+              "The last epoch the bid was charged for by the charge bid instruction"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bidChargedAmount",
+            "docs": [
+              "Lamports charged as the bid for the `bid_charged_epoch`,",
+              "lower than the bid amount when the bid was charged partially"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "migratedVoteAccount",
+            "docs": [
+              "Vote account the bond was migrated to, the bonded stake accounts are redelegated to this vote account.",
+              "Default pubkey means the bond has not been migrated and stakes are delegated to `vote_account`.",
+              "The bond PDA address is still derived from the original `vote_account`."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authorityMint",
+            "docs": [
+              "Bond mint that works as a transferable bond authority credential.",
+              "Holder of a token of the mint may configure the bond without burning the token.",
+              "Default pubkey means the holding mode is disabled and the bond mint token is burnt on configuration."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "createdEpoch",
+            "docs": [
+              "Epoch the bond was created at, the bid cannot be charged for an earlier epoch.",
+              "Zero for bonds created before the field was introduced."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                45
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collateralMint",
+      "docs": [
+        "SPL token mint (e.g., a liquid staking token) whitelisted to be used as a bond collateral.",
+        "Tokens are deposited to a collateral vault, a PDA token account per bond and mint",
+        "owned by the bonds withdrawer authority."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Config the mint is whitelisted for"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The whitelisted SPL token mint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the mint, one token is 10^decimals of token base units"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "lamportsPerToken",
+            "docs": [
+              "Price of one token in lamports, maintained by the admin authority"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "haircutBps",
+            "docs": [
+              "Haircut in basis points deducted from the token price when the collateral is valued as the bond funds"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "resetStake",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
-          ]
-        },
-        {
-          "name": "bond",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bond_account"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "vote_account"
-              }
-            ]
+      "name": "config",
+      "docs": [
+        "Root account that configures the validator bonds program"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "docs": [
+              "Admin authority that can update the config"
+            ],
+            "type": "publicKey"
           },
-          "relations": [
-            "config",
-            "vote_account"
-          ]
-        },
-        {
-          "name": "settlement",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "cannot exist; used to derive settlement authority"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "stake account belonging under the settlement by staker authority"
-          ]
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "bonds withdrawer authority",
-            "to cancel settlement funding of the stake account changing staker authority to address"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bonds_authority"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              }
-            ]
-          }
-        },
-        {
-          "name": "voteAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeConfig",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
+          {
+            "name": "operatorAuthority",
+            "docs": [
+              "Operator authority (bot hot wallet)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "docs": [
+              "How many epochs permitting to claim the settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "docs": [
+              "How many epochs before withdraw is allowed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
+            "docs": [
+              "Minimum amount of lamports to be considered for a stake account operations (e.g., split)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bondsWithdrawerAuthorityBump",
+            "docs": [
+              "PDA bonds stake accounts authority bump seed"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pauseAuthority",
+            "docs": [
+              "Authority that can pause the program in case of emergency"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "docs": [
+              "How many slots to wait before settlement is permitted to be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "docs": [
+              "Minimum value of max_stake_wanted to be configured by vote account owners at bond."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAdminAuthority",
+            "docs": [
+              "Admin authority proposed by the current admin, waiting to be accepted by the new key,",
+              "default pubkey means no change is proposed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOperatorAuthority",
+            "docs": [
+              "Operator authority proposed by the admin, waiting to be accepted by the new key,",
+              "default pubkey means no change is proposed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingPauseAuthority",
+            "docs": [
+              "Pause authority proposed by the admin, waiting to be accepted by the new key,",
+              "default pubkey means no change is proposed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "docs": [
+              "How many epochs a config parameter change is staged before it can be applied.",
+              "When 0, the parameter changes are applied immediately."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingConfigChanges",
+            "docs": [
+              "Parameter changes staged by the admin, to be applied from the effective epoch"
+            ],
+            "type": {
+              "defined": "PendingConfigChanges"
+            }
+          },
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "Bitflag set of the paused instruction families, see `pause_flags` module"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "settlementCreatorAuthority",
+            "docs": [
+              "Operator role permitted to create settlements, default pubkey means the role is held by the operator authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "settlementFunderAuthority",
+            "docs": [
+              "Operator role permitted to fund settlements, default pubkey means the role is held by the operator authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "settlementCloserAuthority",
+            "docs": [
+              "Operator role permitted to reset and withdraw stake accounts of closed settlements,",
+              "default pubkey means the role is held by the operator authority"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bidFeeCollector",
+            "docs": [
+              "Authority of stake accounts split from bonds when the bid is charged"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "claimerFeeLamports",
+            "docs": [
+              "Lamports paid from the claimed amount to the claimer (rent payer) of a settlement claim",
+              "to cover the transaction costs of the permission-less claiming"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "docs": [
+              "Maximum claimer fee as basis points of the claimed amount, the fee is capped for small claims"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
+            "docs": [
+              "Upper bound of the delegated lamports the operator may charge the bid for,",
+              "the bid cannot be charged until it is configured"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "docs": [
+              "Maximum number of epochs a settlement claiming period can be extended in total,",
+              "see `Settlement::claim_expiry_extension`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved space for future changes"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                127
+              ]
+            }
           }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+        ]
+      }
     },
     {
-      "name": "withdrawStake",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "the config account under which the bond was created"
-          ],
-          "relations": [
-            "operator_authority"
-          ]
-        },
-        {
-          "name": "operatorAuthority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "operator authority is allowed to reset the non-delegated stake accounts"
-          ]
-        },
-        {
-          "name": "settlement",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "cannot exist; used to derive settlement authority"
-          ]
-        },
-        {
-          "name": "stakeAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "stake account where staker authority is derived from settlement"
-          ]
-        },
-        {
-          "name": "bondsWithdrawerAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "bonds authority to withdraw the stake account"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "bonds_authority"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Config",
-                "path": "config"
-              }
-            ]
-          }
-        },
-        {
-          "name": "withdrawTo",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "stakeHistory",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "stakeProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
-          }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "settlementClaim",
+      "docs": [
+        "The settlement claim serves for deduplication purposes,",
+        "preventing the same settlement from being claimed multiple times with the same claiming data"
       ],
-      "args": []
-    },
-    {
-      "name": "emergencyPause",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "pause_authority"
-          ]
-        },
-        {
-          "name": "pauseAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "docs": [
+              "settlement account this claim belongs under"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountTo",
+            "docs": [
+              "stake account to which the claim has been withdrawn to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountStaker",
+            "docs": [
+              "staker authority as part of the merkle proof for this claim"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeAccountWithdrawer",
+            "docs": [
+              "withdrawer authority as part of the merkle proof for this claim"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "claim amount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump, one claim per settlement"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rentCollector",
+            "docs": [
+              "rent collector account to get the rent back for claim account creation"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                92
+              ]
+            }
           }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
+        ]
+      }
+    },
+    {
+      "name": "settlementCollateral",
+      "docs": [
+        "Token collateral of a particular mint funded to the settlement.",
+        "The claims are paid pro rata of the funded tokens to their funded lamports value,",
+        "i.e., at the valuation the settlement was funded with regardless of later changes of the collateral mint price."
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement",
+            "docs": [
+              "Settlement the collateral was funded to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint of the funded collateral tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "tokensFunded",
+            "docs": [
+              "Tokens moved to the settlement collateral vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsFunded",
+            "docs": [
+              "Lamports value the tokens were funded with, part of the settlement `collateral_funded`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokensClaimed",
+            "docs": [
+              "Tokens paid to the claimers"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsClaimed",
+            "docs": [
+              "Lamports of the claims paid with the tokens, part of the settlement `collateral_claimed`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA account bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "emergencyResume",
-      "accounts": [
-        {
-          "name": "config",
-          "isMut": true,
-          "isSigner": false,
-          "relations": [
-            "pause_authority"
-          ]
-        },
-        {
-          "name": "pauseAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "eventAuthority",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "__event_authority"
-              }
-            ]
+      "name": "settlement",
+      "docs": [
+        "Settlement account for a particular config and merkle root",
+        "Settlement defines that a protected event happened and it will be settled"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bond",
+            "docs": [
+              "the settlement belongs under this bond, i.e., under a particular validator vote account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakerAuthority",
+            "docs": [
+              "settlement authority used as the 'staker' stake account authority",
+              "of stake accounts funded to this settlement"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "256-bit merkle root the settlement was created with, used as the PDA seed",
+              "and to check the claims against when the settlement has not been amended"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "maxTotalClaim",
+            "docs": [
+              "maximum number of funds that can ever be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxMerkleNodes",
+            "docs": [
+              "maximum number of merkle tree nodes that can ever be claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsFunded",
+            "docs": [
+              "total lamports funded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lamportsClaimed",
+            "docs": [
+              "total lamports that have been claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "merkleNodesClaimed",
+            "docs": [
+              "number of nodes that have been claimed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "epochCreatedFor",
+            "docs": [
+              "what epoch the Settlement has been created for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "slotCreatedAt",
+            "docs": [
+              "when the Settlement was created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rentCollector",
+            "docs": [
+              "address that collects the rent exempt from the Settlement account when closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "splitRentCollector",
+            "docs": [
+              "address that collects rent exempt for \"split stake account\" possibly created on funding settlement"
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "splitRentAmount",
+            "docs": [
+              "amount of lamports that are collected for rent exempt for \"split stake account\""
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bumps",
+            "docs": [
+              "PDA bumps"
+            ],
+            "type": {
+              "defined": "Bumps"
+            }
+          },
+          {
+            "name": "collateralFunded",
+            "docs": [
+              "lamports value of token collateral funded to the settlement, part of `lamports_funded`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "collateralClaimed",
+            "docs": [
+              "lamports claimed from the token collateral, part of `lamports_claimed`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amended",
+            "docs": [
+              "the settlement was amended before any claim, the claims are verified against `amended_merkle_root`"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "amendedMerkleRoot",
+            "docs": [
+              "merkle root replacing the original one when the settlement was amended, zeroed when not amended"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "disputed",
+            "docs": [
+              "the settlement was disputed by the bond authority, claiming is blocked until the admin resolves it"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "disputeReasonHash",
+            "docs": [
+              "hash of the dispute reason provided by the bond authority, kept when the dispute is resolved",
+              "and cleared when the settlement is amended"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "claimExpiryExtension",
+            "docs": [
+              "number of epochs the settlement claiming is extended beyond the config `epochs_to_claim_settlement`"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "accountVersion",
+            "docs": [
+              "layout version of the account, see `VersionedAccount`"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserve space for future extensions"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
           }
-        },
-        {
-          "name": "program",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+        ]
+      }
+    },
     {
-      "name": "bond",
+      "name": "withdrawRequest",
       "docs": [
-        "Bond account for a validator vote address"
+        "Request from a validator to withdraw the bond"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
+            "name": "voteAccount",
             "docs": [
-              "Program root config address. Validator bond is created for this config as PDA"
+              "Validator vote account that requested the withdrawal"
             ],
             "type": "publicKey"
           },
           {
-            "name": "voteAccount",
+            "name": "bond",
             "docs": [
-              "Validator vote address that this bond account is crated for",
-              "INVARIANTS:",
-              "- one bond account per validator vote address",
-              "- this program does NOT change stake account delegation voter_pubkey to any other validator vote account"
+              "Bond account that the withdraw request is for (has to match with vote_account)"
             ],
             "type": "publicKey"
           },
           {
-            "name": "authority",
+            "name": "epoch",
             "docs": [
-              "Authority that may close the bond or withdraw stake accounts associated with the bond",
-              "The same powers has got the owner of the validator vote account"
+              "Epoch when the withdrawal was requested, i.e., when this \"ticket\" is created"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "cpmpe",
+            "name": "requestedAmount",
             "docs": [
-              "Cost per mille per epoch.",
-              "This field represents the bid the bond (vote) account owner is willing to pay",
-              "for up to the `max_stake_wanted` being delegated.",
-              "The bid is in cost per mille per epoch similar to Google ads cpm system.",
-              "---",
-              "The actual amount of lamports deducted from the bond account for the processed bid",
-              "is based on the actual delegated lamports during the epoch."
+              "Amount of lamports to withdraw"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "withdrawnAmount",
+            "docs": [
+              "Amount of lamports withdrawn so far"
             ],
             "type": "u64"
           },
           {
             "name": "bump",
             "docs": [
-              "PDA Bond address bump seed"
+              "PDA account bump"
             ],
             "type": "u8"
           },
           {
-            "name": "maxStakeWanted",
+            "name": "accountVersion",
             "docs": [
-              "Maximum stake (in lamports) that the bond (vote) account owner requests.",
-              "This is the maximum stake that will be distributed to the vote account",
-              "when all other constraints are fulfilled (managed off-chain).",
-              "The vote account owner then goes to auction to obtain up to that maximum.",
-              "Use the `cpmpe` field to define the bid for this purpose."
+              "layout version of the account, see `VersionedAccount`"
             ],
-            "type": "u64"
+            "type": "u8"
           },
           {
             "name": "reserved",
@@ -6912,437 +15075,662 @@ export const IDL: ValidatorBonds = {
             "type": {
               "array": [
                 "u8",
-                134
+                92
               ]
             }
           }
         ]
       }
+    }
+  ],
+  "types": [
+    {
+      "name": "PubkeyValueChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old",
+            "type": "publicKey"
+          },
+          {
+            "name": "new",
+            "type": "publicKey"
+          }
+        ]
+      }
     },
     {
-      "name": "config",
-      "docs": [
-        "Root account that configures the validator bonds program"
-      ],
+      "name": "U64ValueChange",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "adminAuthority",
+            "name": "old",
+            "type": "u64"
+          },
+          {
+            "name": "new",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DelegationInfo",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "voterPubkey",
             "docs": [
-              "Admin authority that can update the config"
+              "to whom the stake is delegated"
             ],
             "type": "publicKey"
           },
           {
-            "name": "operatorAuthority",
+            "name": "stake",
             "docs": [
-              "Operator authority (bot hot wallet)"
+              "activated stake amount, set at delegate() time"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "epochsToClaimSettlement",
+            "name": "activationEpoch",
             "docs": [
-              "How many epochs permitting to claim the settlement"
+              "epoch at which this stake was activated, std::Epoch::MAX if is a bootstrap stake"
             ],
             "type": "u64"
           },
           {
-            "name": "withdrawLockupEpochs",
+            "name": "deactivationEpoch",
             "docs": [
-              "How many epochs before withdraw is allowed"
+              "epoch the stake was deactivated, std::Epoch::MAX if not deactivated"
             ],
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SplitStakeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
           },
           {
-            "name": "minimumStakeLamports",
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChargeBidArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "epoch",
             "docs": [
-              "Minimum amount of lamports to be considered for a stake account operations (e.g., split)"
+              "epoch the bid is charged for"
             ],
             "type": "u64"
           },
           {
-            "name": "bondsWithdrawerAuthorityBump",
+            "name": "delegatedLamports",
             "docs": [
-              "PDA bonds stake accounts authority bump seed"
+              "lamports delegated to the bond vote account in the epoch, the bid is calculated from,",
+              "bounded by the config `bid_max_delegated_lamports`"
             ],
-            "type": "u8"
-          },
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureBondAuthorityMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pauseAuthority",
+            "name": "enabled",
             "docs": [
-              "Authority that can pause the program in case of emergency"
+              "When true the bond mint of the current validator identity becomes a persistent bond authority credential,",
+              "its holder may configure the bond without burning the token.",
+              "When false the holding mode is switched off and the bond mint token is burnt on configuration."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureBondWithMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "validatorIdentity",
+            "docs": [
+              "Validator identity configured within the vote account.",
+              "For the bond authority mint it is the identity the mint was created for."
             ],
             "type": "publicKey"
           },
           {
-            "name": "paused",
-            "type": "bool"
+            "name": "bondAuthority",
+            "docs": [
+              "New bond authority that can manage the bond account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "slotsToStartSettlementClaiming",
+            "name": "cpmpe",
             "docs": [
-              "How many slots to wait before settlement is permitted to be claimed"
+              "New `cpmpe` value (cost per mille per epoch).",
+              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
             ],
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "minBondMaxStakeWanted",
+            "name": "maxStakeWanted",
             "docs": [
-              "Minimum value of max_stake_wanted to be configured by vote account owners at bond."
+              "new max_stake_wanted value that vote account owner declares",
+              "as the maximum delegated stake wanted"
             ],
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureBondArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bondAuthority",
+            "docs": [
+              "New bond authority that can manage the bond account."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "cpmpe",
+            "docs": [
+              "New `cpmpe` value (cost per mille per epoch).",
+              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
+            ],
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "reserved",
+            "name": "maxStakeWanted",
             "docs": [
-              "reserved space for future changes"
+              "New `max_stake_wanted` value that the vote account owner declares",
+              "as the maximum delegated stake desired."
             ],
             "type": {
-              "array": [
-                "u8",
-                463
-              ]
+              "option": "u64"
             }
           }
         ]
       }
     },
     {
-      "name": "settlementClaim",
-      "docs": [
-        "The settlement claim serves for deduplication purposes,",
-        "preventing the same settlement from being claimed multiple times with the same claiming data"
-      ],
+      "name": "InitBondArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "settlement",
-            "docs": [
-              "settlement account this claim belongs under"
-            ],
+            "name": "bondAuthority",
             "type": "publicKey"
           },
           {
-            "name": "stakeAccountTo",
-            "docs": [
-              "stake account to which the claim has been withdrawn to"
-            ],
-            "type": "publicKey"
+            "name": "cpmpe",
+            "type": "u64"
           },
           {
-            "name": "stakeAccountStaker",
-            "docs": [
-              "staker authority as part of the merkle proof for this claim"
-            ],
-            "type": "publicKey"
-          },
+            "name": "maxStakeWanted",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigureCollateralMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "stakeAccountWithdrawer",
-            "docs": [
-              "withdrawer authority as part of the merkle proof for this claim"
-            ],
-            "type": "publicKey"
+            "name": "lamportsPerToken",
+            "type": {
+              "option": "u64"
+            }
           },
+          {
+            "name": "haircutBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundBondCollateralArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "amount",
             "docs": [
-              "claim amount"
+              "amount of tokens (in base units) to be deposited"
             ],
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitCollateralMintArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "bump",
+            "name": "lamportsPerToken",
             "docs": [
-              "PDA account bump, one claim per settlement"
+              "price of one token in lamports"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "rentCollector",
+            "name": "haircutBps",
             "docs": [
-              "rent collector account to get the rent back for claim account creation"
+              "haircut in basis points deducted from the price when valuing the collateral"
             ],
-            "type": "publicKey"
-          },
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ResetSettlementCollateralArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "reserved",
+            "name": "merkleRoot",
             "docs": [
-              "reserve space for future extensions"
+              "merkle root of the closed settlement; settlement PDA seed"
             ],
             "type": {
               "array": [
                 "u8",
-                93
+                32
               ]
             }
+          },
+          {
+            "name": "epochCreatedFor",
+            "docs": [
+              "epoch the closed settlement was created for; settlement PDA seed"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "settlement",
-      "docs": [
-        "Settlement account for a particular config and merkle root",
-        "Settlement defines that a protected event happened and it will be settled"
-      ],
+      "name": "ConfigureConfigArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bond",
-            "docs": [
-              "the settlement belongs under this bond, i.e., under a particular validator vote account"
-            ],
-            "type": "publicKey"
+            "name": "admin",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "stakerAuthority",
-            "docs": [
-              "settlement authority used as the 'staker' stake account authority",
-              "of stake accounts funded to this settlement"
-            ],
-            "type": "publicKey"
+            "name": "operator",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "merkleRoot",
-            "docs": [
-              "256-bit merkle root to check the claims against"
-            ],
+            "name": "pauseAuthority",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "publicKey"
             }
           },
           {
-            "name": "maxTotalClaim",
+            "name": "settlementCreator",
             "docs": [
-              "maximum number of funds that can ever be claimed"
+              "operator roles, default pubkey means the role falls back to the operator authority"
             ],
-            "type": "u64"
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "maxMerkleNodes",
-            "docs": [
-              "maximum number of merkle tree nodes that can ever be claimed"
-            ],
-            "type": "u64"
+            "name": "settlementFunder",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "lamportsFunded",
-            "docs": [
-              "total lamports funded"
-            ],
-            "type": "u64"
+            "name": "settlementCloser",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "lamportsClaimed",
-            "docs": [
-              "total lamports that have been claimed"
-            ],
-            "type": "u64"
+            "name": "bidFeeCollector",
+            "type": {
+              "option": "publicKey"
+            }
           },
           {
-            "name": "merkleNodesClaimed",
+            "name": "bidMaxDelegatedLamports",
             "docs": [
-              "number of nodes that have been claimed"
+              "upper bound of the delegated lamports the bid is charged for, see `Config::bid_max_delegated_lamports`"
             ],
-            "type": "u64"
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "epochCreatedFor",
+            "name": "maxClaimExpiryExtension",
             "docs": [
-              "what epoch the Settlement has been created for"
+              "maximum total extension of a settlement claiming period, see `Config::max_claim_expiry_extension`"
             ],
-            "type": "u64"
+            "type": {
+              "option": "u32"
+            }
           },
           {
-            "name": "slotCreatedAt",
-            "docs": [
-              "when the Settlement was created"
-            ],
-            "type": "u64"
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "rentCollector",
-            "docs": [
-              "address that collects the rent exempt from the Settlement account when closed"
-            ],
-            "type": "publicKey"
+            "name": "withdrawLockupEpochs",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "splitRentCollector",
+            "name": "minimumStakeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeLamports",
             "docs": [
-              "address that collects rent exempt for \"split stake account\" possibly created on funding settlement"
+              "fee for permission-less settlement claiming, see `Config::claimer_fee`"
             ],
             "type": {
-              "option": "publicKey"
+              "option": "u64"
             }
           },
           {
-            "name": "splitRentAmount",
+            "name": "claimerFeeMaxBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "cancelPendingChanges",
             "docs": [
-              "amount of lamports that are collected for rent exempt for \"split stake account\""
+              "drops all pending authority and parameter changes before the new ones are processed"
             ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPauseFlagsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "bitflag set of the paused instruction families, see `pause_flags`, 0 resumes all of them"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "adminAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "operatorAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "epochsToClaimSettlement",
             "type": "u64"
           },
           {
-            "name": "bumps",
-            "docs": [
-              "PDA bumps"
-            ],
-            "type": {
-              "defined": "Bumps"
-            }
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "reserve space for future extensions"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                91
-              ]
-            }
+            "name": "slotsToStartSettlementClaiming",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "withdrawRequest",
-      "docs": [
-        "Request from a validator to withdraw the bond"
-      ],
+      "name": "AmendSettlementArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "voteAccount",
+            "name": "merkleRoot",
             "docs": [
-              "Validator vote account that requested the withdrawal"
+              "new merkle root the claims are verified against,",
+              "the settlement address stays derived from the original merkle root"
             ],
-            "type": "publicKey"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "bond",
+            "name": "maxTotalClaim",
             "docs": [
-              "Bond account that the withdraw request is for (has to match with vote_account)"
+              "new maximal number of lamports that can be claimed from this settlement"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "epoch",
+            "name": "maxMerkleNodes",
             "docs": [
-              "Epoch when the withdrawal was requested, i.e., when this \"ticket\" is created"
+              "new maximal number of merkle tree nodes that can be claimed from this settlement"
             ],
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimSettlementArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "requestedAmount",
+            "name": "proof",
             "docs": [
-              "Amount of lamports to withdraw"
+              "proof that the claim is appropriate"
             ],
-            "type": "u64"
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           },
           {
-            "name": "withdrawnAmount",
+            "name": "treeNodeHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "stakeAccountStaker",
             "docs": [
-              "Amount of lamports withdrawn so far"
+              "staker authority of the stake_account_to; merkle root verification"
             ],
-            "type": "u64"
+            "type": "publicKey"
           },
           {
-            "name": "bump",
+            "name": "stakeAccountWithdrawer",
             "docs": [
-              "PDA account bump"
+              "withdrawer authority of the stake_account_to; merkle root verification"
             ],
-            "type": "u8"
+            "type": "publicKey"
           },
           {
-            "name": "reserved",
+            "name": "claim",
             "docs": [
-              "reserve space for future extensions"
+              "claim amount; merkle root verification"
             ],
-            "type": {
-              "array": [
-                "u8",
-                93
-              ]
-            }
+            "type": "u64"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "PubkeyValueChange",
+      "name": "DisputeSettlementArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "old",
-            "type": "publicKey"
-          },
-          {
-            "name": "new",
-            "type": "publicKey"
+            "name": "reasonHash",
+            "docs": [
+              "hash of the dispute reason published off-chain by the validator"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "U64ValueChange",
+      "name": "ExtendSettlementExpiryArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "old",
-            "type": "u64"
-          },
-          {
-            "name": "new",
-            "type": "u64"
+            "name": "epochs",
+            "docs": [
+              "number of epochs added to the settlement claiming period"
+            ],
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "DelegationInfo",
+      "name": "InitSettlementArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "voterPubkey",
+            "name": "merkleRoot",
             "docs": [
-              "to whom the stake is delegated"
+              "merkle root for this settlement, multiple settlements can be created with the same merkle root,",
+              "settlements will be distinguished by the vote_account"
             ],
-            "type": "publicKey"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "stake",
+            "name": "maxTotalClaim",
             "docs": [
-              "activated stake amount, set at delegate() time"
+              "maximal number of lamports that can be claimed from this settlement"
             ],
             "type": "u64"
           },
           {
-            "name": "activationEpoch",
+            "name": "maxMerkleNodes",
             "docs": [
-              "epoch at which this stake was activated, std::Epoch::MAX if is a bootstrap stake"
+              "maximal number of merkle tree nodes that can be claimed from this settlement"
             ],
             "type": "u64"
           },
           {
-            "name": "deactivationEpoch",
+            "name": "rentCollector",
             "docs": [
-              "epoch the stake was deactivated, std::Epoch::MAX if not deactivated"
+              "collects the rent exempt from the settlement account when closed"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "epoch that the settlement is created for"
             ],
             "type": "u64"
           }
@@ -7350,14 +15738,22 @@ export const IDL: ValidatorBonds = {
       }
     },
     {
-      "name": "SplitStakeData",
+      "name": "MergeStakeArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "address",
+            "name": "settlement",
             "type": "publicKey"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitWithdrawRequestArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "amount",
             "type": "u64"
@@ -7366,378 +15762,926 @@ export const IDL: ValidatorBonds = {
       }
     },
     {
-      "name": "ConfigureBondWithMintArgs",
+      "name": "ConfigParamChanges",
+      "docs": [
+        "Config parameter changes, `None` means the parameter is not changed"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "validatorIdentity",
-            "docs": [
-              "Validator identity configured within the vote account."
-            ],
-            "type": "publicKey"
+            "name": "epochsToClaimSettlement",
+            "type": {
+              "option": "u64"
+            }
           },
           {
-            "name": "bondAuthority",
-            "docs": [
-              "New bond authority that can manage the bond account."
-            ],
+            "name": "withdrawLockupEpochs",
             "type": {
-              "option": "publicKey"
+              "option": "u64"
             }
           },
           {
-            "name": "cpmpe",
-            "docs": [
-              "New `cpmpe` value (cost per mille per epoch).",
-              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
-            ],
+            "name": "minimumStakeLamports",
             "type": {
               "option": "u64"
             }
           },
           {
-            "name": "maxStakeWanted",
-            "docs": [
-              "new max_stake_wanted value that vote account owner declares",
-              "as the maximum delegated stake wanted"
-            ],
+            "name": "slotsToStartSettlementClaiming",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "configChangeDelayEpochs",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
             "type": {
               "option": "u64"
             }
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "type": {
+              "option": "u32"
+            }
           }
         ]
       }
     },
     {
-      "name": "ConfigureBondArgs",
+      "name": "PendingConfigChanges",
+      "docs": [
+        "Config parameters staged by the admin authority.",
+        "Any party may apply them once the `effective_epoch` is reached.",
+        "Fixed-size encoded to keep the offsets of the following Config fields stable,",
+        "a parameter value is considered only when its bit is set in `staged_params`."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bondAuthority",
-            "docs": [
-              "New bond authority that can manage the bond account."
-            ],
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "effectiveEpoch",
+            "docs": [
+              "epoch from which the changes may be applied"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stagedParams",
+            "docs": [
+              "bitflag set of the staged parameters, see `staged_config_params` module, 0 means nothing is staged"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "epochsToClaimSettlement",
+            "type": "u64"
+          },
+          {
+            "name": "withdrawLockupEpochs",
+            "type": "u64"
+          },
+          {
+            "name": "minimumStakeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "slotsToStartSettlementClaiming",
+            "type": "u64"
+          },
+          {
+            "name": "minBondMaxStakeWanted",
+            "type": "u64"
           },
           {
-            "name": "cpmpe",
-            "docs": [
-              "New `cpmpe` value (cost per mille per epoch).",
-              "It defines the bid for the validator to get delegated up to `max_stake_wanted` lamports."
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "configChangeDelayEpochs",
+            "type": "u64"
           },
           {
-            "name": "maxStakeWanted",
-            "docs": [
-              "New `max_stake_wanted` value that the vote account owner declares",
-              "as the maximum delegated stake desired."
-            ],
-            "type": {
-              "option": "u64"
-            }
+            "name": "claimerFeeLamports",
+            "type": "u64"
+          },
+          {
+            "name": "claimerFeeMaxBps",
+            "type": "u16"
+          },
+          {
+            "name": "bidMaxDelegatedLamports",
+            "type": "u64"
+          },
+          {
+            "name": "maxClaimExpiryExtension",
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "InitBondArgs",
+      "name": "Bumps",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bondAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "cpmpe",
-            "type": "u64"
+            "name": "pda",
+            "type": "u8"
           },
           {
-            "name": "maxStakeWanted",
-            "type": "u64"
+            "name": "stakerAuthority",
+            "type": "u8"
           }
         ]
       }
+    }
+  ],
+  "events": [
+    {
+      "name": "MigrateAccountEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "account",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "accountType",
+          "type": "string",
+          "index": false
+        },
+        {
+          "name": "accountVersion",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
+        {
+          "name": "dataSize",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "validatorIdentity",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigureBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bondAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigureBondWithMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "validatorIdentity",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenBurned",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "bondAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ConfigureBondAuthorityMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authorityMint",
+          "type": {
+            "defined": "PubkeyValueChange"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "FundBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAuthoritySigner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositedAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "MintBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "validatorIdentity",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "validatorIdentityTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenMetadata",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ConfigureConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "admin",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "operator",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "pauseAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "epochsToClaimSettlement",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "minimumStakeLamports",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "slotsToStartSettlementClaiming",
-            "type": {
-              "option": "u64"
-            }
-          },
-          {
-            "name": "minBondMaxStakeWanted",
-            "type": {
-              "option": "u64"
-            }
-          }
-        ]
-      }
+      "name": "ChargeBidEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "cpmpe",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "maxStakeWanted",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "delegatedLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "chargedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bidChargedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "splitStakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bidFeeCollector",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "InitConfigArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "adminAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "operatorAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "epochsToClaimSettlement",
-            "type": "u64"
-          },
-          {
-            "name": "withdrawLockupEpochs",
-            "type": "u64"
+      "name": "MigrateBondEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bondVoteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": {
+            "defined": "PubkeyValueChange"
           },
-          {
-            "name": "slotsToStartSettlementClaiming",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ClaimSettlementArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "proof",
-            "docs": [
-              "proof that the claim is appropriate"
-            ],
-            "type": {
-              "vec": {
-                "array": [
-                  "u8",
-                  32
-                ]
-              }
-            }
-          },
-          {
-            "name": "treeNodeHash",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "stakeAccountStaker",
-            "docs": [
-              "staker authority of the stake_account_to; merkle root verification"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakeAccountWithdrawer",
-            "docs": [
-              "withdrawer authority of the stake_account_to; merkle root verification"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "claim",
-            "docs": [
-              "claim amount; merkle root verification"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "InitCollateralMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "decimals",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "lamportsPerToken",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "haircutBps",
+          "type": "u16",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "InitSettlementArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "merkleRoot",
-            "docs": [
-              "merkle root for this settlement, multiple settlements can be created with the same merkle root,",
-              "settlements will be distinguished by the vote_account"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
+      "name": "ConfigureCollateralMintEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "collateralMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lamportsPerToken",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
             }
           },
-          {
-            "name": "maxTotalClaim",
-            "docs": [
-              "maximal number of lamports that can be claimed from this settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "maxMerkleNodes",
-            "docs": [
-              "maximal number of merkle tree nodes that can be claimed from this settlement"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rentCollector",
-            "docs": [
-              "collects the rent exempt from the settlement account when closed"
-            ],
-            "type": "publicKey"
+          "index": false
+        },
+        {
+          "name": "haircutBps",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
           },
-          {
-            "name": "epoch",
-            "docs": [
-              "epoch that the settlement is created for"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "MergeStakeArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "settlement",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "FundBondCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositedAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "vaultAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "collateralValue",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "InitWithdrawRequestArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "amount",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "ClaimWithdrawCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "withdrawRequest",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "collateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawerTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawingTokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "withdrawingAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "withdrawnAmount",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Bumps",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pda",
-            "type": "u8"
+      "name": "FundSettlementCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlementCollateralVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fundingTokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fundingAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "lamportsFunded",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "collateralFunded",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimSettlementCollateralEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "settlementClaim",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimerTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountStaker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountWithdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "tokenAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "settlementLamportsClaimed",
+          "type": {
+            "defined": "U64ValueChange"
           },
-          {
-            "name": "stakerAuthority",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
+          "index": false
+        },
+        {
+          "name": "settlementMerkleNodesClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimerFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "claimerFeeTokens",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "InitBondEvent",
+      "name": "ResetSettlementCollateralEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "config",
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "voteAccount",
+          "name": "mint",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "validatorIdentity",
+          "name": "settlementCollateralVault",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "authority",
+          "name": "collateralVault",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "cpmpe",
+          "name": "returnedTokenAmount",
           "type": "u64",
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "InitConfigEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "maxStakeWanted",
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "adminAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "operatorAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawLockupEpochs",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "epochsToClaimSettlement",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "minimumStakeLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "bondsWithdrawerAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slotsToStartSettlementClaiming",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "ConfigureBondEvent",
+      "name": "ConfigureConfigEvent",
       "fields": [
         {
-          "name": "bondAuthority",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "adminAuthority",
           "type": {
             "option": {
               "defined": "PubkeyValueChange"
@@ -7746,7 +16690,61 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "cpmpe",
+          "name": "operatorAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "pauseAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "settlementCreatorAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "settlementFunderAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "settlementCloserAuthority",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "bidFeeCollector",
+          "type": {
+            "option": {
+              "defined": "PubkeyValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "bidMaxDelegatedLamports",
           "type": {
             "option": {
               "defined": "U64ValueChange"
@@ -7755,35 +16753,79 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "maxStakeWanted",
+          "name": "maxClaimExpiryExtension",
           "type": {
             "option": {
               "defined": "U64ValueChange"
             }
           },
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "ConfigureBondWithMintEvent",
-      "fields": [
+        },
         {
-          "name": "validatorIdentity",
-          "type": "publicKey",
+          "name": "epochsToClaimSettlement",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
           "index": false
         },
         {
-          "name": "bondAuthority",
+          "name": "minimumStakeLamports",
           "type": {
             "option": {
-              "defined": "PubkeyValueChange"
+              "defined": "U64ValueChange"
             }
           },
           "index": false
         },
         {
-          "name": "cpmpe",
+          "name": "withdrawLockupEpochs",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "slotsToStartSettlementClaiming",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "minBondMaxStakeWanted",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "configChangeDelayEpochs",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "claimerFeeLamports",
+          "type": {
+            "option": {
+              "defined": "U64ValueChange"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "claimerFeeMaxBps",
           "type": {
             "option": {
               "defined": "U64ValueChange"
@@ -7792,10 +16834,31 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "maxStakeWanted",
+          "name": "pendingAdminAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "pendingOperatorAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "pendingPauseAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "pendingConfigChanges",
           "type": {
             "option": {
-              "defined": "U64ValueChange"
+              "defined": "PendingConfigChanges"
             }
           },
           "index": false
@@ -7803,63 +16866,65 @@ export const IDL: ValidatorBonds = {
       ]
     },
     {
-      "name": "FundBondEvent",
+      "name": "EmergencyPauseEvent",
       "fields": [
         {
-          "name": "bond",
-          "type": "publicKey",
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "voteAccount",
+          "name": "config",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakeAccount",
+          "name": "adminAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakeAuthoritySigner",
+          "name": "operatorAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "depositedAmount",
+          "name": "epochsToClaimSettlement",
           "type": "u64",
           "index": false
-        }
-      ]
-    },
-    {
-      "name": "MintBondEvent",
-      "fields": [
+        },
         {
-          "name": "bond",
-          "type": "publicKey",
+          "name": "withdrawLockupEpochs",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "validatorIdentity",
-          "type": "publicKey",
+          "name": "minimumStakeLamports",
+          "type": "u64",
           "index": false
         },
         {
-          "name": "validatorIdentityTokenAccount",
+          "name": "pauseAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "tokenMetadata",
-          "type": "publicKey",
+          "name": "pauseFlags",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "InitConfigEvent",
+      "name": "EmergencyResumeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
@@ -7876,12 +16941,12 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
+          "name": "epochsToClaimSettlement",
           "type": "u64",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "withdrawLockupEpochs",
           "type": "u64",
           "index": false
         },
@@ -7891,179 +16956,238 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "bondsWithdrawerAuthority",
+          "name": "pauseAuthority",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "slotsToStartSettlementClaiming",
-          "type": "u64",
+          "name": "pauseFlags",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         }
       ]
     },
     {
-      "name": "ConfigureConfigEvent",
+      "name": "SetPauseFlagsEvent",
       "fields": [
         {
-          "name": "adminAuthority",
-          "type": {
-            "option": {
-              "defined": "PubkeyValueChange"
-            }
-          },
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "operatorAuthority",
-          "type": {
-            "option": {
-              "defined": "PubkeyValueChange"
-            }
-          },
+          "name": "config",
+          "type": "publicKey",
           "index": false
         },
         {
           "name": "pauseAuthority",
-          "type": {
-            "option": {
-              "defined": "PubkeyValueChange"
-            }
-          },
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "pauseFlags",
           "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
+            "defined": "U64ValueChange"
           },
           "index": false
+        }
+      ]
+    },
+    {
+      "name": "ClaimSettlementEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "minimumStakeLamports",
-          "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
-          },
+          "name": "settlementClaim",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
-          "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
-          },
+          "name": "settlement",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "slotsToStartSettlementClaiming",
+          "name": "settlementLamportsClaimed",
           "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
+            "defined": "U64ValueChange"
           },
           "index": false
         },
         {
-          "name": "minBondMaxStakeWanted",
-          "type": {
-            "option": {
-              "defined": "U64ValueChange"
-            }
-          },
+          "name": "settlementMerkleNodesClaimed",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "stakeAccountTo",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountWithdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccountStaker",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claimerFee",
+          "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "EmergencyPauseEvent",
+      "name": "CloseSettlementClaimEvent",
       "fields": [
         {
-          "name": "config",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "adminAuthority",
+          "name": "settlementClaim",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "operatorAuthority",
+          "name": "stakeAccountTo",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
-          "type": "u64",
+          "name": "stakeAccountWithdrawer",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
-          "type": "u64",
+          "name": "stakeAccountStaker",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "minimumStakeLamports",
+          "name": "amount",
           "type": "u64",
           "index": false
         },
         {
-          "name": "pauseAuthority",
+          "name": "rentCollector",
           "type": "publicKey",
           "index": false
+        },
+        {
+          "name": "rentRefund",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "EmergencyResumeEvent",
+      "name": "InitSettlementEvent",
       "fields": [
         {
-          "name": "config",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "adminAuthority",
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "operatorAuthority",
+          "name": "voteAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochsToClaimSettlement",
+          "name": "stakerAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "maxTotalClaim",
           "type": "u64",
           "index": false
         },
         {
-          "name": "withdrawLockupEpochs",
+          "name": "maxMerkleNodes",
           "type": "u64",
           "index": false
         },
         {
-          "name": "minimumStakeLamports",
+          "name": "epochCreatedFor",
           "type": "u64",
           "index": false
         },
         {
-          "name": "pauseAuthority",
+          "name": "slotCreatedAt",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "rentCollector",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "ClaimSettlementEvent",
+      "name": "AmendSettlementEvent",
       "fields": [
         {
-          "name": "settlementClaim",
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
@@ -8073,62 +17197,101 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "settlementLamportsClaimed",
+          "name": "oldMerkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "newMerkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "maxTotalClaim",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
+        {
+          "name": "maxMerkleNodes",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
+        {
+          "name": "slotCreatedAt",
           "type": {
             "defined": "U64ValueChange"
           },
           "index": false
         },
         {
-          "name": "settlementMerkleNodesClaimed",
-          "type": "u64",
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "DisputeSettlementEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "stakeAccountTo",
+          "name": "bond",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakeAccountWithdrawer",
+          "name": "settlement",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "stakeAccountStaker",
+          "name": "voteAccount",
           "type": "publicKey",
           "index": false
         },
         {
-          "name": "amount",
-          "type": "u64",
+          "name": "reasonHash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
           "index": false
         },
         {
-          "name": "rentCollector",
+          "name": "authority",
           "type": "publicKey",
           "index": false
         }
       ]
     },
     {
-      "name": "CloseSettlementClaimEvent",
+      "name": "ResolveDisputeEvent",
       "fields": [
         {
-          "name": "settlement",
-          "type": "publicKey",
+          "name": "version",
+          "type": "u8",
           "index": false
         },
-        {
-          "name": "rentCollector",
-          "type": "publicKey",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "InitSettlementEvent",
-      "fields": [
         {
           "name": "bond",
           "type": "publicKey",
@@ -8140,17 +17303,7 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "voteAccount",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "stakerAuthority",
-          "type": "publicKey",
-          "index": false
-        },
-        {
-          "name": "merkleRoot",
+          "name": "reasonHash",
           "type": {
             "array": [
               "u8",
@@ -8160,27 +17313,46 @@ export const IDL: ValidatorBonds = {
           "index": false
         },
         {
-          "name": "maxTotalClaim",
-          "type": "u64",
+          "name": "adminAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ExtendSettlementExpiryEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
           "index": false
         },
         {
-          "name": "maxMerkleNodes",
-          "type": "u64",
+          "name": "bond",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "epochCreatedFor",
-          "type": "u64",
+          "name": "settlement",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "slotCreatedAt",
-          "type": "u64",
+          "name": "claimExpiryExtension",
+          "type": {
+            "defined": "U64ValueChange"
+          },
           "index": false
         },
         {
-          "name": "rentCollector",
+          "name": "expirationEpoch",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
+        {
+          "name": "authority",
           "type": "publicKey",
           "index": false
         }
@@ -8189,6 +17361,11 @@ export const IDL: ValidatorBonds = {
     {
       "name": "CloseSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
@@ -8268,6 +17445,11 @@ export const IDL: ValidatorBonds = {
     {
       "name": "CancelSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
@@ -8342,6 +17524,11 @@ export const IDL: ValidatorBonds = {
     {
       "name": "FundSettlementEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "bond",
           "type": "publicKey",
@@ -8403,11 +17590,21 @@ export const IDL: ValidatorBonds = {
     {
       "name": "MergeStakeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "settlement",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "stakerAuthority",
           "type": "publicKey",
@@ -8427,6 +17624,13 @@ export const IDL: ValidatorBonds = {
           },
           "index": false
         },
+        {
+          "name": "destinationLamports",
+          "type": {
+            "defined": "U64ValueChange"
+          },
+          "index": false
+        },
         {
           "name": "sourceStake",
           "type": "publicKey",
@@ -8440,12 +17644,27 @@ export const IDL: ValidatorBonds = {
             }
           },
           "index": false
+        },
+        {
+          "name": "sourceLamports",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
       "name": "ResetStakeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
@@ -8475,12 +17694,34 @@ export const IDL: ValidatorBonds = {
           "name": "settlementStakerAuthority",
           "type": "publicKey",
           "index": false
+        },
+        {
+          "name": "stakeAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "operatorAuthority",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "epoch",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
       "name": "WithdrawStakeEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "config",
           "type": "publicKey",
@@ -8518,9 +17759,54 @@ export const IDL: ValidatorBonds = {
         }
       ]
     },
+    {
+      "name": "RedelegateStakeEvent",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "config",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bond",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "stakeAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oldVoteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newVoteAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "deactivated",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
     {
       "name": "InitWithdrawRequestEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "withdrawRequest",
           "type": "publicKey",
@@ -8551,6 +17837,11 @@ export const IDL: ValidatorBonds = {
     {
       "name": "CancelWithdrawRequestEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "withdrawRequest",
           "type": "publicKey",
@@ -8581,6 +17872,11 @@ export const IDL: ValidatorBonds = {
     {
       "name": "ClaimWithdrawRequestEvent",
       "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
         {
           "name": "withdrawRequest",
           "type": "publicKey",
@@ -8601,6 +17897,13 @@ export const IDL: ValidatorBonds = {
           "type": "publicKey",
           "index": false
         },
+        {
+          "name": "mergedStakeAccounts",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
         {
           "name": "splitStake",
           "type": {
@@ -8950,6 +18253,181 @@ export const IDL: ValidatorBonds = {
       "code": 6063,
       "name": "MaxStakeWantedTooLow",
       "msg": "Max stake wanted value is lower to minimum configured value"
+    },
+    {
+      "code": 6064,
+      "name": "NoPendingAuthorityChange",
+      "msg": "No pending authority change for the signer to accept"
+    },
+    {
+      "code": 6065,
+      "name": "NoPendingConfigChanges",
+      "msg": "No pending config changes to apply"
+    },
+    {
+      "code": 6066,
+      "name": "PendingConfigChangesNotEffective",
+      "msg": "Pending config changes are not effective yet"
+    },
+    {
+      "code": 6067,
+      "name": "InvalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6068,
+      "name": "BidAlreadyCharged",
+      "msg": "Bid for the epoch has been already charged"
+    },
+    {
+      "code": 6069,
+      "name": "BidEpochNotFinished",
+      "msg": "Bid can be charged only for already finished epoch"
+    },
+    {
+      "code": 6070,
+      "name": "BidFeeCollectorNotConfigured",
+      "msg": "Bid fee collector is not configured"
+    },
+    {
+      "code": 6071,
+      "name": "BondMigrationNotPermitted",
+      "msg": "Bond migration requires validator identity or withdrawer of both vote accounts"
+    },
+    {
+      "code": 6072,
+      "name": "NothingToRedelegate",
+      "msg": "Stake account is already delegated to the bond vote account"
+    },
+    {
+      "code": 6073,
+      "name": "InvalidCollateralMintAddress",
+      "msg": "Invalid collateral mint account address"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidCollateralHaircut",
+      "msg": "Collateral haircut basis points exceeds 10000"
+    },
+    {
+      "code": 6075,
+      "name": "CollateralAmountTooSmall",
+      "msg": "Collateral token amount has got no value"
+    },
+    {
+      "code": 6076,
+      "name": "CollateralInsufficient",
+      "msg": "Not enough token collateral in the vault"
+    },
+    {
+      "code": 6077,
+      "name": "CollateralClaimExceedsFunded",
+      "msg": "Claim exceeds token collateral funded to the settlement"
+    },
+    {
+      "code": 6078,
+      "name": "SettlementAlreadyClaimed",
+      "msg": "Settlement cannot be amended as it has been already claimed"
+    },
+    {
+      "code": 6079,
+      "name": "SettlementDisputeWindowClosed",
+      "msg": "Settlement dispute window has already passed"
+    },
+    {
+      "code": 6080,
+      "name": "SettlementDisputed",
+      "msg": "Settlement is disputed, waiting for the admin resolution"
+    },
+    {
+      "code": 6081,
+      "name": "SettlementAlreadyDisputed",
+      "msg": "Settlement has been already disputed"
+    },
+    {
+      "code": 6082,
+      "name": "SettlementNotDisputed",
+      "msg": "Settlement is not disputed"
+    },
+    {
+      "code": 6083,
+      "name": "InvalidBondAuthorityMint",
+      "msg": "Bond authority mint is not the bond mint of the validator identity"
+    },
+    {
+      "code": 6084,
+      "name": "BondAuthorityMintNotHeld",
+      "msg": "Token account does not hold the bond authority mint token"
+    },
+    {
+      "code": 6085,
+      "name": "InvalidSettlementExpiryExtension",
+      "msg": "Settlement expiry extension has to be a positive number of epochs"
+    },
+    {
+      "code": 6086,
+      "name": "InvalidClaimerFeeBps",
+      "msg": "Claimer fee basis points exceeds the maximum"
+    },
+    {
+      "code": 6087,
+      "name": "MigrateAccountNotSupported",
+      "msg": "Account type cannot be migrated"
+    },
+    {
+      "code": 6088,
+      "name": "AccountAlreadyMigrated",
+      "msg": "Account has been already migrated to the current version"
+    },
+    {
+      "code": 6089,
+      "name": "PauseFlagsNotChanged",
+      "msg": "Pause flags are already set to the requested value"
+    },
+    {
+      "code": 6090,
+      "name": "BidEpochBeforeBondCreation",
+      "msg": "Bid cannot be charged for an epoch before the bond was created"
+    },
+    {
+      "code": 6091,
+      "name": "BidDelegatedLamportsExceeded",
+      "msg": "Delegated lamports exceed the config maximum for charging the bid"
+    },
+    {
+      "code": 6092,
+      "name": "BidAmountIsZero",
+      "msg": "Bid amount for the epoch is zero"
+    },
+    {
+      "code": 6093,
+      "name": "BondAlreadyExists",
+      "msg": "Bond already exists for the vote account"
+    },
+    {
+      "code": 6094,
+      "name": "SettlementExpiryExtensionExceeded",
+      "msg": "Settlement expiry extension exceeds the config maximum"
+    },
+    {
+      "code": 6095,
+      "name": "BondAliasMismatch",
+      "msg": "Bond alias account does not match the bond migration"
+    },
+    {
+      "code": 6096,
+      "name": "BondAlreadyMigrated",
+      "msg": "Bond stake accounts are already delegated to the vote account"
+    },
+    {
+      "code": 6097,
+      "name": "CollateralClaimTooSmall",
+      "msg": "Settlement claim is too small to be paid with the collateral tokens"
+    },
+    {
+      "code": 6098,
+      "name": "EmptyDisputeReasonHash",
+      "msg": "Settlement dispute reason hash cannot be empty"
     }
   ]
 };
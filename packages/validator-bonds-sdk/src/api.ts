@@ -17,6 +17,7 @@ import {
   SettlementClaim,
   uintToBuffer,
   bondsWithdrawerAuthority,
+  CollateralMint,
 } from './sdk'
 import BN from 'bn.js'
 import { bs58 } from '@coral-xyz/anchor/dist/cjs/utils/bytes'
@@ -425,6 +426,44 @@ export async function findSettlementClaims({
     .map(d => d as ProgramAccount<SettlementClaim>)
 }
 
+export async function getCollateralMint(
+  program: ValidatorBondsProgram,
+  address: PublicKey
+): Promise<CollateralMint> {
+  return program.account.collateralMint.fetch(address)
+}
+
+export async function findCollateralMints({
+  program,
+  config,
+  mint,
+}: {
+  program: ValidatorBondsProgram
+  config?: PublicKey
+  mint?: PublicKey
+}): Promise<ProgramAccount<CollateralMint>[]> {
+  const filters = []
+  if (config) {
+    filters.push({
+      memcmp: {
+        bytes: config.toBase58(),
+        // 8 anchor offset
+        offset: 8,
+      },
+    })
+  }
+  if (mint) {
+    filters.push({
+      memcmp: {
+        bytes: mint.toBase58(),
+        // 8 anchor offset + first data 32B config pubkey
+        offset: 40,
+      },
+    })
+  }
+  return await program.account.collateralMint.all(filters)
+}
+
 function parseNotLocked(
   stakeAccounts: ProgramAccountInfo<StakeAccountParsed>[]
 ): ProgramAccountInfo<StakeAccountParsed>[] {
@@ -0,0 +1,47 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { MARINADE_CONFIG_ADDRESS, ValidatorBondsProgram } from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to accept the admin, operator or pause authority
+ * proposed by the admin with the configure config instruction.
+ * All pending authorities matching the new authority are changed.
+ *
+ * @type {Object} args - Arguments on instruction creation
+ * @param param {ValidatorBondsProgram} args.program - anchor program instance
+ * @param param {PublicKey} args.configAccount - config account to accept the authority for (default: MARINADE config address)
+ * @param param {PublicKey} args.newAuthority [SIGNER] - proposed authority (default: provider wallet address)
+ * @type {Object} return - Return data of generated instruction
+ * @return {TransactionInstruction} return.instruction - Instruction to accept config authority
+ */
+export async function acceptConfigAuthorityInstruction({
+  program,
+  configAccount = MARINADE_CONFIG_ADDRESS,
+  newAuthority = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  newAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  newAuthority =
+    newAuthority instanceof PublicKey ? newAuthority : newAuthority.publicKey
+
+  const instruction = await program.methods
+    .acceptConfigAuthority()
+    .accounts({
+      config: configAccount,
+      newAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,80 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram } from '../sdk'
+import { getBond, getConfig, getSettlement } from '../api'
+import { operatorRoleAuthority } from '../utils'
+import BN from 'bn.js'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to amend the settlement merkle tree data.
+ * Only operator authority is permitted to do this,
+ * the settlement creator role when it is configured.
+ * Possible only before any claim has been made from the settlement.
+ * The settlement address stays derived from the original merkle root
+ * while the claims are verified against the amended merkle root.
+ * The claiming window is restarted from the slot of the amendment
+ * and the dispute of the prior merkle tree is cleared.
+ */
+export async function amendSettlementInstruction({
+  program,
+  settlementAccount,
+  merkleRoot,
+  maxTotalClaim,
+  maxMerkleNodes,
+  configAccount,
+  bondAccount,
+  operatorAuthority,
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  merkleRoot: number[] | Uint8Array | Buffer
+  maxTotalClaim: number | BN
+  maxMerkleNodes: number | BN
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  if (operatorAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    operatorAuthority = operatorRoleAuthority(
+      configData,
+      'settlementCreatorAuthority'
+    )
+  }
+  operatorAuthority =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+
+  const instruction = await program.methods
+    .amendSettlement({
+      merkleRoot: Array.from(merkleRoot),
+      maxTotalClaim: new BN(maxTotalClaim),
+      maxMerkleNodes: new BN(maxMerkleNodes),
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      operatorAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,32 @@
+import { PublicKey, TransactionInstruction } from '@solana/web3.js'
+import { MARINADE_CONFIG_ADDRESS, ValidatorBondsProgram } from '../sdk'
+
+/**
+ * Generate instruction to apply the staged config parameter changes.
+ * This is a permission-less operation available when the effective epoch of the changes is reached.
+ *
+ * @type {Object} args - Arguments on instruction creation
+ * @param param {ValidatorBondsProgram} args.program - anchor program instance
+ * @param param {PublicKey} args.configAccount - config account with the staged changes (default: MARINADE config address)
+ * @type {Object} return - Return data of generated instruction
+ * @return {TransactionInstruction} return.instruction - Instruction to apply config changes
+ */
+export async function applyConfigChangesInstruction({
+  program,
+  configAccount = MARINADE_CONFIG_ADDRESS,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  const instruction = await program.methods
+    .applyConfigChanges()
+    .accounts({
+      config: configAccount,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,110 @@
+import {
+  PublicKey,
+  TransactionInstruction,
+  StakeProgram,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  SYSVAR_CLOCK_PUBKEY,
+  Keypair,
+  Signer,
+  SystemProgram,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram, bondsWithdrawerAuthority } from '../sdk'
+import { getBond, getConfig } from '../api'
+import { anchorProgramWalletPubkey, checkAndGetBondAddress } from '../utils'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import BN from 'bn.js'
+
+/**
+ * Generate instruction to charge the bond bid (cpmpe) for a finished epoch.
+ * Only operator authority is permitted to do this.
+ * The bid amount is split from the bond stake account
+ * and the split stake account is handed over to the config bid fee collector.
+ * A bid partially charged from a too small stake account can be charged
+ * again for the same epoch until the whole bid amount is charged.
+ */
+export async function chargeBidInstruction({
+  program,
+  stakeAccount,
+  epoch,
+  delegatedLamports,
+  configAccount,
+  bondAccount,
+  voteAccount,
+  operatorAuthority,
+  bidFeeCollector,
+  splitStakeAccount = Keypair.generate(),
+  splitStakeRentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  stakeAccount: PublicKey
+  epoch: BN | number
+  delegatedLamports: BN | number
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  bidFeeCollector?: PublicKey
+  splitStakeAccount?: PublicKey | Keypair | Signer | WalletInterface // signer
+  splitStakeRentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+  splitStakeAccount: PublicKey | Keypair | Signer | WalletInterface
+}> {
+  bondAccount = checkAndGetBondAddress(
+    bondAccount,
+    configAccount,
+    voteAccount,
+    program.programId
+  )
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+
+  if (operatorAuthority === undefined || bidFeeCollector === undefined) {
+    const configData = await getConfig(program, configAccount)
+    operatorAuthority = operatorAuthority ?? configData.operatorAuthority
+    bidFeeCollector = bidFeeCollector ?? configData.bidFeeCollector
+  }
+  const operatorAuthorityPubkey =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+
+  const splitStakeAccountPubkey =
+    splitStakeAccount instanceof PublicKey
+      ? splitStakeAccount
+      : splitStakeAccount.publicKey
+  const splitStakeRentPayerPubkey =
+    splitStakeRentPayer instanceof PublicKey
+      ? splitStakeRentPayer
+      : splitStakeRentPayer.publicKey
+
+  const instruction = await program.methods
+    .chargeBid({
+      epoch: new BN(epoch),
+      delegatedLamports: new BN(delegatedLamports),
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      operatorAuthority: operatorAuthorityPubkey,
+      stakeAccount,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId
+      )[0],
+      bidFeeCollector,
+      splitStakeAccount: splitStakeAccountPubkey,
+      splitStakeRentPayer: splitStakeRentPayerPubkey,
+      systemProgram: SystemProgram.programId,
+      stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    splitStakeAccount,
+  }
+}
@@ -0,0 +1,140 @@
+import {
+  Keypair,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  Signer,
+  SystemProgram,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  collateralVaultAddress,
+  settlementClaimAddress,
+  settlementCollateralAddress,
+  settlementStakerAuthority,
+} from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+import BN from 'bn.js'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+import { getBond, getSettlement } from '../api'
+import { MerkleTreeNode } from '../merkleTree'
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from 'solana-spl-token-modern'
+
+/**
+ * Generate instruction to claim settlement from the token collateral
+ * funded to the settlement. Permission-less operation. The legitimacy of the claim
+ * is verified against the merkle proof and the merkle root.
+ * The claim is paid in tokens to a token account owned by the stake account withdrawer
+ * of the merkle tree record, by default its associated token account.
+ * When the rent payer token account is provided the claimer fee configured
+ * in the config is paid in tokens to it and the rest goes to the claimer.
+ */
+export async function claimSettlementCollateralInstruction({
+  program,
+  settlementAccount,
+  mint,
+  claimAmount,
+  merkleProof,
+  stakeAccountStaker,
+  stakeAccountWithdrawer,
+  claimerTokenAccount,
+  configAccount,
+  bondAccount,
+  rentPayer = anchorProgramWalletPubkey(program),
+  rentPayerTokenAccount,
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  mint: PublicKey
+  claimAmount: number | BN
+  merkleProof: (number[] | Uint8Array | Buffer)[]
+  stakeAccountStaker: PublicKey
+  stakeAccountWithdrawer: PublicKey
+  claimerTokenAccount?: PublicKey
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayerTokenAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+  settlementClaimAccount: PublicKey
+  claimerTokenAccount: PublicKey
+}> {
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  claimerTokenAccount =
+    claimerTokenAccount ??
+    getAssociatedTokenAddressSync(mint, stakeAccountWithdrawer, true)
+
+  const merkleProofNumbers = merkleProof.map(proofPathRecord =>
+    Array.isArray(proofPathRecord)
+      ? proofPathRecord
+      : Array.from(proofPathRecord)
+  )
+  const [settlementClaimAccount] = settlementClaimAddress(
+    {
+      settlement: settlementAccount,
+      stakeAccountStaker,
+      stakeAccountWithdrawer,
+      claim: claimAmount,
+    },
+    program.programId
+  )
+  const treeNodeHash = MerkleTreeNode.hash({
+    stakeAuthority: stakeAccountStaker,
+    withdrawAuthority: stakeAccountWithdrawer,
+    claim: claimAmount,
+  }).words
+
+  const instruction = await program.methods
+    .claimSettlementCollateral({
+      proof: merkleProofNumbers,
+      treeNodeHash,
+      claim: new BN(claimAmount),
+      stakeAccountStaker,
+      stakeAccountWithdrawer,
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      settlementClaim: settlementClaimAccount,
+      settlementCollateral: settlementCollateralAddress(
+        settlementAccount,
+        mint,
+        program.programId
+      )[0],
+      mint,
+      settlementStakerAuthority: settlementStakerAuthority(
+        settlementAccount,
+        program.programId
+      )[0],
+      settlementCollateralVault: collateralVaultAddress(
+        settlementAccount,
+        mint,
+        program.programId
+      )[0],
+      claimerTokenAccount,
+      rentPayer,
+      rentPayerTokenAccount: rentPayerTokenAccount ?? null,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .instruction()
+  return {
+    instruction,
+    settlementClaimAccount,
+    claimerTokenAccount,
+  }
+}
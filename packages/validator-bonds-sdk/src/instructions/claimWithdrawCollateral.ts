@@ -0,0 +1,122 @@
+import {
+  Keypair,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  bondAddress,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+  collateralVaultAddress,
+  withdrawRequestAddress,
+} from '../sdk'
+import { getBond, getWithdrawRequest } from '../api'
+import { anchorProgramWalletPubkey } from '../utils'
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from 'solana-spl-token-modern'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to withdraw the bond token collateral
+ * based on the withdraw request.
+ * Only bond authority or validator identity of vote account voter pubkey can do this.
+ * The tokens are valued at the collateral mint price and accounted
+ * to the withdraw request amount the same way as the claimed stake accounts.
+ *
+ * Default withdrawer token account is the associated token account of the authority.
+ */
+export async function claimWithdrawCollateralInstruction({
+  program,
+  mint,
+  withdrawRequestAccount,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  authority = anchorProgramWalletPubkey(program),
+  withdrawerTokenAccount,
+}: {
+  program: ValidatorBondsProgram
+  mint: PublicKey
+  withdrawRequestAccount?: PublicKey
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  withdrawerTokenAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+  withdrawRequestAccount: PublicKey
+  withdrawerTokenAccount: PublicKey
+}> {
+  if (
+    configAccount !== undefined &&
+    voteAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    ;[bondAccount] = bondAddress(configAccount, voteAccount, program.programId)
+  }
+  if (bondAccount === undefined && withdrawRequestAccount !== undefined) {
+    const withdrawRequestData = await getWithdrawRequest(
+      program,
+      withdrawRequestAccount
+    )
+    bondAccount = withdrawRequestData.bond
+  }
+  if (bondAccount === undefined) {
+    throw new Error(
+      'Either [bondAccount], [configAccount and voteAccount] or [withdrawRequestAccount] is required'
+    )
+  }
+  if (configAccount === undefined || voteAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = configAccount ?? bondData.config
+    voteAccount = voteAccount ?? bondData.voteAccount
+  }
+  withdrawRequestAccount =
+    withdrawRequestAccount ??
+    withdrawRequestAddress(bondAccount, program.programId)[0]
+
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+  withdrawerTokenAccount =
+    withdrawerTokenAccount ??
+    getAssociatedTokenAddressSync(mint, authority, true)
+
+  const instruction = await program.methods
+    .claimWithdrawCollateral()
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      authority,
+      withdrawRequest: withdrawRequestAccount,
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId
+      )[0],
+      mint,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId
+      )[0],
+      collateralVault: collateralVaultAddress(
+        bondAccount,
+        mint,
+        program.programId
+      )[0],
+      withdrawerTokenAccount,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .instruction()
+  return {
+    instruction,
+    withdrawRequestAccount,
+    withdrawerTokenAccount,
+  }
+}
@@ -26,6 +26,9 @@ import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
  * from the withdraw request creation.
  *
  * Default withdrawer is validator identity pubkey of the vote account.
+ *
+ * Additional bond stake accounts (`mergeStakeAccounts`) are merged to the stake account
+ * before the withdrawal is processed, all within the one instruction.
  */
 export async function claimWithdrawRequestInstruction({
   program,
@@ -37,6 +40,7 @@ export async function claimWithdrawRequestInstruction({
   authority = anchorProgramWalletPubkey(program),
   splitStakeRentPayer = anchorProgramWalletPubkey(program),
   withdrawer,
+  mergeStakeAccounts = [],
 }: {
   program: ValidatorBondsProgram
   withdrawRequestAccount?: PublicKey
@@ -47,6 +51,7 @@ export async function claimWithdrawRequestInstruction({
   authority?: PublicKey | Keypair | Signer | WalletInterface // signer
   splitStakeRentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
   withdrawer?: PublicKey
+  mergeStakeAccounts?: PublicKey[]
 }): Promise<{
   instruction: TransactionInstruction
   splitStakeAccount: Keypair
@@ -140,6 +145,13 @@ export async function claimWithdrawRequestInstruction({
       stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
       stakeProgram: StakeProgram.programId,
     })
+    .remainingAccounts(
+      mergeStakeAccounts.map(pubkey => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      }))
+    )
     .instruction()
   return {
     instruction,
@@ -0,0 +1,65 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram } from '../sdk'
+import { checkAndGetBondAddress, anchorProgramWalletPubkey } from '../utils'
+import { getBond } from '../api'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to switch the bond mint token to work as a transferable
+ * bond authority credential. When enabled the holder of the bond mint token
+ * (minted for the current validator identity) may configure the bond
+ * without burning the token.
+ * Signature of validator identity of vote account voter pubkey OR bond authority is required.
+ */
+export async function configureBondAuthorityMintInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  authority = anchorProgramWalletPubkey(program),
+  enabled,
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  enabled: boolean
+}): Promise<{
+  bondAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress(
+    bondAccount,
+    configAccount,
+    voteAccount,
+    program.programId
+  )
+  if (configAccount === undefined || voteAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = configAccount ?? bondData.config
+    voteAccount = voteAccount ?? bondData.voteAccount
+  }
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+
+  const instruction = await program.methods
+    .configureBondAuthorityMint({
+      enabled,
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      authority,
+      voteAccount,
+    })
+    .instruction()
+  return {
+    bondAccount,
+    instruction,
+  }
+}
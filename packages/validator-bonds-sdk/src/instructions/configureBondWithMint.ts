@@ -15,6 +15,11 @@ import { getVoteAccount } from '@marinade.finance/web3js-common'
 /**
  * Generate instruction to configure bond account with ownership of bond minted token.
  * Owner of the token has to sign the transaction and the token is burnt.
+ * When the bond mint is configured as the bond authority mint
+ * (see configureBondAuthorityMintInstruction) the token is only required
+ * to be held.
+ * The bond authority mint persists over the validator identity change,
+ * then the validator identity the mint was created for has to be provided.
  */
 export async function configureBondWithMintInstruction({
   program,
@@ -0,0 +1,87 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram, collateralMintAddress } from '../sdk'
+import { getCollateralMint, getConfig } from '../api'
+import BN from 'bn.js'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to configure the price and the haircut
+ * of the whitelisted collateral mint. Admin only operation.
+ * Either [collateralMintAccount] or [configAccount and mint] is required.
+ */
+export async function configureCollateralMintInstruction({
+  program,
+  collateralMintAccount,
+  configAccount,
+  mint,
+  adminAuthority,
+  newLamportsPerToken,
+  newHaircutBps,
+}: {
+  program: ValidatorBondsProgram
+  collateralMintAccount?: PublicKey
+  configAccount?: PublicKey
+  mint?: PublicKey
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  newLamportsPerToken?: BN | number
+  newHaircutBps?: number
+}): Promise<{
+  instruction: TransactionInstruction
+  collateralMintAccount: PublicKey
+}> {
+  if (
+    collateralMintAccount === undefined &&
+    configAccount !== undefined &&
+    mint !== undefined
+  ) {
+    ;[collateralMintAccount] = collateralMintAddress(
+      configAccount,
+      mint,
+      program.programId
+    )
+  }
+  if (collateralMintAccount === undefined) {
+    throw new Error(
+      'Either [collateralMintAccount] or [configAccount and mint] is required'
+    )
+  }
+  if (configAccount === undefined) {
+    const collateralMintData = await getCollateralMint(
+      program,
+      collateralMintAccount
+    )
+    configAccount = collateralMintData.config
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+
+  const instruction = await program.methods
+    .configureCollateralMint({
+      lamportsPerToken:
+        newLamportsPerToken === undefined
+          ? null
+          : new BN(newLamportsPerToken),
+      haircutBps: newHaircutBps ?? null,
+    })
+    .accounts({
+      config: configAccount,
+      adminAuthority,
+      collateralMint: collateralMintAccount,
+    })
+    .instruction()
+  return {
+    instruction,
+    collateralMintAccount,
+  }
+}
@@ -15,18 +15,31 @@ import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 
 /**
  * Generate instruction to configure config account. Available for admin authority.
+ * New admin, operator and pause authorities are proposed and have to be accepted (see `acceptConfigAuthorityInstruction`).
+ * Parameter changes are staged for `configChangeDelayEpochs` epochs when the delay is configured (see `applyConfigChangesInstruction`).
  *
  * @type {Object} args - Arguments on instruction creation
  * @param param {ValidatorBondsProgram} args.program - anchor program instance
  * @param param {PublicKey} args.configAccount - config account to configure (default: MARINADE config address)
  * @param param {PublicKey} args.adminAuthority [SIGNER] - admin authority (default: provider wallet address)
- * @param param {PublicKey} args.newAdmin - admin authority that will be set when field is used
- * @param param {PublicKey} args.newOperator - operator authority that will be set when field is used
- * @param param {PublicKey} args.newPauseAuthority - pause authority that will be set when field is used
+ * @param param {PublicKey} args.newAdmin - admin authority that will be proposed when field is used
+ * @param param {PublicKey} args.newOperator - operator authority that will be proposed when field is used
+ * @param param {PublicKey} args.newPauseAuthority - pause authority that will be proposed when field is used
+ * @param param {PublicKey} args.newSettlementCreator - settlement creator role that will be set when field is used (default pubkey falls back to operator)
+ * @param param {PublicKey} args.newSettlementFunder - settlement funder role that will be set when field is used (default pubkey falls back to operator)
+ * @param param {PublicKey} args.newSettlementCloser - settlement closer role that will be set when field is used (default pubkey falls back to operator)
+ * @param param {PublicKey} args.newBidFeeCollector - account receiving the charged bids that will be set when field is used
+ * @param param {BN|number} args.newBidMaxDelegatedLamports - upper bound of delegated lamports the bid is charged for that will be set when field is used
+ * @param param {number} args.newMaxClaimExpiryExtension - maximum number of epochs a settlement claiming can be extended that will be set when field is used
  * @param param {PublicKey} args.newEpochsToClaimSettlement - number of epochs before settlement claiming timeouts that will be set when field is used
  * @param param {PublicKey} args.newSlotsToStartSettlementClaiming - number of slots that has to expire until when settlement can be claimed
  * @param param {PublicKey} args.newWithdrawLockupEpochs - number of epochs after which withdraw can be executed that will be set when field is used
  * @param param {PublicKey} args.newMinimumStakeLamports - number of lamports as minimum stake account size that will be set when field is used
+ * @param param {BN|number} args.newMinBondMaxStakeWanted - minimal value of max stake wanted the bond can be configured with
+ * @param param {BN|number} args.newConfigChangeDelayEpochs - number of epochs the parameter changes are staged before they can be applied
+ * @param param {BN|number} args.newClaimerFeeLamports - fee paid to a permission-less claimer of a settlement
+ * @param param {number} args.newClaimerFeeMaxBps - maximum of the claimer fee in basis points of the claimed amount
+ * @param param {boolean} args.cancelPendingChanges - drops all pending authority and parameter changes before the new ones are processed (default: false)
  * @type {Object} return - Return data of generated instruction
  * @return {TransactionInstruction} return.instruction - Instruction to configure config
  */
@@ -42,6 +55,16 @@ export async function configureConfigInstruction({
   newWithdrawLockupEpochs,
   newMinimumStakeLamports,
   newMinBondMaxStakeWanted,
+  newSettlementCreator,
+  newSettlementFunder,
+  newSettlementCloser,
+  newBidFeeCollector,
+  newBidMaxDelegatedLamports,
+  newMaxClaimExpiryExtension,
+  newConfigChangeDelayEpochs,
+  newClaimerFeeLamports,
+  newClaimerFeeMaxBps,
+  cancelPendingChanges = false,
 }: {
   program: ValidatorBondsProgram
   configAccount?: PublicKey
@@ -54,6 +77,16 @@ export async function configureConfigInstruction({
   newWithdrawLockupEpochs?: BN | number
   newMinimumStakeLamports?: BN | number
   newMinBondMaxStakeWanted?: BN | number
+  newSettlementCreator?: PublicKey
+  newSettlementFunder?: PublicKey
+  newSettlementCloser?: PublicKey
+  newBidFeeCollector?: PublicKey
+  newBidMaxDelegatedLamports?: BN | number
+  newMaxClaimExpiryExtension?: number
+  newConfigChangeDelayEpochs?: BN | number
+  newClaimerFeeLamports?: BN | number
+  newClaimerFeeMaxBps?: number
+  cancelPendingChanges?: boolean
 }): Promise<{
   instruction: TransactionInstruction
 }> {
@@ -70,6 +103,15 @@ export async function configureConfigInstruction({
     admin: newAdmin ?? null,
     operator: newOperator ?? null,
     pauseAuthority: newPauseAuthority ?? null,
+    settlementCreator: newSettlementCreator ?? null,
+    settlementFunder: newSettlementFunder ?? null,
+    settlementCloser: newSettlementCloser ?? null,
+    bidFeeCollector: newBidFeeCollector ?? null,
+    bidMaxDelegatedLamports:
+      newBidMaxDelegatedLamports !== undefined
+        ? new BN(newBidMaxDelegatedLamports)
+        : null,
+    maxClaimExpiryExtension: newMaxClaimExpiryExtension ?? null,
     epochsToClaimSettlement: newEpochsToClaimSettlement
       ? new BN(newEpochsToClaimSettlement)
       : null,
@@ -85,9 +127,22 @@ export async function configureConfigInstruction({
     minBondMaxStakeWanted: newMinBondMaxStakeWanted
       ? new BN(newMinBondMaxStakeWanted)
       : null,
+    configChangeDelayEpochs:
+      newConfigChangeDelayEpochs !== undefined
+        ? new BN(newConfigChangeDelayEpochs)
+        : null,
+    claimerFeeLamports:
+      newClaimerFeeLamports !== undefined
+        ? new BN(newClaimerFeeLamports)
+        : null,
+    claimerFeeMaxBps: newClaimerFeeMaxBps ?? null,
+    cancelPendingChanges,
   }
 
-  if (Object.values(args).every(v => v === null)) {
+  if (
+    !cancelPendingChanges &&
+    Object.values(args).every(v => v === null || v === false)
+  ) {
     throw new Error('No new config values provided')
   }
 
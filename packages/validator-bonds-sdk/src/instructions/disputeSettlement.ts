@@ -0,0 +1,68 @@
+import {
+  Keypair,
+  PublicKey,
+  SYSVAR_CLOCK_PUBKEY,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram } from '../sdk'
+import { getBond, getSettlement } from '../api'
+import { anchorProgramWalletPubkey } from '../utils'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to dispute the settlement.
+ * Permitted to the bond authority or the validator identity.
+ * Possible only before the settlement claiming starts,
+ * the claiming is then blocked until the admin resolves the dispute
+ * or until the operator amends the settlement.
+ * The reason hash is expected to be a hash of the dispute reason
+ * published off-chain, the empty (all zero) hash is rejected.
+ */
+export async function disputeSettlementInstruction({
+  program,
+  settlementAccount,
+  reasonHash,
+  configAccount,
+  bondAccount,
+  voteAccount,
+  authority = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  reasonHash: number[] | Uint8Array | Buffer
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined || voteAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = configAccount ?? bondData.config
+    voteAccount = voteAccount ?? bondData.voteAccount
+  }
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+
+  const instruction = await program.methods
+    .disputeSettlement({
+      reasonHash: Array.from(reasonHash),
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      voteAccount,
+      settlement: settlementAccount,
+      authority,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
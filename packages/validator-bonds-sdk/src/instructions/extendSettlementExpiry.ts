@@ -0,0 +1,64 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram } from '../sdk'
+import { getBond, getConfig, getSettlement } from '../api'
+import { operatorRoleAuthority } from '../utils'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to extend the claiming period of a single settlement
+ * by number of epochs beyond the config `epochsToClaimSettlement`.
+ * Permitted to the operator authority, the settlement creator role
+ * when it is configured, or to the admin authority.
+ * The total extension is limited by the config `maxClaimExpiryExtension`.
+ */
+export async function extendSettlementExpiryInstruction({
+  program,
+  settlementAccount,
+  epochs,
+  configAccount,
+  bondAccount,
+  authority,
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  epochs: number
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  if (authority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    authority = operatorRoleAuthority(configData, 'settlementCreatorAuthority')
+  }
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+
+  const instruction = await program.methods
+    .extendSettlementExpiry({
+      epochs,
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      authority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
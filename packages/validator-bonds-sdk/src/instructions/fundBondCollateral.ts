@@ -0,0 +1,110 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  ValidatorBondsProgram,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+  collateralVaultAddress,
+} from '../sdk'
+import { checkAndGetBondAddress, anchorProgramWalletPubkey } from '../utils'
+import { getBond } from '../api'
+import BN from 'bn.js'
+import {
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from 'solana-spl-token-modern'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to fund bond with tokens of a whitelisted collateral mint.
+ * Permission-less operation, signature of the token account owner is required.
+ * The tokens are deposited to the bond collateral vault
+ * and the bond is credited with their value lowered by the collateral mint haircut.
+ * Default token account is the associated token account of the token authority.
+ */
+export async function fundBondCollateralInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  mint,
+  amount,
+  tokenAccount,
+  tokenAuthority = anchorProgramWalletPubkey(program),
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  mint: PublicKey
+  amount: BN | number
+  tokenAccount?: PublicKey
+  tokenAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+  bondAccount: PublicKey
+  collateralVault: PublicKey
+}> {
+  if (!bondAccount && !configAccount && voteAccount) {
+    configAccount = MARINADE_CONFIG_ADDRESS
+  }
+  bondAccount = checkAndGetBondAddress(
+    bondAccount,
+    configAccount,
+    voteAccount,
+    program.programId
+  )
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  tokenAuthority =
+    tokenAuthority instanceof PublicKey
+      ? tokenAuthority
+      : tokenAuthority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  tokenAccount =
+    tokenAccount ?? getAssociatedTokenAddressSync(mint, tokenAuthority, true)
+
+  const [collateralVault] = collateralVaultAddress(
+    bondAccount,
+    mint,
+    program.programId
+  )
+  const instruction = await program.methods
+    .fundBondCollateral({ amount: new BN(amount) })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId
+      )[0],
+      mint,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId
+      )[0],
+      collateralVault,
+      tokenAccount,
+      tokenAuthority,
+      rentPayer,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    bondAccount,
+    collateralVault,
+  }
+}
@@ -11,12 +11,13 @@ import {
 } from '@solana/web3.js'
 import { ValidatorBondsProgram, bondAddress } from '../sdk'
 import { getBond, getConfig, getSettlement } from '../api'
-import { anchorProgramWalletPubkey } from '../utils'
+import { anchorProgramWalletPubkey, operatorRoleAuthority } from '../utils'
 import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 
 /**
  * Generate instruction to fund settlement protected event.
- * Only operator authority is permitted to do this.
+ * Only operator authority is permitted to do this,
+ * the settlement funder role when it is configured.
  * Depositing the funded bond stake accounts to the settlement account.
  * The stake account lamports covers the protected event
  * and funds can be claimed from the accounts later.
@@ -64,7 +65,10 @@ export async function fundSettlementInstruction({
 
   if (operatorAuthority === undefined) {
     const configData = await getConfig(program, configAccount)
-    operatorAuthority = configData.operatorAuthority
+    operatorAuthority = operatorRoleAuthority(
+      configData,
+      'settlementFunderAuthority'
+    )
   }
   const operatorAuthorityPubkey =
     operatorAuthority instanceof PublicKey
@@ -0,0 +1,127 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  bondAddress,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+  collateralVaultAddress,
+  settlementCollateralAddress,
+  settlementStakerAuthority,
+} from '../sdk'
+import { getBond, getConfig, getSettlement } from '../api'
+import { anchorProgramWalletPubkey, operatorRoleAuthority } from '../utils'
+import { TOKEN_PROGRAM_ID } from 'solana-spl-token-modern'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to fund settlement from the bond token collateral.
+ * Only operator authority is permitted to do this,
+ * the settlement funder role when it is configured.
+ * Used when the bond stake accounts are insufficient to cover the settlement,
+ * the collateral is credited to the settlement with its haircut value
+ * and the tokens are moved to the settlement collateral vault.
+ */
+export async function fundSettlementCollateralInstruction({
+  program,
+  settlementAccount,
+  mint,
+  configAccount,
+  bondAccount,
+  voteAccount,
+  operatorAuthority,
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  mint: PublicKey
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+  settlementCollateralVault: PublicKey
+}> {
+  if (
+    voteAccount !== undefined &&
+    configAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    ;[bondAccount] = bondAddress(configAccount, voteAccount, program.programId)
+  }
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+
+  if (operatorAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    operatorAuthority = operatorRoleAuthority(
+      configData,
+      'settlementFunderAuthority'
+    )
+  }
+  operatorAuthority =
+    operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+
+  const [settlementCollateralVault] = collateralVaultAddress(
+    settlementAccount,
+    mint,
+    program.programId
+  )
+  const instruction = await program.methods
+    .fundSettlementCollateral()
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      operatorAuthority,
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId
+      )[0],
+      settlementCollateral: settlementCollateralAddress(
+        settlementAccount,
+        mint,
+        program.programId
+      )[0],
+      mint,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId
+      )[0],
+      collateralVault: collateralVaultAddress(
+        bondAccount,
+        mint,
+        program.programId
+      )[0],
+      settlementStakerAuthority: settlementStakerAuthority(
+        settlementAccount,
+        program.programId
+      )[0],
+      settlementCollateralVault,
+      rentPayer,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    settlementCollateralVault,
+  }
+}
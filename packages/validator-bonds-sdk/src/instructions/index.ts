@@ -1,20 +1,39 @@
 export * from './cancelWithdrawRequest'
 export * from './configureConfig'
+export * from './acceptConfigAuthority'
+export * from './applyConfigChanges'
 export * from './configureBond'
 export * from './configureBondWithMint'
+export * from './configureBondAuthorityMint'
 export * from './fundBond'
 export * from './initBond'
 export * from './mintBond'
+export * from './chargeBid'
+export * from './migrateBond'
+export * from './migrateAccount'
 export * from './initConfig'
 export * from './initWithdrawRequest'
 export * from './mergeStake'
 export * from './resetStake'
+export * from './redelegateStake'
 export * from './withdrawStake'
 export * from './initSettlement'
+export * from './amendSettlement'
+export * from './disputeSettlement'
+export * from './resolveDispute'
+export * from './extendSettlementExpiry'
 export * from './closeSettlement'
 export * from './cancelSettlement'
 export * from './fundSettlement'
 export * from './claimSettlement'
+export * from './initCollateralMint'
+export * from './configureCollateralMint'
+export * from './fundBondCollateral'
+export * from './claimWithdrawCollateral'
+export * from './fundSettlementCollateral'
+export * from './claimSettlementCollateral'
+export * from './resetSettlementCollateral'
 export * from './closeSettlementClaim'
 export * from './emergencyPause'
 export * from './emergencyResume'
+export * from './setPauseFlags'
@@ -8,6 +8,7 @@ import {
   MARINADE_CONFIG_ADDRESS,
   ValidatorBondsProgram,
   bondAddress,
+  bondAliasAddress,
 } from '../sdk'
 import { anchorProgramWalletPubkey } from '../utils'
 import BN from 'bn.js'
@@ -64,6 +65,11 @@ export async function initBondInstruction({
     .accounts({
       config: configAccount,
       bond: bondAccount,
+      bondAlias: bondAliasAddress(
+        configAccount,
+        voteAccount,
+        program.programId
+      )[0],
       voteAccount,
       validatorIdentity: validatorIdentity ?? null,
       rentPayer: renPayerPubkey,
@@ -0,0 +1,76 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import {
+  MARINADE_CONFIG_ADDRESS,
+  ValidatorBondsProgram,
+  collateralMintAddress,
+} from '../sdk'
+import { getConfig } from '../api'
+import { anchorProgramWalletPubkey } from '../utils'
+import BN from 'bn.js'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to whitelist an SPL token mint (e.g., liquid staking token)
+ * to be used as the bond collateral. Admin only operation.
+ * The collateral is valued at the lamports per token price lowered by the haircut.
+ */
+export async function initCollateralMintInstruction({
+  program,
+  configAccount = MARINADE_CONFIG_ADDRESS,
+  mint,
+  lamportsPerToken,
+  haircutBps,
+  adminAuthority,
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  mint: PublicKey
+  lamportsPerToken: BN | number
+  haircutBps: number
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+  collateralMintAccount: PublicKey
+}> {
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+
+  const [collateralMintAccount] = collateralMintAddress(
+    configAccount,
+    mint,
+    program.programId
+  )
+  const instruction = await program.methods
+    .initCollateralMint({
+      lamportsPerToken: new BN(lamportsPerToken),
+      haircutBps,
+    })
+    .accounts({
+      config: configAccount,
+      adminAuthority,
+      mint,
+      collateralMint: collateralMintAccount,
+      rentPayer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+    collateralMintAccount,
+  }
+}
@@ -6,14 +6,15 @@ import {
   TransactionInstruction,
 } from '@solana/web3.js'
 import { ValidatorBondsProgram, bondAddress, settlementAddress } from '../sdk'
-import { anchorProgramWalletPubkey } from '../utils'
+import { anchorProgramWalletPubkey, operatorRoleAuthority } from '../utils'
 import BN from 'bn.js'
 import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 import { getBond, getConfig } from '../api'
 
 /**
  * Generate instruction to initialize settlement protected event.
- * Only operator authority is permitted to do this.
+ * Only operator authority is permitted to do this,
+ * the settlement creator role when it is configured.
  * This uploads merkle root and sets max total claim and max merkle nodes,
  * these information is used when claim is settled.
  */
@@ -71,7 +72,10 @@ export async function initSettlementInstruction({
 
   if (operatorAuthority === undefined) {
     const configData = await getConfig(program, configAccount)
-    operatorAuthority = configData.operatorAuthority
+    operatorAuthority = operatorRoleAuthority(
+      configData,
+      'settlementCreatorAuthority'
+    )
   }
   const operatorAuthorityPubkey =
     operatorAuthority instanceof PublicKey
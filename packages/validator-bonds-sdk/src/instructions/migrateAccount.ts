@@ -0,0 +1,44 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram } from '../sdk'
+import { anchorProgramWalletPubkey } from '../utils'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to migrate the program account to the current layout version.
+ * Bond, config, settlement, settlement claim and withdraw request accounts can be migrated.
+ * The instruction is permission-less, the rent payer covers the rent exempt difference
+ * when the account has to be reallocated to the bigger layout.
+ *
+ * @param {PublicKey} args.account - the program account to be migrated
+ */
+export async function migrateAccountInstruction({
+  program,
+  account,
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  account: PublicKey
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+
+  const instruction = await program.methods
+    .migrateAccount()
+    .accounts({
+      account,
+      rentPayer,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
@@ -0,0 +1,94 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  bondAddress,
+  bondAliasAddress,
+} from '../sdk'
+import {
+  anchorProgramWalletPubkey,
+  bondStakeVoteAccount,
+  checkAndGetBondAddress,
+} from '../utils'
+import { getBond } from '../api'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to migrate the bond to a new vote account.
+ * The bond address stays derived from the original vote account,
+ * the bond stake accounts are moved with `redelegateStakeInstruction` afterwards.
+ * Signature of validator identity or authorized withdrawer of both vote accounts is required.
+ * No bond may exist for the new vote account.
+ * The bond alias account is created for the new vote account (rent paid by the rent payer)
+ * and the alias of the vote account the bond was migrated to before is closed.
+ *
+ * @param {PublicKey} args.voteAccount - the original vote account the bond was created for
+ * @param {PublicKey} args.newVoteAccount - the vote account the bond is migrated to
+ */
+export async function migrateBondInstruction({
+  program,
+  bondAccount,
+  configAccount,
+  voteAccount,
+  newVoteAccount,
+  authority = anchorProgramWalletPubkey(program),
+  rentPayer = anchorProgramWalletPubkey(program),
+}: {
+  program: ValidatorBondsProgram
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+  newVoteAccount: PublicKey
+  authority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  rentPayer?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  bondAccount: PublicKey
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress(
+    bondAccount,
+    configAccount,
+    voteAccount,
+    program.programId
+  )
+  const bondData = await getBond(program, bondAccount)
+  authority = authority instanceof PublicKey ? authority : authority.publicKey
+  rentPayer = rentPayer instanceof PublicKey ? rentPayer : rentPayer.publicKey
+  const oldBondAlias = bondData.migratedVoteAccount.equals(PublicKey.default)
+    ? null
+    : bondAliasAddress(
+        bondData.config,
+        bondData.migratedVoteAccount,
+        program.programId
+      )[0]
+  const newBondAlias = newVoteAccount.equals(bondData.voteAccount)
+    ? null
+    : bondAliasAddress(bondData.config, newVoteAccount, program.programId)[0]
+
+  const instruction = await program.methods
+    .migrateBond()
+    .accounts({
+      config: bondData.config,
+      bond: bondAccount,
+      voteAccount: bondStakeVoteAccount(bondData),
+      newVoteAccount,
+      newVoteAccountBond: bondAddress(
+        bondData.config,
+        newVoteAccount,
+        program.programId
+      )[0],
+      oldBondAlias,
+      newBondAlias,
+      authority,
+      rentPayer,
+    })
+    .instruction()
+  return {
+    bondAccount,
+    instruction,
+  }
+}
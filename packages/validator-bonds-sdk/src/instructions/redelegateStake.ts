@@ -0,0 +1,64 @@
+import {
+  PublicKey,
+  STAKE_CONFIG_ID,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_STAKE_HISTORY_PUBKEY,
+  StakeProgram,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram, bondsWithdrawerAuthority } from '../sdk'
+import { bondStakeVoteAccount, checkAndGetBondAddress } from '../utils'
+import { getBond } from '../api'
+
+/**
+ * Generate instruction to move a bond stake account to the vote account the bond was migrated to.
+ * This is a permission-less operation.
+ * The active stake account is deactivated by the first call,
+ * the deactivated stake account is delegated to the new vote account by the second call.
+ *
+ * @param {PublicKey} args.voteAccount - the original vote account the bond was created for
+ */
+export async function redelegateStakeInstruction({
+  program,
+  stakeAccount,
+  bondAccount,
+  configAccount,
+  voteAccount,
+}: {
+  program: ValidatorBondsProgram
+  stakeAccount: PublicKey
+  bondAccount?: PublicKey
+  configAccount?: PublicKey
+  voteAccount?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  bondAccount = checkAndGetBondAddress(
+    bondAccount,
+    configAccount,
+    voteAccount,
+    program.programId
+  )
+  const bondData = await getBond(program, bondAccount)
+
+  const instruction = await program.methods
+    .redelegateStake()
+    .accounts({
+      config: bondData.config,
+      bond: bondAccount,
+      stakeAccount,
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        bondData.config,
+        program.programId
+      )[0],
+      voteAccount: bondStakeVoteAccount(bondData),
+      stakeHistory: SYSVAR_STAKE_HISTORY_PUBKEY,
+      stakeConfig: STAKE_CONFIG_ID,
+      clock: SYSVAR_CLOCK_PUBKEY,
+      stakeProgram: StakeProgram.programId,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
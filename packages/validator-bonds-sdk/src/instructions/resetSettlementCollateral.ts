@@ -0,0 +1,126 @@
+import { EpochInfo, PublicKey, TransactionInstruction } from '@solana/web3.js'
+import {
+  ValidatorBondsProgram,
+  bondAddress,
+  bondsWithdrawerAuthority,
+  collateralMintAddress,
+  collateralVaultAddress,
+  settlementAddress,
+  settlementCollateralAddress,
+  settlementStakerAuthority,
+} from '../sdk'
+import { getBond, getConfig } from '../api'
+import { operatorRoleAuthority } from '../utils'
+import BN from 'bn.js'
+import { TOKEN_PROGRAM_ID } from 'solana-spl-token-modern'
+
+/**
+ * Generate instruction to return the unclaimed token collateral
+ * of a closed settlement back to the bond collateral vault.
+ * Permission-less operation. The closed settlement is identified
+ * by its merkle root and epoch, the rent of the settlement collateral vault
+ * is returned to the settlement funder.
+ */
+export async function resetSettlementCollateralInstruction({
+  program,
+  mint,
+  settlementMerkleRoot,
+  settlementEpoch,
+  configAccount,
+  bondAccount,
+  voteAccount,
+  rentCollector,
+}: {
+  program: ValidatorBondsProgram
+  mint: PublicKey
+  settlementMerkleRoot: number[] | Uint8Array | Buffer
+  settlementEpoch: number | BN | EpochInfo
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  voteAccount?: PublicKey
+  rentCollector?: PublicKey
+}): Promise<{
+  instruction: TransactionInstruction
+  settlementAccount: PublicKey
+}> {
+  if (
+    voteAccount !== undefined &&
+    configAccount !== undefined &&
+    bondAccount === undefined
+  ) {
+    ;[bondAccount] = bondAddress(configAccount, voteAccount, program.programId)
+  }
+  if (bondAccount === undefined) {
+    throw new Error(
+      'Either [bondAccount] or [voteAccount and configAccount] is required'
+    )
+  }
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  if (rentCollector === undefined) {
+    const configData = await getConfig(program, configAccount)
+    rentCollector = operatorRoleAuthority(
+      configData,
+      'settlementFunderAuthority'
+    )
+  }
+
+  const [settlementAccount] = settlementAddress(
+    bondAccount,
+    settlementMerkleRoot,
+    settlementEpoch,
+    program.programId
+  )
+  const epochCreatedFor =
+    typeof settlementEpoch === 'number' || settlementEpoch instanceof BN
+      ? new BN(settlementEpoch)
+      : new BN(settlementEpoch.epoch)
+  const instruction = await program.methods
+    .resetSettlementCollateral({
+      merkleRoot: Array.from(settlementMerkleRoot),
+      epochCreatedFor,
+    })
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      collateralMint: collateralMintAddress(
+        configAccount,
+        mint,
+        program.programId
+      )[0],
+      settlementCollateral: settlementCollateralAddress(
+        settlementAccount,
+        mint,
+        program.programId
+      )[0],
+      mint,
+      settlementStakerAuthority: settlementStakerAuthority(
+        settlementAccount,
+        program.programId
+      )[0],
+      settlementCollateralVault: collateralVaultAddress(
+        settlementAccount,
+        mint,
+        program.programId
+      )[0],
+      bondsWithdrawerAuthority: bondsWithdrawerAuthority(
+        configAccount,
+        program.programId
+      )[0],
+      collateralVault: collateralVaultAddress(
+        bondAccount,
+        mint,
+        program.programId
+      )[0],
+      rentCollector,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .instruction()
+  return {
+    instruction,
+    settlementAccount,
+  }
+}
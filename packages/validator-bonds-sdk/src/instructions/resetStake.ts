@@ -1,5 +1,7 @@
 import {
+  Keypair,
   PublicKey,
+  Signer,
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_STAKE_HISTORY_PUBKEY,
   StakeProgram,
@@ -10,12 +12,14 @@ import { ValidatorBondsProgram } from '../sdk'
 import { checkAndGetBondAddress } from '../utils'
 import { getBond } from '../api'
 import { getStakeAccount } from '../web3.js/index'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 
 /**
  * Generate instruction to reset stake from closed settlement.
  * This is a permission-less operation.
  * When settlement was closed this brings the stake accounts back
  * to state them being funded to bonds program.
+ * When the settlement closer role is configured, the role authority signs.
  */
 export async function resetStakeInstruction({
   program,
@@ -24,6 +28,7 @@ export async function resetStakeInstruction({
   bondAccount,
   configAccount,
   voteAccount,
+  operatorAuthority,
 }: {
   program: ValidatorBondsProgram
   stakeAccount: PublicKey
@@ -31,6 +36,7 @@ export async function resetStakeInstruction({
   bondAccount?: PublicKey
   configAccount?: PublicKey
   voteAccount?: PublicKey
+  operatorAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
 }): Promise<{
   instruction: TransactionInstruction
 }> {
@@ -53,6 +59,10 @@ export async function resetStakeInstruction({
     const bondData = await getBond(program, bondAccount)
     configAccount = bondData.config
   }
+  const operatorAuthorityPubkey =
+    operatorAuthority === undefined || operatorAuthority instanceof PublicKey
+      ? operatorAuthority
+      : operatorAuthority.publicKey
 
   const instruction = await program.methods
     .resetStake()
@@ -66,6 +76,7 @@ export async function resetStakeInstruction({
       stakeConfig: STAKE_CONFIG_ID,
       clock: SYSVAR_CLOCK_PUBKEY,
       stakeProgram: StakeProgram.programId,
+      operatorAuthority: operatorAuthorityPubkey ?? null,
     })
     .instruction()
   return {
@@ -0,0 +1,61 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { ValidatorBondsProgram } from '../sdk'
+import { getBond, getConfig, getSettlement } from '../api'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to resolve the dispute of the settlement.
+ * Only admin authority is permitted to do this.
+ * When the dispute is justified, the settlement is expected to be amended
+ * or cancelled before the dispute is resolved.
+ */
+export async function resolveDisputeInstruction({
+  program,
+  settlementAccount,
+  configAccount,
+  bondAccount,
+  adminAuthority,
+}: {
+  program: ValidatorBondsProgram
+  settlementAccount: PublicKey
+  configAccount?: PublicKey
+  bondAccount?: PublicKey
+  adminAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (bondAccount === undefined) {
+    const settlementData = await getSettlement(program, settlementAccount)
+    bondAccount = settlementData.bond
+  }
+  if (configAccount === undefined) {
+    const bondData = await getBond(program, bondAccount)
+    configAccount = bondData.config
+  }
+  if (adminAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    adminAuthority = configData.adminAuthority
+  }
+  adminAuthority =
+    adminAuthority instanceof PublicKey
+      ? adminAuthority
+      : adminAuthority.publicKey
+
+  const instruction = await program.methods
+    .resolveDispute()
+    .accounts({
+      config: configAccount,
+      bond: bondAccount,
+      settlement: settlementAccount,
+      adminAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
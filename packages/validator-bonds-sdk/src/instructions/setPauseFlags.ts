@@ -0,0 +1,48 @@
+import {
+  Keypair,
+  PublicKey,
+  Signer,
+  TransactionInstruction,
+} from '@solana/web3.js'
+import { MARINADE_CONFIG_ADDRESS, ValidatorBondsProgram } from '../sdk'
+import { getConfig } from '../api'
+import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
+
+/**
+ * Generate instruction to pause and resume the instruction families independently.
+ * Pause authority only operation.
+ * @param {number} args.pauseFlags - bitflag set of the paused instruction families (see PAUSE_FLAGS), 0 resumes all of them
+ */
+export async function setPauseFlagsInstruction({
+  program,
+  configAccount = MARINADE_CONFIG_ADDRESS,
+  pauseAuthority,
+  pauseFlags,
+}: {
+  program: ValidatorBondsProgram
+  configAccount?: PublicKey
+  pauseAuthority?: PublicKey | Keypair | Signer | WalletInterface // signer
+  pauseFlags: number
+}): Promise<{
+  instruction: TransactionInstruction
+}> {
+  if (pauseAuthority === undefined) {
+    const configData = await getConfig(program, configAccount)
+    pauseAuthority = configData.pauseAuthority
+  }
+  pauseAuthority =
+    pauseAuthority instanceof PublicKey
+      ? pauseAuthority
+      : pauseAuthority.publicKey
+
+  const instruction = await program.methods
+    .setPauseFlags({ pauseFlags })
+    .accounts({
+      config: configAccount,
+      pauseAuthority,
+    })
+    .instruction()
+  return {
+    instruction,
+  }
+}
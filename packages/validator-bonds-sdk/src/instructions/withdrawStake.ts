@@ -9,13 +9,15 @@ import {
 } from '@solana/web3.js'
 import { ValidatorBondsProgram } from '../sdk'
 import { getConfig } from '../api'
+import { operatorRoleAuthority } from '../utils'
 import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
 
 /**
  * Generate instruction to withdraw lamports from stake accounts
  * in `Initialized` state. Non-delegated initialized stake accounts
  * are considered as operator owned.
- * Only operator may call this operation.
+ * Only operator may call this operation,
+ * the settlement closer role when it is configured.
  */
 export async function withdrawStakeInstruction({
   program,
@@ -36,7 +38,10 @@ export async function withdrawStakeInstruction({
 }> {
   if (operatorAuthority === undefined) {
     const configData = await getConfig(program, configAccount)
-    operatorAuthority = configData.operatorAuthority
+    operatorAuthority = operatorRoleAuthority(
+      configData,
+      'settlementCloserAuthority'
+    )
   }
   operatorAuthority =
     operatorAuthority instanceof PublicKey
@@ -15,7 +15,6 @@ import { getBond, getWithdrawRequest } from '../api'
 import assert from 'assert'
 import { StakeAccountParsed, findStakeAccounts } from '../web3.js/stakeAccount'
 import BN from 'bn.js'
-import { mergeStakeInstruction } from '../instructions/mergeStake'
 import { claimWithdrawRequestInstruction } from '../instructions/claimWithdrawRequest'
 import { anchorProgramWalletPubkey } from '../utils'
 import { Wallet as WalletInterface } from '@coral-xyz/anchor/dist/cjs/provider'
@@ -129,8 +128,10 @@ export async function orchestrateWithdrawDeposit({
       accounts: ProgramAccountInfo<StakeAccountParsed>[]
     }>(
       (acc, accountInfo) => {
-        if (acc.stakesAmount < amountToWithdraw) {
-          acc.stakesAmount.add(new BN(accountInfo.account.lamports))
+        if (acc.stakesAmount.lt(amountToWithdraw)) {
+          acc.stakesAmount = acc.stakesAmount.add(
+            new BN(accountInfo.account.lamports)
+          )
           acc.accounts.push(accountInfo)
         }
         return acc
@@ -147,22 +148,10 @@ export async function orchestrateWithdrawDeposit({
   if (stakeAccountsToWithdraw.accounts.length > 0) {
     const destinationStakeAccount =
       stakeAccountsToWithdraw.accounts[0].publicKey
-    // going through from the second item that we want to merge all to the first one
-    for (
-      let mergeIndex = 1;
-      mergeIndex < stakeAccountsToWithdraw.accounts.length;
-      mergeIndex++
-    ) {
-      const sourceStakeAccount =
-        stakeAccountsToWithdraw.accounts[mergeIndex].publicKey
-      const mergeIx = await mergeStakeInstruction({
-        program,
-        configAccount,
-        sourceStakeAccount,
-        destinationStakeAccount,
-      })
-      instructions.push(mergeIx.instruction)
-    }
+    // all the other stake accounts are merged to the first one by the claim instruction
+    const mergeStakeAccounts = stakeAccountsToWithdraw.accounts
+      .slice(1)
+      .map(accountInfo => accountInfo.publicKey)
     const withdrawDeposit = await claimWithdrawRequestInstruction({
       program,
       configAccount,
@@ -173,6 +162,7 @@ export async function orchestrateWithdrawDeposit({
       voteAccount: withdrawRequestData.voteAccount,
       splitStakeRentPayer,
       withdrawer,
+      mergeStakeAccounts,
     })
     instructions.push(withdrawDeposit.instruction)
 
@@ -37,15 +37,21 @@ export type ValidatorBondsProgram = AnchorProgram<ValidatorBonds>
 // --- ACCOUNTS ---
 export type Config = IdlAccounts<ValidatorBonds>['config']
 export type Bond = IdlAccounts<ValidatorBonds>['bond']
+export type BondAlias = IdlAccounts<ValidatorBonds>['bondAlias']
 export type SettlementClaim = IdlAccounts<ValidatorBonds>['settlementClaim']
 export type Settlement = IdlAccounts<ValidatorBonds>['settlement']
 export type WithdrawRequest = IdlAccounts<ValidatorBonds>['withdrawRequest']
+export type CollateralMint = IdlAccounts<ValidatorBonds>['collateralMint']
+export type SettlementCollateral =
+  IdlAccounts<ValidatorBonds>['settlementCollateral']
 
 // --- TYPES ---
 export type InitConfigArgs = IdlTypes<ValidatorBonds>['InitConfigArgs']
 export type ConfigureConfigArgs =
   IdlTypes<ValidatorBonds>['ConfigureConfigArgs']
 export type InitBondArgs = IdlTypes<ValidatorBonds>['InitBondArgs']
+export type PendingConfigChanges =
+  IdlTypes<ValidatorBonds>['PendingConfigChanges']
 
 // --- CONSTANTS ---
 function seedFromConstants(seedName: string): Uint8Array {
@@ -59,10 +65,16 @@ function seedFromConstants(seedName: string): Uint8Array {
   return new Uint8Array(JSON.parse(constant.value))
 }
 export const BOND_SEED = seedFromConstants('BOND_SEED')
+export const BOND_ALIAS_SEED = seedFromConstants('BOND_ALIAS_SEED')
 export const BOND_MINT_SEED = seedFromConstants('BOND_MINT_SEED')
 export const SETTLEMENT_SEED = seedFromConstants('SETTLEMENT_SEED')
 export const WITHDRAW_REQUEST_SEED = seedFromConstants('WITHDRAW_REQUEST_SEED')
 export const SETTLEMENT_CLAIM_SEED = seedFromConstants('SETTLEMENT_CLAIM_SEED')
+export const COLLATERAL_MINT_SEED = seedFromConstants('COLLATERAL_MINT_SEED')
+export const COLLATERAL_VAULT_SEED = seedFromConstants('COLLATERAL_VAULT_SEED')
+export const SETTLEMENT_COLLATERAL_SEED = seedFromConstants(
+  'SETTLEMENT_COLLATERAL_SEED'
+)
 export const BONDS_WITHDRAWER_AUTHORITY_SEED = seedFromConstants(
   'BONDS_WITHDRAWER_AUTHORITY_SEED'
 )
@@ -70,6 +82,20 @@ export const SETTLEMENT_STAKER_AUTHORITY_SEED = seedFromConstants(
   'SETTLEMENT_STAKER_AUTHORITY_SEED'
 )
 
+// --- PAUSE FLAGS ---
+// instruction families that can be paused independently, see setPauseFlagsInstruction
+export const PAUSE_FLAGS = {
+  bond: 1,
+  withdraw: 1 << 1,
+  settlement: 1 << 2,
+  claim: 1 << 3,
+  stake: 1 << 4,
+}
+export const PAUSE_FLAGS_ALL = Object.values(PAUSE_FLAGS).reduce(
+  (all, flag) => all | flag,
+  0
+)
+
 // --- EVENTS ---
 export const INIT_CONFIG_EVENT = 'InitConfigEvent'
 export type InitConfigEvent =
@@ -90,12 +116,28 @@ export const CONFIGURE_BOND_WITH_MINT_EVENT = 'ConfigureBondWithMintEvent'
 export type ConfigureBondWithMintEvent =
   IdlEvents<ValidatorBonds>[typeof CONFIGURE_BOND_WITH_MINT_EVENT]
 
+export const CONFIGURE_BOND_AUTHORITY_MINT_EVENT =
+  'ConfigureBondAuthorityMintEvent'
+export type ConfigureBondAuthorityMintEvent =
+  IdlEvents<ValidatorBonds>[typeof CONFIGURE_BOND_AUTHORITY_MINT_EVENT]
+
 export const MINT_BOND_EVENT = 'MintBondEvent'
 export type MintBondEvent = IdlEvents<ValidatorBonds>[typeof MINT_BOND_EVENT]
 
 export const FUND_BOND_EVENT = 'FundBondEvent'
 export type FundBondEvent = IdlEvents<ValidatorBonds>[typeof FUND_BOND_EVENT]
 
+export const CHARGE_BID_EVENT = 'ChargeBidEvent'
+export type ChargeBidEvent = IdlEvents<ValidatorBonds>[typeof CHARGE_BID_EVENT]
+
+export const MIGRATE_BOND_EVENT = 'MigrateBondEvent'
+export type MigrateBondEvent =
+  IdlEvents<ValidatorBonds>[typeof MIGRATE_BOND_EVENT]
+
+export const MIGRATE_ACCOUNT_EVENT = 'MigrateAccountEvent'
+export type MigrateAccountEvent =
+  IdlEvents<ValidatorBonds>[typeof MIGRATE_ACCOUNT_EVENT]
+
 export const FUND_SETTLEMENT_EVENT = 'FundSettlementEvent'
 export type FundSettlementEvent =
   IdlEvents<ValidatorBonds>[typeof FUND_SETTLEMENT_EVENT]
@@ -112,6 +154,22 @@ export const INIT_SETTLEMENT_EVENT = 'InitSettlementEvent'
 export type InitSettlementEvent =
   IdlEvents<ValidatorBonds>[typeof INIT_SETTLEMENT_EVENT]
 
+export const AMEND_SETTLEMENT_EVENT = 'AmendSettlementEvent'
+export type AmendSettlementEvent =
+  IdlEvents<ValidatorBonds>[typeof AMEND_SETTLEMENT_EVENT]
+
+export const DISPUTE_SETTLEMENT_EVENT = 'DisputeSettlementEvent'
+export type DisputeSettlementEvent =
+  IdlEvents<ValidatorBonds>[typeof DISPUTE_SETTLEMENT_EVENT]
+
+export const RESOLVE_DISPUTE_EVENT = 'ResolveDisputeEvent'
+export type ResolveDisputeEvent =
+  IdlEvents<ValidatorBonds>[typeof RESOLVE_DISPUTE_EVENT]
+
+export const EXTEND_SETTLEMENT_EXPIRY_EVENT = 'ExtendSettlementExpiryEvent'
+export type ExtendSettlementExpiryEvent =
+  IdlEvents<ValidatorBonds>[typeof EXTEND_SETTLEMENT_EXPIRY_EVENT]
+
 export const CLOSE_SETTLEMENT_EVENT = 'CloseSettlementEvent'
 export type CloseSettlementEvent =
   IdlEvents<ValidatorBonds>[typeof CLOSE_SETTLEMENT_EVENT]
@@ -128,6 +186,10 @@ export const RESET_STAKE_EVENT = 'ResetStakeEvent'
 export type ResetStakeEvent =
   IdlEvents<ValidatorBonds>[typeof RESET_STAKE_EVENT]
 
+export const REDELEGATE_STAKE_EVENT = 'RedelegateStakeEvent'
+export type RedelegateStakeEvent =
+  IdlEvents<ValidatorBonds>[typeof REDELEGATE_STAKE_EVENT]
+
 export const WITHDRAW_STAKE_EVENT = 'WithdrawStakeEvent'
 export type WithdrawStakeEvent =
   IdlEvents<ValidatorBonds>[typeof WITHDRAW_STAKE_EVENT]
@@ -152,6 +214,40 @@ export const EMERGENCY_RESUME_EVENT = 'EmergencyResumeEvent'
 export type EmergencyResumeEvent =
   IdlEvents<ValidatorBonds>[typeof EMERGENCY_RESUME_EVENT]
 
+export const SET_PAUSE_FLAGS_EVENT = 'SetPauseFlagsEvent'
+export type SetPauseFlagsEvent =
+  IdlEvents<ValidatorBonds>[typeof SET_PAUSE_FLAGS_EVENT]
+
+export const INIT_COLLATERAL_MINT_EVENT = 'InitCollateralMintEvent'
+export type InitCollateralMintEvent =
+  IdlEvents<ValidatorBonds>[typeof INIT_COLLATERAL_MINT_EVENT]
+
+export const CONFIGURE_COLLATERAL_MINT_EVENT = 'ConfigureCollateralMintEvent'
+export type ConfigureCollateralMintEvent =
+  IdlEvents<ValidatorBonds>[typeof CONFIGURE_COLLATERAL_MINT_EVENT]
+
+export const FUND_BOND_COLLATERAL_EVENT = 'FundBondCollateralEvent'
+export type FundBondCollateralEvent =
+  IdlEvents<ValidatorBonds>[typeof FUND_BOND_COLLATERAL_EVENT]
+
+export const CLAIM_WITHDRAW_COLLATERAL_EVENT = 'ClaimWithdrawCollateralEvent'
+export type ClaimWithdrawCollateralEvent =
+  IdlEvents<ValidatorBonds>[typeof CLAIM_WITHDRAW_COLLATERAL_EVENT]
+
+export const FUND_SETTLEMENT_COLLATERAL_EVENT = 'FundSettlementCollateralEvent'
+export type FundSettlementCollateralEvent =
+  IdlEvents<ValidatorBonds>[typeof FUND_SETTLEMENT_COLLATERAL_EVENT]
+
+export const CLAIM_SETTLEMENT_COLLATERAL_EVENT =
+  'ClaimSettlementCollateralEvent'
+export type ClaimSettlementCollateralEvent =
+  IdlEvents<ValidatorBonds>[typeof CLAIM_SETTLEMENT_COLLATERAL_EVENT]
+
+export const RESET_SETTLEMENT_COLLATERAL_EVENT =
+  'ResetSettlementCollateralEvent'
+export type ResetSettlementCollateralEvent =
+  IdlEvents<ValidatorBonds>[typeof RESET_SETTLEMENT_COLLATERAL_EVENT]
+
 export const Errors = parseIdlErrors(generated.IDL)
 
 /**
@@ -211,6 +307,17 @@ export function bondAddress(
   )
 }
 
+export function bondAliasAddress(
+  config: PublicKey,
+  voteAccount: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BOND_ALIAS_SEED, config.toBytes(), voteAccount.toBytes()],
+    validatorBondsProgramId
+  )
+}
+
 export function bondsWithdrawerAuthority(
   config: PublicKey,
   validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID
@@ -307,3 +414,44 @@ export function bondMintAddress(
     validatorBondsProgramId
   )
 }
+
+export function collateralMintAddress(
+  config: PublicKey,
+  mint: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [COLLATERAL_MINT_SEED, config.toBytes(), mint.toBytes()],
+    validatorBondsProgramId
+  )
+}
+
+/**
+ * Collateral vault token account of the bond,
+ * or of the settlement when the collateral was funded to it.
+ */
+export function collateralVaultAddress(
+  owner: PublicKey,
+  mint: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [COLLATERAL_VAULT_SEED, owner.toBytes(), mint.toBytes()],
+    validatorBondsProgramId
+  )
+}
+
+/**
+ * Per-mint collateral accounting of the settlement,
+ * it keeps the tokens funded to the settlement to be paid pro rata to the claims.
+ */
+export function settlementCollateralAddress(
+  settlement: PublicKey,
+  mint: PublicKey,
+  validatorBondsProgramId: PublicKey = VALIDATOR_BONDS_PROGRAM_ID
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SETTLEMENT_COLLATERAL_SEED, settlement.toBytes(), mint.toBytes()],
+    validatorBondsProgramId
+  )
+}
@@ -1,6 +1,6 @@
 import { Program, Idl } from '@coral-xyz/anchor'
 import { PublicKey } from '@solana/web3.js'
-import { bondAddress as sdkBondAddress } from './sdk'
+import { Bond, Config, bondAddress as sdkBondAddress } from './sdk'
 
 // available at @marinade.finance/anchor-common
 export function anchorProgramWalletPubkey<IDL extends Idl = Idl>(
@@ -31,3 +31,29 @@ export function checkAndGetBondAddress(
     )
   }
 }
+
+/**
+ * Authority holding the scoped operator role at the config,
+ * the operator authority holds the role when it is not configured.
+ */
+export function operatorRoleAuthority(
+  config: Config,
+  role:
+    | 'settlementCreatorAuthority'
+    | 'settlementFunderAuthority'
+    | 'settlementCloserAuthority'
+): PublicKey {
+  return config[role].equals(PublicKey.default)
+    ? config.operatorAuthority
+    : config[role]
+}
+
+/**
+ * Vote account the bond stake accounts are expected to be delegated to,
+ * the vote account the bond was migrated to or the bond vote account.
+ */
+export function bondStakeVoteAccount(bond: Bond): PublicKey {
+  return bond.migratedVoteAccount.equals(PublicKey.default)
+    ? bond.voteAccount
+    : bond.migratedVoteAccount
+}
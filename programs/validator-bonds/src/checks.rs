@@ -3,15 +3,20 @@
 
 use crate::error::ErrorCode;
 use crate::state::bond::Bond;
+use crate::state::settlement::Settlement;
+use crate::utils::merkle_proof;
 use anchor_lang::prelude::*;
 use anchor_lang::prelude::{msg, Pubkey};
 use anchor_lang::require_keys_eq;
+use anchor_lang::solana_program::hash::hashv;
 use anchor_lang::solana_program::stake::program::ID as stake_program_id;
 use anchor_lang::solana_program::stake::state::{Delegation, Meta, Stake, StakeState};
 use anchor_lang::solana_program::stake_history::{Epoch, StakeHistoryEntry};
 use anchor_lang::solana_program::system_program::ID as system_program_id;
 use anchor_lang::solana_program::vote::program::id as vote_program_id;
 use anchor_spl::stake::StakeAccount;
+use merkle_tree::psr_claim::TreeNode;
+use merkle_tree::{hash_leaf, LEAF_PREFIX};
 use std::ops::Deref;
 
 /// Verification the account is owned by vote program + matching validator identity
@@ -137,6 +142,48 @@ pub fn check_stake_valid_delegation(
     }
 }
 
+/// Check if the stake account is delegated to the bond vote account,
+/// for a migrated bond the stake account may be delegated to the original or to the migrated vote account
+pub fn check_bond_stake_valid_delegation(
+    stake_account: &StakeAccount,
+    bond: &Bond,
+) -> Result<Delegation> {
+    if bond.stake_vote_account() != bond.vote_account {
+        if let Some(delegation) = get_delegation(stake_account)? {
+            if delegation.voter_pubkey == bond.vote_account {
+                return Ok(delegation);
+            }
+        }
+    }
+    check_stake_valid_delegation(stake_account, &bond.stake_vote_account())
+}
+
+/// Verification that both vote accounts are owned by the same validator,
+/// the authority has to be the validator identity or the authorized withdrawer of both vote accounts
+pub fn check_vote_accounts_same_owner(
+    authority: &Pubkey,
+    vote_account: &UncheckedAccount,
+    new_vote_account: &UncheckedAccount,
+) -> Result<()> {
+    if is_vote_account_owner(authority, vote_account)?
+        && is_vote_account_owner(authority, new_vote_account)?
+    {
+        Ok(())
+    } else {
+        Err(error!(ErrorCode::BondMigrationNotPermitted).with_values((
+            "vote_account, new_vote_account",
+            format!("{}, {}", vote_account.key(), new_vote_account.key()),
+        )))
+    }
+}
+
+fn is_vote_account_owner(authority: &Pubkey, vote_account: &UncheckedAccount) -> Result<bool> {
+    Ok(
+        get_validator_vote_account_validator_identity(vote_account)? == *authority
+            || get_validator_vote_account_authorized_withdrawer(vote_account)? == *authority,
+    )
+}
+
 /// the StakeAccount::delegation could be used, but we want to verify
 /// the state of the stake account that is generally expected for the protocol
 pub fn get_delegation(stake_account: &StakeAccount) -> Result<Option<Delegation>> {
@@ -219,7 +266,7 @@ pub fn check_stake_exist_and_fully_activated(
     }
 }
 
-pub fn deserialize_stake_account(account: &UncheckedAccount) -> Result<StakeAccount> {
+pub fn deserialize_stake_account(account: &AccountInfo) -> Result<StakeAccount> {
     require_keys_eq!(
         *account.owner,
         stake_program_id,
@@ -236,6 +283,75 @@ pub fn is_closed(account: &UncheckedAccount) -> bool {
     account.try_lamports().unwrap_or(0) == 0 && account.owner == &system_program_id
 }
 
+/// The account has not been created by a program, it may hold lamports only
+pub fn is_uninitialized(account: &UncheckedAccount) -> bool {
+    account.owner == &system_program_id && account.data_is_empty()
+}
+
+/// Verification the claim is part of the settlement merkle tree and the settlement limits are not exceeded
+pub fn check_settlement_claim(
+    settlement: &Settlement,
+    proof: Vec<[u8; 32]>,
+    tree_node_hash: [u8; 32],
+    stake_account_staker: Pubkey,
+    stake_account_withdrawer: Pubkey,
+    claim: u64,
+) -> Result<()> {
+    require!(!settlement.disputed, ErrorCode::SettlementDisputed);
+
+    // settlement_claim PDA address verification
+    let tree_node = TreeNode {
+        stake_authority: stake_account_staker,
+        withdraw_authority: stake_account_withdrawer,
+        claim,
+        proof: None,
+    };
+    let tree_node_bytes = tree_node.hash().to_bytes();
+    if tree_node_bytes != tree_node_hash {
+        return Err(
+            error!(ErrorCode::ClaimSettlementMerkleTreeNodeMismatch).with_values((
+                "tree_node_bytes vs. tree_node_hash_args",
+                format!("'{:?}' vs. '{:?}'", tree_node_bytes, tree_node_hash),
+            )),
+        );
+    }
+
+    if settlement.lamports_claimed + claim > settlement.max_total_claim {
+        return Err(error!(ErrorCode::ClaimAmountExceedsMaxTotalClaim)
+            .with_account_name("settlement")
+            .with_values((
+                "lamports_claimed + claim > max_total_claim",
+                format!(
+                    "{} + {} <= {}",
+                    settlement.lamports_claimed, claim, settlement.max_total_claim
+                ),
+            )));
+    }
+    if settlement.merkle_nodes_claimed + 1 > settlement.max_merkle_nodes {
+        return Err(error!(ErrorCode::ClaimCountExceedsMaxMerkleNodes)
+            .with_account_name("settlement")
+            .with_values((
+                "merkle_nodes_claimed + 1 > max_merkle_nodes",
+                format!(
+                    "{} + 1 <= {}",
+                    settlement.merkle_nodes_claimed, settlement.max_merkle_nodes
+                ),
+            )));
+    }
+
+    if !merkle_proof::verify(
+        proof,
+        settlement.claim_merkle_root(),
+        hash_leaf!(tree_node_bytes).to_bytes(),
+    ) {
+        return Err(error!(ErrorCode::ClaimSettlementProofFailed).with_values((
+            "Merkle proof verification failed",
+            format!("Tree node: {:?}", tree_node),
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -253,7 +369,13 @@ mod tests {
             cpmpe: 0,
             bump: 0,
             max_stake_wanted: 0,
-            reserved: [0; 134],
+            bid_charged_epoch: 0,
+            bid_charged_amount: 0,
+            migrated_vote_account: Pubkey::default(),
+            authority_mint: Pubkey::default(),
+            account_version: 0,
+            created_epoch: 0,
+            reserved: [0; 45],
         }
     }
 
@@ -392,6 +514,103 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn bond_stake_valid_delegation_check() {
+        let vote_account = Pubkey::new_unique();
+        let migrated_vote_account = Pubkey::new_unique();
+        let mut bond = test_bond_with_authority(Pubkey::new_unique());
+        bond.vote_account = vote_account;
+
+        let original_stake_account = get_delegated_stake_account(Some(vote_account), None, None);
+        let migrated_stake_account =
+            get_delegated_stake_account(Some(migrated_vote_account), None, None);
+        let other_stake_account = get_delegated_stake_account(None, None, None);
+
+        assert!(check_bond_stake_valid_delegation(&original_stake_account, &bond).is_ok());
+        assert_eq!(
+            check_bond_stake_valid_delegation(&migrated_stake_account, &bond),
+            Err(ErrorCode::BondStakeWrongDelegation.into())
+        );
+
+        bond.migrated_vote_account = migrated_vote_account;
+        assert!(check_bond_stake_valid_delegation(&original_stake_account, &bond).is_ok());
+        assert!(check_bond_stake_valid_delegation(&migrated_stake_account, &bond).is_ok());
+        assert_eq!(
+            check_bond_stake_valid_delegation(&other_stake_account, &bond),
+            Err(ErrorCode::BondStakeWrongDelegation.into())
+        );
+    }
+
+    #[test]
+    pub fn vote_accounts_same_owner_check() {
+        let owner = vote_program_id();
+        let mut lamports = 10000_u64;
+        let mut new_lamports = 10000_u64;
+        let account_key = Pubkey::new_unique();
+        let new_account_key = Pubkey::new_unique();
+        let (vote_init, mut serialized_data) = get_vote_account_data();
+        let account = AccountInfo::new(
+            &account_key,
+            false,
+            true,
+            &mut lamports,
+            serialized_data.deref_mut(),
+            &owner,
+            false,
+            3,
+        );
+        let vote_account = UncheckedAccount::try_from(&account);
+
+        // the same validator identity, different withdrawer
+        let new_vote_init = VoteInit {
+            node_pubkey: vote_init.node_pubkey,
+            authorized_voter: Pubkey::new_unique(),
+            authorized_withdrawer: Pubkey::new_unique(),
+            commission: 0,
+        };
+        let mut new_serialized_data = serialize_vote_init(&new_vote_init);
+        let new_account = AccountInfo::new(
+            &new_account_key,
+            false,
+            true,
+            &mut new_lamports,
+            new_serialized_data.deref_mut(),
+            &owner,
+            false,
+            3,
+        );
+        let new_vote_account = UncheckedAccount::try_from(&new_account);
+
+        check_vote_accounts_same_owner(&vote_init.node_pubkey, &vote_account, &new_vote_account)
+            .unwrap();
+        assert_eq!(
+            check_vote_accounts_same_owner(
+                &vote_init.authorized_withdrawer,
+                &vote_account,
+                &new_vote_account
+            ),
+            Err(ErrorCode::BondMigrationNotPermitted.into())
+        );
+        assert_eq!(
+            check_vote_accounts_same_owner(
+                &new_vote_init.authorized_withdrawer,
+                &vote_account,
+                &new_vote_account
+            ),
+            Err(ErrorCode::BondMigrationNotPermitted.into())
+        );
+        check_vote_accounts_same_owner(
+            &new_vote_init.authorized_withdrawer,
+            &new_vote_account,
+            &new_vote_account,
+        )
+        .unwrap();
+        assert_eq!(
+            check_vote_accounts_same_owner(&Pubkey::new_unique(), &vote_account, &new_vote_account),
+            Err(ErrorCode::BondMigrationNotPermitted.into())
+        );
+    }
+
     #[test]
     pub fn stake_initialized_with_authority_check() {
         let uninitialized_stake_account = get_stake_account(StakeStateV2::Uninitialized);
@@ -696,16 +915,20 @@ mod tests {
     }
 
     pub fn get_vote_account_data() -> (VoteInit, Vec<u8>) {
-        let clock = get_clock();
         let vote_init = VoteInit {
             node_pubkey: Pubkey::new_unique(),
             authorized_voter: Pubkey::new_unique(),
             authorized_withdrawer: Pubkey::new_unique(),
             commission: 0,
         };
-        let vote_state = VoteState::new(&vote_init, &clock);
-        let vote_state_versions = VoteStateVersions::Current(Box::new(vote_state));
-        let serialized_data = bincode::serialize(&vote_state_versions).unwrap();
+        let serialized_data = serialize_vote_init(&vote_init);
         (vote_init, serialized_data)
     }
+
+    pub fn serialize_vote_init(vote_init: &VoteInit) -> Vec<u8> {
+        let clock = get_clock();
+        let vote_state = VoteState::new(vote_init, &clock);
+        let vote_state_versions = VoteStateVersions::Current(Box::new(vote_state));
+        bincode::serialize(&vote_state_versions).unwrap()
+    }
 }
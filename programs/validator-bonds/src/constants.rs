@@ -9,6 +9,9 @@ pub const PROGRAM_ID: &str = "vBoNdEvzMrSai7is21XgVYik65mqtaKXuSdMBJ1xkW4";
 #[constant]
 pub const BOND_SEED: &[u8] = b"bond_account";
 
+#[constant]
+pub const BOND_ALIAS_SEED: &[u8] = b"bond_alias";
+
 #[constant]
 pub const BOND_MINT_SEED: &[u8] = b"bond_mint";
 #[constant]
@@ -18,10 +21,29 @@ pub const WITHDRAW_REQUEST_SEED: &[u8] = b"withdraw_account";
 #[constant]
 pub const SETTLEMENT_CLAIM_SEED: &[u8] = b"claim_account";
 
+#[constant]
+pub const COLLATERAL_MINT_SEED: &[u8] = b"collateral_mint";
+#[constant]
+pub const COLLATERAL_VAULT_SEED: &[u8] = b"collateral_vault";
+#[constant]
+pub const SETTLEMENT_COLLATERAL_SEED: &[u8] = b"settlement_collateral";
+
 #[constant]
 pub const BONDS_WITHDRAWER_AUTHORITY_SEED: &[u8] = b"bonds_authority";
 
 #[constant]
 pub const SETTLEMENT_STAKER_AUTHORITY_SEED: &[u8] = b"settlement_authority";
 
+/// version of the events layout, increased on every change of an event structure
+#[constant]
+pub const EVENT_VERSION: u8 = 1;
+
+/// layout version of the program accounts, see `state::VersionedAccount`
+#[constant]
+pub const ACCOUNT_VERSION: u8 = 1;
+
+pub const MAX_HAIRCUT_BPS: u16 = 10_000;
+
+pub const MAX_CLAIMER_FEE_BPS: u16 = 1_000;
+
 pub const MIN_STAKE_LAMPORTS: u64 = 1_000_000_000;
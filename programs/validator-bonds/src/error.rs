@@ -196,4 +196,109 @@ pub enum ErrorCode {
 
     #[msg("Max stake wanted value is lower to minimum configured value")]
     MaxStakeWantedTooLow, // 6063 0x17af
+
+    #[msg("No pending authority change for the signer to accept")]
+    NoPendingAuthorityChange, // 6064 0x17b0
+
+    #[msg("No pending config changes to apply")]
+    NoPendingConfigChanges, // 6065 0x17b1
+
+    #[msg("Pending config changes are not effective yet")]
+    PendingConfigChangesNotEffective, // 6066 0x17b2
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags, // 6067 0x17b3
+
+    #[msg("Bid for the epoch has been already charged")]
+    BidAlreadyCharged, // 6068 0x17b4
+
+    #[msg("Bid can be charged only for already finished epoch")]
+    BidEpochNotFinished, // 6069 0x17b5
+
+    #[msg("Bid fee collector is not configured")]
+    BidFeeCollectorNotConfigured, // 6070 0x17b6
+
+    #[msg("Bond migration requires validator identity or withdrawer of both vote accounts")]
+    BondMigrationNotPermitted, // 6071 0x17b7
+
+    #[msg("Stake account is already delegated to the bond vote account")]
+    NothingToRedelegate, // 6072 0x17b8
+
+    #[msg("Invalid collateral mint account address")]
+    InvalidCollateralMintAddress, // 6073 0x17b9
+
+    #[msg("Collateral haircut basis points exceeds 10000")]
+    InvalidCollateralHaircut, // 6074 0x17ba
+
+    #[msg("Collateral token amount has got no value")]
+    CollateralAmountTooSmall, // 6075 0x17bb
+
+    #[msg("Not enough token collateral in the vault")]
+    CollateralInsufficient, // 6076 0x17bc
+
+    #[msg("Claim exceeds token collateral funded to the settlement")]
+    CollateralClaimExceedsFunded, // 6077 0x17bd
+
+    #[msg("Settlement cannot be amended as it has been already claimed")]
+    SettlementAlreadyClaimed, // 6078 0x17be
+
+    #[msg("Settlement dispute window has already passed")]
+    SettlementDisputeWindowClosed, // 6079 0x17bf
+
+    #[msg("Settlement is disputed, waiting for the admin resolution")]
+    SettlementDisputed, // 6080 0x17c0
+
+    #[msg("Settlement has been already disputed")]
+    SettlementAlreadyDisputed, // 6081 0x17c1
+
+    #[msg("Settlement is not disputed")]
+    SettlementNotDisputed, // 6082 0x17c2
+
+    #[msg("Bond authority mint is not the bond mint of the validator identity")]
+    InvalidBondAuthorityMint, // 6083 0x17c3
+
+    #[msg("Token account does not hold the bond authority mint token")]
+    BondAuthorityMintNotHeld, // 6084 0x17c4
+
+    #[msg("Settlement expiry extension has to be a positive number of epochs")]
+    InvalidSettlementExpiryExtension, // 6085 0x17c5
+
+    #[msg("Claimer fee basis points exceeds the maximum")]
+    InvalidClaimerFeeBps, // 6086 0x17c6
+
+    #[msg("Account type cannot be migrated")]
+    MigrateAccountNotSupported, // 6087 0x17c7
+
+    #[msg("Account has been already migrated to the current version")]
+    AccountAlreadyMigrated, // 6088 0x17c8
+
+    #[msg("Pause flags are already set to the requested value")]
+    PauseFlagsNotChanged, // 6089 0x17c9
+
+    #[msg("Bid cannot be charged for an epoch before the bond was created")]
+    BidEpochBeforeBondCreation, // 6090 0x17ca
+
+    #[msg("Delegated lamports exceed the config maximum for charging the bid")]
+    BidDelegatedLamportsExceeded, // 6091 0x17cb
+
+    #[msg("Bid amount for the epoch is zero")]
+    BidAmountIsZero, // 6092 0x17cc
+
+    #[msg("Bond already exists for the vote account")]
+    BondAlreadyExists, // 6093 0x17cd
+
+    #[msg("Settlement expiry extension exceeds the config maximum")]
+    SettlementExpiryExtensionExceeded, // 6094 0x17ce
+
+    #[msg("Bond alias account does not match the bond migration")]
+    BondAliasMismatch, // 6095 0x17cf
+
+    #[msg("Bond stake accounts are already delegated to the vote account")]
+    BondAlreadyMigrated, // 6096 0x17d0
+
+    #[msg("Settlement claim is too small to be paid with the collateral tokens")]
+    CollateralClaimTooSmall, // 6097 0x17d1
+
+    #[msg("Settlement dispute reason hash cannot be empty")]
+    EmptyDisputeReasonHash, // 6098 0x17d2
 }
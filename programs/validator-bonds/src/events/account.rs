@@ -0,0 +1,12 @@
+use crate::events::U64ValueChange;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateAccountEvent {
+    pub version: u8,
+    pub account: Pubkey,
+    /// name of the migrated account type
+    pub account_type: String,
+    pub account_version: U64ValueChange,
+    pub data_size: U64ValueChange,
+}
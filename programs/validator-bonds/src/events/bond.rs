@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct InitBondEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub config: Pubkey,
     pub vote_account: Pubkey,
@@ -14,6 +15,7 @@ pub struct InitBondEvent {
 
 #[event]
 pub struct ConfigureBondEvent {
+    pub version: u8,
     pub bond_authority: Option<PubkeyValueChange>,
     pub cpmpe: Option<U64ValueChange>,
     pub max_stake_wanted: Option<U64ValueChange>,
@@ -21,14 +23,25 @@ pub struct ConfigureBondEvent {
 
 #[event]
 pub struct ConfigureBondWithMintEvent {
+    pub version: u8,
     pub validator_identity: Pubkey,
+    /// false when the bond authority mint token was only held (not burnt)
+    pub token_burned: bool,
     pub bond_authority: Option<PubkeyValueChange>,
     pub cpmpe: Option<U64ValueChange>,
     pub max_stake_wanted: Option<U64ValueChange>,
 }
 
+#[event]
+pub struct ConfigureBondAuthorityMintEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub authority_mint: PubkeyValueChange,
+}
+
 #[event]
 pub struct FundBondEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub vote_account: Pubkey,
     pub stake_account: Pubkey,
@@ -38,8 +51,40 @@ pub struct FundBondEvent {
 
 #[event]
 pub struct MintBondEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub validator_identity: Pubkey,
     pub validator_identity_token_account: Pubkey,
     pub token_metadata: Pubkey,
 }
+
+#[event]
+pub struct ChargeBidEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub epoch: u64,
+    pub cpmpe: u64,
+    pub max_stake_wanted: u64,
+    pub delegated_lamports: u64,
+    /// bid calculated for the epoch
+    pub bid_amount: u64,
+    /// lamports charged by this instruction
+    pub charged_amount: u64,
+    /// lamports charged in total for the epoch
+    pub bid_charged_amount: u64,
+    pub stake_account: Pubkey,
+    pub split_stake_account: Pubkey,
+    pub bid_fee_collector: Pubkey,
+}
+
+#[event]
+pub struct MigrateBondEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    /// vote account the bond address is derived from
+    pub bond_vote_account: Pubkey,
+    /// vote account the bond stake accounts are delegated to
+    pub vote_account: PubkeyValueChange,
+    pub authority: Pubkey,
+}
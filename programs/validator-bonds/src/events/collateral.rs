@@ -0,0 +1,93 @@
+use crate::events::U64ValueChange;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct InitCollateralMintEvent {
+    pub version: u8,
+    pub config: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub lamports_per_token: u64,
+    pub haircut_bps: u16,
+}
+
+#[event]
+pub struct ConfigureCollateralMintEvent {
+    pub version: u8,
+    pub collateral_mint: Pubkey,
+    pub mint: Pubkey,
+    pub lamports_per_token: Option<U64ValueChange>,
+    pub haircut_bps: Option<U64ValueChange>,
+}
+
+#[event]
+pub struct FundBondCollateralEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub mint: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub token_authority: Pubkey,
+    pub deposited_amount: u64,
+    pub vault_amount: u64,
+    pub collateral_value: u64,
+}
+
+#[event]
+pub struct ClaimWithdrawCollateralEvent {
+    pub version: u8,
+    pub withdraw_request: Pubkey,
+    pub bond: Pubkey,
+    pub vote_account: Pubkey,
+    pub mint: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub withdrawer_token_account: Pubkey,
+    pub withdrawing_token_amount: u64,
+    pub withdrawing_amount: u64,
+    pub withdrawn_amount: U64ValueChange,
+}
+
+#[event]
+pub struct FundSettlementCollateralEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub mint: Pubkey,
+    pub settlement_collateral_vault: Pubkey,
+    pub funding_token_amount: u64,
+    pub funding_amount: u64,
+    pub lamports_funded: u64,
+    pub collateral_funded: u64,
+}
+
+#[event]
+pub struct ClaimSettlementCollateralEvent {
+    pub version: u8,
+    pub settlement: Pubkey,
+    pub settlement_claim: Pubkey,
+    pub mint: Pubkey,
+    pub claimer_token_account: Pubkey,
+    pub stake_account_staker: Pubkey,
+    pub stake_account_withdrawer: Pubkey,
+    pub amount: u64,
+    pub token_amount: u64,
+    pub settlement_lamports_claimed: U64ValueChange,
+    pub settlement_merkle_nodes_claimed: u64,
+    pub rent_collector: Pubkey,
+    /// part of the amount paid to the rent collector (claimer)
+    pub claimer_fee: u64,
+    /// part of the token amount paid to the rent collector (claimer)
+    pub claimer_fee_tokens: u64,
+}
+
+#[event]
+pub struct ResetSettlementCollateralEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub mint: Pubkey,
+    pub settlement_collateral_vault: Pubkey,
+    pub collateral_vault: Pubkey,
+    pub returned_token_amount: u64,
+}
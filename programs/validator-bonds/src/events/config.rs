@@ -1,8 +1,10 @@
 use crate::events::{PubkeyValueChange, U64ValueChange};
+use crate::state::config::PendingConfigChanges;
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct InitConfigEvent {
+    pub version: u8,
     pub config: Pubkey,
     pub admin_authority: Pubkey,
     pub operator_authority: Pubkey,
@@ -15,18 +17,35 @@ pub struct InitConfigEvent {
 
 #[event]
 pub struct ConfigureConfigEvent {
+    pub version: u8,
     pub admin_authority: Option<PubkeyValueChange>,
     pub operator_authority: Option<PubkeyValueChange>,
     pub pause_authority: Option<PubkeyValueChange>,
+    pub settlement_creator_authority: Option<PubkeyValueChange>,
+    pub settlement_funder_authority: Option<PubkeyValueChange>,
+    pub settlement_closer_authority: Option<PubkeyValueChange>,
+    pub bid_fee_collector: Option<PubkeyValueChange>,
+    pub bid_max_delegated_lamports: Option<U64ValueChange>,
+    pub max_claim_expiry_extension: Option<U64ValueChange>,
     pub epochs_to_claim_settlement: Option<U64ValueChange>,
     pub minimum_stake_lamports: Option<U64ValueChange>,
     pub withdraw_lockup_epochs: Option<U64ValueChange>,
     pub slots_to_start_settlement_claiming: Option<U64ValueChange>,
     pub min_bond_max_stake_wanted: Option<U64ValueChange>,
+    pub config_change_delay_epochs: Option<U64ValueChange>,
+    pub claimer_fee_lamports: Option<U64ValueChange>,
+    pub claimer_fee_max_bps: Option<U64ValueChange>,
+    /// authorities proposed by admin, they are changed once the new key accepts
+    pub pending_admin_authority: Option<Pubkey>,
+    pub pending_operator_authority: Option<Pubkey>,
+    pub pending_pause_authority: Option<Pubkey>,
+    /// parameter changes staged by admin, they are changed once applied after the effective epoch
+    pub pending_config_changes: Option<PendingConfigChanges>,
 }
 
 #[event]
 pub struct EmergencyPauseEvent {
+    pub version: u8,
     pub config: Pubkey,
     pub admin_authority: Pubkey,
     pub operator_authority: Pubkey,
@@ -34,10 +53,12 @@ pub struct EmergencyPauseEvent {
     pub withdraw_lockup_epochs: u64,
     pub minimum_stake_lamports: u64,
     pub pause_authority: Pubkey,
+    pub pause_flags: U64ValueChange,
 }
 
 #[event]
 pub struct EmergencyResumeEvent {
+    pub version: u8,
     pub config: Pubkey,
     pub admin_authority: Pubkey,
     pub operator_authority: Pubkey,
@@ -45,4 +66,13 @@ pub struct EmergencyResumeEvent {
     pub withdraw_lockup_epochs: u64,
     pub minimum_stake_lamports: u64,
     pub pause_authority: Pubkey,
+    pub pause_flags: U64ValueChange,
+}
+
+#[event]
+pub struct SetPauseFlagsEvent {
+    pub version: u8,
+    pub config: Pubkey,
+    pub pause_authority: Pubkey,
+    pub pause_flags: U64ValueChange,
 }
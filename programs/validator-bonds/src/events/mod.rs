@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::stake::state::Delegation;
 
+pub mod account;
 pub mod bond;
+pub mod collateral;
 pub mod config;
 pub mod settlement;
 pub mod settlement_claim;
@@ -1,9 +1,10 @@
-use crate::events::SplitStakeData;
+use crate::events::{SplitStakeData, U64ValueChange};
 
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct InitSettlementEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub settlement: Pubkey,
     pub vote_account: Pubkey,
@@ -16,8 +17,52 @@ pub struct InitSettlementEvent {
     pub rent_collector: Pubkey,
 }
 
+#[event]
+pub struct AmendSettlementEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub old_merkle_root: [u8; 32],
+    pub new_merkle_root: [u8; 32],
+    pub max_total_claim: U64ValueChange,
+    pub max_merkle_nodes: U64ValueChange,
+    /// the claiming and dispute window is restarted from the amendment slot
+    pub slot_created_at: U64ValueChange,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct DisputeSettlementEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub vote_account: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct ResolveDisputeEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub admin_authority: Pubkey,
+}
+
+#[event]
+pub struct ExtendSettlementExpiryEvent {
+    pub version: u8,
+    pub bond: Pubkey,
+    pub settlement: Pubkey,
+    pub claim_expiry_extension: U64ValueChange,
+    pub expiration_epoch: U64ValueChange,
+    pub authority: Pubkey,
+}
+
 #[event]
 pub struct CloseSettlementEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub settlement: Pubkey,
     pub merkle_root: [u8; 32],
@@ -35,6 +80,7 @@ pub struct CloseSettlementEvent {
 
 #[event]
 pub struct CancelSettlementEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub settlement: Pubkey,
     pub merkle_root: [u8; 32],
@@ -51,6 +97,7 @@ pub struct CancelSettlementEvent {
 
 #[event]
 pub struct FundSettlementEvent {
+    pub version: u8,
     pub bond: Pubkey,
     pub settlement: Pubkey,
     pub funding_amount: u64,
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct ClaimSettlementEvent {
+    pub version: u8,
     pub settlement_claim: Pubkey,
     pub settlement: Pubkey,
     pub settlement_lamports_claimed: U64ValueChange,
@@ -12,10 +13,20 @@ pub struct ClaimSettlementEvent {
     pub stake_account_staker: Pubkey,
     pub amount: u64,
     pub rent_collector: Pubkey,
+    /// part of the amount paid to the rent collector (claimer)
+    pub claimer_fee: u64,
 }
 
 #[event]
 pub struct CloseSettlementClaimEvent {
+    pub version: u8,
     pub settlement: Pubkey,
+    pub settlement_claim: Pubkey,
+    pub stake_account_to: Pubkey,
+    pub stake_account_withdrawer: Pubkey,
+    pub stake_account_staker: Pubkey,
+    pub amount: u64,
     pub rent_collector: Pubkey,
+    pub rent_refund: u64,
+    pub epoch: u64,
 }
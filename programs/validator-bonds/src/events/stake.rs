@@ -1,28 +1,38 @@
-use crate::events::DelegationInfo;
+use crate::events::{DelegationInfo, U64ValueChange};
 use anchor_lang::prelude::*;
 
 #[event]
 pub struct MergeStakeEvent {
+    pub version: u8,
     pub config: Pubkey,
+    pub settlement: Pubkey,
     pub staker_authority: Pubkey,
     pub destination_stake: Pubkey,
     pub destination_delegation: Option<DelegationInfo>,
+    pub destination_lamports: U64ValueChange,
     pub source_stake: Pubkey,
     pub source_delegation: Option<DelegationInfo>,
+    pub source_lamports: u64,
+    pub epoch: u64,
 }
 
 #[event]
 pub struct ResetStakeEvent {
+    pub version: u8,
     pub config: Pubkey,
     pub bond: Pubkey,
     pub settlement: Pubkey,
     pub stake_account: Pubkey,
     pub vote_account: Pubkey,
     pub settlement_staker_authority: Pubkey,
+    pub stake_amount: u64,
+    pub operator_authority: Option<Pubkey>,
+    pub epoch: u64,
 }
 
 #[event]
 pub struct WithdrawStakeEvent {
+    pub version: u8,
     pub config: Pubkey,
     pub operator_authority: Pubkey,
     pub settlement: Pubkey,
@@ -31,3 +41,15 @@ pub struct WithdrawStakeEvent {
     pub settlement_staker_authority: Pubkey,
     pub withdrawn_amount: u64,
 }
+
+#[event]
+pub struct RedelegateStakeEvent {
+    pub version: u8,
+    pub config: Pubkey,
+    pub bond: Pubkey,
+    pub stake_account: Pubkey,
+    pub old_vote_account: Pubkey,
+    pub new_vote_account: Pubkey,
+    /// true when the stake account was deactivated, false when delegated to the new vote account
+    pub deactivated: bool,
+}
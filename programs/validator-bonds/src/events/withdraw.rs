@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 
 #[event]
 pub struct InitWithdrawRequestEvent {
+    pub version: u8,
     pub withdraw_request: Pubkey,
     pub bond: Pubkey,
     pub vote_account: Pubkey,
@@ -12,6 +13,7 @@ pub struct InitWithdrawRequestEvent {
 
 #[event]
 pub struct CancelWithdrawRequestEvent {
+    pub version: u8,
     pub withdraw_request: Pubkey,
     pub bond: Pubkey,
     pub authority: Pubkey,
@@ -21,10 +23,13 @@ pub struct CancelWithdrawRequestEvent {
 
 #[event]
 pub struct ClaimWithdrawRequestEvent {
+    pub version: u8,
     pub withdraw_request: Pubkey,
     pub bond: Pubkey,
     pub vote_account: Pubkey,
     pub stake_account: Pubkey,
+    /// bond stake accounts merged to the stake account before claiming
+    pub merged_stake_accounts: Vec<Pubkey>,
     pub split_stake: Option<SplitStakeData>,
     pub new_stake_account_owner: Pubkey,
     pub withdrawing_amount: u64,
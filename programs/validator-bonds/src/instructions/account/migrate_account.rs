@@ -0,0 +1,122 @@
+use crate::constants::{ACCOUNT_VERSION, EVENT_VERSION};
+use crate::error::ErrorCode;
+use crate::events::{account::MigrateAccountEvent, U64ValueChange};
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::state::settlement_claim::SettlementClaim;
+use crate::state::withdraw_request::WithdrawRequest;
+use crate::state::VersionedAccount;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+/// Upgrades a program account (bond, config, settlement, settlement claim or withdraw request)
+/// in place to the current layout version. When the current layout is bigger than the account
+/// the account is reallocated and the rent exempt difference is paid by the rent payer.
+/// Permission-less, the account data is only converted to the current layout.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: the account type is resolved from the discriminator in the code
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::MigrateAccountNotSupported,
+    )]
+    pub account: UncheckedAccount<'info>,
+
+    /// rent exempt payer of the account reallocation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    pub fn process(ctx: Context<MigrateAccount>) -> Result<()> {
+        let discriminator: [u8; 8] = ctx
+            .accounts
+            .account
+            .try_borrow_data()?
+            .get(..8)
+            .ok_or(ErrorCode::MigrateAccountNotSupported)?
+            .try_into()
+            .unwrap();
+
+        let (account_type, (old_data_size, old_account_version)) =
+            if discriminator == Bond::DISCRIMINATOR {
+                ("Bond", migrate_account::<Bond>(&ctx)?)
+            } else if discriminator == Config::DISCRIMINATOR {
+                ("Config", migrate_account::<Config>(&ctx)?)
+            } else if discriminator == Settlement::DISCRIMINATOR {
+                ("Settlement", migrate_account::<Settlement>(&ctx)?)
+            } else if discriminator == SettlementClaim::DISCRIMINATOR {
+                ("SettlementClaim", migrate_account::<SettlementClaim>(&ctx)?)
+            } else if discriminator == WithdrawRequest::DISCRIMINATOR {
+                ("WithdrawRequest", migrate_account::<WithdrawRequest>(&ctx)?)
+            } else {
+                return err!(ErrorCode::MigrateAccountNotSupported);
+            };
+
+        emit_cpi!(MigrateAccountEvent {
+            version: EVENT_VERSION,
+            account: ctx.accounts.account.key(),
+            account_type: account_type.to_string(),
+            account_version: U64ValueChange {
+                old: old_account_version as u64,
+                new: ACCOUNT_VERSION as u64,
+            },
+            data_size: U64ValueChange {
+                old: old_data_size as u64,
+                new: ctx.accounts.account.data_len() as u64,
+            },
+        });
+
+        Ok(())
+    }
+}
+
+/// Returns the original data size and layout version of the account
+fn migrate_account<T: VersionedAccount>(ctx: &Context<MigrateAccount>) -> Result<(usize, u8)> {
+    let account = &ctx.accounts.account;
+    let old_data_size = account.data_len();
+
+    // the same space as on the account init
+    let data_size = 8 + std::mem::size_of::<T>();
+    if old_data_size < data_size {
+        let rent_exempt = Rent::get()?
+            .minimum_balance(data_size)
+            .saturating_sub(account.lamports());
+        if rent_exempt > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.rent_payer.to_account_info(),
+                        to: account.to_account_info(),
+                    },
+                ),
+                rent_exempt,
+            )?;
+        }
+        // new space is zeroed as the reserved space of the current layout
+        account.realloc(data_size, true)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    let mut versioned = T::try_deserialize(&mut &data[..])?;
+    let old_account_version = versioned.account_version();
+    require_gt!(
+        ACCOUNT_VERSION,
+        old_account_version,
+        ErrorCode::AccountAlreadyMigrated
+    );
+    // version 0 to 1: the layout is the same, only the version is set
+    versioned.set_account_version(ACCOUNT_VERSION);
+    versioned.try_serialize(&mut &mut data[..])?;
+
+    Ok((old_data_size, old_account_version))
+}
@@ -0,0 +1,263 @@
+use crate::checks::{
+    check_bond_stake_valid_delegation, check_stake_is_initialized_with_withdrawer_authority,
+    check_stake_is_not_locked,
+};
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
+use crate::error::ErrorCode;
+use crate::events::bond::ChargeBidEvent;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use crate::utils::minimal_size_stake_account;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar::stake_history;
+use anchor_lang::solana_program::{
+    stake,
+    stake::state::{StakeAuthorize, StakeStateV2},
+};
+use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ChargeBidArgs {
+    /// epoch the bid is charged for
+    pub epoch: u64,
+    /// lamports delegated to the bond vote account in the epoch, the bid is calculated from,
+    /// bounded by the config `bid_max_delegated_lamports`
+    pub delegated_lamports: u64,
+}
+
+/// Charging the bid (cpmpe) of the bond for the finished epoch.
+/// The bid amount is split from the bond stake account and handed over to the bid fee collector.
+/// The bid is charged once per epoch, epochs are charged in ascending order and not before the bond creation.
+/// When the bond stake account is not big enough for the whole bid the bid is charged partially
+/// and the rest of the bid can be charged for the same epoch (e.g., from another bond stake account).
+/// Permission-ed to operator authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ChargeBid<'info> {
+    #[account(
+        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    /// operator signer authority is allowed to charge the bid
+    pub operator_authority: Signer<'info>,
+
+    /// bond stake account the bid is charged from
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// CHECK: PDA
+    /// authority that manages (owns) all stakes account under the bonds program
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: address verified against config
+    /// new staker and withdrawer authority of the charged split stake account
+    #[account(
+        address = config.bid_fee_collector @ ErrorCode::BidFeeCollectorNotConfigured,
+    )]
+    pub bid_fee_collector: UncheckedAccount<'info>,
+
+    /// a new stake account where the charged bid amount is split into
+    #[account(
+        init,
+        payer = split_stake_rent_payer,
+        space = std::mem::size_of::<StakeStateV2>(),
+        owner = stake_program.key(),
+    )]
+    pub split_stake_account: Account<'info, StakeAccount>,
+
+    /// the rent exempt payer of the split_stake_account creation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub split_stake_rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: have no CPU budget to parse
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl<'info> ChargeBid<'info> {
+    pub fn process(
+        ctx: Context<ChargeBid>,
+        ChargeBidArgs {
+            epoch,
+            delegated_lamports,
+        }: ChargeBidArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+        require_keys_neq!(
+            ctx.accounts.config.bid_fee_collector,
+            Pubkey::default(),
+            ErrorCode::BidFeeCollectorNotConfigured
+        );
+        // at latest the previous epoch can be charged
+        require_gt!(
+            ctx.accounts.clock.epoch,
+            epoch,
+            ErrorCode::BidEpochNotFinished
+        );
+        require_gte!(
+            epoch,
+            ctx.accounts.bond.created_epoch,
+            ErrorCode::BidEpochBeforeBondCreation
+        );
+        require_gte!(
+            epoch,
+            ctx.accounts.bond.bid_charged_epoch,
+            ErrorCode::BidAlreadyCharged
+        );
+        require_gte!(
+            ctx.accounts.config.bid_max_delegated_lamports,
+            delegated_lamports,
+            ErrorCode::BidDelegatedLamportsExceeded
+        );
+
+        let bid_amount = ctx.accounts.bond.bid_amount(delegated_lamports);
+        if bid_amount == 0 {
+            return Err(error!(ErrorCode::BidAmountIsZero).with_values((
+                "cpmpe, delegated_lamports",
+                format!("{}, {}", ctx.accounts.bond.cpmpe, delegated_lamports),
+            )));
+        }
+        // the partially charged bid of the epoch is charged up to the bid amount
+        let bid_charged_amount = if epoch == ctx.accounts.bond.bid_charged_epoch {
+            ctx.accounts.bond.bid_charged_amount
+        } else {
+            0
+        };
+        if bid_charged_amount >= bid_amount {
+            return Err(error!(ErrorCode::BidAlreadyCharged).with_values((
+                "bid_charged_amount >= bid_amount",
+                format!("{} >= {}", bid_charged_amount, bid_amount),
+            )));
+        }
+
+        // stake account is managed by bonds program and not funded to any settlement
+        let stake_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &ctx.accounts.stake_account,
+            &ctx.accounts.bonds_withdrawer_authority.key(),
+            "stake_account",
+        )?;
+        require_keys_eq!(
+            stake_meta.authorized.staker,
+            ctx.accounts.bonds_withdrawer_authority.key(),
+            ErrorCode::StakeAccountIsFundedToSettlement,
+        );
+        check_bond_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond)?;
+        check_stake_is_not_locked(
+            &ctx.accounts.stake_account,
+            &ctx.accounts.clock,
+            "stake_account",
+        )?;
+
+        // the stake account has to be capable to exist after the split
+        let stake_account_min_size = minimal_size_stake_account(&stake_meta, &ctx.accounts.config);
+        let amount_available = ctx
+            .accounts
+            .stake_account
+            .get_lamports()
+            .saturating_sub(stake_account_min_size);
+        let charged_amount = (bid_amount - bid_charged_amount).min(amount_available);
+        if charged_amount == 0 {
+            return Err(error!(ErrorCode::StakeAccountNotBigEnoughToSplit)
+                .with_account_name("stake_account")
+                .with_values(("stake_account_min_size", stake_account_min_size)));
+        }
+
+        let config_key = ctx.accounts.config.key();
+        let bonds_withdrawer_authority_seeds: &[&[u8]] = &[
+            BONDS_WITHDRAWER_AUTHORITY_SEED,
+            config_key.as_ref(),
+            &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+        ];
+        let split_instruction = stake::instruction::split(
+            ctx.accounts.stake_account.to_account_info().key,
+            ctx.accounts.bonds_withdrawer_authority.key,
+            charged_amount,
+            &ctx.accounts.split_stake_account.key(),
+        )
+        .last()
+        .unwrap()
+        .clone();
+        invoke_signed(
+            &split_instruction,
+            &[
+                ctx.accounts.stake_program.to_account_info(),
+                ctx.accounts.stake_account.to_account_info(),
+                ctx.accounts.split_stake_account.to_account_info(),
+                ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+            ],
+            &[bonds_withdrawer_authority_seeds],
+        )?;
+
+        // handing over the split stake account to the bid fee collector
+        for stake_authorize in [StakeAuthorize::Staker, StakeAuthorize::Withdrawer] {
+            authorize(
+                CpiContext::new_with_signer(
+                    ctx.accounts.stake_program.to_account_info(),
+                    Authorize {
+                        stake: ctx.accounts.split_stake_account.to_account_info(),
+                        authorized: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                        new_authorized: ctx.accounts.bid_fee_collector.to_account_info(),
+                        clock: ctx.accounts.clock.to_account_info(),
+                    },
+                    &[bonds_withdrawer_authority_seeds],
+                ),
+                stake_authorize,
+                None,
+            )?;
+        }
+
+        ctx.accounts.bond.bid_charged_epoch = epoch;
+        ctx.accounts.bond.bid_charged_amount = bid_charged_amount + charged_amount;
+
+        emit_cpi!(ChargeBidEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.bond.vote_account,
+            epoch,
+            cpmpe: ctx.accounts.bond.cpmpe,
+            max_stake_wanted: ctx.accounts.bond.max_stake_wanted,
+            delegated_lamports,
+            bid_amount,
+            charged_amount,
+            bid_charged_amount: ctx.accounts.bond.bid_charged_amount,
+            stake_account: ctx.accounts.stake_account.key(),
+            split_stake_account: ctx.accounts.split_stake_account.key(),
+            bid_fee_collector: ctx.accounts.bid_fee_collector.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
 use crate::checks::check_bond_authority;
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
 use crate::events::{bond::ConfigureBondEvent, PubkeyValueChange, U64ValueChange};
 use crate::instructions::verify_max_stake_wanted;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 
@@ -53,7 +54,10 @@ impl<'info> ConfigureBond<'info> {
         ctx: Context<ConfigureBond>,
         configure_bond_args: ConfigureBondArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -75,6 +79,7 @@ impl<'info> ConfigureBond<'info> {
         )?;
 
         emit_cpi!(ConfigureBondEvent {
+            version: EVENT_VERSION,
             bond_authority: bond_authority_change,
             cpmpe: cpmpe_change,
             max_stake_wanted: max_stake_wanted_change,
@@ -0,0 +1,87 @@
+use crate::checks::{check_bond_authority, get_validator_vote_account_validator_identity};
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{bond::ConfigureBondAuthorityMintEvent, PubkeyValueChange};
+use crate::state::bond::{find_bond_mint, Bond};
+use crate::state::config::{pause_flags, Config};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ConfigureBondAuthorityMintArgs {
+    /// When true the bond mint of the current validator identity becomes a persistent bond authority credential,
+    /// its holder may configure the bond without burning the token.
+    /// When false the holding mode is switched off and the bond mint token is burnt on configuration.
+    pub enabled: bool,
+}
+
+/// Switching the bond mint token (see mint_bond.rs) to work as a transferable bond authority credential
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureBondAuthorityMint<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// validator vote account validator identity or bond authority may change the account
+    pub authority: Signer<'info>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+}
+
+impl<'info> ConfigureBondAuthorityMint<'info> {
+    pub fn process(
+        ctx: Context<ConfigureBondAuthorityMint>,
+        ConfigureBondAuthorityMintArgs { enabled }: ConfigureBondAuthorityMintArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::BondChangeNotPermitted
+        );
+
+        let new_authority_mint = if enabled {
+            let validator_identity =
+                get_validator_vote_account_validator_identity(&ctx.accounts.vote_account)?;
+            find_bond_mint(&ctx.accounts.bond.key(), &validator_identity).0
+        } else {
+            Pubkey::default()
+        };
+        let old_authority_mint = ctx.accounts.bond.authority_mint;
+        ctx.accounts.bond.authority_mint = new_authority_mint;
+
+        emit_cpi!(ConfigureBondAuthorityMintEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            authority_mint: PubkeyValueChange {
+                old: old_authority_mint,
+                new: new_authority_mint,
+            },
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,10 @@
 use crate::checks::get_validator_vote_account_validator_identity;
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
 use crate::events::bond::ConfigureBondWithMintEvent;
 use crate::instructions::{configure_bond, ConfigureBondArgs, ConfigureBondChanges};
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 use anchor_spl::token::Mint;
@@ -12,6 +13,7 @@ use anchor_spl::token::{burn, Burn, Token, TokenAccount};
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct ConfigureBondWithMintArgs {
     /// Validator identity configured within the vote account.
+    /// For the bond authority mint it is the identity the mint was created for.
     pub validator_identity: Pubkey,
     /// New bond authority that can manage the bond account.
     pub bond_authority: Option<Pubkey>,
@@ -23,7 +25,9 @@ pub struct ConfigureBondWithMintArgs {
     pub max_stake_wanted: Option<u64>,
 }
 
-/// Change parameters of validator bond account with token burning
+/// Change parameters of validator bond account with token burning.
+/// When the mint is configured as the bond authority mint (see configure_bond_authority_mint.rs)
+/// the token is not burnt, holding it is enough to change the bond.
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: ConfigureBondWithMintArgs)]
@@ -61,7 +65,7 @@ pub struct ConfigureBondWithMint<'info> {
     )]
     pub vote_account: UncheckedAccount<'info>,
 
-    /// token account to burn bond mint configuration tokens from
+    /// token account to burn bond mint configuration tokens from (or holding the bond authority mint token)
     #[account(
         mut,
         token::mint = mint,
@@ -79,16 +83,32 @@ impl<'info> ConfigureBondWithMint<'info> {
         ctx: Context<ConfigureBondWithMint>,
         configure_bond_mint_args: ConfigureBondWithMintArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
-
-        let validator_identity_vote_account =
-            get_validator_vote_account_validator_identity(&ctx.accounts.vote_account)?;
-        require_keys_eq!(
-            configure_bond_mint_args.validator_identity,
-            validator_identity_vote_account,
-            ErrorCode::ValidatorIdentityBondMintMismatch
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
         );
 
+        let is_authority_mint = ctx
+            .accounts
+            .bond
+            .is_authority_mint(&ctx.accounts.mint.key());
+        if is_authority_mint {
+            // the credential persists over the validator identity change
+            require_gt!(
+                ctx.accounts.token_account.amount,
+                0,
+                ErrorCode::BondAuthorityMintNotHeld
+            );
+        } else {
+            let validator_identity_vote_account =
+                get_validator_vote_account_validator_identity(&ctx.accounts.vote_account)?;
+            require_keys_eq!(
+                configure_bond_mint_args.validator_identity,
+                validator_identity_vote_account,
+                ErrorCode::ValidatorIdentityBondMintMismatch
+            );
+        }
+
         let ConfigureBondChanges {
             bond_authority_change,
             cpmpe_change,
@@ -103,20 +123,24 @@ impl<'info> ConfigureBondWithMint<'info> {
             },
         )?;
 
-        burn(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Burn {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    from: ctx.accounts.token_account.to_account_info(),
-                    authority: ctx.accounts.token_authority.to_account_info(),
-                },
-            ),
-            1,
-        )?;
+        if !is_authority_mint {
+            burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.token_account.to_account_info(),
+                        authority: ctx.accounts.token_authority.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+        }
 
         emit_cpi!(ConfigureBondWithMintEvent {
+            version: EVENT_VERSION,
             validator_identity: configure_bond_mint_args.validator_identity,
+            token_burned: !is_authority_mint,
             bond_authority: bond_authority_change,
             cpmpe: cpmpe_change,
             max_stake_wanted: max_stake_wanted_change,
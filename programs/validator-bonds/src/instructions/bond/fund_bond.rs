@@ -1,11 +1,12 @@
 use crate::checks::{
-    check_stake_exist_and_fully_activated, check_stake_is_initialized_with_withdrawer_authority,
-    check_stake_is_not_locked, check_stake_valid_delegation,
+    check_bond_stake_valid_delegation, check_stake_exist_and_fully_activated,
+    check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
 };
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
 use crate::events::bond::FundBondEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::stake::state::StakeAuthorize;
 use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
@@ -58,7 +59,10 @@ pub struct FundBond<'info> {
 
 impl<'info> FundBond<'info> {
     pub fn process(ctx: Context<FundBond>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
 
         // check current stake account withdrawer authority with permission to authorize
         check_stake_is_initialized_with_withdrawer_authority(
@@ -77,7 +81,7 @@ impl<'info> FundBond<'info> {
             ctx.accounts.clock.epoch,
             &ctx.accounts.stake_history,
         )?;
-        check_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond.vote_account)?;
+        check_bond_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond)?;
 
         // when the stake account is already "owned" by the bonds program, return OK
         if check_stake_is_initialized_with_withdrawer_authority(
@@ -124,6 +128,7 @@ impl<'info> FundBond<'info> {
         )?;
 
         emit_cpi!(FundBondEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.bond.key(),
             vote_account: ctx.accounts.bond.vote_account.key(),
             stake_account: ctx.accounts.stake_account.key(),
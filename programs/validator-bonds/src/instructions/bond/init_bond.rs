@@ -1,10 +1,12 @@
 use crate::checks::{
     check_vote_account_validator_identity, get_validator_vote_account_validator_identity,
+    is_uninitialized,
 };
+use crate::constants::{ACCOUNT_VERSION, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::bond::InitBondEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 
@@ -45,6 +47,19 @@ pub struct InitBond<'info> {
     )]
     pub bond: Account<'info, Bond>,
 
+    /// CHECK: PDA, checked in code to be uninitialized
+    /// alias of the vote account created on bond migration, no bond may be created
+    /// for the vote account another bond was migrated to
+    #[account(
+        seeds = [
+            b"bond_alias",
+            config.key().as_ref(),
+            vote_account.key().as_ref()
+        ],
+        bump,
+    )]
+    pub bond_alias: UncheckedAccount<'info>,
+
     /// rent exempt payer of validator bond account creation
     #[account(
         mut,
@@ -64,7 +79,14 @@ impl<'info> InitBond<'info> {
             max_stake_wanted,
         }: InitBondArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
+        require!(
+            is_uninitialized(&ctx.accounts.bond_alias),
+            ErrorCode::BondAlreadyExists
+        );
 
         let mut cpmpe = cpmpe;
         let mut bond_authority = bond_authority;
@@ -98,9 +120,16 @@ impl<'info> InitBond<'info> {
             cpmpe,
             max_stake_wanted,
             bump: ctx.bumps.bond,
-            reserved: [0; 134],
+            bid_charged_epoch: 0,
+            bid_charged_amount: 0,
+            migrated_vote_account: Pubkey::default(),
+            authority_mint: Pubkey::default(),
+            account_version: ACCOUNT_VERSION,
+            created_epoch: Clock::get()?.epoch,
+            reserved: [0; 45],
         });
         emit_cpi!(InitBondEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.bond.key(),
             config: ctx.accounts.bond.config,
             vote_account: ctx.accounts.bond.vote_account,
@@ -0,0 +1,171 @@
+use crate::checks::{check_vote_accounts_same_owner, is_uninitialized};
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{bond::MigrateBondEvent, PubkeyValueChange};
+use crate::state::bond::Bond;
+use crate::state::bond_alias::BondAlias;
+use crate::state::config::{pause_flags, Config};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+/// Migrating the bond to a new validator vote account.
+/// The bond address stays derived from the original vote account, the new vote account is stored
+/// at the bond and the bonded stake accounts are then redelegated by the permission-less redelegate stake instruction.
+/// The bond alias account is created for the new vote account, no other bond can be initialized for it then.
+/// Permission-ed to validator identity or authorized withdrawer of both vote accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateBond<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    /// the vote account the bond stake accounts are currently delegated to
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+        constraint = bond.stake_vote_account() == vote_account.key() @ ErrorCode::VoteAccountMismatch,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: check&deserialize the vote account in the code
+    /// the vote account the bond is migrated to
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub new_vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: PDA, checked in code to be uninitialized
+    /// bond address of the new vote account, no bond may exist for the vote account the bond is migrated to
+    #[account(
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            new_vote_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub new_vote_account_bond: UncheckedAccount<'info>,
+
+    /// alias of the vote account the bond is currently migrated to, closed on the migration
+    /// required when the bond has been already migrated
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = bond @ ErrorCode::BondAliasMismatch,
+        constraint = old_bond_alias.vote_account == bond.migrated_vote_account @ ErrorCode::BondAliasMismatch,
+        seeds = [
+            b"bond_alias",
+            config.key().as_ref(),
+            bond.migrated_vote_account.as_ref(),
+        ],
+        bump = old_bond_alias.bump,
+    )]
+    pub old_bond_alias: Option<Account<'info, BondAlias>>,
+
+    /// alias of the vote account the bond is migrated to
+    /// required when the bond is not migrated back to the original vote account
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<BondAlias>(),
+        seeds = [
+            b"bond_alias",
+            config.key().as_ref(),
+            new_vote_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub new_bond_alias: Option<Account<'info, BondAlias>>,
+
+    /// validator identity or authorized withdrawer of both vote accounts
+    pub authority: Signer<'info>,
+
+    /// rent exempt payer of the bond alias account creation, refunded on the alias close
+    #[account(
+        mut,
+        owner = system_program.key()
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBond<'info> {
+    pub fn process(ctx: Context<MigrateBond>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
+
+        check_vote_accounts_same_owner(
+            &ctx.accounts.authority.key(),
+            &ctx.accounts.vote_account,
+            &ctx.accounts.new_vote_account,
+        )?;
+
+        let old_vote_account = ctx.accounts.bond.stake_vote_account();
+        let new_vote_account = ctx.accounts.new_vote_account.key();
+        require_keys_neq!(
+            old_vote_account,
+            new_vote_account,
+            ErrorCode::BondAlreadyMigrated
+        );
+
+        // the alias of the vote account the bond is migrated from is closed by the account constraint
+        require_eq!(
+            ctx.accounts.old_bond_alias.is_some(),
+            ctx.accounts.bond.migrated_vote_account != Pubkey::default(),
+            ErrorCode::BondAliasMismatch
+        );
+
+        // the original vote account is derived to the migrated bond itself
+        let is_migrated_back = new_vote_account == ctx.accounts.bond.vote_account;
+        require_eq!(
+            ctx.accounts.new_bond_alias.is_some(),
+            !is_migrated_back,
+            ErrorCode::BondAliasMismatch
+        );
+        if let Some(new_bond_alias) = &mut ctx.accounts.new_bond_alias {
+            require!(
+                is_uninitialized(&ctx.accounts.new_vote_account_bond),
+                ErrorCode::BondAlreadyExists
+            );
+            new_bond_alias.set_inner(BondAlias {
+                bond: ctx.accounts.bond.key(),
+                vote_account: new_vote_account,
+                bump: ctx.bumps.new_bond_alias,
+                reserved: [0; 32],
+            });
+        }
+        // migrating back to the original vote account removes the alias
+        ctx.accounts.bond.migrated_vote_account = if is_migrated_back {
+            Pubkey::default()
+        } else {
+            new_vote_account
+        };
+
+        emit_cpi!(MigrateBondEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            bond_vote_account: ctx.accounts.bond.vote_account,
+            vote_account: PubkeyValueChange {
+                old: old_vote_account,
+                new: new_vote_account,
+            },
+            authority: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+}
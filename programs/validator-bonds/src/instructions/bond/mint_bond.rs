@@ -1,9 +1,9 @@
 use crate::checks::get_validator_vote_account_validator_identity;
-use crate::constants::BOND_MINT_SEED;
+use crate::constants::{BOND_MINT_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::bond::MintBondEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use anchor_lang::prelude::*;
 
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
@@ -12,13 +12,14 @@ use anchor_spl::{
     metadata::{
         create_metadata_accounts_v3,
         mpl_token_metadata::types::{Creator, DataV2},
-        CreateMetadataAccountsV3, Metadata,
+        update_metadata_accounts_v2, CreateMetadataAccountsV3, Metadata, UpdateMetadataAccountsV2,
     },
     token::{mint_to, Mint, MintTo, Token, TokenAccount},
 };
 
 /// Minting a bond SPL token that can be used for configuring the bond account.
-// see configure_mint_bond.rs
+/// The token metadata names the bond and its vote account, existing metadata is updated to the current format.
+// see configure_bond_with_mint.rs and configure_bond_authority_mint.rs
 #[event_cpi]
 #[derive(Accounts)]
 pub struct MintBond<'info> {
@@ -91,7 +92,10 @@ pub struct MintBond<'info> {
 
 impl<'info> MintBond<'info> {
     pub fn process(ctx: Context<MintBond>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
 
         let validator_identity_vote_account =
             get_validator_vote_account_validator_identity(&ctx.accounts.vote_account)?;
@@ -125,6 +129,8 @@ impl<'info> MintBond<'info> {
             1,
         )?;
 
+        let metadata =
+            bond_token_metadata(&ctx.accounts.bond.key(), &ctx.accounts.vote_account.key());
         if ctx.accounts.metadata.get_lamports() == 0 {
             create_metadata_accounts_v3(
                 CpiContext::new_with_signer(
@@ -140,26 +146,31 @@ impl<'info> MintBond<'info> {
                     },
                     &mint_signer,
                 ),
-                DataV2 {
-                    name: "Validator Bonds".to_string(),
-                    symbol: "VBOND".to_string(),
-                    uri: "https://github.com/marinade-finance/validator-bonds".to_string(),
-                    seller_fee_basis_points: 0,
-                    creators: Some(vec![Creator {
-                        address: ctx.accounts.bond.key(),
-                        verified: false,
-                        share: 100,
-                    }]),
-                    collection: None,
-                    uses: None,
-                },
+                metadata,
                 false,
                 true,
                 None,
             )?;
+        } else {
+            // metadata created before the per bond metadata was introduced
+            update_metadata_accounts_v2(
+                CpiContext::new_with_signer(
+                    ctx.accounts.metadata_program.to_account_info(),
+                    UpdateMetadataAccountsV2 {
+                        metadata: ctx.accounts.metadata.to_account_info(),
+                        update_authority: ctx.accounts.mint.to_account_info(),
+                    },
+                    &mint_signer,
+                ),
+                None,
+                Some(metadata),
+                None,
+                None,
+            )?;
         }
 
         emit_cpi!(MintBondEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.bond.key(),
             validator_identity_token_account: ctx.accounts.validator_identity_token_account.key(),
             validator_identity: ctx.accounts.validator_identity.key(),
@@ -169,3 +180,25 @@ impl<'info> MintBond<'info> {
         Ok(())
     }
 }
+
+/// Token metadata of the bond mint, the name is limited to 32 characters
+/// and so only the vote account prefix is shown there, the full addresses are part of the uri.
+fn bond_token_metadata(bond: &Pubkey, vote_account: &Pubkey) -> DataV2 {
+    let vote_account_str = vote_account.to_string();
+    DataV2 {
+        name: format!("VBOND {}", &vote_account_str[..8]),
+        symbol: "VBOND".to_string(),
+        uri: format!(
+            "https://github.com/marinade-finance/validator-bonds?bond={}&vote_account={}",
+            bond, vote_account_str
+        ),
+        seller_fee_basis_points: 0,
+        creators: Some(vec![Creator {
+            address: *bond,
+            verified: false,
+            share: 100,
+        }]),
+        collection: None,
+        uses: None,
+    }
+}
@@ -1,12 +1,18 @@
 pub mod init_bond;
 
+pub mod charge_bid;
 pub mod configure_bond;
+pub mod configure_bond_authority_mint;
 pub mod configure_bond_with_mint;
 pub mod fund_bond;
+pub mod migrate_bond;
 pub mod mint_bond;
 
+pub use charge_bid::*;
 pub use configure_bond::*;
+pub use configure_bond_authority_mint::*;
 pub use configure_bond_with_mint::*;
 pub use fund_bond::*;
 pub use init_bond::*;
+pub use migrate_bond::*;
 pub use mint_bond::*;
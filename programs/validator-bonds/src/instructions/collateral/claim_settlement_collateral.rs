@@ -0,0 +1,282 @@
+use crate::checks::check_settlement_claim;
+use crate::constants::{ACCOUNT_VERSION, EVENT_VERSION, SETTLEMENT_STAKER_AUTHORITY_SEED};
+use crate::error::ErrorCode;
+use crate::events::collateral::ClaimSettlementCollateralEvent;
+use crate::events::U64ValueChange;
+use crate::instructions::ClaimSettlementArgs;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement::Settlement;
+use crate::state::settlement_claim::SettlementClaim;
+use crate::state::settlement_collateral::SettlementCollateral;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+/// Claims a settlement from the token collateral funded to the settlement.
+/// The claim in lamports is paid in tokens pro rata to the tokens funded to the settlement
+/// to a token account owned by the withdrawer authority of the merkle tree record.
+/// The claimer fee (see `Config::claimer_fee`) is paid in tokens to the rent payer token account when provided.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ClaimSettlementArgs)]
+pub struct ClaimSettlementCollateral<'info> {
+    /// the config account under which the settlement was created
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = settlement.expiration_epoch(config.epochs_to_claim_settlement) >= clock.epoch @ ErrorCode::SettlementExpired,
+        constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming <= clock.slot @ ErrorCode::SettlementNotReadyForClaiming,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.merkle_root.as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Box<Account<'info, Settlement>>,
+
+    /// deduplication, merkle tree record cannot be claimed twice
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<SettlementClaim>(),
+        seeds = [
+            b"claim_account",
+            settlement.key().as_ref(),
+            params.tree_node_hash.as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement_claim: Box<Account<'info, SettlementClaim>>,
+
+    /// tokens funded to the settlement, the claim is paid pro rata from them
+    #[account(
+        mut,
+        has_one = settlement @ ErrorCode::SettlementAccountMismatch,
+        has_one = mint @ ErrorCode::InvalidCollateralMintAddress,
+        seeds = [
+            b"settlement_collateral",
+            settlement.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = settlement_collateral.bump,
+    )]
+    pub settlement_collateral: Box<Account<'info, SettlementCollateral>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA
+    /// owner of the settlement collateral vault
+    #[account(
+        seeds = [
+            b"settlement_authority",
+            settlement.key().as_ref(),
+        ],
+        bump = settlement.bumps.staker_authority,
+    )]
+    pub settlement_staker_authority: UncheckedAccount<'info>,
+
+    /// settlement collateral vault the claim is paid from
+    #[account(
+        mut,
+        seeds = [
+            b"collateral_vault",
+            settlement.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = settlement_staker_authority,
+    )]
+    pub settlement_collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// a token account that will receive the tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = params.stake_account_withdrawer,
+    )]
+    pub claimer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// a token account of the rent payer that receives the claimer fee, no fee is paid when not provided
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = rent_payer,
+    )]
+    pub rent_payer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// upon claiming, a claim account is created to confirm the occurrence of the claim
+    /// when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here
+    #[account(
+        mut,
+        owner = system_program.key()
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ClaimSettlementCollateral<'info> {
+    pub fn process(
+        ctx: Context<ClaimSettlementCollateral>,
+        ClaimSettlementArgs {
+            proof,
+            tree_node_hash,
+            claim,
+            stake_account_staker,
+            stake_account_withdrawer,
+        }: ClaimSettlementArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::CLAIM),
+            ErrorCode::ProgramIsPaused
+        );
+
+        check_settlement_claim(
+            &ctx.accounts.settlement,
+            proof,
+            tree_node_hash,
+            stake_account_staker,
+            stake_account_withdrawer,
+            claim,
+        )?;
+
+        if ctx.accounts.settlement_collateral.lamports_claimed + claim
+            > ctx.accounts.settlement_collateral.lamports_funded
+        {
+            return Err(error!(ErrorCode::CollateralClaimExceedsFunded)
+                .with_account_name("settlement_collateral")
+                .with_values((
+                    "lamports_claimed + claim > lamports_funded",
+                    format!(
+                        "{} + {} > {}",
+                        ctx.accounts.settlement_collateral.lamports_claimed,
+                        claim,
+                        ctx.accounts.settlement_collateral.lamports_funded
+                    ),
+                )));
+        }
+
+        // paid at the valuation the settlement was funded with, not at the current collateral mint price
+        let token_amount = ctx.accounts.settlement_collateral.tokens_of_claim(claim);
+        require_gt!(token_amount, 0, ErrorCode::CollateralClaimTooSmall);
+        if token_amount > ctx.accounts.settlement_collateral_vault.amount {
+            return Err(error!(ErrorCode::CollateralInsufficient)
+                .with_account_name("settlement_collateral_vault")
+                .with_values((
+                    "token_amount > vault_amount",
+                    format!(
+                        "{} > {}",
+                        token_amount, ctx.accounts.settlement_collateral_vault.amount
+                    ),
+                )));
+        }
+
+        // claimer fee is taken pro rata from the paid tokens
+        let claimer_fee = if ctx.accounts.rent_payer_token_account.is_some() {
+            ctx.accounts.config.claimer_fee(claim)
+        } else {
+            0
+        };
+        let claimer_fee_tokens =
+            (token_amount as u128 * claimer_fee as u128 / claim as u128) as u64;
+
+        ctx.accounts.settlement_claim.set_inner(SettlementClaim {
+            settlement: ctx.accounts.settlement.key(),
+            stake_account_to: ctx.accounts.claimer_token_account.key(),
+            stake_account_staker,
+            stake_account_withdrawer,
+            amount: claim,
+            bump: ctx.bumps.settlement_claim,
+            rent_collector: ctx.accounts.rent_payer.key(),
+            account_version: ACCOUNT_VERSION,
+            reserved: [0; 92],
+        });
+
+        let settlement_key = ctx.accounts.settlement.key();
+        let settlement_staker_authority_seeds: &[&[u8]] = &[
+            SETTLEMENT_STAKER_AUTHORITY_SEED,
+            settlement_key.as_ref(),
+            &[ctx.accounts.settlement.bumps.staker_authority],
+        ];
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.settlement_collateral_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.claimer_token_account.to_account_info(),
+                    authority: ctx.accounts.settlement_staker_authority.to_account_info(),
+                },
+                &[settlement_staker_authority_seeds],
+            ),
+            token_amount - claimer_fee_tokens,
+            ctx.accounts.mint.decimals,
+        )?;
+        if let Some(rent_payer_token_account) = &ctx.accounts.rent_payer_token_account {
+            if claimer_fee_tokens > 0 {
+                transfer_checked(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: ctx.accounts.settlement_collateral_vault.to_account_info(),
+                            mint: ctx.accounts.mint.to_account_info(),
+                            to: rent_payer_token_account.to_account_info(),
+                            authority: ctx.accounts.settlement_staker_authority.to_account_info(),
+                        },
+                        &[settlement_staker_authority_seeds],
+                    ),
+                    claimer_fee_tokens,
+                    ctx.accounts.mint.decimals,
+                )?;
+            }
+        }
+
+        ctx.accounts.settlement.lamports_claimed += claim;
+        ctx.accounts.settlement.collateral_claimed += claim;
+        ctx.accounts.settlement.merkle_nodes_claimed += 1;
+        ctx.accounts.settlement_collateral.lamports_claimed += claim;
+        ctx.accounts.settlement_collateral.tokens_claimed += token_amount;
+
+        emit_cpi!(ClaimSettlementCollateralEvent {
+            version: EVENT_VERSION,
+            settlement: ctx.accounts.settlement_claim.settlement,
+            settlement_claim: ctx.accounts.settlement_claim.key(),
+            mint: ctx.accounts.mint.key(),
+            claimer_token_account: ctx.accounts.claimer_token_account.key(),
+            stake_account_staker,
+            stake_account_withdrawer,
+            amount: claim,
+            token_amount,
+            settlement_lamports_claimed: U64ValueChange {
+                old: ctx.accounts.settlement.lamports_claimed - claim,
+                new: ctx.accounts.settlement.lamports_claimed,
+            },
+            settlement_merkle_nodes_claimed: ctx.accounts.settlement.merkle_nodes_claimed,
+            rent_collector: ctx.accounts.settlement_claim.rent_collector,
+            claimer_fee,
+            claimer_fee_tokens,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use crate::checks::check_bond_authority;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
+use crate::error::ErrorCode;
+use crate::events::collateral::ClaimWithdrawCollateralEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::{pause_flags, Config};
+use crate::state::withdraw_request::WithdrawRequest;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+/// Withdrawing the token collateral of the bond based on the withdraw request.
+/// The tokens are valued at the collateral mint price and accounted to the withdraw request amount,
+/// the same as stake accounts are by the claim withdraw request instruction.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimWithdrawCollateral<'info> {
+    /// the config root configuration account
+    config: Box<Account<'info, Config>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    /// CHECK: deserialization of the vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// validator vote account node identity or bond authority may claim
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = vote_account @ ErrorCode::WithdrawRequestVoteAccountMismatch,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = withdraw_request.epoch + config.withdraw_lockup_epochs < clock.epoch @ ErrorCode::WithdrawRequestNotReady,
+        seeds = [
+            b"withdraw_account",
+            bond.key().as_ref(),
+        ],
+        bump = withdraw_request.bump
+    )]
+    pub withdraw_request: Box<Account<'info, WithdrawRequest>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::InvalidCollateralMintAddress,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Box<Account<'info, CollateralMint>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// bond collateral vault the tokens are withdrawn from
+    #[account(
+        mut,
+        seeds = [
+            b"collateral_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// whatever token account of the mint, authority signature states his intention to withdraw the tokens there
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub withdrawer_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> ClaimWithdrawCollateral<'info> {
+    pub fn process(ctx: Context<ClaimWithdrawCollateral>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
+
+        // the amount that has not yet been withdrawn from the request
+        let amount_to_fulfill_withdraw = ctx
+            .accounts
+            .withdraw_request
+            .requested_amount
+            .saturating_sub(ctx.accounts.withdraw_request.withdrawn_amount);
+        require_gt!(
+            amount_to_fulfill_withdraw,
+            0,
+            ErrorCode::WithdrawRequestAlreadyFulfilled,
+        );
+
+        // claim is permission-ed as the init withdraw request
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::InvalidWithdrawRequestAuthority
+        );
+
+        let withdrawing_token_amount = ctx
+            .accounts
+            .collateral_mint
+            .tokens_for_market_value(amount_to_fulfill_withdraw)
+            .min(ctx.accounts.collateral_vault.amount);
+        let withdrawing_amount = ctx
+            .accounts
+            .collateral_mint
+            .market_value(withdrawing_token_amount);
+        if withdrawing_token_amount == 0 {
+            return Err(error!(ErrorCode::CollateralInsufficient)
+                .with_account_name("collateral_vault")
+                .with_values(("vault_amount", ctx.accounts.collateral_vault.amount)));
+        }
+
+        let old_withdrawn_amount = ctx.accounts.withdraw_request.withdrawn_amount;
+        ctx.accounts.withdraw_request.withdrawn_amount = ctx
+            .accounts
+            .withdraw_request
+            .withdrawn_amount
+            .saturating_add(withdrawing_amount);
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.collateral_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.withdrawer_token_account.to_account_info(),
+                    authority: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                },
+                &[&[
+                    BONDS_WITHDRAWER_AUTHORITY_SEED,
+                    &ctx.accounts.config.key().as_ref(),
+                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+                ]],
+            ),
+            withdrawing_token_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        emit_cpi!(ClaimWithdrawCollateralEvent {
+            version: EVENT_VERSION,
+            withdraw_request: ctx.accounts.withdraw_request.key(),
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.vote_account.key(),
+            mint: ctx.accounts.mint.key(),
+            collateral_vault: ctx.accounts.collateral_vault.key(),
+            withdrawer_token_account: ctx.accounts.withdrawer_token_account.key(),
+            withdrawing_token_amount,
+            withdrawing_amount,
+            withdrawn_amount: U64ValueChange {
+                old: old_withdrawn_amount,
+                new: ctx.accounts.withdraw_request.withdrawn_amount,
+            },
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::constants::{EVENT_VERSION, MAX_HAIRCUT_BPS};
+use crate::error::ErrorCode;
+use crate::events::{collateral::ConfigureCollateralMintEvent, U64ValueChange};
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ConfigureCollateralMintArgs {
+    pub lamports_per_token: Option<u64>,
+    pub haircut_bps: Option<u16>,
+}
+
+/// Configures the price and the haircut of the whitelisted collateral mint.
+/// Permission-ed to the admin authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureCollateralMint<'info> {
+    #[account(
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can change the collateral mint params
+    pub admin_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            collateral_mint.mint.as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+}
+
+impl<'info> ConfigureCollateralMint<'info> {
+    pub fn process(
+        ctx: Context<ConfigureCollateralMint>,
+        ConfigureCollateralMintArgs {
+            lamports_per_token,
+            haircut_bps,
+        }: ConfigureCollateralMintArgs,
+    ) -> Result<()> {
+        let collateral_mint = &mut ctx.accounts.collateral_mint;
+
+        let lamports_per_token_change = if let Some(lamports_per_token) = lamports_per_token {
+            let old = collateral_mint.lamports_per_token;
+            collateral_mint.lamports_per_token = lamports_per_token;
+            Some(U64ValueChange {
+                old,
+                new: lamports_per_token,
+            })
+        } else {
+            None
+        };
+
+        let haircut_bps_change = if let Some(haircut_bps) = haircut_bps {
+            require_gte!(
+                MAX_HAIRCUT_BPS,
+                haircut_bps,
+                ErrorCode::InvalidCollateralHaircut
+            );
+            let old = collateral_mint.haircut_bps;
+            collateral_mint.haircut_bps = haircut_bps;
+            Some(U64ValueChange {
+                old: old as u64,
+                new: haircut_bps as u64,
+            })
+        } else {
+            None
+        };
+
+        emit_cpi!(ConfigureCollateralMintEvent {
+            version: EVENT_VERSION,
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            mint: ctx.accounts.collateral_mint.mint,
+            lamports_per_token: lamports_per_token_change,
+            haircut_bps: haircut_bps_change,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::collateral::FundBondCollateralEvent;
+use crate::state::bond::Bond;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::{pause_flags, Config};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct FundBondCollateralArgs {
+    /// amount of tokens (in base units) to be deposited
+    pub amount: u64,
+}
+
+/// Funds the bond with tokens of a whitelisted collateral mint.
+/// The tokens are deposited to the bond collateral vault owned by the bonds withdrawer authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundBondCollateral<'info> {
+    pub config: Account<'info, Config>,
+
+    /// bond account to be deposited to with the provided tokens
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::InvalidCollateralMintAddress,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: PDA
+    /// owner of the collateral vault, it's the bonds withdrawer authority
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump,
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// bond collateral vault for the mint, created on the first deposit
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        seeds = [
+            b"collateral_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// token account the tokens are deposited from
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// authority signature permitting to transfer the tokens from the token account
+    pub token_authority: Signer<'info>,
+
+    /// rent exempt payer of the collateral vault creation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundBondCollateral<'info> {
+    pub fn process(
+        ctx: Context<FundBondCollateral>,
+        FundBondCollateralArgs { amount }: FundBondCollateralArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::BOND),
+            ErrorCode::ProgramIsPaused
+        );
+        require_gt!(amount, 0, ErrorCode::CollateralAmountTooSmall);
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.collateral_vault.to_account_info(),
+                    authority: ctx.accounts.token_authority.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        ctx.accounts.collateral_vault.reload()?;
+
+        emit_cpi!(FundBondCollateralEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            vote_account: ctx.accounts.bond.vote_account,
+            mint: ctx.accounts.mint.key(),
+            collateral_vault: ctx.accounts.collateral_vault.key(),
+            token_authority: ctx.accounts.token_authority.key(),
+            deposited_amount: amount,
+            vault_amount: ctx.accounts.collateral_vault.amount,
+            collateral_value: ctx
+                .accounts
+                .collateral_mint
+                .collateral_value(ctx.accounts.collateral_vault.amount),
+        });
+
+        Ok(())
+    }
+}
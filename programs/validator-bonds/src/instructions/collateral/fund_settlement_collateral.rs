@@ -0,0 +1,216 @@
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
+use crate::error::ErrorCode;
+use crate::events::collateral::FundSettlementCollateralEvent;
+use crate::state::bond::Bond;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement::Settlement;
+use crate::state::settlement_collateral::SettlementCollateral;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+
+/// Funding the settlement from the bond token collateral, used when the bond stake accounts are insufficient.
+/// The collateral is credited to the settlement with its haircut value,
+/// the tokens are moved to the settlement collateral vault where the claims are paid from.
+/// Permission-ed to the settlement funder role of the operator.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundSettlementCollateral<'info> {
+    #[account(
+        constraint = config.settlement_funder() == operator_authority.key() @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.merkle_root.as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Box<Account<'info, Settlement>>,
+
+    /// operator signer authority is allowed to fund the settlement account
+    pub operator_authority: Signer<'info>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::InvalidCollateralMintAddress,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Box<Account<'info, CollateralMint>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA
+    /// authority that manages (owns) the bond collateral vaults
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// bond collateral vault the settlement is funded from
+    #[account(
+        mut,
+        seeds = [
+            b"collateral_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA
+    /// owner of the settlement collateral vault
+    #[account(
+        seeds = [
+            b"settlement_authority",
+            settlement.key().as_ref(),
+        ],
+        bump = settlement.bumps.staker_authority,
+    )]
+    pub settlement_staker_authority: UncheckedAccount<'info>,
+
+    /// settlement collateral vault the claims are paid from
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        seeds = [
+            b"collateral_vault",
+            settlement.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = settlement_staker_authority,
+    )]
+    pub settlement_collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// tokens and their lamports value funded to the settlement, the claims are paid pro rata from them
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<SettlementCollateral>(),
+        seeds = [
+            b"settlement_collateral",
+            settlement.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement_collateral: Box<Account<'info, SettlementCollateral>>,
+
+    /// rent exempt payer of the settlement collateral vault and the settlement collateral creation,
+    /// the rent is returned to the settlement funder when the collateral is reset after the settlement is closed
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundSettlementCollateral<'info> {
+    pub fn process(ctx: Context<FundSettlementCollateral>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        if ctx.accounts.settlement.lamports_funded >= ctx.accounts.settlement.max_total_claim {
+            msg!("Settlement is already fully funded");
+            return Ok(());
+        }
+
+        let amount_needed =
+            ctx.accounts.settlement.max_total_claim - ctx.accounts.settlement.lamports_funded;
+        let funding_token_amount = ctx
+            .accounts
+            .collateral_mint
+            .tokens_for_collateral_value(amount_needed)
+            .min(ctx.accounts.collateral_vault.amount);
+        // rounding up the token amount may overflow the needed amount
+        let funding_amount = ctx
+            .accounts
+            .collateral_mint
+            .collateral_value(funding_token_amount)
+            .min(amount_needed);
+        if funding_amount == 0 {
+            return Err(error!(ErrorCode::CollateralInsufficient)
+                .with_account_name("collateral_vault")
+                .with_values(("vault_amount", ctx.accounts.collateral_vault.amount)));
+        }
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.collateral_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.settlement_collateral_vault.to_account_info(),
+                    authority: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                },
+                &[&[
+                    BONDS_WITHDRAWER_AUTHORITY_SEED,
+                    &ctx.accounts.config.key().as_ref(),
+                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+                ]],
+            ),
+            funding_token_amount,
+            ctx.accounts.mint.decimals,
+        )?;
+
+        ctx.accounts.settlement.lamports_funded += funding_amount;
+        ctx.accounts.settlement.collateral_funded += funding_amount;
+        let settlement_collateral = &mut ctx.accounts.settlement_collateral;
+        settlement_collateral.settlement = ctx.accounts.settlement.key();
+        settlement_collateral.mint = ctx.accounts.mint.key();
+        settlement_collateral.bump = ctx.bumps.settlement_collateral;
+        settlement_collateral.tokens_funded += funding_token_amount;
+        settlement_collateral.lamports_funded += funding_amount;
+
+        emit_cpi!(FundSettlementCollateralEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            mint: ctx.accounts.mint.key(),
+            settlement_collateral_vault: ctx.accounts.settlement_collateral_vault.key(),
+            funding_token_amount,
+            funding_amount,
+            lamports_funded: ctx.accounts.settlement.lamports_funded,
+            collateral_funded: ctx.accounts.settlement.collateral_funded,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::constants::{EVENT_VERSION, MAX_HAIRCUT_BPS};
+use crate::error::ErrorCode;
+use crate::events::collateral::InitCollateralMintEvent;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitCollateralMintArgs {
+    /// price of one token in lamports
+    pub lamports_per_token: u64,
+    /// haircut in basis points deducted from the price when valuing the collateral
+    pub haircut_bps: u16,
+}
+
+/// Whitelisting an SPL token mint (e.g., liquid staking token) to be used as the bond collateral.
+/// Permission-ed to the admin authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitCollateralMint<'info> {
+    #[account(
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// only the admin authority can whitelist the collateral mint
+    pub admin_authority: Signer<'info>,
+
+    /// the token mint to be whitelisted
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + std::mem::size_of::<CollateralMint>(),
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub collateral_mint: Account<'info, CollateralMint>,
+
+    /// rent exempt payer of the collateral mint account creation
+    #[account(
+        mut,
+        owner = system_program.key(),
+    )]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitCollateralMint<'info> {
+    pub fn process(
+        ctx: Context<InitCollateralMint>,
+        InitCollateralMintArgs {
+            lamports_per_token,
+            haircut_bps,
+        }: InitCollateralMintArgs,
+    ) -> Result<()> {
+        require_gte!(
+            MAX_HAIRCUT_BPS,
+            haircut_bps,
+            ErrorCode::InvalidCollateralHaircut
+        );
+
+        ctx.accounts.collateral_mint.set_inner(CollateralMint {
+            config: ctx.accounts.config.key(),
+            mint: ctx.accounts.mint.key(),
+            decimals: ctx.accounts.mint.decimals,
+            lamports_per_token,
+            haircut_bps,
+            bump: ctx.bumps.collateral_mint,
+            reserved: [0; 64],
+        });
+
+        emit_cpi!(InitCollateralMintEvent {
+            version: EVENT_VERSION,
+            config: ctx.accounts.collateral_mint.config,
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            mint: ctx.accounts.collateral_mint.mint,
+            decimals: ctx.accounts.collateral_mint.decimals,
+            lamports_per_token,
+            haircut_bps,
+        });
+
+        Ok(())
+    }
+}
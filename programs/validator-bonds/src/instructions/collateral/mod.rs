@@ -0,0 +1,15 @@
+pub mod claim_settlement_collateral;
+pub mod claim_withdraw_collateral;
+pub mod configure_collateral_mint;
+pub mod fund_bond_collateral;
+pub mod fund_settlement_collateral;
+pub mod init_collateral_mint;
+pub mod reset_settlement_collateral;
+
+pub use claim_settlement_collateral::*;
+pub use claim_withdraw_collateral::*;
+pub use configure_collateral_mint::*;
+pub use fund_bond_collateral::*;
+pub use fund_settlement_collateral::*;
+pub use init_collateral_mint::*;
+pub use reset_settlement_collateral::*;
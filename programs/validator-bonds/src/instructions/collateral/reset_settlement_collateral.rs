@@ -0,0 +1,208 @@
+use crate::checks::is_closed;
+use crate::constants::{EVENT_VERSION, SETTLEMENT_STAKER_AUTHORITY_SEED};
+use crate::error::ErrorCode;
+use crate::events::collateral::ResetSettlementCollateralEvent;
+use crate::state::bond::Bond;
+use crate::state::collateral_mint::CollateralMint;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement_collateral::SettlementCollateral;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    close_account, transfer_checked, CloseAccount, Mint, Token, TokenAccount, TransferChecked,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ResetSettlementCollateralArgs {
+    /// merkle root of the closed settlement; settlement PDA seed
+    pub merkle_root: [u8; 32],
+    /// epoch the closed settlement was created for; settlement PDA seed
+    pub epoch_created_for: u64,
+}
+
+/// Returning the unclaimed token collateral of a closed settlement back to the bond collateral vault.
+/// The settlement collateral vault and the settlement collateral are closed and their rent is returned to the settlement funder.
+/// Permission-less.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ResetSettlementCollateralArgs)]
+pub struct ResetSettlementCollateral<'info> {
+    /// the config account under which the bond was created
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+
+    /// CHECK: in code
+    /// cannot exist; the PDA binds the settlement to the bond
+    #[account(
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            params.merkle_root.as_ref(),
+            params.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement: UncheckedAccount<'info>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = mint @ ErrorCode::InvalidCollateralMintAddress,
+        seeds = [
+            b"collateral_mint",
+            config.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = collateral_mint.bump,
+    )]
+    pub collateral_mint: Box<Account<'info, CollateralMint>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA
+    /// owner of the settlement collateral vault
+    #[account(
+        seeds = [
+            b"settlement_authority",
+            settlement.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub settlement_staker_authority: UncheckedAccount<'info>,
+
+    /// settlement collateral vault to be emptied and closed
+    #[account(
+        mut,
+        seeds = [
+            b"collateral_vault",
+            settlement.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = settlement_staker_authority,
+    )]
+    pub settlement_collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// tokens funded to the closed settlement, closed together with the settlement collateral vault
+    #[account(
+        mut,
+        close = rent_collector,
+        has_one = settlement @ ErrorCode::SettlementAccountMismatch,
+        has_one = mint @ ErrorCode::InvalidCollateralMintAddress,
+        seeds = [
+            b"settlement_collateral",
+            settlement.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = settlement_collateral.bump,
+    )]
+    pub settlement_collateral: Box<Account<'info, SettlementCollateral>>,
+
+    /// CHECK: PDA
+    /// owner of the bond collateral vault
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// bond collateral vault the unclaimed tokens are returned to
+    #[account(
+        mut,
+        seeds = [
+            b"collateral_vault",
+            bond.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = bonds_withdrawer_authority,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the settlement funder gets back the rent of the settlement collateral vault and the settlement collateral
+    #[account(
+        mut,
+        address = config.settlement_funder() @ ErrorCode::RentCollectorMismatch,
+    )]
+    pub rent_collector: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ResetSettlementCollateral<'info> {
+    pub fn process(
+        ctx: Context<ResetSettlementCollateral>,
+        _params: ResetSettlementCollateralArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::STAKE),
+            ErrorCode::ProgramIsPaused
+        );
+
+        // The rule stipulates to reset only when the settlement does exist.
+        require!(
+            is_closed(&ctx.accounts.settlement),
+            ErrorCode::SettlementNotClosed
+        );
+
+        let settlement_key = ctx.accounts.settlement.key();
+        let settlement_staker_authority_seeds: &[&[u8]] = &[
+            SETTLEMENT_STAKER_AUTHORITY_SEED,
+            settlement_key.as_ref(),
+            &[ctx.bumps.settlement_staker_authority],
+        ];
+
+        let returned_token_amount = ctx.accounts.settlement_collateral_vault.amount;
+        if returned_token_amount > 0 {
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.settlement_collateral_vault.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        to: ctx.accounts.collateral_vault.to_account_info(),
+                        authority: ctx.accounts.settlement_staker_authority.to_account_info(),
+                    },
+                    &[settlement_staker_authority_seeds],
+                ),
+                returned_token_amount,
+                ctx.accounts.mint.decimals,
+            )?;
+        }
+
+        close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.settlement_collateral_vault.to_account_info(),
+                destination: ctx.accounts.rent_collector.to_account_info(),
+                authority: ctx.accounts.settlement_staker_authority.to_account_info(),
+            },
+            &[settlement_staker_authority_seeds],
+        ))?;
+
+        emit_cpi!(ResetSettlementCollateralEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            settlement: settlement_key,
+            mint: ctx.accounts.mint.key(),
+            settlement_collateral_vault: ctx.accounts.settlement_collateral_vault.key(),
+            collateral_vault: ctx.accounts.collateral_vault.key(),
+            returned_token_amount,
+        });
+
+        Ok(())
+    }
+}
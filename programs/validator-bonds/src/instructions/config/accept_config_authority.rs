@@ -0,0 +1,94 @@
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::{config::ConfigureConfigEvent, PubkeyValueChange};
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+
+/// Second step of the authority change, the proposed authority accepts the role.
+/// All pending authorities matching the signer are changed.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptConfigAuthority<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+
+    /// the authority proposed by admin with the configure config instruction
+    pub new_authority: Signer<'info>,
+}
+
+impl<'info> AcceptConfigAuthority<'info> {
+    pub fn process(ctx: Context<AcceptConfigAuthority>) -> Result<()> {
+        let new_authority = ctx.accounts.new_authority.key();
+        let config = &mut ctx.accounts.config;
+
+        let admin_authority_change = if config.pending_admin_authority == new_authority {
+            let old = config.admin_authority;
+            config.admin_authority = new_authority;
+            config.pending_admin_authority = Pubkey::default();
+            Some(PubkeyValueChange {
+                old,
+                new: new_authority,
+            })
+        } else {
+            None
+        };
+
+        let operator_authority_change = if config.pending_operator_authority == new_authority {
+            let old = config.operator_authority;
+            config.operator_authority = new_authority;
+            config.pending_operator_authority = Pubkey::default();
+            Some(PubkeyValueChange {
+                old,
+                new: new_authority,
+            })
+        } else {
+            None
+        };
+
+        let pause_authority_change = if config.pending_pause_authority == new_authority {
+            let old = config.pause_authority;
+            config.pause_authority = new_authority;
+            config.pending_pause_authority = Pubkey::default();
+            Some(PubkeyValueChange {
+                old,
+                new: new_authority,
+            })
+        } else {
+            None
+        };
+
+        require!(
+            admin_authority_change.is_some()
+                || operator_authority_change.is_some()
+                || pause_authority_change.is_some(),
+            ErrorCode::NoPendingAuthorityChange
+        );
+
+        emit_cpi!(ConfigureConfigEvent {
+            version: EVENT_VERSION,
+            admin_authority: admin_authority_change,
+            operator_authority: operator_authority_change,
+            pause_authority: pause_authority_change,
+            settlement_creator_authority: None,
+            settlement_funder_authority: None,
+            settlement_closer_authority: None,
+            bid_fee_collector: None,
+            bid_max_delegated_lamports: None,
+            max_claim_expiry_extension: None,
+            epochs_to_claim_settlement: None,
+            withdraw_lockup_epochs: None,
+            minimum_stake_lamports: None,
+            slots_to_start_settlement_claiming: None,
+            min_bond_max_stake_wanted: None,
+            config_change_delay_epochs: None,
+            claimer_fee_lamports: None,
+            claimer_fee_max_bps: None,
+            pending_admin_authority: None,
+            pending_operator_authority: None,
+            pending_pause_authority: None,
+            pending_config_changes: None,
+        });
+
+        Ok(())
+    }
+}
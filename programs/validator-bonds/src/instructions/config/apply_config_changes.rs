@@ -0,0 +1,63 @@
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::config::ConfigureConfigEvent;
+use crate::instructions::config::configure_config::set_config_params;
+use crate::state::config::{Config, PendingConfigChanges};
+use anchor_lang::prelude::*;
+
+/// Permission-less crank applying the staged config parameter changes
+/// when the effective epoch is reached.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApplyConfigChanges<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> ApplyConfigChanges<'info> {
+    pub fn process(ctx: Context<ApplyConfigChanges>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let pending_changes = config.pending_config_changes;
+        require!(
+            pending_changes.is_staged(),
+            ErrorCode::NoPendingConfigChanges
+        );
+        let clock = Clock::get()?;
+        require_gte!(
+            clock.epoch,
+            pending_changes.effective_epoch,
+            ErrorCode::PendingConfigChangesNotEffective
+        );
+        config.pending_config_changes = PendingConfigChanges::default();
+
+        let mut event = ConfigureConfigEvent {
+            version: EVENT_VERSION,
+            admin_authority: None,
+            operator_authority: None,
+            pause_authority: None,
+            settlement_creator_authority: None,
+            settlement_funder_authority: None,
+            settlement_closer_authority: None,
+            bid_fee_collector: None,
+            bid_max_delegated_lamports: None,
+            max_claim_expiry_extension: None,
+            epochs_to_claim_settlement: None,
+            withdraw_lockup_epochs: None,
+            minimum_stake_lamports: None,
+            slots_to_start_settlement_claiming: None,
+            min_bond_max_stake_wanted: None,
+            config_change_delay_epochs: None,
+            claimer_fee_lamports: None,
+            claimer_fee_max_bps: None,
+            pending_admin_authority: None,
+            pending_operator_authority: None,
+            pending_pause_authority: None,
+            pending_config_changes: None,
+        };
+        set_config_params(config, &pending_changes.changes(), &mut event);
+
+        emit_cpi!(event);
+
+        Ok(())
+    }
+}
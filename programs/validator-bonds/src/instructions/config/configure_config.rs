@@ -1,6 +1,7 @@
+use crate::constants::{EVENT_VERSION, MAX_CLAIMER_FEE_BPS};
 use crate::error::ErrorCode;
 use crate::events::{config::ConfigureConfigEvent, PubkeyValueChange, U64ValueChange};
-use crate::state::config::Config;
+use crate::state::config::{Config, ConfigParamChanges, PendingConfigChanges};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -8,14 +9,32 @@ pub struct ConfigureConfigArgs {
     pub admin: Option<Pubkey>,
     pub operator: Option<Pubkey>,
     pub pause_authority: Option<Pubkey>,
+    /// operator roles, default pubkey means the role falls back to the operator authority
+    pub settlement_creator: Option<Pubkey>,
+    pub settlement_funder: Option<Pubkey>,
+    pub settlement_closer: Option<Pubkey>,
+    pub bid_fee_collector: Option<Pubkey>,
+    /// upper bound of the delegated lamports the bid is charged for, see `Config::bid_max_delegated_lamports`
+    pub bid_max_delegated_lamports: Option<u64>,
+    /// maximum total extension of a settlement claiming period, see `Config::max_claim_expiry_extension`
+    pub max_claim_expiry_extension: Option<u32>,
     pub epochs_to_claim_settlement: Option<u64>,
     pub withdraw_lockup_epochs: Option<u64>,
     pub minimum_stake_lamports: Option<u64>,
     pub slots_to_start_settlement_claiming: Option<u64>,
     pub min_bond_max_stake_wanted: Option<u64>,
+    pub config_change_delay_epochs: Option<u64>,
+    /// fee for permission-less settlement claiming, see `Config::claimer_fee`
+    pub claimer_fee_lamports: Option<u64>,
+    pub claimer_fee_max_bps: Option<u16>,
+    /// drops all pending authority and parameter changes before the new ones are processed
+    pub cancel_pending_changes: bool,
 }
 
-/// Configures bond program with the config root account params
+/// Configures bond program with the config root account params.
+/// Authority changes are only proposed and have to be accepted by the new authority.
+/// Operator role keys are set directly to be rotated at any time.
+/// Parameter changes are staged when `config_change_delay_epochs` is set.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct ConfigureConfig<'info> {
@@ -36,93 +55,255 @@ impl<'info> ConfigureConfig<'info> {
             admin,
             operator,
             pause_authority,
+            settlement_creator,
+            settlement_funder,
+            settlement_closer,
+            bid_fee_collector,
+            bid_max_delegated_lamports,
+            max_claim_expiry_extension,
             epochs_to_claim_settlement,
             withdraw_lockup_epochs,
             minimum_stake_lamports,
             slots_to_start_settlement_claiming,
             min_bond_max_stake_wanted,
+            config_change_delay_epochs,
+            claimer_fee_lamports,
+            claimer_fee_max_bps,
+            cancel_pending_changes,
         }: ConfigureConfigArgs,
     ) -> Result<()> {
-        let admin_authority_change = admin.map(|admin| {
-            let old = ctx.accounts.config.admin_authority;
-            ctx.accounts.config.admin_authority = admin;
-            PubkeyValueChange { old, new: admin }
-        });
+        if let Some(claimer_fee_max_bps) = claimer_fee_max_bps {
+            require_gte!(
+                MAX_CLAIMER_FEE_BPS,
+                claimer_fee_max_bps,
+                ErrorCode::InvalidClaimerFeeBps
+            );
+        }
 
-        let operator_authority_change = operator.map(|operator| {
-            let old = ctx.accounts.config.operator_authority;
-            ctx.accounts.config.operator_authority = operator;
-            PubkeyValueChange { old, new: operator }
-        });
+        let config = &mut ctx.accounts.config;
+
+        if cancel_pending_changes {
+            config.pending_admin_authority = Pubkey::default();
+            config.pending_operator_authority = Pubkey::default();
+            config.pending_pause_authority = Pubkey::default();
+            config.pending_config_changes = PendingConfigChanges::default();
+        }
 
-        let pause_authority_change = pause_authority.map(|pause_authority| {
-            let old = ctx.accounts.config.pause_authority;
-            ctx.accounts.config.pause_authority = pause_authority;
+        // authorities are changed in two steps, the new key has to accept
+        if let Some(admin) = admin {
+            config.pending_admin_authority = admin;
+        }
+        if let Some(operator) = operator {
+            config.pending_operator_authority = operator;
+        }
+        if let Some(pause_authority) = pause_authority {
+            config.pending_pause_authority = pause_authority;
+        }
+
+        let settlement_creator_change = settlement_creator.map(|settlement_creator| {
+            let old = config.settlement_creator_authority;
+            config.settlement_creator_authority = settlement_creator;
             PubkeyValueChange {
                 old,
-                new: pause_authority,
+                new: settlement_creator,
             }
         });
 
-        let epochs_to_claim_settlement_change =
-            epochs_to_claim_settlement.map(|claim_settlement| {
-                let old = ctx.accounts.config.epochs_to_claim_settlement;
-                ctx.accounts.config.epochs_to_claim_settlement = claim_settlement;
-                U64ValueChange {
-                    old,
-                    new: claim_settlement,
-                }
-            });
+        let settlement_funder_change = settlement_funder.map(|settlement_funder| {
+            let old = config.settlement_funder_authority;
+            config.settlement_funder_authority = settlement_funder;
+            PubkeyValueChange {
+                old,
+                new: settlement_funder,
+            }
+        });
 
-        let withdraw_lockup_epochs_change = withdraw_lockup_epochs.map(|withdraw_lockup| {
-            let old = ctx.accounts.config.withdraw_lockup_epochs;
-            ctx.accounts.config.withdraw_lockup_epochs = withdraw_lockup;
-            U64ValueChange {
+        let settlement_closer_change = settlement_closer.map(|settlement_closer| {
+            let old = config.settlement_closer_authority;
+            config.settlement_closer_authority = settlement_closer;
+            PubkeyValueChange {
                 old,
-                new: withdraw_lockup,
+                new: settlement_closer,
             }
         });
 
-        let minimum_stake_lamports_change = minimum_stake_lamports.map(|minimum_stake| {
-            let old = ctx.accounts.config.minimum_stake_lamports;
-            ctx.accounts.config.minimum_stake_lamports = minimum_stake;
-            U64ValueChange {
+        let bid_fee_collector_change = bid_fee_collector.map(|bid_fee_collector| {
+            let old = config.bid_fee_collector;
+            config.bid_fee_collector = bid_fee_collector;
+            PubkeyValueChange {
                 old,
-                new: minimum_stake,
+                new: bid_fee_collector,
             }
         });
 
-        let slots_to_start_settlement_claiming_change =
-            slots_to_start_settlement_claiming.map(|slots_to_start| {
-                let old = ctx.accounts.config.slots_to_start_settlement_claiming;
-                ctx.accounts.config.slots_to_start_settlement_claiming = slots_to_start;
+        let changes = ConfigParamChanges {
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs,
+            minimum_stake_lamports,
+            slots_to_start_settlement_claiming,
+            min_bond_max_stake_wanted,
+            config_change_delay_epochs,
+            claimer_fee_lamports,
+            claimer_fee_max_bps,
+            bid_max_delegated_lamports,
+            max_claim_expiry_extension,
+        };
+
+        let mut event = ConfigureConfigEvent {
+            version: EVENT_VERSION,
+            admin_authority: None,
+            operator_authority: None,
+            pause_authority: None,
+            settlement_creator_authority: settlement_creator_change,
+            settlement_funder_authority: settlement_funder_change,
+            settlement_closer_authority: settlement_closer_change,
+            bid_fee_collector: bid_fee_collector_change,
+            bid_max_delegated_lamports: None,
+            max_claim_expiry_extension: None,
+            epochs_to_claim_settlement: None,
+            withdraw_lockup_epochs: None,
+            minimum_stake_lamports: None,
+            slots_to_start_settlement_claiming: None,
+            min_bond_max_stake_wanted: None,
+            config_change_delay_epochs: None,
+            claimer_fee_lamports: None,
+            claimer_fee_max_bps: None,
+            pending_admin_authority: admin,
+            pending_operator_authority: operator,
+            pending_pause_authority: pause_authority,
+            pending_config_changes: None,
+        };
+
+        if !changes.is_empty() {
+            if config.config_change_delay_epochs == 0 {
+                set_config_params(config, &changes, &mut event);
+            } else {
+                // a new staging replaces the not yet applied one
+                let pending_changes = PendingConfigChanges::new(
+                    Clock::get()?
+                        .epoch
+                        .saturating_add(config.config_change_delay_epochs),
+                    &changes,
+                );
+                event.pending_config_changes = Some(pending_changes);
+                config.pending_config_changes = pending_changes;
+            }
+        }
+
+        emit_cpi!(event);
+
+        Ok(())
+    }
+}
+
+/// Writes the parameter changes to config and records the old and new values to the event
+pub(crate) fn set_config_params(
+    config: &mut Config,
+    changes: &ConfigParamChanges,
+    event: &mut ConfigureConfigEvent,
+) {
+    event.epochs_to_claim_settlement = changes.epochs_to_claim_settlement.map(|claim_settlement| {
+        let old = config.epochs_to_claim_settlement;
+        config.epochs_to_claim_settlement = claim_settlement;
+        U64ValueChange {
+            old,
+            new: claim_settlement,
+        }
+    });
+
+    event.withdraw_lockup_epochs = changes.withdraw_lockup_epochs.map(|withdraw_lockup| {
+        let old = config.withdraw_lockup_epochs;
+        config.withdraw_lockup_epochs = withdraw_lockup;
+        U64ValueChange {
+            old,
+            new: withdraw_lockup,
+        }
+    });
+
+    event.minimum_stake_lamports = changes.minimum_stake_lamports.map(|minimum_stake| {
+        let old = config.minimum_stake_lamports;
+        config.minimum_stake_lamports = minimum_stake;
+        U64ValueChange {
+            old,
+            new: minimum_stake,
+        }
+    });
+
+    event.slots_to_start_settlement_claiming =
+        changes
+            .slots_to_start_settlement_claiming
+            .map(|slots_to_start| {
+                let old = config.slots_to_start_settlement_claiming;
+                config.slots_to_start_settlement_claiming = slots_to_start;
                 U64ValueChange {
                     old,
                     new: slots_to_start,
                 }
             });
 
-        let min_bond_max_stake_wanted_change =
-            min_bond_max_stake_wanted.map(|min_bond_max_stake_wanted| {
-                let old = ctx.accounts.config.min_bond_max_stake_wanted;
-                ctx.accounts.config.min_bond_max_stake_wanted = min_bond_max_stake_wanted;
+    event.min_bond_max_stake_wanted =
+        changes
+            .min_bond_max_stake_wanted
+            .map(|min_bond_max_stake_wanted| {
+                let old = config.min_bond_max_stake_wanted;
+                config.min_bond_max_stake_wanted = min_bond_max_stake_wanted;
                 U64ValueChange {
                     old,
                     new: min_bond_max_stake_wanted,
                 }
             });
 
-        emit_cpi!(ConfigureConfigEvent {
-            admin_authority: admin_authority_change,
-            operator_authority: operator_authority_change,
-            pause_authority: pause_authority_change,
-            epochs_to_claim_settlement: epochs_to_claim_settlement_change,
-            withdraw_lockup_epochs: withdraw_lockup_epochs_change,
-            minimum_stake_lamports: minimum_stake_lamports_change,
-            slots_to_start_settlement_claiming: slots_to_start_settlement_claiming_change,
-            min_bond_max_stake_wanted: min_bond_max_stake_wanted_change,
-        });
+    event.config_change_delay_epochs =
+        changes
+            .config_change_delay_epochs
+            .map(|config_change_delay_epochs| {
+                let old = config.config_change_delay_epochs;
+                config.config_change_delay_epochs = config_change_delay_epochs;
+                U64ValueChange {
+                    old,
+                    new: config_change_delay_epochs,
+                }
+            });
 
-        Ok(())
-    }
+    event.claimer_fee_lamports = changes.claimer_fee_lamports.map(|claimer_fee_lamports| {
+        let old = config.claimer_fee_lamports;
+        config.claimer_fee_lamports = claimer_fee_lamports;
+        U64ValueChange {
+            old,
+            new: claimer_fee_lamports,
+        }
+    });
+
+    event.claimer_fee_max_bps = changes.claimer_fee_max_bps.map(|claimer_fee_max_bps| {
+        let old = config.claimer_fee_max_bps;
+        config.claimer_fee_max_bps = claimer_fee_max_bps;
+        U64ValueChange {
+            old: old as u64,
+            new: claimer_fee_max_bps as u64,
+        }
+    });
+
+    event.bid_max_delegated_lamports =
+        changes
+            .bid_max_delegated_lamports
+            .map(|bid_max_delegated_lamports| {
+                let old = config.bid_max_delegated_lamports;
+                config.bid_max_delegated_lamports = bid_max_delegated_lamports;
+                U64ValueChange {
+                    old,
+                    new: bid_max_delegated_lamports,
+                }
+            });
+
+    event.max_claim_expiry_extension =
+        changes
+            .max_claim_expiry_extension
+            .map(|max_claim_expiry_extension| {
+                let old = config.max_claim_expiry_extension;
+                config.max_claim_expiry_extension = max_claim_expiry_extension;
+                U64ValueChange {
+                    old: old as u64,
+                    new: max_claim_expiry_extension as u64,
+                }
+            });
 }
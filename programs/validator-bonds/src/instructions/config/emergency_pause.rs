@@ -1,9 +1,19 @@
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
-use crate::events::config::{EmergencyPauseEvent, EmergencyResumeEvent};
-use crate::state::config::Config;
+use crate::events::config::{EmergencyPauseEvent, EmergencyResumeEvent, SetPauseFlagsEvent};
+use crate::events::U64ValueChange;
+use crate::state::config::{pause_flags, Config};
 use anchor_lang::prelude::*;
 
-/// The program can be paused in case of an emergency
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SetPauseFlagsArgs {
+    /// bitflag set of the paused instruction families, see `pause_flags`, 0 resumes all of them
+    pub pause_flags: u8,
+}
+
+/// The program can be paused in case of an emergency.
+/// The emergency pause and resume pause and resume all instruction families,
+/// the instruction families are paused and resumed independently with set pause flags.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct EmergencyPauseResume<'info> {
@@ -18,9 +28,12 @@ pub struct EmergencyPauseResume<'info> {
 
 impl<'info> EmergencyPauseResume<'info> {
     pub fn pause(ctx: Context<EmergencyPauseResume>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::AlreadyPaused);
+        let current_flags = ctx.accounts.config.effective_pause_flags();
+        require!(current_flags != pause_flags::ALL, ErrorCode::AlreadyPaused);
+        ctx.accounts.config.pause_flags = pause_flags::ALL;
         ctx.accounts.config.paused = true;
         emit_cpi!(EmergencyPauseEvent {
+            version: EVENT_VERSION,
             config: ctx.accounts.config.key(),
             pause_authority: ctx.accounts.pause_authority.key(),
             admin_authority: ctx.accounts.config.admin_authority,
@@ -28,15 +41,22 @@ impl<'info> EmergencyPauseResume<'info> {
             epochs_to_claim_settlement: ctx.accounts.config.epochs_to_claim_settlement,
             withdraw_lockup_epochs: ctx.accounts.config.withdraw_lockup_epochs,
             minimum_stake_lamports: ctx.accounts.config.minimum_stake_lamports,
+            pause_flags: U64ValueChange {
+                old: current_flags as u64,
+                new: ctx.accounts.config.pause_flags as u64,
+            },
         });
 
         Ok(())
     }
 
     pub fn resume(ctx: Context<EmergencyPauseResume>) -> Result<()> {
-        require!(ctx.accounts.config.paused, ErrorCode::NotPaused);
+        let current_flags = ctx.accounts.config.effective_pause_flags();
+        require!(current_flags != 0, ErrorCode::NotPaused);
+        ctx.accounts.config.pause_flags = 0;
         ctx.accounts.config.paused = false;
         emit_cpi!(EmergencyResumeEvent {
+            version: EVENT_VERSION,
             config: ctx.accounts.config.key(),
             pause_authority: ctx.accounts.pause_authority.key(),
             admin_authority: ctx.accounts.config.admin_authority,
@@ -44,7 +64,37 @@ impl<'info> EmergencyPauseResume<'info> {
             epochs_to_claim_settlement: ctx.accounts.config.epochs_to_claim_settlement,
             withdraw_lockup_epochs: ctx.accounts.config.withdraw_lockup_epochs,
             minimum_stake_lamports: ctx.accounts.config.minimum_stake_lamports,
+            pause_flags: U64ValueChange {
+                old: current_flags as u64,
+                new: ctx.accounts.config.pause_flags as u64,
+            },
+        });
+        Ok(())
+    }
+
+    pub fn set_pause_flags(
+        ctx: Context<EmergencyPauseResume>,
+        SetPauseFlagsArgs { pause_flags }: SetPauseFlagsArgs,
+    ) -> Result<()> {
+        check_pause_flags(pause_flags)?;
+        let current_flags = ctx.accounts.config.effective_pause_flags();
+        require_neq!(current_flags, pause_flags, ErrorCode::PauseFlagsNotChanged);
+        ctx.accounts.config.pause_flags = pause_flags;
+        ctx.accounts.config.paused = pause_flags != 0;
+        emit_cpi!(SetPauseFlagsEvent {
+            version: EVENT_VERSION,
+            config: ctx.accounts.config.key(),
+            pause_authority: ctx.accounts.pause_authority.key(),
+            pause_flags: U64ValueChange {
+                old: current_flags as u64,
+                new: pause_flags as u64,
+            },
         });
         Ok(())
     }
 }
+
+fn check_pause_flags(flags: u8) -> Result<()> {
+    require!(flags & !pause_flags::ALL == 0, ErrorCode::InvalidPauseFlags);
+    Ok(())
+}
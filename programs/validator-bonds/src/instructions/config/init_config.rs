@@ -1,6 +1,6 @@
-use crate::constants::MIN_STAKE_LAMPORTS;
+use crate::constants::{ACCOUNT_VERSION, EVENT_VERSION, MIN_STAKE_LAMPORTS};
 use crate::events::config::InitConfigEvent;
-use crate::state::config::{find_bonds_withdrawer_authority, Config};
+use crate::state::config::{find_bonds_withdrawer_authority, Config, PendingConfigChanges};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
@@ -57,10 +57,26 @@ impl<'info> InitConfig<'info> {
             paused: false,
             slots_to_start_settlement_claiming,
             min_bond_max_stake_wanted: 0,
-            reserved: [0; 463],
+            pending_admin_authority: Pubkey::default(),
+            pending_operator_authority: Pubkey::default(),
+            pending_pause_authority: Pubkey::default(),
+            config_change_delay_epochs: 0,
+            pending_config_changes: PendingConfigChanges::default(),
+            pause_flags: 0,
+            settlement_creator_authority: Pubkey::default(),
+            settlement_funder_authority: Pubkey::default(),
+            settlement_closer_authority: Pubkey::default(),
+            bid_fee_collector: Pubkey::default(),
+            claimer_fee_lamports: 0,
+            claimer_fee_max_bps: 0,
+            account_version: ACCOUNT_VERSION,
+            bid_max_delegated_lamports: 0,
+            max_claim_expiry_extension: 0,
+            reserved: [0; 127],
         });
 
         emit_cpi!(InitConfigEvent {
+            version: EVENT_VERSION,
             config: ctx.accounts.config.key(),
             admin_authority: ctx.accounts.config.admin_authority,
             operator_authority: ctx.accounts.config.operator_authority,
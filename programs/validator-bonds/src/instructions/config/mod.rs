@@ -1,7 +1,11 @@
+pub mod accept_config_authority;
+pub mod apply_config_changes;
 pub mod configure_config;
 pub mod emergency_pause;
 pub mod init_config;
 
+pub use accept_config_authority::*;
+pub use apply_config_changes::*;
 pub use configure_config::*;
 pub use emergency_pause::*;
 pub use init_config::*;
@@ -1,10 +1,14 @@
+pub mod account;
 pub mod bond;
+pub mod collateral;
 pub mod config;
 pub mod settlement;
 pub mod stake;
 pub mod withdraw;
 
+pub use account::*;
 pub use bond::*;
+pub use collateral::*;
 pub use config::*;
 pub use settlement::*;
 pub use stake::*;
@@ -0,0 +1,127 @@
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::settlement::AmendSettlementEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement::Settlement;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AmendSettlementArgs {
+    /// new merkle root the claims are verified against,
+    /// the settlement address stays derived from the original merkle root
+    pub merkle_root: [u8; 32],
+    /// new maximal number of lamports that can be claimed from this settlement
+    pub max_total_claim: u64,
+    /// new maximal number of merkle tree nodes that can be claimed from this settlement
+    pub max_merkle_nodes: u64,
+}
+
+/// Amends the settlement data when the merkle tree has to be corrected.
+/// Possible only before any claim has been made from the settlement.
+/// The dispute and claiming window is restarted to give the bond authority
+/// time to review the amended merkle tree, the dispute of the prior merkle tree is cleared.
+/// Permission-ed for the settlement creator role of the operator.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AmendSettlement<'info> {
+    #[account(
+        constraint = config.settlement_creator() == operator_authority.key() @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.merkle_root.as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// operator signer authority holding the settlement creator role
+    pub operator_authority: Signer<'info>,
+}
+
+impl<'info> AmendSettlement<'info> {
+    pub fn process(
+        ctx: Context<AmendSettlement>,
+        AmendSettlementArgs {
+            merkle_root,
+            max_total_claim,
+            max_merkle_nodes,
+        }: AmendSettlementArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require_eq!(
+            ctx.accounts.settlement.merkle_nodes_claimed,
+            0,
+            ErrorCode::SettlementAlreadyClaimed
+        );
+
+        if max_total_claim == 0 || max_merkle_nodes == 0 {
+            return Err(error!(ErrorCode::EmptySettlementMerkleTree).with_values((
+                "max_total_claim, max_merkle_nodes",
+                format!("{}, {}", max_total_claim, max_merkle_nodes),
+            )));
+        }
+
+        let old_merkle_root = ctx.accounts.settlement.claim_merkle_root();
+        let old_max_total_claim = ctx.accounts.settlement.max_total_claim;
+        let old_max_merkle_nodes = ctx.accounts.settlement.max_merkle_nodes;
+        let old_slot_created_at = ctx.accounts.settlement.slot_created_at;
+        let clock = Clock::get()?;
+
+        ctx.accounts.settlement.amended = true;
+        ctx.accounts.settlement.amended_merkle_root = merkle_root;
+        ctx.accounts.settlement.max_total_claim = max_total_claim;
+        ctx.accounts.settlement.max_merkle_nodes = max_merkle_nodes;
+        ctx.accounts.settlement.slot_created_at = clock.slot;
+        // the amended merkle tree may be disputed again
+        ctx.accounts.settlement.disputed = false;
+        ctx.accounts.settlement.dispute_reason_hash = [0; 32];
+
+        emit_cpi!(AmendSettlementEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            old_merkle_root,
+            new_merkle_root: merkle_root,
+            max_total_claim: U64ValueChange {
+                old: old_max_total_claim,
+                new: max_total_claim,
+            },
+            max_merkle_nodes: U64ValueChange {
+                old: old_max_merkle_nodes,
+                new: max_merkle_nodes,
+            },
+            slot_created_at: U64ValueChange {
+                old: old_slot_created_at,
+                new: clock.slot,
+            },
+            authority: ctx.accounts.operator_authority.key(),
+        });
+
+        Ok(())
+    }
+}
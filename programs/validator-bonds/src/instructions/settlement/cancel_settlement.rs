@@ -1,8 +1,9 @@
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
 use crate::events::settlement::CancelSettlementEvent;
 use crate::instructions::withdraw_refund_stake_account;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::Settlement;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::stake_history;
@@ -84,7 +85,10 @@ pub struct CancelSettlement<'info> {
 
 impl<'info> CancelSettlement<'info> {
     pub fn process(ctx: Context<CancelSettlement>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         if ctx.accounts.settlement.split_rent_collector.is_some() {
             withdraw_refund_stake_account(
@@ -101,6 +105,7 @@ impl<'info> CancelSettlement<'info> {
         }
 
         emit_cpi!(CancelSettlementEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.settlement.bond.key(),
             settlement: ctx.accounts.settlement.key(),
             merkle_root: ctx.accounts.settlement.merkle_root,
@@ -1,21 +1,19 @@
 use crate::checks::{
-    check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
+    check_settlement_claim, check_stake_is_initialized_with_withdrawer_authority,
+    check_stake_is_not_locked,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{ACCOUNT_VERSION, BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::settlement_claim::ClaimSettlementEvent;
 use crate::events::U64ValueChange;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::Settlement;
 use crate::state::settlement_claim::SettlementClaim;
-use crate::utils::{merkle_proof, minimal_size_stake_account};
+use crate::utils::minimal_size_stake_account;
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hashv;
 use anchor_lang::solana_program::sysvar::stake_history;
 use anchor_spl::stake::{withdraw, Stake, StakeAccount, Withdraw};
-use merkle_tree::psr_claim::TreeNode;
-use merkle_tree::{hash_leaf, LEAF_PREFIX};
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct ClaimSettlementArgs {
@@ -31,7 +29,9 @@ pub struct ClaimSettlementArgs {
     pub claim: u64,
 }
 
-/// Claims a settlement by withdrawing settlement funded stake account
+/// Claims a settlement by withdrawing settlement funded stake account.
+/// Permission-less, the claimer pays the rent of the claim account (refunded on closing)
+/// and receives the config claimer fee carved from the claimed amount.
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: ClaimSettlementArgs)]
@@ -53,7 +53,7 @@ pub struct ClaimSettlement<'info> {
     #[account(
         mut,
         has_one = bond @ ErrorCode::BondAccountMismatch,
-        constraint = settlement.epoch_created_for + config.epochs_to_claim_settlement >= clock.epoch @ ErrorCode::SettlementExpired,
+        constraint = settlement.expiration_epoch(config.epochs_to_claim_settlement) >= clock.epoch @ ErrorCode::SettlementExpired,
         constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming <= clock.slot @ ErrorCode::SettlementNotReadyForClaiming,
         seeds = [
             b"settlement_account",
@@ -103,6 +103,7 @@ pub struct ClaimSettlement<'info> {
 
     /// upon claiming, a claim account is created to confirm the occurrence of the claim
     /// when the settlement withdrawal window expires, the claim account is closed, and the rent is refunded here
+    /// the claimer fee is paid here
     #[account(
         mut,
         owner = system_program.key()
@@ -125,60 +126,25 @@ impl<'info> ClaimSettlement<'info> {
         ctx: Context<ClaimSettlement>,
         ClaimSettlementArgs {
             proof,
-            tree_node_hash: tree_node_hash_args,
+            tree_node_hash,
             claim,
             stake_account_staker,
             stake_account_withdrawer,
         }: ClaimSettlementArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::CLAIM),
+            ErrorCode::ProgramIsPaused
+        );
 
-        // settlement_claim PDA address verification
-        let tree_node = TreeNode {
-            stake_authority: stake_account_staker,
-            withdraw_authority: stake_account_withdrawer,
+        check_settlement_claim(
+            &ctx.accounts.settlement,
+            proof,
+            tree_node_hash,
+            stake_account_staker,
+            stake_account_withdrawer,
             claim,
-            proof: None,
-        };
-        let tree_node_bytes = tree_node.hash().to_bytes();
-        if tree_node_bytes != tree_node_hash_args {
-            return Err(
-                error!(ErrorCode::ClaimSettlementMerkleTreeNodeMismatch).with_values((
-                    "tree_node_bytes vs. tree_node_hash_args",
-                    format!("'{:?}' vs. '{:?}'", tree_node_bytes, tree_node_hash_args),
-                )),
-            );
-        }
-
-        if ctx.accounts.settlement.lamports_claimed + claim
-            > ctx.accounts.settlement.max_total_claim
-        {
-            return Err(error!(ErrorCode::ClaimAmountExceedsMaxTotalClaim)
-                .with_account_name("settlement")
-                .with_values((
-                    "lamports_claimed + claim > max_total_claim",
-                    format!(
-                        "{} + {} <= {}",
-                        ctx.accounts.settlement.lamports_claimed,
-                        claim,
-                        ctx.accounts.settlement.max_total_claim
-                    ),
-                )));
-        }
-        if ctx.accounts.settlement.merkle_nodes_claimed + 1
-            > ctx.accounts.settlement.max_merkle_nodes
-        {
-            return Err(error!(ErrorCode::ClaimCountExceedsMaxMerkleNodes)
-                .with_account_name("settlement")
-                .with_values((
-                    "merkle_nodes_claimed + 1 > max_merkle_nodes",
-                    format!(
-                        "{} + 1 <= {}",
-                        ctx.accounts.settlement.merkle_nodes_claimed,
-                        ctx.accounts.settlement.max_merkle_nodes
-                    ),
-                )));
-        }
+        )?;
 
         // stake account is managed by bonds program
         let stake_from_meta = check_stake_is_initialized_with_withdrawer_authority(
@@ -232,18 +198,6 @@ impl<'info> ClaimSettlement<'info> {
                 )));
         }
 
-        let tree_node_hash = tree_node.hash().to_bytes();
-        if !merkle_proof::verify(
-            proof,
-            ctx.accounts.settlement.merkle_root,
-            hash_leaf!(tree_node_hash).to_bytes(),
-        ) {
-            return Err(error!(ErrorCode::ClaimSettlementProofFailed).with_values((
-                "Merkle proof verification failed",
-                format!("Tree node: {:?}", tree_node),
-            )));
-        }
-
         ctx.accounts.settlement_claim.set_inner(SettlementClaim {
             settlement: ctx.accounts.settlement.key(),
             stake_account_to: ctx.accounts.stake_account_to.key(),
@@ -252,9 +206,17 @@ impl<'info> ClaimSettlement<'info> {
             amount: claim,
             bump: ctx.bumps.settlement_claim,
             rent_collector: ctx.accounts.rent_payer.key(),
-            reserved: [0; 93],
+            account_version: ACCOUNT_VERSION,
+            reserved: [0; 92],
         });
 
+        let claimer_fee = ctx.accounts.config.claimer_fee(claim);
+        let config_key = ctx.accounts.config.key();
+        let bonds_withdrawer_authority_seeds: &[&[u8]] = &[
+            BONDS_WITHDRAWER_AUTHORITY_SEED,
+            config_key.as_ref(),
+            &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+        ];
         withdraw(
             CpiContext::new_with_signer(
                 ctx.accounts.stake_program.to_account_info(),
@@ -265,20 +227,34 @@ impl<'info> ClaimSettlement<'info> {
                     clock: ctx.accounts.clock.to_account_info(),
                     stake_history: ctx.accounts.stake_history.to_account_info(),
                 },
-                &[&[
-                    BONDS_WITHDRAWER_AUTHORITY_SEED,
-                    &ctx.accounts.config.key().as_ref(),
-                    &[ctx.accounts.config.bonds_withdrawer_authority_bump],
-                ]],
+                &[bonds_withdrawer_authority_seeds],
             ),
-            claim,
+            claim - claimer_fee,
             None,
         )?;
+        if claimer_fee > 0 {
+            withdraw(
+                CpiContext::new_with_signer(
+                    ctx.accounts.stake_program.to_account_info(),
+                    Withdraw {
+                        stake: ctx.accounts.stake_account_from.to_account_info(),
+                        withdrawer: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                        to: ctx.accounts.rent_payer.to_account_info(),
+                        clock: ctx.accounts.clock.to_account_info(),
+                        stake_history: ctx.accounts.stake_history.to_account_info(),
+                    },
+                    &[bonds_withdrawer_authority_seeds],
+                ),
+                claimer_fee,
+                None,
+            )?;
+        }
 
         ctx.accounts.settlement.lamports_claimed += claim;
         ctx.accounts.settlement.merkle_nodes_claimed += 1;
 
         emit_cpi!(ClaimSettlementEvent {
+            version: EVENT_VERSION,
             settlement: ctx.accounts.settlement_claim.settlement,
             settlement_claim: ctx.accounts.settlement_claim.key(),
             stake_account_to: ctx.accounts.settlement_claim.stake_account_to,
@@ -291,6 +267,7 @@ impl<'info> ClaimSettlement<'info> {
             stake_account_withdrawer: ctx.accounts.settlement_claim.stake_account_withdrawer,
             amount: ctx.accounts.settlement_claim.amount,
             rent_collector: ctx.accounts.settlement_claim.rent_collector,
+            claimer_fee,
         });
 
         Ok(())
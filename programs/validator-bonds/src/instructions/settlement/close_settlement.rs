@@ -1,12 +1,12 @@
 use crate::checks::{
-    check_stake_is_initialized_with_withdrawer_authority, check_stake_valid_delegation,
+    check_bond_stake_valid_delegation, check_stake_is_initialized_with_withdrawer_authority,
     deserialize_stake_account,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::settlement::CloseSettlementEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::Settlement;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::sysvar::stake_history;
@@ -36,7 +36,7 @@ pub struct CloseSettlement<'info> {
         has_one = bond @ ErrorCode::BondAccountMismatch,
         has_one = rent_collector @ ErrorCode::RentCollectorMismatch,
         constraint = (settlement.split_rent_collector.is_none() || settlement.split_rent_collector.unwrap() == split_rent_collector.key()) @ ErrorCode::RentCollectorMismatch,
-        constraint = settlement.epoch_created_for + config.epochs_to_claim_settlement < clock.epoch @ ErrorCode::SettlementNotExpired,
+        constraint = settlement.expiration_epoch(config.epochs_to_claim_settlement) < clock.epoch @ ErrorCode::SettlementNotExpired,
         seeds = [
             b"settlement_account",
             bond.key().as_ref(),
@@ -82,7 +82,10 @@ pub struct CloseSettlement<'info> {
 
 impl<'info> CloseSettlement<'info> {
     pub fn process(ctx: Context<CloseSettlement>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         if ctx.accounts.settlement.split_rent_collector.is_some() {
             withdraw_refund_stake_account(
@@ -99,6 +102,7 @@ impl<'info> CloseSettlement<'info> {
         }
 
         emit_cpi!(CloseSettlementEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.settlement.bond.key(),
             settlement: ctx.accounts.settlement.key(),
             merkle_root: ctx.accounts.settlement.merkle_root,
@@ -114,8 +118,10 @@ impl<'info> CloseSettlement<'info> {
                 .settlement
                 .split_rent_collector
                 .map(|_| ctx.accounts.split_rent_refund_account.key()),
-            expiration_epoch: ctx.accounts.settlement.epoch_created_for
-                + ctx.accounts.config.epochs_to_claim_settlement,
+            expiration_epoch: ctx
+                .accounts
+                .settlement
+                .expiration_epoch(ctx.accounts.config.epochs_to_claim_settlement),
             current_epoch: ctx.accounts.clock.epoch,
         });
 
@@ -143,7 +149,7 @@ pub fn withdraw_refund_stake_account<'info>(
         "stake_account",
     )?;
     // stake account is delegated to bond's validator vote account
-    check_stake_valid_delegation(&stake_account, &bond.vote_account)?;
+    check_bond_stake_valid_delegation(&stake_account, bond)?;
 
     withdraw(
         CpiContext::new_with_signer(
@@ -1,4 +1,5 @@
 use crate::checks::is_closed;
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
 use crate::events::settlement_claim::CloseSettlementClaimEvent;
 use crate::state::settlement_claim::SettlementClaim;
@@ -36,8 +37,16 @@ impl<'info> CloseSettlementClaim<'info> {
         );
 
         emit_cpi!(CloseSettlementClaimEvent {
+            version: EVENT_VERSION,
             settlement: ctx.accounts.settlement.key(),
+            settlement_claim: ctx.accounts.settlement_claim.key(),
+            stake_account_to: ctx.accounts.settlement_claim.stake_account_to,
+            stake_account_withdrawer: ctx.accounts.settlement_claim.stake_account_withdrawer,
+            stake_account_staker: ctx.accounts.settlement_claim.stake_account_staker,
+            amount: ctx.accounts.settlement_claim.amount,
             rent_collector: ctx.accounts.rent_collector.key(),
+            rent_refund: ctx.accounts.settlement_claim.to_account_info().lamports(),
+            epoch: Clock::get()?.epoch,
         });
 
         Ok(())
@@ -0,0 +1,107 @@
+use crate::checks::check_bond_authority;
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::settlement::DisputeSettlementEvent;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement::Settlement;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct DisputeSettlementArgs {
+    /// hash of the dispute reason published off-chain by the validator
+    pub reason_hash: [u8; 32],
+}
+
+/// Validator flags the settlement as wrong, claiming is blocked until the admin resolves the dispute
+/// or until the operator amends the settlement.
+/// Possible only before the settlement claiming starts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DisputeSettlement<'info> {
+    /// the config account under which the bond was created
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            vote_account.key().as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// CHECK: check&deserialize of the validator vote account in the code
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        constraint = settlement.slot_created_at + config.slots_to_start_settlement_claiming > clock.slot @ ErrorCode::SettlementDisputeWindowClosed,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.merkle_root.as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// validator vote account node identity or bond authority may dispute the settlement
+    pub authority: Signer<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> DisputeSettlement<'info> {
+    pub fn process(
+        ctx: Context<DisputeSettlement>,
+        DisputeSettlementArgs { reason_hash }: DisputeSettlementArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            check_bond_authority(
+                &ctx.accounts.authority.key(),
+                &ctx.accounts.bond,
+                &ctx.accounts.vote_account
+            ),
+            ErrorCode::BondChangeNotPermitted
+        );
+
+        require!(reason_hash != [0; 32], ErrorCode::EmptyDisputeReasonHash);
+
+        // a resolved dispute keeps the reason hash, the settlement can be disputed only once per merkle root,
+        // the amendment of the settlement clears the dispute
+        require!(
+            !ctx.accounts.settlement.disputed
+                && ctx.accounts.settlement.dispute_reason_hash == [0; 32],
+            ErrorCode::SettlementAlreadyDisputed
+        );
+
+        ctx.accounts.settlement.disputed = true;
+        ctx.accounts.settlement.dispute_reason_hash = reason_hash;
+
+        emit_cpi!(DisputeSettlementEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            vote_account: ctx.accounts.vote_account.key(),
+            reason_hash,
+            authority: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::settlement::ExtendSettlementExpiryEvent;
+use crate::events::U64ValueChange;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement::Settlement;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ExtendSettlementExpiryArgs {
+    /// number of epochs added to the settlement claiming period
+    pub epochs: u32,
+}
+
+/// Extends the claiming period of a single settlement beyond the config `epochs_to_claim_settlement`,
+/// e.g., when the claiming was delayed by an outage.
+/// The total extension is limited by the config `max_claim_expiry_extension`.
+/// Permission-ed for the settlement creator role of the operator or the admin authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendSettlementExpiry<'info> {
+    #[account(
+        constraint = config.settlement_creator() == authority.key() || config.admin_authority == authority.key() @ ErrorCode::InvalidOperatorAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.merkle_root.as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// operator signer authority holding the settlement creator role or the admin authority
+    pub authority: Signer<'info>,
+}
+
+impl<'info> ExtendSettlementExpiry<'info> {
+    pub fn process(
+        ctx: Context<ExtendSettlementExpiry>,
+        ExtendSettlementExpiryArgs { epochs }: ExtendSettlementExpiryArgs,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require_gt!(epochs, 0, ErrorCode::InvalidSettlementExpiryExtension);
+
+        let epochs_to_claim_settlement = ctx.accounts.config.epochs_to_claim_settlement;
+        let settlement = &mut ctx.accounts.settlement;
+        let old_extension = settlement.claim_expiry_extension;
+        let old_expiration_epoch = settlement.expiration_epoch(epochs_to_claim_settlement);
+        let new_extension = old_extension
+            .checked_add(epochs)
+            .ok_or(ErrorCode::InvalidSettlementExpiryExtension)?;
+        if new_extension > ctx.accounts.config.max_claim_expiry_extension {
+            return Err(error!(ErrorCode::SettlementExpiryExtensionExceeded)
+                .with_account_name("settlement")
+                .with_values((
+                    "claim_expiry_extension + epochs > max_claim_expiry_extension",
+                    format!(
+                        "{} + {} > {}",
+                        old_extension, epochs, ctx.accounts.config.max_claim_expiry_extension
+                    ),
+                )));
+        }
+        settlement.claim_expiry_extension = new_extension;
+
+        emit_cpi!(ExtendSettlementExpiryEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            settlement: settlement.key(),
+            claim_expiry_extension: U64ValueChange {
+                old: old_extension as u64,
+                new: settlement.claim_expiry_extension as u64,
+            },
+            expiration_epoch: U64ValueChange {
+                old: old_expiration_epoch,
+                new: settlement.expiration_epoch(epochs_to_claim_settlement),
+            },
+            authority: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,13 +1,13 @@
 use crate::checks::{
-    check_stake_is_initialized_with_withdrawer_authority, check_stake_is_not_locked,
-    check_stake_valid_delegation,
+    check_bond_stake_valid_delegation, check_stake_is_initialized_with_withdrawer_authority,
+    check_stake_is_not_locked,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::settlement::FundSettlementEvent;
 use crate::events::SplitStakeData;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::Settlement;
 use crate::utils::{minimal_size_stake_account, return_unused_split_stake_account_rent};
 use anchor_lang::prelude::*;
@@ -23,12 +23,12 @@ use anchor_spl::stake::{
 
 /// Funding the settlement by providing a stake account delegated to a particular validator vote account based on the Merkle proof.
 /// The settlement has been previously created by the operator to fulfill some protected event (e.g., slashing).
-/// Permission-ed to operator authority.
+/// Permission-ed to the settlement funder role of the operator.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct FundSettlement<'info> {
     #[account(
-        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+        constraint = config.settlement_funder() == operator_authority.key() @ ErrorCode::InvalidOperatorAuthority,
     )]
     pub config: Account<'info, Config>,
 
@@ -124,7 +124,10 @@ pub struct FundSettlement<'info> {
 
 impl<'info> FundSettlement<'info> {
     pub fn process(ctx: Context<FundSettlement>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         if ctx.accounts.settlement.lamports_funded >= ctx.accounts.settlement.max_total_claim {
             msg!("Settlement is already fully funded");
@@ -153,10 +156,8 @@ impl<'info> FundSettlement<'info> {
             ErrorCode::StakeAccountIsFundedToSettlement,
         );
         // only stake account delegated to (i.e., funded by) the bond validator vote account
-        let stake_delegation = check_stake_valid_delegation(
-            &ctx.accounts.stake_account,
-            &ctx.accounts.bond.vote_account,
-        )?;
+        let stake_delegation =
+            check_bond_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond)?;
         // funded stake account cannot be locked as we want to deactivate&withdraw
         check_stake_is_not_locked(
             &ctx.accounts.stake_account,
@@ -276,6 +277,7 @@ impl<'info> FundSettlement<'info> {
         ctx.accounts.settlement.lamports_funded += funding_amount;
 
         emit_cpi!(FundSettlementEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.bond.key(),
             settlement: ctx.accounts.settlement.key(),
             funding_amount,
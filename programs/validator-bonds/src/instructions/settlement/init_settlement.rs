@@ -1,7 +1,8 @@
+use crate::constants::{ACCOUNT_VERSION, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::settlement::InitSettlementEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::{find_settlement_staker_authority, Bumps, Settlement};
 use anchor_lang::prelude::*;
 
@@ -21,13 +22,13 @@ pub struct InitSettlementArgs {
 }
 
 /// Creates settlement account for the bond.
-/// Permission-ed for operator authority.
+/// Permission-ed for the settlement creator role of the operator.
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(params: InitSettlementArgs)]
 pub struct InitSettlement<'info> {
     #[account(
-        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+        constraint = config.settlement_creator() == operator_authority.key() @ ErrorCode::InvalidOperatorAuthority,
     )]
     pub config: Account<'info, Config>,
 
@@ -56,7 +57,7 @@ pub struct InitSettlement<'info> {
     )]
     pub settlement: Account<'info, Settlement>,
 
-    /// operator signer authority that is allowed to create the settlement account
+    /// operator signer authority holding the settlement creator role
     pub operator_authority: Signer<'info>,
 
     /// rent exempt payer of account creation
@@ -80,7 +81,10 @@ impl<'info> InitSettlement<'info> {
             epoch,
         }: InitSettlementArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
 
         if max_total_claim == 0 || max_merkle_nodes == 0 {
             return Err(error!(ErrorCode::EmptySettlementMerkleTree).with_values((
@@ -111,9 +115,18 @@ impl<'info> InitSettlement<'info> {
                 pda: ctx.bumps.settlement,
                 staker_authority: authority_bump,
             },
-            reserved: [0; 91],
+            collateral_funded: 0,
+            collateral_claimed: 0,
+            amended: false,
+            amended_merkle_root: [0; 32],
+            disputed: false,
+            dispute_reason_hash: [0; 32],
+            claim_expiry_extension: 0,
+            account_version: ACCOUNT_VERSION,
+            reserved: [0; 4],
         });
         emit_cpi!(InitSettlementEvent {
+            version: EVENT_VERSION,
             settlement: ctx.accounts.settlement.key(),
             bond: ctx.accounts.settlement.bond,
             vote_account: ctx.accounts.bond.vote_account,
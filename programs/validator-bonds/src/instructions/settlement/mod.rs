@@ -1,13 +1,21 @@
+pub mod amend_settlement;
 pub mod cancel_settlement;
 pub mod claim_settlement;
 pub mod close_settlement;
 pub mod close_settlement_claim;
+pub mod dispute_settlement;
+pub mod extend_settlement_expiry;
 pub mod fund_settlement;
 pub mod init_settlement;
+pub mod resolve_dispute;
 
+pub use amend_settlement::*;
 pub use cancel_settlement::*;
 pub use claim_settlement::*;
 pub use close_settlement::*;
 pub use close_settlement_claim::*;
+pub use dispute_settlement::*;
+pub use extend_settlement_expiry::*;
 pub use fund_settlement::*;
 pub use init_settlement::*;
+pub use resolve_dispute::*;
@@ -0,0 +1,72 @@
+use crate::constants::EVENT_VERSION;
+use crate::error::ErrorCode;
+use crate::events::settlement::ResolveDisputeEvent;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use crate::state::settlement::Settlement;
+use anchor_lang::prelude::*;
+
+/// Resolves the dispute of the settlement, the claiming is unblocked.
+/// When the dispute is justified, the settlement is expected to be amended (which clears the dispute) or cancelled instead.
+/// Permission-ed to the admin authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        has_one = admin_authority @ ErrorCode::InvalidAdminAuthority,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref()
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    #[account(
+        mut,
+        has_one = bond @ ErrorCode::BondAccountMismatch,
+        seeds = [
+            b"settlement_account",
+            bond.key().as_ref(),
+            settlement.merkle_root.as_ref(),
+            settlement.epoch_created_for.to_le_bytes().as_ref(),
+        ],
+        bump = settlement.bumps.pda,
+    )]
+    pub settlement: Account<'info, Settlement>,
+
+    /// only the admin authority can resolve the dispute
+    pub admin_authority: Signer<'info>,
+}
+
+impl<'info> ResolveDispute<'info> {
+    pub fn process(ctx: Context<ResolveDispute>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::SETTLEMENT),
+            ErrorCode::ProgramIsPaused
+        );
+
+        require!(
+            ctx.accounts.settlement.disputed,
+            ErrorCode::SettlementNotDisputed
+        );
+
+        ctx.accounts.settlement.disputed = false;
+
+        emit_cpi!(ResolveDisputeEvent {
+            version: EVENT_VERSION,
+            bond: ctx.accounts.bond.key(),
+            settlement: ctx.accounts.settlement.key(),
+            reason_hash: ctx.accounts.settlement.dispute_reason_hash,
+            admin_authority: ctx.accounts.admin_authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,8 +1,11 @@
 use crate::checks::get_delegation;
-use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, SETTLEMENT_STAKER_AUTHORITY_SEED};
+use crate::constants::{
+    BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION, SETTLEMENT_STAKER_AUTHORITY_SEED,
+};
 use crate::error::ErrorCode;
 use crate::events::stake::MergeStakeEvent;
-use crate::state::config::{find_bonds_withdrawer_authority, Config};
+use crate::events::U64ValueChange;
+use crate::state::config::{find_bonds_withdrawer_authority, pause_flags, Config};
 use crate::state::settlement::find_settlement_staker_authority;
 
 use anchor_lang::{
@@ -49,7 +52,10 @@ impl<'info> MergeStake<'info> {
         ctx: Context<MergeStake>,
         MergeStakeArgs { settlement }: MergeStakeArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::STAKE),
+            ErrorCode::ProgramIsPaused
+        );
 
         let destination_meta =
             ctx.accounts.destination_stake.meta().ok_or(
@@ -109,6 +115,10 @@ impl<'info> MergeStake<'info> {
         let (settlement_staker_authority, settlement_bump) =
             find_settlement_staker_authority(&settlement);
 
+        let source_lamports = ctx.accounts.source_stake.to_account_info().lamports();
+        let destination_lamports_before =
+            ctx.accounts.destination_stake.to_account_info().lamports();
+
         let merge_instruction = &merge(
             &ctx.accounts.destination_stake.key(),
             &ctx.accounts.source_stake.key(),
@@ -157,12 +167,20 @@ impl<'info> MergeStake<'info> {
         };
 
         emit_cpi!(MergeStakeEvent {
+            version: EVENT_VERSION,
             config: ctx.accounts.config.key(),
+            settlement,
             staker_authority: ctx.accounts.staker_authority.key(),
             destination_stake: ctx.accounts.destination_stake.key(),
             destination_delegation: destination_delegation.map(Into::into),
+            destination_lamports: U64ValueChange {
+                old: destination_lamports_before,
+                new: ctx.accounts.destination_stake.to_account_info().lamports(),
+            },
             source_stake: ctx.accounts.source_stake.key(),
             source_delegation: source_delegation.map(Into::into),
+            source_lamports,
+            epoch: ctx.accounts.clock.epoch,
         });
 
         Ok(())
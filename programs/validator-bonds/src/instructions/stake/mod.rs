@@ -1,7 +1,9 @@
 pub mod merge_stake;
+pub mod redelegate_stake;
 pub mod reset_stake;
 pub mod withdraw_stake;
 
 pub use merge_stake::*;
+pub use redelegate_stake::*;
 pub use reset_stake::*;
 pub use withdraw_stake::*;
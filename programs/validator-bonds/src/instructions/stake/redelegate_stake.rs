@@ -0,0 +1,147 @@
+use crate::checks::{
+    check_bond_stake_valid_delegation, check_stake_is_initialized_with_withdrawer_authority,
+};
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
+use crate::error::ErrorCode;
+use crate::events::stake::RedelegateStakeEvent;
+use crate::state::bond::Bond;
+use crate::state::config::{pause_flags, Config};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::vote::program::ID as vote_program_id;
+use anchor_lang::solana_program::{stake, sysvar::stake_history};
+use anchor_spl::stake::{deactivate_stake, DeactivateStake, Stake, StakeAccount};
+
+/// Moving a bond stake account to the vote account the bond was migrated to.
+/// The active stake account is deactivated first, once deactivated it's delegated to the new vote account.
+/// Permission-less operation, to be called twice per stake account (deactivate, delegate).
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RedelegateStake<'info> {
+    pub config: Account<'info, Config>,
+
+    #[account(
+        has_one = config @ ErrorCode::ConfigAccountMismatch,
+        seeds = [
+            b"bond_account",
+            config.key().as_ref(),
+            bond.vote_account.as_ref(),
+        ],
+        bump = bond.bump,
+    )]
+    pub bond: Account<'info, Bond>,
+
+    /// bond stake account delegated to the original bond vote account
+    #[account(mut)]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    /// CHECK: PDA
+    /// bonds withdrawer authority is the staker of the bond stake accounts
+    #[account(
+        seeds = [
+            b"bonds_authority",
+            config.key().as_ref(),
+        ],
+        bump = config.bonds_withdrawer_authority_bump
+    )]
+    pub bonds_withdrawer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: the vote account the bond was migrated to
+    #[account(
+        owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
+        constraint = bond.stake_vote_account() == vote_account.key() @ ErrorCode::VoteAccountMismatch,
+    )]
+    pub vote_account: UncheckedAccount<'info>,
+
+    /// CHECK: have no CPU budget to parse
+    #[account(address = stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+
+    /// CHECK: CPI
+    #[account(address = stake::config::ID)]
+    pub stake_config: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+
+    pub stake_program: Program<'info, Stake>,
+}
+
+impl<'info> RedelegateStake<'info> {
+    pub fn process(ctx: Context<RedelegateStake>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::STAKE),
+            ErrorCode::ProgramIsPaused
+        );
+
+        // stake account is managed by bonds program and not funded to any settlement
+        let stake_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &ctx.accounts.stake_account,
+            &ctx.accounts.bonds_withdrawer_authority.key(),
+            "stake_account",
+        )?;
+        require_keys_eq!(
+            stake_meta.authorized.staker,
+            ctx.accounts.bonds_withdrawer_authority.key(),
+            ErrorCode::StakeAccountIsFundedToSettlement,
+        );
+        let delegation =
+            check_bond_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond)?;
+        require_keys_neq!(
+            delegation.voter_pubkey,
+            ctx.accounts.vote_account.key(),
+            ErrorCode::NothingToRedelegate
+        );
+
+        let config_key = ctx.accounts.config.key();
+        let bonds_withdrawer_authority_seeds: &[&[&[u8]]] = &[&[
+            BONDS_WITHDRAWER_AUTHORITY_SEED,
+            config_key.as_ref(),
+            &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+        ]];
+        let deactivated = if delegation.deactivation_epoch == u64::MAX {
+            deactivate_stake(CpiContext::new_with_signer(
+                ctx.accounts.stake_program.to_account_info(),
+                DeactivateStake {
+                    stake: ctx.accounts.stake_account.to_account_info(),
+                    staker: ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                },
+                bonds_withdrawer_authority_seeds,
+            ))?;
+            true
+        } else {
+            // the stake program refuses the delegation until the stake is fully deactivated
+            let delegate_instruction = &stake::instruction::delegate_stake(
+                &ctx.accounts.stake_account.key(),
+                &ctx.accounts.bonds_withdrawer_authority.key(),
+                &ctx.accounts.vote_account.key(),
+            );
+            invoke_signed(
+                delegate_instruction,
+                &[
+                    ctx.accounts.stake_program.to_account_info(),
+                    ctx.accounts.stake_account.to_account_info(),
+                    ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+                    ctx.accounts.vote_account.to_account_info(),
+                    ctx.accounts.clock.to_account_info(),
+                    ctx.accounts.stake_history.to_account_info(),
+                    ctx.accounts.stake_config.to_account_info(),
+                ],
+                bonds_withdrawer_authority_seeds,
+            )?;
+            false
+        };
+
+        emit_cpi!(RedelegateStakeEvent {
+            version: EVENT_VERSION,
+            config: ctx.accounts.config.key(),
+            bond: ctx.accounts.bond.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            old_vote_account: delegation.voter_pubkey,
+            new_vote_account: ctx.accounts.vote_account.key(),
+            deactivated,
+        });
+
+        Ok(())
+    }
+}
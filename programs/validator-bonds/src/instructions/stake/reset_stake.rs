@@ -1,11 +1,12 @@
 use crate::checks::{
-    check_stake_is_initialized_with_withdrawer_authority, check_stake_valid_delegation, is_closed,
+    check_bond_stake_valid_delegation, check_stake_is_initialized_with_withdrawer_authority,
+    is_closed,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::stake::ResetStakeEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::find_settlement_staker_authority;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
@@ -15,6 +16,7 @@ use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
 
 /// Resetting the stake authority of a funded stake account belonging to a removed settlement.
 /// I.e., for the provided stake account, it changes the stake authority from the settlement stake authority to the bonds withdrawer authority.
+/// Permission-less unless the settlement closer role is configured, then permission-ed to the role.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct ResetStake<'info> {
@@ -23,11 +25,11 @@ pub struct ResetStake<'info> {
 
     #[account(
         has_one = config @ ErrorCode::ConfigAccountMismatch,
-        has_one = vote_account @ ErrorCode::VoteAccountMismatch,
+        constraint = bond.stake_vote_account() == vote_account.key() @ ErrorCode::VoteAccountMismatch,
         seeds = [
             b"bond_account",
             config.key().as_ref(),
-            vote_account.key().as_ref()
+            bond.vote_account.as_ref()
         ],
         bump = bond.bump,
     )]
@@ -54,6 +56,7 @@ pub struct ResetStake<'info> {
     pub bonds_withdrawer_authority: UncheckedAccount<'info>,
 
     /// CHECK: the validator vote account to which the stake account is delegated, check in code
+    /// for a migrated bond it is the vote account the bond was migrated to
     #[account(
         owner = vote_program_id @ ErrorCode::InvalidVoteAccountProgramId,
     )]
@@ -70,11 +73,34 @@ pub struct ResetStake<'info> {
     pub clock: Sysvar<'info, Clock>,
 
     pub stake_program: Program<'info, Stake>,
+
+    /// operator authority holding the settlement closer role, required only when the role is configured
+    pub operator_authority: Option<Signer<'info>>,
 }
 
 impl<'info> ResetStake<'info> {
     pub fn process(ctx: Context<ResetStake>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::STAKE),
+            ErrorCode::ProgramIsPaused
+        );
+
+        if ctx.accounts.config.settlement_closer_authority != Pubkey::default() {
+            let operator_authority = ctx
+                .accounts
+                .operator_authority
+                .as_ref()
+                .map(|operator_authority| operator_authority.key())
+                .ok_or(
+                    error!(ErrorCode::InvalidOperatorAuthority)
+                        .with_account_name("operator_authority"),
+                )?;
+            require_keys_eq!(
+                operator_authority,
+                ctx.accounts.config.settlement_closer_authority,
+                ErrorCode::InvalidOperatorAuthority
+            );
+        }
 
         // The rule stipulates to reset only when the settlement does exist.
         require!(
@@ -89,7 +115,7 @@ impl<'info> ResetStake<'info> {
             "stake_account",
         )?;
         // a bond account is tightly coupled to a vote account, this stake account belongs to bond
-        check_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond.vote_account)?;
+        check_bond_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond)?;
         // stake account is funded to removed settlement
         let settlement_staker_authority =
             find_settlement_staker_authority(&ctx.accounts.settlement.key()).0;
@@ -124,7 +150,7 @@ impl<'info> ResetStake<'info> {
         let delegate_instruction = &stake::instruction::delegate_stake(
             &ctx.accounts.stake_account.key(),
             &ctx.accounts.bonds_withdrawer_authority.key(),
-            &ctx.accounts.bond.vote_account,
+            &ctx.accounts.vote_account.key(),
         );
         invoke_signed(
             delegate_instruction,
@@ -145,12 +171,20 @@ impl<'info> ResetStake<'info> {
         )?;
 
         emit_cpi!(ResetStakeEvent {
+            version: EVENT_VERSION,
             config: ctx.accounts.config.key(),
             bond: ctx.accounts.bond.key(),
             settlement: ctx.accounts.settlement.key(),
             stake_account: ctx.accounts.stake_account.key(),
             vote_account: ctx.accounts.vote_account.key(),
             settlement_staker_authority,
+            stake_amount: ctx.accounts.stake_account.to_account_info().lamports(),
+            operator_authority: ctx
+                .accounts
+                .operator_authority
+                .as_ref()
+                .map(|operator_authority| operator_authority.key()),
+            epoch: ctx.accounts.clock.epoch,
         });
 
         Ok(())
@@ -2,10 +2,10 @@
 // allowing deprecation as anchor 0.29.0 works with old version of StakeState struct
 
 use crate::checks::{check_stake_is_initialized_with_withdrawer_authority, is_closed};
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::stake::WithdrawStakeEvent;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::settlement::find_settlement_staker_authority;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{stake::state::StakeState, sysvar::stake_history};
@@ -19,11 +19,11 @@ use std::ops::Deref;
 pub struct WithdrawStake<'info> {
     /// the config account under which the bond was created
     #[account(
-        has_one = operator_authority @ ErrorCode::InvalidOperatorAuthority,
+        constraint = config.settlement_closer() == operator_authority.key() @ ErrorCode::InvalidOperatorAuthority,
     )]
     pub config: Account<'info, Config>,
 
-    /// operator authority is allowed to reset the non-delegated stake accounts
+    /// operator authority holding the settlement closer role is allowed to withdraw the non-delegated stake accounts
     pub operator_authority: Signer<'info>,
 
     /// CHECK: in code
@@ -60,7 +60,10 @@ pub struct WithdrawStake<'info> {
 
 impl<'info> WithdrawStake<'info> {
     pub fn process(ctx: Context<WithdrawStake>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::STAKE),
+            ErrorCode::ProgramIsPaused
+        );
 
         // The rule stipulates to withdraw only when the settlement does exist.
         require!(
@@ -122,6 +125,7 @@ impl<'info> WithdrawStake<'info> {
         )?;
 
         emit_cpi!(WithdrawStakeEvent {
+            version: EVENT_VERSION,
             config: ctx.accounts.config.key(),
             operator_authority: ctx.accounts.operator_authority.key(),
             settlement: ctx.accounts.settlement.key(),
@@ -1,8 +1,9 @@
 use crate::checks::check_bond_authority;
+use crate::constants::EVENT_VERSION;
 use crate::error::ErrorCode;
 use crate::events::withdraw::CancelWithdrawRequestEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::withdraw_request::WithdrawRequest;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
@@ -55,7 +56,10 @@ pub struct CancelWithdrawRequest<'info> {
 
 impl<'info> CancelWithdrawRequest<'info> {
     pub fn process(ctx: Context<CancelWithdrawRequest>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -67,6 +71,7 @@ impl<'info> CancelWithdrawRequest<'info> {
         );
 
         emit_cpi!(CancelWithdrawRequestEvent {
+            version: EVENT_VERSION,
             withdraw_request: ctx.accounts.withdraw_request.key(),
             bond: ctx.accounts.bond.key(),
             authority: ctx.accounts.authority.key(),
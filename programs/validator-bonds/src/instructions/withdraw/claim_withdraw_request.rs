@@ -1,16 +1,17 @@
 use crate::checks::{
-    check_bond_authority, check_stake_is_initialized_with_withdrawer_authority,
-    check_stake_valid_delegation,
+    check_bond_authority, check_bond_stake_valid_delegation,
+    check_stake_is_initialized_with_withdrawer_authority, deserialize_stake_account,
 };
-use crate::constants::BONDS_WITHDRAWER_AUTHORITY_SEED;
+use crate::constants::{BONDS_WITHDRAWER_AUTHORITY_SEED, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::withdraw::ClaimWithdrawRequestEvent;
 use crate::events::{SplitStakeData, U64ValueChange};
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::withdraw_request::WithdrawRequest;
 use crate::utils::{minimal_size_stake_account, return_unused_split_stake_account_rent};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::stake::instruction::merge;
 use anchor_lang::solana_program::stake::state::{StakeAuthorize, StakeStateV2};
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
 use anchor_lang::solana_program::{program::invoke_signed, stake};
@@ -18,7 +19,9 @@ use anchor_spl::stake::{authorize, Authorize, Stake, StakeAccount};
 
 /// Withdrawing funds from a bond account requires creating a withdrawal request first.
 /// The withdrawal process involves taking a StakeAccount associated with the bonds program
-/// and changing its owner (withdrawer and staker authorities) back to the validator vote withdrawer.
+/// and changing its owner (withdrawer and staker authorities) to the withdrawer chosen by the claiming authority.
+/// Additional bond stake accounts may be passed as remaining accounts, they are merged to the stake account
+/// first, so the withdrawal is processed in one transaction without leaving small stake accounts behind.
 #[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimWithdrawRequest<'info> {
@@ -75,7 +78,7 @@ pub struct ClaimWithdrawRequest<'info> {
     pub stake_account: Account<'info, StakeAccount>,
 
     /// CHECK: whatever address, authority signature states his intention to withdraw the funds
-    /// New owner of the stake account, it will be accounted to the withdrawer authority
+    /// New owner (staker and withdrawer) of the stake account, an explicit destination chosen by the claiming authority
     pub withdrawer: UncheckedAccount<'info>,
 
     /// this is a whatever address that does not exist
@@ -106,8 +109,11 @@ pub struct ClaimWithdrawRequest<'info> {
 }
 
 impl<'info> ClaimWithdrawRequest<'info> {
-    pub fn process(ctx: Context<ClaimWithdrawRequest>) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+    pub fn process(ctx: Context<'_, '_, '_, 'info, ClaimWithdrawRequest<'info>>) -> Result<()> {
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
 
         require_gt!(
             ctx.accounts
@@ -129,7 +135,7 @@ impl<'info> ClaimWithdrawRequest<'info> {
         );
 
         // stake account is delegated to the validator vote account associated with the bond
-        check_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond.vote_account)?;
+        check_bond_stake_valid_delegation(&ctx.accounts.stake_account, &ctx.accounts.bond)?;
 
         // stake account belongs under the bonds program
         let stake_meta = check_stake_is_initialized_with_withdrawer_authority(
@@ -144,6 +150,8 @@ impl<'info> ClaimWithdrawRequest<'info> {
             ErrorCode::StakeAccountIsFundedToSettlement,
         );
 
+        let merged_stake_accounts = merge_remaining_stake_accounts(&ctx)?;
+
         // the amount that has not yet been withdrawn from the request
         let amount_to_fulfill_withdraw = ctx
             .accounts
@@ -269,10 +277,12 @@ impl<'info> ClaimWithdrawRequest<'info> {
         )?;
 
         emit_cpi!(ClaimWithdrawRequestEvent {
+            version: EVENT_VERSION,
             bond: ctx.accounts.bond.key(),
             vote_account: ctx.accounts.vote_account.key(),
             withdraw_request: ctx.accounts.withdraw_request.key(),
             stake_account: ctx.accounts.stake_account.key(),
+            merged_stake_accounts,
             split_stake: if is_split {
                 Some(SplitStakeData {
                     address: ctx.accounts.split_stake_account.key(),
@@ -298,3 +308,54 @@ impl<'info> ClaimWithdrawRequest<'info> {
         Ok(())
     }
 }
+
+/// Merges the remaining accounts to the claimed stake account.
+/// Every merged stake account has to be a bond stake account not funded to a settlement.
+fn merge_remaining_stake_accounts<'info>(
+    ctx: &Context<'_, '_, '_, 'info, ClaimWithdrawRequest<'info>>,
+) -> Result<Vec<Pubkey>> {
+    let mut merged_stake_accounts: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    for source_stake in ctx.remaining_accounts.iter() {
+        require_keys_neq!(
+            source_stake.key(),
+            ctx.accounts.stake_account.key(),
+            ErrorCode::MergeMismatchSameSourceDestination
+        );
+        let source_stake_account = deserialize_stake_account(source_stake)?;
+        check_bond_stake_valid_delegation(&source_stake_account, &ctx.accounts.bond)?;
+        let source_meta = check_stake_is_initialized_with_withdrawer_authority(
+            &source_stake_account,
+            &ctx.accounts.bonds_withdrawer_authority.key(),
+            "remaining_accounts",
+        )?;
+        require_keys_eq!(
+            source_meta.authorized.staker,
+            ctx.accounts.bonds_withdrawer_authority.key(),
+            ErrorCode::StakeAccountIsFundedToSettlement,
+        );
+
+        let merge_instruction = &merge(
+            &ctx.accounts.stake_account.key(),
+            &source_stake.key(),
+            &ctx.accounts.bonds_withdrawer_authority.key(),
+        )[0];
+        invoke_signed(
+            merge_instruction,
+            &[
+                ctx.accounts.stake_program.to_account_info(),
+                ctx.accounts.stake_account.to_account_info(),
+                source_stake.clone(),
+                ctx.accounts.clock.to_account_info(),
+                ctx.accounts.stake_history.to_account_info(),
+                ctx.accounts.bonds_withdrawer_authority.to_account_info(),
+            ],
+            &[&[
+                BONDS_WITHDRAWER_AUTHORITY_SEED,
+                &ctx.accounts.config.key().as_ref(),
+                &[ctx.accounts.config.bonds_withdrawer_authority_bump],
+            ]],
+        )?;
+        merged_stake_accounts.push(source_stake.key());
+    }
+    Ok(merged_stake_accounts)
+}
@@ -1,8 +1,9 @@
 use crate::checks::check_bond_authority;
+use crate::constants::{ACCOUNT_VERSION, EVENT_VERSION};
 use crate::error::ErrorCode;
 use crate::events::withdraw::InitWithdrawRequestEvent;
 use crate::state::bond::Bond;
-use crate::state::config::Config;
+use crate::state::config::{pause_flags, Config};
 use crate::state::withdraw_request::WithdrawRequest;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::vote::program::ID as vote_program_id;
@@ -67,7 +68,10 @@ impl<'info> InitWithdrawRequest<'info> {
         ctx: Context<InitWithdrawRequest>,
         InitWithdrawRequestArgs { amount }: InitWithdrawRequestArgs,
     ) -> Result<()> {
-        require!(!ctx.accounts.config.paused, ErrorCode::ProgramIsPaused);
+        require!(
+            !ctx.accounts.config.is_paused(pause_flags::WITHDRAW),
+            ErrorCode::ProgramIsPaused
+        );
 
         require!(
             check_bond_authority(
@@ -86,9 +90,11 @@ impl<'info> InitWithdrawRequest<'info> {
             epoch: clock.epoch,
             withdrawn_amount: 0,
             requested_amount: amount,
-            reserved: [0; 93],
+            account_version: ACCOUNT_VERSION,
+            reserved: [0; 92],
         });
         emit_cpi!(InitWithdrawRequestEvent {
+            version: EVENT_VERSION,
             withdraw_request: ctx.accounts.withdraw_request.key(),
             bond: ctx.accounts.withdraw_request.bond.key(),
             vote_account: ctx.accounts.withdraw_request.vote_account.key(),
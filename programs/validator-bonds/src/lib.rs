@@ -30,10 +30,15 @@ security_txt! {
 
 declare_id!("vBoNdEvzMrSai7is21XgVYik65mqtaKXuSdMBJ1xkW4");
 
-fn check_context<T: Bumps>(ctx: &Context<T>) -> Result<()> {
-    if !check_id(ctx.program_id) {
+fn check_program_id(program_id: &Pubkey) -> Result<()> {
+    if !check_id(program_id) {
         return err!(ErrorCode::InvalidProgramId);
     }
+    Ok(())
+}
+
+fn check_context<T: Bumps>(ctx: &Context<T>) -> Result<()> {
+    check_program_id(ctx.program_id)?;
     // make sure there are no extra accounts
     if !ctx.remaining_accounts.is_empty() {
         return err!(ErrorCode::UnexpectedRemainingAccounts);
@@ -50,6 +55,11 @@ pub mod validator_bonds {
         InitConfig::process(ctx, init_config_args)
     }
 
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        check_context(&ctx)?;
+        MigrateAccount::process(ctx)
+    }
+
     pub fn configure_config(
         ctx: Context<ConfigureConfig>,
         configure_config_args: ConfigureConfigArgs,
@@ -58,6 +68,16 @@ pub mod validator_bonds {
         ConfigureConfig::process(ctx, configure_config_args)
     }
 
+    pub fn accept_config_authority(ctx: Context<AcceptConfigAuthority>) -> Result<()> {
+        check_context(&ctx)?;
+        AcceptConfigAuthority::process(ctx)
+    }
+
+    pub fn apply_config_changes(ctx: Context<ApplyConfigChanges>) -> Result<()> {
+        check_context(&ctx)?;
+        ApplyConfigChanges::process(ctx)
+    }
+
     pub fn init_bond(ctx: Context<InitBond>, init_bond_args: InitBondArgs) -> Result<()> {
         check_context(&ctx)?;
         InitBond::process(ctx, init_bond_args)
@@ -79,6 +99,14 @@ pub mod validator_bonds {
         ConfigureBondWithMint::process(ctx, args)
     }
 
+    pub fn configure_bond_authority_mint(
+        ctx: Context<ConfigureBondAuthorityMint>,
+        args: ConfigureBondAuthorityMintArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ConfigureBondAuthorityMint::process(ctx, args)
+    }
+
     pub fn mint_bond(ctx: Context<MintBond>) -> Result<()> {
         check_context(&ctx)?;
         MintBond::process(ctx)
@@ -89,6 +117,16 @@ pub mod validator_bonds {
         FundBond::process(ctx)
     }
 
+    pub fn charge_bid(ctx: Context<ChargeBid>, charge_bid_args: ChargeBidArgs) -> Result<()> {
+        check_context(&ctx)?;
+        ChargeBid::process(ctx, charge_bid_args)
+    }
+
+    pub fn migrate_bond(ctx: Context<MigrateBond>) -> Result<()> {
+        check_context(&ctx)?;
+        MigrateBond::process(ctx)
+    }
+
     pub fn init_withdraw_request(
         ctx: Context<InitWithdrawRequest>,
         create_withdraw_request_args: InitWithdrawRequestArgs,
@@ -102,8 +140,11 @@ pub mod validator_bonds {
         CancelWithdrawRequest::process(ctx)
     }
 
-    pub fn claim_withdraw_request(ctx: Context<ClaimWithdrawRequest>) -> Result<()> {
-        check_context(&ctx)?;
+    /// remaining accounts are the bond stake accounts merged to the claimed stake account
+    pub fn claim_withdraw_request<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimWithdrawRequest<'info>>,
+    ) -> Result<()> {
+        check_program_id(ctx.program_id)?;
         ClaimWithdrawRequest::process(ctx)
     }
 
@@ -115,6 +156,35 @@ pub mod validator_bonds {
         InitSettlement::process(ctx, init_settlement_args)
     }
 
+    pub fn amend_settlement(
+        ctx: Context<AmendSettlement>,
+        amend_settlement_args: AmendSettlementArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        AmendSettlement::process(ctx, amend_settlement_args)
+    }
+
+    pub fn dispute_settlement(
+        ctx: Context<DisputeSettlement>,
+        dispute_settlement_args: DisputeSettlementArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        DisputeSettlement::process(ctx, dispute_settlement_args)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>) -> Result<()> {
+        check_context(&ctx)?;
+        ResolveDispute::process(ctx)
+    }
+
+    pub fn extend_settlement_expiry(
+        ctx: Context<ExtendSettlementExpiry>,
+        args: ExtendSettlementExpiryArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ExtendSettlementExpiry::process(ctx, args)
+    }
+
     pub fn close_settlement(ctx: Context<CloseSettlement>) -> Result<()> {
         check_context(&ctx)?;
         CloseSettlement::process(ctx)
@@ -153,11 +223,66 @@ pub mod validator_bonds {
         ResetStake::process(ctx)
     }
 
+    pub fn redelegate_stake(ctx: Context<RedelegateStake>) -> Result<()> {
+        check_context(&ctx)?;
+        RedelegateStake::process(ctx)
+    }
+
     pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
         check_context(&ctx)?;
         WithdrawStake::process(ctx)
     }
 
+    pub fn init_collateral_mint(
+        ctx: Context<InitCollateralMint>,
+        init_collateral_mint_args: InitCollateralMintArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        InitCollateralMint::process(ctx, init_collateral_mint_args)
+    }
+
+    pub fn configure_collateral_mint(
+        ctx: Context<ConfigureCollateralMint>,
+        configure_collateral_mint_args: ConfigureCollateralMintArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ConfigureCollateralMint::process(ctx, configure_collateral_mint_args)
+    }
+
+    pub fn fund_bond_collateral(
+        ctx: Context<FundBondCollateral>,
+        fund_bond_collateral_args: FundBondCollateralArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        FundBondCollateral::process(ctx, fund_bond_collateral_args)
+    }
+
+    pub fn claim_withdraw_collateral(ctx: Context<ClaimWithdrawCollateral>) -> Result<()> {
+        check_context(&ctx)?;
+        ClaimWithdrawCollateral::process(ctx)
+    }
+
+    pub fn fund_settlement_collateral(ctx: Context<FundSettlementCollateral>) -> Result<()> {
+        check_context(&ctx)?;
+        FundSettlementCollateral::process(ctx)
+    }
+
+    pub fn claim_settlement_collateral(
+        ctx: Context<ClaimSettlementCollateral>,
+        claim_settlement_args: ClaimSettlementArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ClaimSettlementCollateral::process(ctx, claim_settlement_args)
+    }
+
+    pub fn reset_settlement_collateral(
+        ctx: Context<ResetSettlementCollateral>,
+        reset_settlement_collateral_args: ResetSettlementCollateralArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        ResetSettlementCollateral::process(ctx, reset_settlement_collateral_args)
+    }
+
     pub fn emergency_pause(ctx: Context<EmergencyPauseResume>) -> Result<()> {
         check_context(&ctx)?;
         EmergencyPauseResume::pause(ctx)
@@ -167,6 +292,14 @@ pub mod validator_bonds {
         check_context(&ctx)?;
         EmergencyPauseResume::resume(ctx)
     }
+
+    pub fn set_pause_flags(
+        ctx: Context<EmergencyPauseResume>,
+        set_pause_flags_args: SetPauseFlagsArgs,
+    ) -> Result<()> {
+        check_context(&ctx)?;
+        EmergencyPauseResume::set_pause_flags(ctx, set_pause_flags_args)
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use crate::constants::{BOND_MINT_SEED, BOND_SEED};
 use crate::error::ErrorCode;
 use crate::ID;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 
 /// Bond account for a validator vote address
 #[account]
@@ -35,11 +36,54 @@ pub struct Bond {
     /// The vote account owner then goes to auction to obtain up to that maximum.
     /// Use the `cpmpe` field to define the bid for this purpose.
     pub max_stake_wanted: u64,
+    /// The last epoch the bid was charged for by the charge bid instruction
+    pub bid_charged_epoch: u64,
+    /// Lamports charged as the bid for the `bid_charged_epoch`,
+    /// lower than the bid amount when the bid was charged partially
+    pub bid_charged_amount: u64,
+    /// Vote account the bond was migrated to, the bonded stake accounts are redelegated to this vote account.
+    /// Default pubkey means the bond has not been migrated and stakes are delegated to `vote_account`.
+    /// The bond PDA address is still derived from the original `vote_account`.
+    pub migrated_vote_account: Pubkey,
+    /// Bond mint that works as a transferable bond authority credential.
+    /// Holder of a token of the mint may configure the bond without burning the token.
+    /// Default pubkey means the holding mode is disabled and the bond mint token is burnt on configuration.
+    pub authority_mint: Pubkey,
+    /// layout version of the account, see `VersionedAccount`
+    pub account_version: u8,
+    /// Epoch the bond was created at, the bid cannot be charged for an earlier epoch.
+    /// Zero for bonds created before the field was introduced.
+    pub created_epoch: u64,
     /// reserve space for future extensions
-    pub reserved: [u8; 134],
+    pub reserved: [u8; 45],
 }
 
 impl Bond {
+    /// Vote account the bond stake accounts are expected to be delegated to
+    pub fn stake_vote_account(&self) -> Pubkey {
+        if self.migrated_vote_account == Pubkey::default() {
+            self.vote_account
+        } else {
+            self.migrated_vote_account
+        }
+    }
+
+    /// The mint is the configured bond authority mint, see `authority_mint`
+    pub fn is_authority_mint(&self, mint: &Pubkey) -> bool {
+        self.authority_mint != Pubkey::default() && self.authority_mint == *mint
+    }
+
+    /// Bid in lamports for the epoch based on the cpmpe (cost per mille per epoch),
+    /// i.e., cpmpe lamports are paid for each 1000 SOLs delegated up to the `max_stake_wanted`
+    pub fn bid_amount(&self, delegated_lamports: u64) -> u64 {
+        let charged_stake = if self.max_stake_wanted > 0 {
+            delegated_lamports.min(self.max_stake_wanted)
+        } else {
+            delegated_lamports
+        };
+        (self.cpmpe as u128 * charged_stake as u128 / (1000 * LAMPORTS_PER_SOL) as u128) as u64
+    }
+
     pub fn address(&self) -> Result<Pubkey> {
         Pubkey::create_program_address(
             &[
@@ -61,6 +105,13 @@ pub fn find_bond_address(config: &Pubkey, vote_account: &Pubkey) -> (Pubkey, u8)
     )
 }
 
-pub fn find_bond_mint(bond_account: &Pubkey) -> (Pubkey, u8) {
-    Pubkey::find_program_address(&[BOND_MINT_SEED, bond_account.as_ref()], &ID)
+pub fn find_bond_mint(bond_account: &Pubkey, validator_identity: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            BOND_MINT_SEED,
+            bond_account.as_ref(),
+            validator_identity.as_ref(),
+        ],
+        &ID,
+    )
 }
@@ -0,0 +1,25 @@
+use crate::constants::BOND_ALIAS_SEED;
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Marker of the vote account a bond was migrated to.
+/// The bond stake accounts are delegated to the vote account, no other bond may be created for it.
+#[account]
+#[derive(Debug)]
+pub struct BondAlias {
+    /// Bond account that was migrated to the vote account
+    pub bond: Pubkey,
+    /// Vote account the bond was migrated to, the alias PDA address is derived from it
+    pub vote_account: Pubkey,
+    /// PDA account bump
+    pub bump: u8,
+    /// reserve space for future extensions
+    pub reserved: [u8; 32],
+}
+
+pub fn find_bond_alias_address(config: &Pubkey, vote_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[BOND_ALIAS_SEED, config.as_ref(), vote_account.as_ref()],
+        &ID,
+    )
+}
@@ -0,0 +1,197 @@
+use crate::constants::{COLLATERAL_MINT_SEED, COLLATERAL_VAULT_SEED, MAX_HAIRCUT_BPS};
+use crate::error::ErrorCode;
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// SPL token mint (e.g., a liquid staking token) whitelisted to be used as a bond collateral.
+/// Tokens are deposited to a collateral vault, a PDA token account per bond and mint
+/// owned by the bonds withdrawer authority.
+#[account]
+#[derive(Debug)]
+pub struct CollateralMint {
+    /// Config the mint is whitelisted for
+    pub config: Pubkey,
+    /// The whitelisted SPL token mint
+    pub mint: Pubkey,
+    /// Decimals of the mint, one token is 10^decimals of token base units
+    pub decimals: u8,
+    /// Price of one token in lamports, maintained by the admin authority
+    pub lamports_per_token: u64,
+    /// Haircut in basis points deducted from the token price when the collateral is valued as the bond funds
+    pub haircut_bps: u16,
+    /// PDA account bump
+    pub bump: u8,
+    /// reserve space for future extensions
+    pub reserved: [u8; 64],
+}
+
+impl CollateralMint {
+    /// Lamports value (rounded down) of the token amount at the configured price
+    pub fn market_value(&self, token_amount: u64) -> u64 {
+        mul_div_floor(
+            token_amount as u128,
+            self.lamports_per_token as u128,
+            self.token_unit(),
+        )
+    }
+
+    /// Lamports value (rounded down) of the token amount the bond is credited with,
+    /// i.e., price lowered by the haircut
+    pub fn collateral_value(&self, token_amount: u64) -> u64 {
+        mul_div_floor(
+            token_amount as u128,
+            self.haircut_price(),
+            self.collateral_unit(),
+        )
+    }
+
+    /// Token amount (rounded up) whose collateral value covers the lamports,
+    /// u64::MAX when the collateral has got no value
+    pub fn tokens_for_collateral_value(&self, lamports: u64) -> u64 {
+        mul_div_ceil(
+            lamports as u128,
+            self.collateral_unit(),
+            self.haircut_price(),
+        )
+    }
+
+    /// Token amount (rounded down) of the lamports market value
+    pub fn tokens_for_market_value(&self, lamports: u64) -> u64 {
+        if self.lamports_per_token == 0 {
+            return 0;
+        }
+        mul_div_floor(
+            lamports as u128,
+            self.token_unit(),
+            self.lamports_per_token as u128,
+        )
+    }
+
+    fn token_unit(&self) -> u128 {
+        10u128.saturating_pow(self.decimals as u32)
+    }
+
+    /// price of one token in lamports multiplied by the basis points remaining after the haircut
+    fn haircut_price(&self) -> u128 {
+        self.lamports_per_token as u128
+            * (MAX_HAIRCUT_BPS - self.haircut_bps.min(MAX_HAIRCUT_BPS)) as u128
+    }
+
+    fn collateral_unit(&self) -> u128 {
+        self.token_unit().saturating_mul(MAX_HAIRCUT_BPS as u128)
+    }
+
+    pub fn address(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                COLLATERAL_MINT_SEED,
+                self.config.as_ref(),
+                self.mint.as_ref(),
+                &[self.bump],
+            ],
+            &ID,
+        )
+        .map_err(|_| ErrorCode::InvalidCollateralMintAddress.into())
+    }
+}
+
+pub fn find_collateral_mint_address(config: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLATERAL_MINT_SEED, config.as_ref(), mint.as_ref()], &ID)
+}
+
+/// `a * b / divisor` rounded down, saturated to u64::MAX on overflow
+fn mul_div_floor(a: u128, b: u128, divisor: u128) -> u64 {
+    a.checked_mul(b)
+        .map_or(u64::MAX as u128, |product| product / divisor)
+        .min(u64::MAX as u128) as u64
+}
+
+/// `a * b / divisor` rounded up, saturated to u64::MAX on overflow or zero divisor
+fn mul_div_ceil(a: u128, b: u128, divisor: u128) -> u64 {
+    if divisor == 0 {
+        return u64::MAX;
+    }
+    a.checked_mul(b)
+        .and_then(|product| product.checked_add(divisor - 1))
+        .map_or(u64::MAX as u128, |product| product / divisor)
+        .min(u64::MAX as u128) as u64
+}
+
+/// Collateral vault token account of the bond, or of the settlement when the collateral was funded to it
+pub fn find_collateral_vault_address(owner: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLATERAL_VAULT_SEED, owner.as_ref(), mint.as_ref()], &ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collateral_mint(decimals: u8, lamports_per_token: u64, haircut_bps: u16) -> CollateralMint {
+        CollateralMint {
+            config: Pubkey::default(),
+            mint: Pubkey::default(),
+            decimals,
+            lamports_per_token,
+            haircut_bps,
+            bump: 0,
+            reserved: [0; 64],
+        }
+    }
+
+    #[test]
+    fn collateral_valuation() {
+        // 1 token (9 decimals) = 1.1 SOL, 10% haircut
+        let mint = collateral_mint(9, 1_100_000_000, 1_000);
+        assert_eq!(mint.market_value(1_000_000_000), 1_100_000_000);
+        assert_eq!(mint.collateral_value(1_000_000_000), 990_000_000);
+        assert_eq!(mint.tokens_for_market_value(1_100_000_000), 1_000_000_000);
+        assert_eq!(mint.tokens_for_collateral_value(990_000_000), 1_000_000_000);
+    }
+
+    #[test]
+    fn collateral_valuation_rounding() {
+        // 3 lamports per token base unit, 1/3 haircut rounds the value
+        let mint = collateral_mint(0, 3, 3_333);
+        assert_eq!(mint.market_value(1), 3);
+        // 1 * 3 * 6667 / 10000 = 2.0001
+        assert_eq!(mint.collateral_value(1), 2);
+        assert_eq!(mint.tokens_for_collateral_value(3), 2);
+        assert_eq!(mint.tokens_for_market_value(5), 1);
+
+        // funding rounds the tokens up, the funded tokens always cover the lamports
+        let mint = collateral_mint(9, 1_234_567_891, 777);
+        for lamports in [1, 7, 999, 1_000_000_001, 123_456_789_012] {
+            let funded_tokens = mint.tokens_for_collateral_value(lamports);
+            assert!(mint.collateral_value(funded_tokens) >= lamports);
+        }
+    }
+
+    #[test]
+    fn collateral_valuation_zero_price() {
+        let mint = collateral_mint(9, 0, 0);
+        assert_eq!(mint.market_value(1_000), 0);
+        assert_eq!(mint.collateral_value(1_000), 0);
+        assert_eq!(mint.tokens_for_market_value(1_000), 0);
+        assert_eq!(mint.tokens_for_collateral_value(1_000), u64::MAX);
+
+        // full haircut makes the collateral valueless
+        let mint = collateral_mint(9, 1_000, MAX_HAIRCUT_BPS);
+        assert_eq!(mint.market_value(1_000_000_000), 1_000);
+        assert_eq!(mint.collateral_value(1_000_000_000), 0);
+        assert_eq!(mint.tokens_for_collateral_value(1), u64::MAX);
+    }
+
+    #[test]
+    fn collateral_valuation_overflow() {
+        let mint = collateral_mint(0, u64::MAX, 0);
+        assert_eq!(mint.market_value(u64::MAX), u64::MAX);
+        assert_eq!(mint.collateral_value(u64::MAX), u64::MAX);
+        assert_eq!(mint.tokens_for_market_value(u64::MAX), 1);
+        assert_eq!(mint.tokens_for_collateral_value(u64::MAX), 1);
+
+        let mint = collateral_mint(u8::MAX, u64::MAX, 0);
+        assert_eq!(mint.market_value(u64::MAX), 0);
+        assert_eq!(mint.tokens_for_market_value(u64::MAX), u64::MAX);
+        assert_eq!(mint.tokens_for_collateral_value(u64::MAX), u64::MAX);
+    }
+}
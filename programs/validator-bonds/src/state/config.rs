@@ -21,17 +21,265 @@ pub struct Config {
     pub bonds_withdrawer_authority_bump: u8,
     /// Authority that can pause the program in case of emergency
     pub pause_authority: Pubkey,
-    // Defines if the program is paused
+    // Defines if any operation of the program is paused, see `pause_flags` for the paused operations
     pub paused: bool,
     /// How many slots to wait before settlement is permitted to be claimed
     pub slots_to_start_settlement_claiming: u64,
     /// Minimum value of max_stake_wanted to be configured by vote account owners at bond.
     pub min_bond_max_stake_wanted: u64,
+    /// Admin authority proposed by the current admin, waiting to be accepted by the new key,
+    /// default pubkey means no change is proposed
+    pub pending_admin_authority: Pubkey,
+    /// Operator authority proposed by the admin, waiting to be accepted by the new key,
+    /// default pubkey means no change is proposed
+    pub pending_operator_authority: Pubkey,
+    /// Pause authority proposed by the admin, waiting to be accepted by the new key,
+    /// default pubkey means no change is proposed
+    pub pending_pause_authority: Pubkey,
+    /// How many epochs a config parameter change is staged before it can be applied.
+    /// When 0, the parameter changes are applied immediately.
+    pub config_change_delay_epochs: u64,
+    /// Parameter changes staged by the admin, to be applied from the effective epoch
+    pub pending_config_changes: PendingConfigChanges,
+    /// Bitflag set of the paused instruction families, see `pause_flags` module
+    pub pause_flags: u8,
+    /// Operator role permitted to create settlements, default pubkey means the role is held by the operator authority
+    pub settlement_creator_authority: Pubkey,
+    /// Operator role permitted to fund settlements, default pubkey means the role is held by the operator authority
+    pub settlement_funder_authority: Pubkey,
+    /// Operator role permitted to reset and withdraw stake accounts of closed settlements,
+    /// default pubkey means the role is held by the operator authority
+    pub settlement_closer_authority: Pubkey,
+    /// Authority of stake accounts split from bonds when the bid is charged
+    pub bid_fee_collector: Pubkey,
+    /// Lamports paid from the claimed amount to the claimer (rent payer) of a settlement claim
+    /// to cover the transaction costs of the permission-less claiming
+    pub claimer_fee_lamports: u64,
+    /// Maximum claimer fee as basis points of the claimed amount, the fee is capped for small claims
+    pub claimer_fee_max_bps: u16,
+    /// layout version of the account, see `VersionedAccount`
+    pub account_version: u8,
+    /// Upper bound of the delegated lamports the operator may charge the bid for,
+    /// the bid cannot be charged until it is configured
+    pub bid_max_delegated_lamports: u64,
+    /// Maximum number of epochs a settlement claiming period can be extended in total,
+    /// see `Settlement::claim_expiry_extension`
+    pub max_claim_expiry_extension: u32,
     /// reserved space for future changes
-    pub reserved: [u8; 463],
+    pub reserved: [u8; 127],
+}
+
+/// Instruction families that can be paused independently by the pause authority
+pub mod pause_flags {
+    /// init, configure, fund and mint bond, fund bond collateral
+    pub const BOND: u8 = 1;
+    /// init, cancel and claim withdraw request, claim withdraw collateral
+    pub const WITHDRAW: u8 = 1 << 1;
+    /// init, amend, fund, cancel and close settlement, dispute settlement and resolve dispute,
+    /// charge bid, fund settlement collateral
+    pub const SETTLEMENT: u8 = 1 << 2;
+    /// claim settlement, claim settlement collateral
+    pub const CLAIM: u8 = 1 << 3;
+    /// merge, reset and withdraw stake, reset settlement collateral
+    pub const STAKE: u8 = 1 << 4;
+    pub const ALL: u8 = BOND | WITHDRAW | SETTLEMENT | CLAIM | STAKE;
+}
+
+/// Config parameter changes, `None` means the parameter is not changed
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, Default, PartialEq)]
+pub struct ConfigParamChanges {
+    pub epochs_to_claim_settlement: Option<u64>,
+    pub withdraw_lockup_epochs: Option<u64>,
+    pub minimum_stake_lamports: Option<u64>,
+    pub slots_to_start_settlement_claiming: Option<u64>,
+    pub min_bond_max_stake_wanted: Option<u64>,
+    pub config_change_delay_epochs: Option<u64>,
+    pub claimer_fee_lamports: Option<u64>,
+    pub claimer_fee_max_bps: Option<u16>,
+    pub bid_max_delegated_lamports: Option<u64>,
+    pub max_claim_expiry_extension: Option<u32>,
+}
+
+impl ConfigParamChanges {
+    pub fn is_empty(&self) -> bool {
+        self.epochs_to_claim_settlement.is_none()
+            && self.withdraw_lockup_epochs.is_none()
+            && self.minimum_stake_lamports.is_none()
+            && self.slots_to_start_settlement_claiming.is_none()
+            && self.min_bond_max_stake_wanted.is_none()
+            && self.config_change_delay_epochs.is_none()
+            && self.claimer_fee_lamports.is_none()
+            && self.claimer_fee_max_bps.is_none()
+            && self.bid_max_delegated_lamports.is_none()
+            && self.max_claim_expiry_extension.is_none()
+    }
+}
+
+/// Parameters of the staged config changes, see `PendingConfigChanges::staged_params`
+pub mod staged_config_params {
+    pub const EPOCHS_TO_CLAIM_SETTLEMENT: u16 = 1;
+    pub const WITHDRAW_LOCKUP_EPOCHS: u16 = 1 << 1;
+    pub const MINIMUM_STAKE_LAMPORTS: u16 = 1 << 2;
+    pub const SLOTS_TO_START_SETTLEMENT_CLAIMING: u16 = 1 << 3;
+    pub const MIN_BOND_MAX_STAKE_WANTED: u16 = 1 << 4;
+    pub const CONFIG_CHANGE_DELAY_EPOCHS: u16 = 1 << 5;
+    pub const CLAIMER_FEE_LAMPORTS: u16 = 1 << 6;
+    pub const CLAIMER_FEE_MAX_BPS: u16 = 1 << 7;
+    pub const BID_MAX_DELEGATED_LAMPORTS: u16 = 1 << 8;
+    pub const MAX_CLAIM_EXPIRY_EXTENSION: u16 = 1 << 9;
+}
+
+/// Config parameters staged by the admin authority.
+/// Any party may apply them once the `effective_epoch` is reached.
+/// Fixed-size encoded to keep the offsets of the following Config fields stable,
+/// a parameter value is considered only when its bit is set in `staged_params`.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct PendingConfigChanges {
+    /// epoch from which the changes may be applied
+    pub effective_epoch: u64,
+    /// bitflag set of the staged parameters, see `staged_config_params` module, 0 means nothing is staged
+    pub staged_params: u16,
+    pub epochs_to_claim_settlement: u64,
+    pub withdraw_lockup_epochs: u64,
+    pub minimum_stake_lamports: u64,
+    pub slots_to_start_settlement_claiming: u64,
+    pub min_bond_max_stake_wanted: u64,
+    pub config_change_delay_epochs: u64,
+    pub claimer_fee_lamports: u64,
+    pub claimer_fee_max_bps: u16,
+    pub bid_max_delegated_lamports: u64,
+    pub max_claim_expiry_extension: u32,
+}
+
+impl PendingConfigChanges {
+    pub fn new(effective_epoch: u64, changes: &ConfigParamChanges) -> Self {
+        use staged_config_params::*;
+        let mut pending = PendingConfigChanges {
+            effective_epoch,
+            ..Default::default()
+        };
+        let mut stage = |flag: u16, value: Option<u64>| -> u64 {
+            if value.is_some() {
+                pending.staged_params |= flag;
+            }
+            value.unwrap_or_default()
+        };
+        let epochs_to_claim_settlement = stage(
+            EPOCHS_TO_CLAIM_SETTLEMENT,
+            changes.epochs_to_claim_settlement,
+        );
+        let withdraw_lockup_epochs = stage(WITHDRAW_LOCKUP_EPOCHS, changes.withdraw_lockup_epochs);
+        let minimum_stake_lamports = stage(MINIMUM_STAKE_LAMPORTS, changes.minimum_stake_lamports);
+        let slots_to_start_settlement_claiming = stage(
+            SLOTS_TO_START_SETTLEMENT_CLAIMING,
+            changes.slots_to_start_settlement_claiming,
+        );
+        let min_bond_max_stake_wanted =
+            stage(MIN_BOND_MAX_STAKE_WANTED, changes.min_bond_max_stake_wanted);
+        let config_change_delay_epochs = stage(
+            CONFIG_CHANGE_DELAY_EPOCHS,
+            changes.config_change_delay_epochs,
+        );
+        let claimer_fee_lamports = stage(CLAIMER_FEE_LAMPORTS, changes.claimer_fee_lamports);
+        let claimer_fee_max_bps = stage(
+            CLAIMER_FEE_MAX_BPS,
+            changes.claimer_fee_max_bps.map(u64::from),
+        ) as u16;
+        let bid_max_delegated_lamports = stage(
+            BID_MAX_DELEGATED_LAMPORTS,
+            changes.bid_max_delegated_lamports,
+        );
+        let max_claim_expiry_extension = stage(
+            MAX_CLAIM_EXPIRY_EXTENSION,
+            changes.max_claim_expiry_extension.map(u64::from),
+        ) as u32;
+        PendingConfigChanges {
+            epochs_to_claim_settlement,
+            withdraw_lockup_epochs,
+            minimum_stake_lamports,
+            slots_to_start_settlement_claiming,
+            min_bond_max_stake_wanted,
+            config_change_delay_epochs,
+            claimer_fee_lamports,
+            claimer_fee_max_bps,
+            bid_max_delegated_lamports,
+            max_claim_expiry_extension,
+            ..pending
+        }
+    }
+
+    pub fn is_staged(&self) -> bool {
+        self.staged_params != 0
+    }
+
+    /// The staged parameters, the not staged ones are `None`
+    pub fn changes(&self) -> ConfigParamChanges {
+        use staged_config_params::*;
+        let staged = |flag: u16| self.staged_params & flag != 0;
+        ConfigParamChanges {
+            epochs_to_claim_settlement: staged(EPOCHS_TO_CLAIM_SETTLEMENT)
+                .then_some(self.epochs_to_claim_settlement),
+            withdraw_lockup_epochs: staged(WITHDRAW_LOCKUP_EPOCHS)
+                .then_some(self.withdraw_lockup_epochs),
+            minimum_stake_lamports: staged(MINIMUM_STAKE_LAMPORTS)
+                .then_some(self.minimum_stake_lamports),
+            slots_to_start_settlement_claiming: staged(SLOTS_TO_START_SETTLEMENT_CLAIMING)
+                .then_some(self.slots_to_start_settlement_claiming),
+            min_bond_max_stake_wanted: staged(MIN_BOND_MAX_STAKE_WANTED)
+                .then_some(self.min_bond_max_stake_wanted),
+            config_change_delay_epochs: staged(CONFIG_CHANGE_DELAY_EPOCHS)
+                .then_some(self.config_change_delay_epochs),
+            claimer_fee_lamports: staged(CLAIMER_FEE_LAMPORTS).then_some(self.claimer_fee_lamports),
+            claimer_fee_max_bps: staged(CLAIMER_FEE_MAX_BPS).then_some(self.claimer_fee_max_bps),
+            bid_max_delegated_lamports: staged(BID_MAX_DELEGATED_LAMPORTS)
+                .then_some(self.bid_max_delegated_lamports),
+            max_claim_expiry_extension: staged(MAX_CLAIM_EXPIRY_EXTENSION)
+                .then_some(self.max_claim_expiry_extension),
+        }
+    }
 }
 
 impl Config {
+    /// Paused instruction families.
+    /// Config paused before the pause flags were introduced has got all operations paused.
+    pub fn effective_pause_flags(&self) -> u8 {
+        if self.paused && self.pause_flags == 0 {
+            pause_flags::ALL
+        } else {
+            self.pause_flags
+        }
+    }
+
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.effective_pause_flags() & flags != 0
+    }
+
+    pub fn settlement_creator(&self) -> Pubkey {
+        self.role_or_operator(self.settlement_creator_authority)
+    }
+
+    pub fn settlement_funder(&self) -> Pubkey {
+        self.role_or_operator(self.settlement_funder_authority)
+    }
+
+    pub fn settlement_closer(&self) -> Pubkey {
+        self.role_or_operator(self.settlement_closer_authority)
+    }
+
+    /// Fee for the claimer carved from the claim amount
+    pub fn claimer_fee(&self, claim: u64) -> u64 {
+        let max_fee = (claim as u128 * self.claimer_fee_max_bps as u128 / 10_000) as u64;
+        self.claimer_fee_lamports.min(max_fee)
+    }
+
+    fn role_or_operator(&self, role_authority: Pubkey) -> Pubkey {
+        if role_authority == Pubkey::default() {
+            self.operator_authority
+        } else {
+            role_authority
+        }
+    }
+
     pub fn bonds_withdrawer_authority(&self, config_address: &Pubkey) -> Result<Pubkey> {
         Pubkey::create_program_address(
             &[
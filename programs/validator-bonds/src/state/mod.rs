@@ -1,5 +1,44 @@
+use crate::state::bond::Bond;
+use crate::state::config::Config;
+use crate::state::settlement::Settlement;
+use crate::state::settlement_claim::SettlementClaim;
+use crate::state::withdraw_request::WithdrawRequest;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
 pub mod bond;
+pub mod bond_alias;
+pub mod collateral_mint;
 pub mod config;
 pub mod settlement;
 pub mod settlement_claim;
+pub mod settlement_collateral;
 pub mod withdraw_request;
+
+/// Program account with the layout version stored in the byte carved from the reserved space.
+/// Version 0 is the layout of accounts created before the versioning was introduced,
+/// the fields added since then were carved from zero-initialized reserved space,
+/// i.e., the version 0 is binary compatible with the version 1.
+pub trait VersionedAccount: AccountSerialize + AccountDeserialize + Discriminator {
+    fn account_version(&self) -> u8;
+
+    fn set_account_version(&mut self, account_version: u8);
+}
+
+macro_rules! versioned_account {
+    ($($account:ty),* $(,)?) => {
+        $(
+            impl VersionedAccount for $account {
+                fn account_version(&self) -> u8 {
+                    self.account_version
+                }
+
+                fn set_account_version(&mut self, account_version: u8) {
+                    self.account_version = account_version;
+                }
+            }
+        )*
+    };
+}
+
+versioned_account!(Bond, Config, Settlement, SettlementClaim, WithdrawRequest);
@@ -14,7 +14,8 @@ pub struct Settlement {
     /// settlement authority used as the 'staker' stake account authority
     /// of stake accounts funded to this settlement
     pub staker_authority: Pubkey,
-    /// 256-bit merkle root to check the claims against
+    /// 256-bit merkle root the settlement was created with, used as the PDA seed
+    /// and to check the claims against when the settlement has not been amended
     pub merkle_root: [u8; 32],
     /// maximum number of funds that can ever be claimed
     pub max_total_claim: u64,
@@ -38,8 +39,25 @@ pub struct Settlement {
     pub split_rent_amount: u64,
     /// PDA bumps
     pub bumps: Bumps,
+    /// lamports value of token collateral funded to the settlement, part of `lamports_funded`
+    pub collateral_funded: u64,
+    /// lamports claimed from the token collateral, part of `lamports_claimed`
+    pub collateral_claimed: u64,
+    /// the settlement was amended before any claim, the claims are verified against `amended_merkle_root`
+    pub amended: bool,
+    /// merkle root replacing the original one when the settlement was amended, zeroed when not amended
+    pub amended_merkle_root: [u8; 32],
+    /// the settlement was disputed by the bond authority, claiming is blocked until the admin resolves it
+    pub disputed: bool,
+    /// hash of the dispute reason provided by the bond authority, kept when the dispute is resolved
+    /// and cleared when the settlement is amended
+    pub dispute_reason_hash: [u8; 32],
+    /// number of epochs the settlement claiming is extended beyond the config `epochs_to_claim_settlement`
+    pub claim_expiry_extension: u32,
+    /// layout version of the account, see `VersionedAccount`
+    pub account_version: u8,
     /// reserve space for future extensions
-    pub reserved: [u8; 91],
+    pub reserved: [u8; 4],
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, Default)]
@@ -49,6 +67,20 @@ pub struct Bumps {
 }
 
 impl Settlement {
+    /// merkle root the claims are verified against
+    pub fn claim_merkle_root(&self) -> [u8; 32] {
+        if self.amended {
+            self.amended_merkle_root
+        } else {
+            self.merkle_root
+        }
+    }
+
+    /// the last epoch the settlement can be claimed at, afterwards it can be closed
+    pub fn expiration_epoch(&self, epochs_to_claim_settlement: u64) -> u64 {
+        self.epoch_created_for + epochs_to_claim_settlement + self.claim_expiry_extension as u64
+    }
+
     pub fn address(&self) -> Result<Pubkey> {
         Pubkey::create_program_address(
             &[
@@ -23,8 +23,10 @@ pub struct SettlementClaim {
     pub bump: u8,
     /// rent collector account to get the rent back for claim account creation
     pub rent_collector: Pubkey,
+    /// layout version of the account, see `VersionedAccount`
+    pub account_version: u8,
     /// reserve space for future extensions
-    pub reserved: [u8; 93],
+    pub reserved: [u8; 92],
 }
 
 impl SettlementClaim {
@@ -0,0 +1,91 @@
+use crate::constants::SETTLEMENT_COLLATERAL_SEED;
+use crate::ID;
+use anchor_lang::prelude::*;
+
+/// Token collateral of a particular mint funded to the settlement.
+/// The claims are paid pro rata of the funded tokens to their funded lamports value,
+/// i.e., at the valuation the settlement was funded with regardless of later changes of the collateral mint price.
+#[account]
+#[derive(Debug)]
+pub struct SettlementCollateral {
+    /// Settlement the collateral was funded to
+    pub settlement: Pubkey,
+    /// Mint of the funded collateral tokens
+    pub mint: Pubkey,
+    /// Tokens moved to the settlement collateral vault
+    pub tokens_funded: u64,
+    /// Lamports value the tokens were funded with, part of the settlement `collateral_funded`
+    pub lamports_funded: u64,
+    /// Tokens paid to the claimers
+    pub tokens_claimed: u64,
+    /// Lamports of the claims paid with the tokens, part of the settlement `collateral_claimed`
+    pub lamports_claimed: u64,
+    /// PDA account bump
+    pub bump: u8,
+    /// reserve space for future extensions
+    pub reserved: [u8; 32],
+}
+
+impl SettlementCollateral {
+    /// Token amount (rounded down) paying the lamports claim pro rata to the funded tokens,
+    /// the claim exhausting the funded lamports takes all the remaining tokens
+    pub fn tokens_of_claim(&self, claim: u64) -> u64 {
+        if self.lamports_claimed.saturating_add(claim) >= self.lamports_funded {
+            return self.tokens_funded.saturating_sub(self.tokens_claimed);
+        }
+        (claim as u128 * self.tokens_funded as u128 / self.lamports_funded as u128) as u64
+    }
+}
+
+pub fn find_settlement_collateral_address(settlement: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SETTLEMENT_COLLATERAL_SEED,
+            settlement.as_ref(),
+            mint.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settlement_collateral(tokens_funded: u64, lamports_funded: u64) -> SettlementCollateral {
+        SettlementCollateral {
+            settlement: Pubkey::default(),
+            mint: Pubkey::default(),
+            tokens_funded,
+            lamports_funded,
+            tokens_claimed: 0,
+            lamports_claimed: 0,
+            bump: 0,
+            reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn claims_pro_rata() {
+        // 10 tokens funded at 2.5 lamports each
+        let mut collateral = settlement_collateral(10, 25);
+        assert_eq!(collateral.tokens_of_claim(10), 4);
+        assert_eq!(collateral.tokens_of_claim(1), 0);
+
+        // all the claims together pay exactly the funded tokens
+        for claim in [7, 7, 7, 4] {
+            let tokens = collateral.tokens_of_claim(claim);
+            collateral.tokens_claimed += tokens;
+            collateral.lamports_claimed += claim;
+        }
+        assert_eq!(collateral.tokens_claimed, 10);
+        assert_eq!(collateral.lamports_claimed, 25);
+    }
+
+    #[test]
+    fn claims_pro_rata_overflow() {
+        let collateral = settlement_collateral(u64::MAX, u64::MAX);
+        assert_eq!(collateral.tokens_of_claim(u64::MAX - 1), u64::MAX - 1);
+        assert_eq!(collateral.tokens_of_claim(u64::MAX), u64::MAX);
+    }
+}
@@ -19,8 +19,10 @@ pub struct WithdrawRequest {
     pub withdrawn_amount: u64,
     /// PDA account bump
     pub bump: u8,
+    /// layout version of the account, see `VersionedAccount`
+    pub account_version: u8,
     /// reserve space for future extensions
-    pub reserved: [u8; 93],
+    pub reserved: [u8; 92],
 }
 
 impl WithdrawRequest {
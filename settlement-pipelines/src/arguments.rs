@@ -1,13 +1,16 @@
+use crate::metrics::MetricsRpcSender;
+use crate::rpc::FailoverRpcSender;
 use anchor_client::anchor_lang::prelude::Pubkey;
 use anchor_client::{Cluster, DynSigner, Program};
 use anyhow::anyhow;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use log::debug;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::RpcClientConfig;
 use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
 use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
 use solana_transaction_executor::{PriorityFeePolicy, TipPolicy};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use validator_bonds_common::{constants::MARINADE_CONFIG_ADDRESS, get_validator_bonds_program};
@@ -24,6 +27,14 @@ pub struct GlobalOpts {
     )]
     pub rpc_url: String,
 
+    /// RPC urls the requests fail over to when --rpc-url is rate limited or unavailable, in order of preference
+    #[arg(long, env, value_delimiter = ',')]
+    pub rpc_fallback_url: Vec<String>,
+
+    /// Maximum of requests per second sent to every RPC endpoint
+    #[arg(long, env)]
+    pub rpc_rate_limit: Option<u32>,
+
     #[arg(long = "commitment", default_value = "confirmed")]
     pub commitment: CommitmentLevel,
 
@@ -45,6 +56,14 @@ pub struct GlobalOpts {
 
     #[arg(long)]
     pub skip_preflight: bool,
+
+    /// Transactions are simulated and reported, nothing is sent to the network
+    #[arg(long, global = true, default_value_t = false)]
+    pub dry_run: bool,
+
+    /// RPC url used to simulate transactions in --dry-run mode, if not provided, --rpc-url is used
+    #[arg(long, env)]
+    pub simulation_rpc_url: Option<String>,
 }
 
 #[derive(Debug, Args)]
@@ -67,6 +86,65 @@ pub struct TipPolicyOpts {
     tip_multiplier: Option<u64>,
 }
 
+#[derive(Debug, Args)]
+pub struct PipelineStateOpts {
+    /// JSON journal file to persist the pipeline progress in, a re-run resumes only the pending work
+    #[arg(long, env)]
+    pub state_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct ReportOpts {
+    /// Format of the report printed at the end of the run
+    #[arg(long, env, value_enum, default_value_t = ReportFormat::Text)]
+    pub report_format: ReportFormat,
+
+    /// File where the report is written to in the --report-format
+    #[arg(long, env)]
+    pub report_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+pub struct NotificationOpts {
+    /// Generic webhook the JSON notification (summary on completion, alert on errors) is posted to
+    #[arg(long, env)]
+    pub notify_webhook_url: Option<String>,
+
+    /// Slack incoming webhook the notifications are posted to
+    #[arg(long, env)]
+    pub notify_slack_webhook_url: Option<String>,
+
+    /// Discord webhook the notifications are posted to
+    #[arg(long, env)]
+    pub notify_discord_webhook_url: Option<String>,
+
+    /// Only the alerts on errors are posted, the summary is not
+    #[arg(long, env, default_value_t = false)]
+    pub notify_alerts_only: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct MetricsOpts {
+    /// Prometheus text file the metrics are written to at the end of the run (e.g., for node_exporter textfile collector)
+    #[arg(long, env)]
+    pub metrics_file: Option<PathBuf>,
+
+    /// Prometheus push gateway the metrics are pushed to at the end of the run
+    #[arg(long, env)]
+    pub metrics_pushgateway_url: Option<String>,
+
+    /// Job name the metrics are pushed under to the push gateway
+    #[arg(long, env, default_value = "settlement-pipelines")]
+    pub metrics_job: String,
+}
+
 pub fn load_default_keypair(s: Option<&str>) -> anyhow::Result<Option<Arc<Keypair>>> {
     if s.is_none() || s.unwrap().is_empty() {
         load_keypair(DEFAULT_KEYPAIR_PATH).map_or_else(|_e| Ok(None), |keypair| Ok(Some(keypair)))
@@ -163,28 +241,67 @@ pub struct InitializedGlobalOpts {
     pub tip_policy: TipPolicy,
     pub rpc_client: Arc<RpcClient>,
     pub program: Program<Arc<DynSigner>>,
+    /// RPC client to simulate transactions with, available only in --dry-run mode
+    pub simulation_rpc_client: Option<Arc<RpcClient>>,
 }
 
 /// Initialize the Anchor Solana client
 pub fn get_rpc_client(global_opts: &GlobalOpts) -> anyhow::Result<(Arc<RpcClient>, String)> {
     let rpc_url = global_opts.rpc_url.clone();
-    let anchor_cluster = Cluster::from_str(&rpc_url)
-        .map_err(|e| anyhow!("Could not parse JSON RPC url `{:?}`: {}", rpc_url, e))?;
-    let rpc_client = Arc::new(RpcClient::new_with_commitment(
-        anchor_cluster.to_string(),
-        CommitmentConfig {
+    let endpoint_urls = std::iter::once(&rpc_url)
+        .chain(global_opts.rpc_fallback_url.iter())
+        .map(|url| {
+            Cluster::from_str(url)
+                .map(|cluster| cluster.to_string())
+                .map_err(|e| anyhow!("Could not parse JSON RPC url `{:?}`: {}", url, e))
+        })
+        .collect::<anyhow::Result<Vec<String>>>()?;
+    // latency of every RPC request is measured for the metrics
+    let rpc_client = Arc::new(RpcClient::new_sender(
+        MetricsRpcSender::new(FailoverRpcSender::new(
+            &endpoint_urls,
+            global_opts.rpc_rate_limit,
+        )?),
+        RpcClientConfig::with_commitment(CommitmentConfig {
             commitment: CommitmentLevel::Confirmed,
-        },
+        }),
     ));
     Ok((rpc_client, rpc_url))
 }
 
+/// Initialize the RPC client for simulation of transactions when --dry-run is set
+pub fn get_simulation_rpc_client(
+    global_opts: &GlobalOpts,
+) -> anyhow::Result<Option<Arc<RpcClient>>> {
+    if !global_opts.dry_run {
+        return Ok(None);
+    }
+    let rpc_url = global_opts
+        .simulation_rpc_url
+        .clone()
+        .unwrap_or(global_opts.rpc_url.clone());
+    let anchor_cluster = Cluster::from_str(&rpc_url).map_err(|e| {
+        anyhow!(
+            "Could not parse simulation JSON RPC url `{:?}`: {}",
+            rpc_url,
+            e
+        )
+    })?;
+    Ok(Some(Arc::new(RpcClient::new_with_commitment(
+        anchor_cluster.to_string(),
+        CommitmentConfig {
+            commitment: global_opts.commitment,
+        },
+    ))))
+}
+
 pub fn init_from_opts(
     global_opts: &GlobalOpts,
     priority_fee_policy_opts: &PriorityFeePolicyOpts,
     tip_policy_opts: &TipPolicyOpts,
 ) -> anyhow::Result<InitializedGlobalOpts> {
     let (rpc_client, _) = get_rpc_client(global_opts)?;
+    let simulation_rpc_client = get_simulation_rpc_client(global_opts)?;
 
     let default_keypair = load_default_keypair(global_opts.keypair.as_deref())?;
     let fee_payer_keypair = if let Some(fee_payer) = global_opts.fee_payer.clone() {
@@ -217,5 +334,6 @@ pub fn init_from_opts(
         tip_policy,
         rpc_client,
         program,
+        simulation_rpc_client,
     })
 }
@@ -0,0 +1,15 @@
+use clap::Parser;
+use settlement_pipelines::cli_result::CliResult;
+use settlement_pipelines::commands::reconcile::{run, Args, ReconcileReport};
+use settlement_pipelines::init::init_log;
+use settlement_pipelines::reporting::with_reporting;
+
+#[tokio::main]
+async fn main() -> CliResult {
+    let args: Args = Args::parse();
+    init_log(&args.global_opts);
+
+    let mut reporting = ReconcileReport::report_handler();
+    let result = run(args, &mut reporting).await;
+    with_reporting::<ReconcileReport>(&reporting, result).await
+}
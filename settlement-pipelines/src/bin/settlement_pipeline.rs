@@ -0,0 +1,764 @@
+use anyhow::anyhow;
+use clap::{Args, Parser, Subcommand};
+use log::info;
+use serde::Serialize;
+use serde_json::{json, Value};
+use settlement_engine::utils::read_from_json_file;
+use settlement_pipelines::arguments::{
+    get_rpc_client, GlobalOpts, MetricsOpts, NotificationOpts, ReportOpts,
+};
+use settlement_pipelines::cli_result::{CliError, CliResult};
+use settlement_pipelines::commands::claim_settlement::ClaimSettlementReport;
+use settlement_pipelines::commands::close_settlement::CloseSettlementReport;
+use settlement_pipelines::commands::init_settlement::InitSettlementReport;
+use settlement_pipelines::commands::list_claimable_epoch::list_claimable_epochs;
+use settlement_pipelines::commands::list_settlement::list_settlements;
+use settlement_pipelines::commands::reconcile::ReconcileReport;
+use settlement_pipelines::commands::{
+    claim_settlement, close_settlement, generate_settlements, init_settlement,
+    list_claimable_epoch, list_settlement, parse_snapshot, public_report, reconcile,
+};
+use settlement_pipelines::epoch_plan::{EpochPlan, PlanStep};
+use settlement_pipelines::init::{init_log, init_log_with_verbosity};
+use settlement_pipelines::json_data::BondSettlement;
+use settlement_pipelines::metrics::MetricsExporter;
+use settlement_pipelines::notification::Notifiers;
+use settlement_pipelines::pipeline_state::PipelineStateStore;
+use settlement_pipelines::reporting::{
+    to_report_data, with_reporting, PrintReportable, ReportHandler, ReportWithData,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::str::FromStr;
+
+/// Settlement pipelines in one binary, the subcommands are the same as the standalone binaries
+/// and `run-epoch` executes the whole lifecycle of an epoch from a YAML plan
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Parses the ledger snapshot to the validator and stake meta collections
+    ParseSnapshot(parse_snapshot::Args),
+    /// Generates the protected events, settlements and merkle trees JSON files
+    GenerateSettlements(generate_settlements::Args),
+    /// Derives the Settlement addresses from the merkle tree JSON files
+    ListSettlement(list_settlement::Args),
+    /// Creates and funds the Settlements on-chain
+    InitSettlement(init_settlement::Args),
+    /// Prints the epochs with Settlements that can be claimed
+    ListClaimableEpoch(list_claimable_epoch::Args),
+    /// Claims the claimable Settlements
+    ClaimSettlement(claim_settlement::Args),
+    /// Closes the expired Settlements and resets the stake accounts
+    CloseSettlement(close_settlement::Args),
+    /// Prints the public report of the Settlements of an epoch and posts it to Discord
+    PublicReport(public_report::Args),
+    /// Reconciles the on-chain Settlements with the merkle tree JSON files
+    Reconcile(reconcile::Args),
+    /// Executes the lifecycle of Settlements for an epoch as defined in the YAML plan
+    RunEpoch(RunEpochArgs),
+}
+
+#[derive(Args, Debug)]
+struct RunEpochArgs {
+    /// YAML file with the epoch plan
+    #[arg(long)]
+    plan: PathBuf,
+
+    /// Logging to be verbose
+    #[arg(long, short, default_value_t = false)]
+    verbose: bool,
+
+    #[clap(flatten)]
+    report_opts: ReportOpts,
+
+    #[clap(flatten)]
+    notification_opts: NotificationOpts,
+
+    #[clap(flatten)]
+    metrics_opts: MetricsOpts,
+}
+
+#[tokio::main]
+async fn main() -> CliResult {
+    match Cli::parse().command {
+        Command::ParseSnapshot(args) => {
+            init_log_with_verbosity(false);
+            CliResult(run_blocking(move || parse_snapshot::run(args)).await)
+        }
+        Command::GenerateSettlements(args) => {
+            init_log_with_verbosity(false);
+            CliResult(run_blocking(move || generate_settlements::run(args)).await)
+        }
+        Command::ListSettlement(args) => {
+            init_log(&args.global_opts);
+            CliResult(list_settlement::run(args))
+        }
+        Command::ListClaimableEpoch(args) => {
+            init_log(&args.global_opts);
+            CliResult(list_claimable_epoch::run(args).await)
+        }
+        Command::PublicReport(args) => {
+            init_log_with_verbosity(false);
+            CliResult(public_report::run(args).await)
+        }
+        Command::InitSettlement(args) => {
+            init_log(&args.global_opts);
+            let mut reporting = InitSettlementReport::report_handler();
+            let result = init_settlement::run(args, &mut reporting).await;
+            with_reporting::<InitSettlementReport>(&reporting, result).await
+        }
+        Command::ClaimSettlement(args) => {
+            init_log(&args.global_opts);
+            let mut reporting = ClaimSettlementReport::report_handler();
+            let result = claim_settlement::run(args, &mut reporting).await;
+            with_reporting::<ClaimSettlementReport>(&reporting, result).await
+        }
+        Command::CloseSettlement(args) => {
+            init_log(&args.global_opts);
+            let mut reporting = CloseSettlementReport::report_handler();
+            let result = close_settlement::run(args, &mut reporting).await;
+            with_reporting::<CloseSettlementReport>(&reporting, result).await
+        }
+        Command::Reconcile(args) => {
+            init_log(&args.global_opts);
+            let mut reporting = ReconcileReport::report_handler();
+            let result = reconcile::run(args, &mut reporting).await;
+            with_reporting::<ReconcileReport>(&reporting, result).await
+        }
+        Command::RunEpoch(args) => {
+            init_log_with_verbosity(args.verbose);
+            let mut reporting = RunEpochReport::report_handler();
+            reporting.set_report_opts(&args.report_opts);
+            reporting.set_notifiers(Notifiers::new("run-epoch", &args.notification_opts));
+            reporting.set_metrics_exporter(MetricsExporter::new("run-epoch", &args.metrics_opts));
+            let result = run_epoch(&args.plan, &mut reporting).await;
+            with_reporting::<RunEpochReport>(&reporting, result).await
+        }
+    }
+}
+
+/// Snapshot parsing and settlements generation are CPU heavy and blocking
+async fn run_blocking<F>(f: F) -> anyhow::Result<()>
+where
+    F: FnOnce() -> anyhow::Result<()> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await?
+}
+
+async fn run_epoch(
+    plan_path: &Path,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> anyhow::Result<()> {
+    let plan = EpochPlan::load(plan_path)?;
+    info!(
+        "Running plan '{:?}' for epoch {}, steps: {}",
+        plan_path,
+        plan.epoch,
+        plan.steps
+            .iter()
+            .map(|step| step.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    reporting.reportable.init(&plan);
+
+    for step in &plan.steps {
+        info!("Epoch {} step {}", plan.epoch, step);
+        let succeeded = match step {
+            PlanStep::ParseSnapshot { ledger_path } => {
+                run_parse_snapshot(&plan, step, ledger_path, reporting).await
+            }
+            PlanStep::GenerateSettlements {
+                settlement_config,
+                past_validator_meta_collection,
+                whitelist_stake_authority,
+            } => {
+                run_generate_settlements(
+                    &plan,
+                    step,
+                    settlement_config,
+                    past_validator_meta_collection,
+                    whitelist_stake_authority,
+                    reporting,
+                )
+                .await
+            }
+            PlanStep::ListSettlement => run_list_settlement(&plan, step, reporting),
+            PlanStep::InitSettlement { args } => {
+                run_init_settlement(&plan, step, args, reporting).await
+            }
+            PlanStep::ClaimSettlement { args } => {
+                run_claim_settlement(&plan, step, args, reporting).await
+            }
+            PlanStep::CloseSettlement { args } => {
+                run_close_settlement(&plan, step, args, reporting).await
+            }
+        };
+        if !succeeded {
+            // later steps depend on the outcome of the prior ones, a re-run resumes from here
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_parse_snapshot(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    ledger_path: &Path,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    let outputs = [
+        plan.validator_meta_collection(),
+        plan.stake_meta_collection(),
+    ];
+    if outputs_exist(&outputs) {
+        return reporting
+            .reportable
+            .skip(step, "snapshot collections already exist");
+    }
+    let args = match (path_arg(&outputs[0]), path_arg(&outputs[1])) {
+        (Ok(validator_meta), Ok(stake_meta)) => parse_snapshot::Args {
+            ledger_path: ledger_path.to_path_buf(),
+            output_validator_meta_collection: validator_meta,
+            output_stake_meta_collection: stake_meta,
+        },
+        (Err(e), _) | (_, Err(e)) => return record_step_error(step, e, reporting),
+    };
+    let result = run_blocking(move || parse_snapshot::run(args)).await;
+    record_file_step(step, result, &outputs, reporting)
+}
+
+async fn run_generate_settlements(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    settlement_config: &Path,
+    past_validator_meta_collection: &Option<PathBuf>,
+    whitelist_stake_authority: &Option<Vec<String>>,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    let outputs = [
+        plan.protected_event_collection(),
+        plan.settlement_collection(),
+        plan.merkle_tree_collection(),
+    ];
+    if outputs_exist(&outputs) {
+        return reporting
+            .reportable
+            .skip(step, "settlement collections already exist");
+    }
+    let args = || -> anyhow::Result<generate_settlements::Args> {
+        Ok(generate_settlements::Args {
+            validator_meta_collection: path_arg(&plan.validator_meta_collection())?,
+            past_validator_meta_collection: past_validator_meta_collection
+                .as_deref()
+                .map(path_arg)
+                .transpose()?,
+            stake_meta_collection: path_arg(&plan.stake_meta_collection())?,
+            output_protected_event_collection: path_arg(&outputs[0])?,
+            output_settlement_collection: path_arg(&outputs[1])?,
+            output_merkle_tree_collection: path_arg(&outputs[2])?,
+            whitelist_stake_authority: whitelist_stake_authority
+                .as_ref()
+                .map(|whitelist| {
+                    whitelist
+                        .iter()
+                        .map(|stake_authority| {
+                            Pubkey::from_str(stake_authority).map_err(|e| {
+                                anyhow!(
+                                    "Invalid whitelisted stake authority '{}': {:?}",
+                                    stake_authority,
+                                    e
+                                )
+                            })
+                        })
+                        .collect::<anyhow::Result<Vec<_>>>()
+                })
+                .transpose()?,
+            settlement_config: path_arg(settlement_config)?,
+        })
+    };
+    let args = match args() {
+        Ok(args) => args,
+        Err(e) => return record_step_error(step, e, reporting),
+    };
+    let result = run_blocking(move || generate_settlements::run(args)).await;
+    record_file_step(step, result, &outputs, reporting)
+}
+
+fn run_list_settlement(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    let outputs = [plan.settlement_list()];
+    if outputs_exist(&outputs) {
+        return reporting
+            .reportable
+            .skip(step, "settlement list already exists");
+    }
+    let args = match parse_step_args::<list_settlement::Args>(
+        plan,
+        step,
+        vec![
+            "-m".to_string(),
+            plan.merkle_tree_collection().display().to_string(),
+            "--out".to_string(),
+            plan.settlement_list().display().to_string(),
+        ],
+        &[],
+    ) {
+        Ok(args) => args,
+        Err(e) => return record_step_error(step, e, reporting),
+    };
+    let result = list_settlement::run(args);
+    record_file_step(step, result, &outputs, reporting)
+}
+
+async fn run_init_settlement(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    step_args: &[String],
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    let args = match parse_step_args::<init_settlement::Args>(
+        plan,
+        step,
+        vec![
+            "--input-merkle-tree-collection".to_string(),
+            plan.merkle_tree_collection().display().to_string(),
+            "--input-settlement-collection".to_string(),
+            plan.settlement_collection().display().to_string(),
+            "--epoch".to_string(),
+            plan.epoch.to_string(),
+        ],
+        step_args,
+    ) {
+        Ok(args) => args,
+        Err(e) => return record_step_error(step, e, reporting),
+    };
+    // the journal is not consulted, init-settlement reconciles it with the on-chain state
+    match check_settlements(plan, &args.global_opts.config) {
+        Ok(Some(reason)) => return reporting.reportable.skip(step, &reason),
+        Ok(None) => {}
+        Err(e) => return record_step_error(step, e, reporting),
+    }
+
+    let mut step_reporting = InitSettlementReport::report_handler();
+    let result = init_settlement::run(args, &mut step_reporting).await;
+    record_reportable_step(step, &step_reporting, result, reporting).await
+}
+
+async fn run_claim_settlement(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    step_args: &[String],
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    let args = match parse_step_args::<claim_settlement::Args>(
+        plan,
+        step,
+        vec![
+            "--settlement-json-files".to_string(),
+            plan.merkle_tree_collection().display().to_string(),
+            plan.settlement_collection().display().to_string(),
+        ],
+        step_args,
+    ) {
+        Ok(args) => args,
+        Err(e) => return record_step_error(step, e, reporting),
+    };
+    match check_journal(plan, &args.global_opts, |pipeline_state, s| {
+        pipeline_state.is_closed(plan.epoch, &s.settlement_address)
+    })
+    .await
+    {
+        Ok(Some(reason)) => return reporting.reportable.skip(step, &reason),
+        Ok(None) => {}
+        Err(e) => return record_step_error(step, e, reporting),
+    }
+    let claimable_epochs = match get_rpc_client(&args.global_opts) {
+        Ok((rpc_client, _)) => list_claimable_epochs(rpc_client, &args.global_opts.config).await,
+        Err(e) => Err(e),
+    };
+    match claimable_epochs {
+        Ok(claimable_epochs) if !claimable_epochs.contains(&plan.epoch) => {
+            return reporting.reportable.skip(
+                step,
+                &format!(
+                    "epoch {} is not claimable, claimable epochs: {:?}",
+                    plan.epoch, claimable_epochs
+                ),
+            );
+        }
+        Ok(_) => {}
+        Err(e) => return record_step_error(step, CliError::retry_able(e), reporting),
+    }
+
+    let mut step_reporting = ClaimSettlementReport::report_handler();
+    let result = claim_settlement::run(args, &mut step_reporting).await;
+    record_reportable_step(step, &step_reporting, result, reporting).await
+}
+
+async fn run_close_settlement(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    step_args: &[String],
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    if !plan.settlement_list().is_file() {
+        return record_step_error(
+            step,
+            anyhow!(
+                "Settlement list '{:?}' does not exist, the list-settlement step has to be run first",
+                plan.settlement_list()
+            ),
+            reporting,
+        );
+    }
+    let args = match parse_step_args::<close_settlement::Args>(
+        plan,
+        step,
+        vec![
+            "--past-settlements".to_string(),
+            plan.settlement_list().display().to_string(),
+        ],
+        step_args,
+    ) {
+        Ok(args) => args,
+        Err(e) => return record_step_error(step, e, reporting),
+    };
+    match check_journal(plan, &args.global_opts, |pipeline_state, s| {
+        pipeline_state.is_closed(plan.epoch, &s.settlement_address)
+    })
+    .await
+    {
+        Ok(Some(reason)) => return reporting.reportable.skip(step, &reason),
+        Ok(None) => {}
+        Err(e) => return record_step_error(step, e, reporting),
+    }
+
+    let mut step_reporting = CloseSettlementReport::report_handler();
+    let result = close_settlement::run(args, &mut step_reporting).await;
+    record_reportable_step(step, &step_reporting, result, reporting).await
+}
+
+/// Arguments of an on-chain step are parsed as they were passed on the command line:
+/// the inputs derived from the plan, the plan global arguments and the step arguments
+fn parse_step_args<T: Parser>(
+    plan: &EpochPlan,
+    step: &PlanStep,
+    plan_inputs: Vec<String>,
+    step_args: &[String],
+) -> anyhow::Result<T> {
+    let mut args = vec![step.name().to_string()];
+    args.extend(plan_inputs);
+    if let Some(state_file) = &plan.state_file {
+        if !matches!(step, PlanStep::ListSettlement) {
+            args.push("--state-file".to_string());
+            args.push(state_file.display().to_string());
+        }
+    }
+    args.extend(plan.expand_args(step_args)?);
+    T::try_parse_from(args).map_err(|e| anyhow!("Invalid arguments of step {}: {}", step, e))
+}
+
+/// Returns the reason to skip the step when there are no Settlements for the epoch
+fn check_settlements(plan: &EpochPlan, config_address: &Pubkey) -> anyhow::Result<Option<String>> {
+    if epoch_settlements(plan, config_address)?.is_empty() {
+        Ok(Some(format!("no settlements for epoch {}", plan.epoch)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns the reason to skip the step when the journal records the predicate
+/// as fulfilled for all the Settlements of the epoch
+async fn check_journal<F>(
+    plan: &EpochPlan,
+    global_opts: &GlobalOpts,
+    predicate: F,
+) -> anyhow::Result<Option<String>>
+where
+    F: Fn(&PipelineStateStore, &BondSettlement) -> bool,
+{
+    let settlements = epoch_settlements(plan, &global_opts.config)?;
+    if settlements.is_empty() {
+        return Ok(Some(format!("no settlements for epoch {}", plan.epoch)));
+    }
+    let state_file = if let Some(state_file) = &plan.state_file {
+        state_file
+    } else {
+        return Ok(None);
+    };
+    let (rpc_client, _) = get_rpc_client(global_opts)?;
+    let cluster = rpc_client
+        .get_genesis_hash()
+        .await
+        .map_err(CliError::retry_able)?;
+    let pipeline_state = PipelineStateStore::read_only(state_file, &global_opts.config, &cluster)?;
+    if settlements
+        .iter()
+        .all(|settlement| predicate(&pipeline_state, settlement))
+    {
+        Ok(Some(format!(
+            "all {} settlements already processed as recorded in '{:?}'",
+            settlements.len(),
+            state_file
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
+fn epoch_settlements(
+    plan: &EpochPlan,
+    config_address: &Pubkey,
+) -> anyhow::Result<Vec<BondSettlement>> {
+    let settlement_list = plan.settlement_list();
+    let merkle_tree_collection = plan.merkle_tree_collection();
+    if settlement_list.is_file() {
+        read_from_json_file(&settlement_list).map_err(|e| {
+            anyhow!(
+                "Cannot load settlement list '{:?}': {:?}",
+                settlement_list,
+                e
+            )
+        })
+    } else if merkle_tree_collection.is_file() {
+        list_settlements(&[merkle_tree_collection], config_address)
+    } else {
+        Err(anyhow!(
+            "Merkle tree collection '{:?}' does not exist, the generate-settlements step has to be run first",
+            merkle_tree_collection
+        ))
+    }
+}
+
+fn outputs_exist(outputs: &[PathBuf]) -> bool {
+    outputs.iter().all(|output| output.is_file())
+}
+
+fn path_arg(path: &Path) -> anyhow::Result<String> {
+    path.to_str()
+        .map(|path| path.to_string())
+        .ok_or_else(|| anyhow!("Invalid file path '{:?}'", path))
+}
+
+fn record_file_step(
+    step: &PlanStep,
+    result: anyhow::Result<()>,
+    outputs: &[PathBuf],
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    match result {
+        Ok(_) => {
+            let report = outputs
+                .iter()
+                .map(|output| format!("written {:?}", output))
+                .collect();
+            let data = json!({ "outputs": outputs });
+            reporting
+                .reportable
+                .record(step, StepOutcome::Executed, (report, data))
+        }
+        Err(e) => record_step_error(step, e, reporting),
+    }
+}
+
+async fn record_reportable_step<T: PrintReportable>(
+    step: &PlanStep,
+    step_reporting: &ReportHandler<T>,
+    result: anyhow::Result<()>,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    let report = step_reporting.reportable.get_report_with_data().await;
+    reporting.merge_errors(step, step_reporting);
+    let failed = step_reporting.has_errors() || result.is_err();
+    if let Err(e) = result {
+        add_step_error(step, e, reporting);
+    }
+    let outcome = if failed {
+        StepOutcome::Failed
+    } else {
+        StepOutcome::Executed
+    };
+    reporting.reportable.record(step, outcome, report)
+}
+
+fn record_step_error(
+    step: &PlanStep,
+    error: anyhow::Error,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) -> bool {
+    add_step_error(step, error, reporting);
+    reporting
+        .reportable
+        .record(step, StepOutcome::Failed, (vec![], Value::Null))
+}
+
+fn add_step_error(
+    step: &PlanStep,
+    error: anyhow::Error,
+    reporting: &mut ReportHandler<RunEpochReport>,
+) {
+    match error.downcast::<CliError>() {
+        Ok(CliError::RetryAble(e)) => reporting.add_retry_able_error(anyhow!("[{}] {}", step, e)),
+        Ok(CliError::Processing(e)) => reporting.add_error(anyhow!("[{}] {}", step, e)),
+        Err(e) => reporting.add_error(anyhow!("[{}] {}", step, e)),
+    }
+}
+
+enum StepOutcome {
+    Executed,
+    Skipped(String),
+    Failed,
+}
+
+struct StepRecord {
+    step: String,
+    outcome: StepOutcome,
+    report: Vec<String>,
+    data: Value,
+}
+
+struct RunEpochReport {
+    epoch: u64,
+    planned_steps: Vec<String>,
+    steps: Vec<StepRecord>,
+}
+
+impl PrintReportable for RunEpochReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async { self.get_report_with_data().await.0 })
+    }
+
+    fn get_report_with_data(&self) -> Pin<Box<dyn Future<Output = ReportWithData> + '_>> {
+        Box::pin(async {
+            let count = |predicate: fn(&StepOutcome) -> bool| {
+                self.steps
+                    .iter()
+                    .filter(|record| predicate(&record.outcome))
+                    .count()
+            };
+            let mut report = vec![format!(
+                "RunEpoch (epoch: {}): executed {}, skipped {}, failed {} of {} planned steps",
+                self.epoch,
+                count(|outcome| matches!(outcome, StepOutcome::Executed)),
+                count(|outcome| matches!(outcome, StepOutcome::Skipped(_))),
+                count(|outcome| matches!(outcome, StepOutcome::Failed)),
+                self.planned_steps.len()
+            )];
+            for record in &self.steps {
+                report.push(match &record.outcome {
+                    StepOutcome::Executed => format!("  {}: executed", record.step),
+                    StepOutcome::Skipped(reason) => {
+                        format!("  {}: skipped, {}", record.step, reason)
+                    }
+                    StepOutcome::Failed => format!("  {}: FAILED", record.step),
+                });
+                report.extend(record.report.iter().map(|line| format!("    {}", line)));
+            }
+            let not_run_steps = &self.planned_steps[self.steps.len()..];
+            if !not_run_steps.is_empty() {
+                report.push(format!("  not run steps: {}", not_run_steps.join(", ")));
+            }
+
+            let data = RunEpochReportData {
+                command: "run-epoch",
+                epoch: self.epoch,
+                steps: self
+                    .steps
+                    .iter()
+                    .map(|record| RunEpochReportStep {
+                        step: &record.step,
+                        status: match record.outcome {
+                            StepOutcome::Executed => "executed",
+                            StepOutcome::Skipped(_) => "skipped",
+                            StepOutcome::Failed => "failed",
+                        },
+                        skip_reason: match &record.outcome {
+                            StepOutcome::Skipped(reason) => Some(reason),
+                            _ => None,
+                        },
+                        report: &record.data,
+                    })
+                    .collect(),
+                not_run_steps,
+            };
+            (report, to_report_data(&data))
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct RunEpochReportData<'a> {
+    command: &'static str,
+    epoch: u64,
+    steps: Vec<RunEpochReportStep<'a>>,
+    not_run_steps: &'a [String],
+}
+
+#[derive(Serialize)]
+struct RunEpochReportStep<'a> {
+    step: &'a str,
+    status: &'static str,
+    skip_reason: Option<&'a String>,
+    /// structured report of the step command
+    report: &'a Value,
+}
+
+impl RunEpochReport {
+    fn report_handler() -> ReportHandler<Self> {
+        let reportable = Self {
+            epoch: 0,
+            planned_steps: vec![],
+            steps: vec![],
+        };
+        ReportHandler::new(reportable)
+    }
+
+    fn init(&mut self, plan: &EpochPlan) {
+        self.epoch = plan.epoch;
+        self.planned_steps = plan
+            .steps
+            .iter()
+            .map(|step| step.name().to_string())
+            .collect();
+    }
+
+    /// Returns if the run may continue with the next step
+    fn record(
+        &mut self,
+        step: &PlanStep,
+        outcome: StepOutcome,
+        (report, data): ReportWithData,
+    ) -> bool {
+        let succeeded = !matches!(outcome, StepOutcome::Failed);
+        self.steps.push(StepRecord {
+            step: step.name().to_string(),
+            outcome,
+            report,
+            data,
+        });
+        succeeded
+    }
+
+    fn skip(&mut self, step: &PlanStep, reason: &str) -> bool {
+        info!("Step {} skipped, {}", step, reason);
+        self.record(
+            step,
+            StepOutcome::Skipped(reason.to_string()),
+            (vec![], Value::Null),
+        )
+    }
+}
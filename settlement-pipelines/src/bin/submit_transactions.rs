@@ -0,0 +1,180 @@
+use anyhow::anyhow;
+use clap::Parser;
+use log::info;
+use settlement_pipelines::arguments::{
+    get_rpc_client, get_simulation_rpc_client, to_tip_policy, GlobalOpts, TipPolicyOpts,
+};
+use settlement_pipelines::cli_result::CliResult;
+use settlement_pipelines::executor::execute_signed_transaction;
+use settlement_pipelines::export::{read_transactions_export, ExportNonce};
+use settlement_pipelines::init::{get_executor, init_log};
+use settlement_pipelines::reporting::{with_reporting, PrintReportable, ReportHandler};
+use settlement_pipelines::simulation::{simulate_transaction_list, SimulatedAccounts};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::str::FromStr;
+
+/// Sending the transactions exported by a pipeline (e.g., init-settlement --export-marinade-funding)
+/// after they were signed offline
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[clap(flatten)]
+    global_opts: GlobalOpts,
+
+    #[clap(flatten)]
+    tip_policy_opts: TipPolicyOpts,
+
+    /// Exported transactions file where the transactions were replaced with the signed ones
+    #[arg(long)]
+    signed_transactions: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> CliResult {
+    let mut reporting = SubmitTransactionsReport::report_handler();
+    let result = real_main(&mut reporting).await;
+    with_reporting::<SubmitTransactionsReport>(&reporting, result).await
+}
+
+async fn real_main(reporting: &mut ReportHandler<SubmitTransactionsReport>) -> anyhow::Result<()> {
+    let args: Args = Args::parse();
+    init_log(&args.global_opts);
+
+    let (rpc_client, _) = get_rpc_client(&args.global_opts)?;
+    let transactions_export = read_transactions_export(&args.signed_transactions)?;
+    info!(
+        "Submitting {} transactions signed by {} from '{:?}'",
+        transactions_export.transactions.len(),
+        transactions_export.signer,
+        args.signed_transactions
+    );
+    reporting.reportable.transactions_count = transactions_export.transactions.len();
+
+    if let Some(simulation_rpc_client) = get_simulation_rpc_client(&args.global_opts)? {
+        let transactions = transactions_export
+            .transactions
+            .iter()
+            .map(|exported_transaction| exported_transaction.to_transaction())
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if let Err(e) = simulate_transaction_list(
+            simulation_rpc_client,
+            &transactions,
+            &SimulatedAccounts::default(),
+        )
+        .await
+        {
+            reporting.add_error(e);
+        }
+        return Ok(());
+    }
+
+    let transaction_executor = get_executor(
+        rpc_client.clone(),
+        to_tip_policy(&args.tip_policy_opts),
+        None,
+    );
+    for exported_transaction in transactions_export.transactions {
+        let transaction = match exported_transaction.to_transaction() {
+            Ok(transaction) => transaction,
+            Err(e) => {
+                reporting.add_error(e);
+                continue;
+            }
+        };
+        if !transaction.is_signed() || transaction.verify().is_err() {
+            reporting.add_error_string(format!(
+                "Transaction '{}' is not signed or its signatures are not valid",
+                exported_transaction.description
+            ));
+            continue;
+        }
+        let nonce_account = match exported_transaction
+            .nonce_account
+            .as_deref()
+            .map(Pubkey::from_str)
+        {
+            Some(Ok(nonce_account)) => nonce_account,
+            _ => {
+                reporting.add_error_string(format!(
+                    "Transaction '{}' is not built with a durable nonce, re-export and sign the transaction again",
+                    exported_transaction.description
+                ));
+                continue;
+            }
+        };
+        match ExportNonce::load(&rpc_client, &nonce_account).await {
+            Ok(nonce) if nonce.nonce_hash == transaction.message.recent_blockhash => {}
+            Ok(_) => {
+                reporting.add_error_string(format!(
+                    "Transaction '{}' nonce account {} was advanced, the transaction was already executed or it has to be re-exported and signed again",
+                    exported_transaction.description, nonce_account
+                ));
+                continue;
+            }
+            Err(e) => {
+                reporting.add_retry_able_error(e);
+                continue;
+            }
+        }
+
+        match execute_signed_transaction(transaction_executor.clone(), transaction).await {
+            Ok(signature) => {
+                info!(
+                    "Transaction '{}' confirmed: {}",
+                    exported_transaction.description, signature
+                );
+                reporting
+                    .reportable
+                    .submitted
+                    .push((exported_transaction.description, signature));
+            }
+            Err(e) => {
+                reporting.add_retry_able_error(anyhow!(
+                    "Transaction '{}' failed to be executed: {:?}",
+                    exported_transaction.description,
+                    e
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct SubmitTransactionsReport {
+    transactions_count: usize,
+    /// transaction description, signature
+    submitted: Vec<(String, Signature)>,
+}
+
+impl PrintReportable for SubmitTransactionsReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async {
+            let mut report = vec![format!(
+                "SubmitTransactions: submitted {}/{} transactions",
+                self.submitted.len(),
+                self.transactions_count
+            )];
+            report.extend(
+                self.submitted
+                    .iter()
+                    .map(|(description, signature)| format!("  {}: {}", description, signature)),
+            );
+            report
+        })
+    }
+}
+
+impl SubmitTransactionsReport {
+    fn report_handler() -> ReportHandler<Self> {
+        let reportable = Self {
+            transactions_count: 0,
+            submitted: vec![],
+        };
+        ReportHandler::new(reportable)
+    }
+}
@@ -0,0 +1,1408 @@
+use crate::anchor::add_instruction_to_builder;
+use crate::arguments::{
+    init_from_opts, load_keypair, GlobalOpts, InitializedGlobalOpts, MetricsOpts, NotificationOpts,
+    PipelineStateOpts, PriorityFeePolicyOpts, ReportOpts, TipPolicyOpts,
+};
+use crate::cli_result::CliError;
+use crate::executor::{execute_parallel_with_rate, PipelineExecutor};
+use crate::init::get_executor;
+use crate::json_data::{resolve_combined_optional, CombinedMerkleTreeSettlementCollections};
+use crate::metrics::{metrics, MetricsExporter};
+use crate::notification::Notifiers;
+use crate::pipeline_state::PipelineStateStore;
+use crate::reporting::{to_report_data, PrintReportable, ReportHandler, ReportWithData};
+use crate::settlements::{
+    get_creation_signatures, list_claimable_settlements, ClaimableSettlementsReturn,
+    SETTLEMENT_CLAIM_ACCOUNT_SIZE,
+};
+use crate::stake_accounts::{prioritize_for_claiming, STAKE_ACCOUNT_RENT_EXEMPTION};
+use crate::stake_accounts_cache::StakeAccountsCache;
+use anchor_client::{DynSigner, Program};
+use anyhow::anyhow;
+use clap::Parser;
+use log::{debug, error, info, warn};
+use merkle_tree::psr_claim::TreeNode;
+use serde::Serialize;
+use settlement_engine::merkle_tree_collection::MerkleTreeCollection;
+use settlement_engine::settlement_claims::SettlementCollection;
+use settlement_engine::utils::read_from_json_file;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::clock::Clock;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::stake::program::ID as stake_program_id;
+use solana_sdk::stake_history::StakeHistory;
+use solana_sdk::system_program;
+use solana_sdk::sysvar::{clock::ID as clock_id, stake_history::ID as stake_history_id};
+use solana_transaction_builder::TransactionBuilder;
+use solana_transaction_executor::PriorityFeePolicy;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::time::sleep;
+use validator_bonds::instructions::ClaimSettlementArgs;
+use validator_bonds::state::bond::find_bond_address;
+use validator_bonds::state::config::find_bonds_withdrawer_authority;
+use validator_bonds::state::settlement::find_settlement_address;
+use validator_bonds::state::settlement_claim::find_settlement_claim_address;
+use validator_bonds::ID as validator_bonds_id;
+use validator_bonds_common::config::get_config;
+use validator_bonds_common::constants::find_event_authority;
+use validator_bonds_common::settlement_claims::{
+    collect_existence_settlement_claims_from_addresses, get_settlement_claims_for_settlement,
+};
+use validator_bonds_common::settlements::get_settlements_for_pubkeys;
+use validator_bonds_common::stake_accounts::{
+    get_clock, get_stake_history, CollectedStakeAccounts,
+};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    #[clap(flatten)]
+    pub global_opts: GlobalOpts,
+
+    /// List of JSON files with tree collection and settlements
+    #[arg(
+        short = 's',
+        long,
+        value_delimiter = ' ',
+        num_args(1..),
+    )]
+    settlement_json_files: Vec<PathBuf>,
+
+    /// forcing epoch, overriding ones loaded from json files of settlement_json_files
+    /// mostly useful for testing purposes
+    #[arg(long)]
+    epoch: Option<u64>,
+
+    #[clap(flatten)]
+    priority_fee_policy_opts: PriorityFeePolicyOpts,
+
+    #[clap(flatten)]
+    tip_policy_opts: TipPolicyOpts,
+
+    #[clap(flatten)]
+    pipeline_state_opts: PipelineStateOpts,
+
+    #[clap(flatten)]
+    report_opts: ReportOpts,
+
+    #[clap(flatten)]
+    notification_opts: NotificationOpts,
+
+    #[clap(flatten)]
+    metrics_opts: MetricsOpts,
+
+    /// keypair payer for rent of accounts, if not provided, fee payer keypair is used
+    #[arg(long)]
+    rent_payer: Option<String>,
+
+    /// maximum of lamports the rent payer pays for the SettlementClaim accounts created in this run,
+    /// the claims with the highest amount are claimed first
+    #[arg(long)]
+    max_rent_budget_lamports: Option<u64>,
+
+    /// maximum number of claims per settlement claimed in this run
+    #[arg(long)]
+    max_claims_per_settlement: Option<u64>,
+
+    /// skip the claims with amount lower than the SettlementClaim rent, by default all claims are claimed
+    #[arg(long)]
+    skip_claims_below_rent: bool,
+
+    /// stake authorities that are claimed for even when the SettlementClaim rent exceeds the claimed amount
+    #[arg(long, value_delimiter = ',', requires = "skip_claims_below_rent")]
+    whitelist_stake_authority: Vec<Pubkey>,
+}
+
+/// Runs the claim-settlement command, the pipeline state journal is saved in whatever case
+pub async fn run(
+    args: Args,
+    reporting: &mut ReportHandler<ClaimSettlementReport>,
+) -> anyhow::Result<()> {
+    reporting.set_report_opts(&args.report_opts);
+    reporting.set_notifiers(Notifiers::new("claim-settlement", &args.notification_opts));
+    reporting.set_metrics_exporter(MetricsExporter::new("claim-settlement", &args.metrics_opts));
+    let result = real_main(args, reporting).await;
+    if let Err(e) = reporting.reportable.pipeline_state.save() {
+        reporting.add_error(e);
+    }
+    metrics().record_pipeline_state(&reporting.reportable.pipeline_state);
+    result
+}
+
+async fn real_main(
+    args: Args,
+    reporting: &mut ReportHandler<ClaimSettlementReport>,
+) -> anyhow::Result<()> {
+    let InitializedGlobalOpts {
+        fee_payer,
+        operator_authority: _,
+        priority_fee_policy,
+        tip_policy,
+        rpc_client,
+        program,
+        simulation_rpc_client,
+    } = init_from_opts(
+        &args.global_opts,
+        &args.priority_fee_policy_opts,
+        &args.tip_policy_opts,
+    )?;
+
+    let cluster = rpc_client
+        .get_genesis_hash()
+        .await
+        .map_err(CliError::retry_able)?;
+    reporting.reportable.pipeline_state = PipelineStateStore::load(
+        args.pipeline_state_opts.state_file.as_deref(),
+        "claim-settlement",
+        args.global_opts.dry_run,
+        &args.global_opts.config,
+        &cluster,
+    )?;
+
+    let config_address = args.global_opts.config;
+    info!(
+        "Claiming settlements for validator-bonds config: {}",
+        config_address
+    );
+    let config = get_config(rpc_client.clone(), config_address)
+        .await
+        .map_err(CliError::retry_able)?;
+
+    let json_loaded_claiming_data = load_json(&args.settlement_json_files, args.epoch)?;
+
+    let rent_payer = if let Some(rent_payer) = args.rent_payer.clone() {
+        load_keypair(&rent_payer)?
+    } else {
+        fee_payer.clone()
+    };
+
+    let minimal_stake_lamports = config.minimum_stake_lamports + STAKE_ACCOUNT_RENT_EXEMPTION;
+
+    // loaded from json files
+    let json_per_epoch_claim_records = JsonClaimSettlementRecord::load_as_vec_per_epoch(
+        json_loaded_claiming_data,
+        config_address,
+        args.epoch,
+    );
+
+    // loaded from RPC on-chain data
+    let claimable_settlements =
+        list_claimable_settlements(rpc_client.clone(), &config_address, &config).await?;
+
+    reporting
+        .reportable
+        .init(rpc_client.clone(), &claimable_settlements);
+
+    let mut transaction_builder = TransactionBuilder::limited(fee_payer.clone());
+    transaction_builder.add_signer_checked(&rent_payer);
+    let transaction_executor = get_executor(rpc_client.clone(), tip_policy, simulation_rpc_client);
+
+    let clock = get_clock(rpc_client.clone())
+        .await
+        .map_err(CliError::retry_able)?;
+    let stake_history = get_stake_history(rpc_client.clone())
+        .await
+        .map_err(CliError::retry_able)?;
+
+    let settlement_claim_rent = rpc_client
+        .get_minimum_balance_for_rent_exemption(SETTLEMENT_CLAIM_ACCOUNT_SIZE)
+        .await
+        .map_err(CliError::retry_able)?;
+    let mut claim_policy = ClaimPolicy::new(
+        settlement_claim_rent,
+        args.max_rent_budget_lamports,
+        args.max_claims_per_settlement,
+        args.skip_claims_below_rent,
+        &args.whitelist_stake_authority,
+    );
+
+    let mut settlements_to_claim: Vec<SettlementToClaim> = vec![];
+    for claimable_settlement in claimable_settlements {
+        let json_matching_settlement =
+            match get_settlement_from_json(&json_per_epoch_claim_records, &claimable_settlement) {
+                Ok(json_record) => json_record,
+                Err(e) => {
+                    reporting.add_cli_error(e);
+                    continue;
+                }
+            };
+
+        // Loading SettlementClaim records for this settlement from RPC on-chain
+        let (settlement_claims, already_claimed_count) = match get_existence_of_settlement_claims(
+            rpc_client.clone(),
+            &json_matching_settlement,
+            claimable_settlement.settlement.epoch_created_for,
+            &reporting.reportable.pipeline_state,
+        )
+        .await
+        {
+            Ok(settlement_claims) => {
+                let already_claimed = reporting.reportable.update_claim_records_before(
+                    &json_matching_settlement.settlement_address,
+                    &settlement_claims,
+                );
+                (settlement_claims, already_claimed)
+            }
+            Err(e) => {
+                reporting.add_cli_error(e);
+                continue;
+            }
+        };
+
+        // TODO: before claiming there should be a check for merging stake accounts
+
+        info!(
+            "Claiming settlement {}, vote account {}, claim amount {}, for epoch {}, number of FROM stake accounts {}, already claimed nodes {}",
+            claimable_settlement.settlement_address,
+            json_matching_settlement.vote_account_address,
+            lamports_to_sol(json_matching_settlement.max_total_claim_sum),
+            claimable_settlement.settlement.epoch_created_for,
+            claimable_settlement.stake_accounts.len(),
+            already_claimed_count,
+        );
+
+        settlements_to_claim.push(SettlementToClaim {
+            claimable_settlement,
+            json_record: json_matching_settlement,
+            settlement_claims,
+        });
+    }
+
+    let prepared_claims = prepare_claims(
+        rpc_client.clone(),
+        &settlements_to_claim,
+        &mut claim_policy,
+        reporting,
+        minimal_stake_lamports,
+        &clock,
+        &stake_history,
+    )
+    .await;
+    reporting
+        .reportable
+        .set_rent_budget(claim_policy.rent_budget, claim_policy.rent_spent);
+
+    for (settlement_to_claim, settlement_prepared_claims) in
+        settlements_to_claim.iter().zip(prepared_claims)
+    {
+        claim_settlement(
+            &program,
+            rpc_client.clone(),
+            &mut transaction_builder,
+            transaction_executor.clone(),
+            settlement_to_claim,
+            settlement_prepared_claims,
+            &config_address,
+            &rent_payer.pubkey(),
+            &priority_fee_policy,
+            reporting,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+fn load_json(
+    settlement_json_files: &[PathBuf],
+    args_epoch: Option<u64>,
+) -> anyhow::Result<HashMap<u64, CombinedMerkleTreeSettlementCollections>> {
+    let mut json_data: HashMap<u64, MerkleTreeLoadedData> = HashMap::new();
+    for path in settlement_json_files.iter().filter(|path| {
+        if path.is_file() {
+            debug!("Processing file: {:?}", path);
+            true
+        } else {
+            debug!("Skipping file: {:?}, as it's not a file", path);
+            false
+        }
+    }) {
+        load_json_data_to_merkle_tree(path, &mut json_data)?;
+    }
+    let claiming_data = json_data
+        .into_iter()
+        .map(|(epoch, data)| {
+            Ok((
+                args_epoch.unwrap_or(epoch),
+                resolve_combined_optional(data.merkle_tree_collection, data.settlement_collection)?,
+            ))
+        })
+        .collect::<anyhow::Result<HashMap<_, _>>>()?;
+    info!(
+        "Loaded json data from {:?} for epochs: {:?}",
+        settlement_json_files
+            .iter()
+            .map(|p| p.to_str())
+            .collect::<Vec<_>>(),
+        claiming_data.keys().collect::<Vec<_>>()
+    );
+    Ok(claiming_data)
+}
+
+fn load_json_data_to_merkle_tree(
+    path: &PathBuf,
+    loaded_data: &mut HashMap<u64, MerkleTreeLoadedData>,
+) -> Result<(), CliError> {
+    debug!("Loading data from file: {:?}", path);
+    let json_loading_result = if let Ok(merkle_tree_collection) = read_from_json_file(path) {
+        insert_merkle_tree_loaded_data(loaded_data, Some(merkle_tree_collection), None)
+    } else if let Ok(settlement_collection) = read_from_json_file(path) {
+        insert_merkle_tree_loaded_data(loaded_data, None, Some(settlement_collection))
+    } else {
+        Err(anyhow!("Cannot load JSON data from file: {:?}", path))
+    };
+
+    json_loading_result.map_err(|e| {
+        error!("Error loading JSON data from file: {:?}, {:?}", path, e);
+        CliError::Processing(e)
+    })
+}
+
+/// Claimable settlement matched with the JSON merkle tree data and the existence of its SettlementClaims
+struct SettlementToClaim {
+    claimable_settlement: ClaimableSettlementsReturn,
+    json_record: JsonClaimSettlementRecord,
+    settlement_claims: Vec<(Pubkey, bool)>,
+}
+
+/// Claim with resolved stake accounts that is to be sent
+struct PreparedClaim<'a> {
+    tree_node: &'a TreeNode,
+    proof: Vec<[u8; 32]>,
+    settlement_claim_address: Pubkey,
+    stake_account_from: Pubkey,
+    stake_account_to: Pubkey,
+}
+
+/// Why a claim was not claimed in this run by the [ClaimPolicy]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum ClaimSkipReason {
+    /// the SettlementClaim rent is higher than the claimed amount, the staker is not whitelisted (--skip-claims-below-rent)
+    RentExceedsClaim,
+    /// --max-claims-per-settlement reached
+    SettlementClaimCap,
+    /// --max-rent-budget-lamports exhausted
+    RentBudgetExceeded,
+}
+
+impl ClaimSkipReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ClaimSkipReason::RentExceedsClaim => "rent exceeds claim",
+            ClaimSkipReason::SettlementClaimCap => "settlement claim cap",
+            ClaimSkipReason::RentBudgetExceeded => "rent budget exceeded",
+        }
+    }
+}
+
+/// Limits of the claims sent in one run, every claim creates a SettlementClaim account paid by the rent payer
+struct ClaimPolicy {
+    settlement_claim_rent: u64,
+    rent_budget: Option<u64>,
+    rent_spent: u64,
+    max_claims_per_settlement: Option<u64>,
+    skip_claims_below_rent: bool,
+    whitelist_stake_authority: HashSet<Pubkey>,
+    claims_per_settlement: HashMap<Pubkey, u64>,
+}
+
+impl ClaimPolicy {
+    fn new(
+        settlement_claim_rent: u64,
+        rent_budget: Option<u64>,
+        max_claims_per_settlement: Option<u64>,
+        skip_claims_below_rent: bool,
+        whitelist_stake_authority: &[Pubkey],
+    ) -> Self {
+        Self {
+            settlement_claim_rent,
+            rent_budget,
+            rent_spent: 0,
+            max_claims_per_settlement,
+            skip_claims_below_rent,
+            whitelist_stake_authority: whitelist_stake_authority.iter().copied().collect(),
+            claims_per_settlement: HashMap::new(),
+        }
+    }
+
+    fn check(&self, settlement: &Pubkey, tree_node: &TreeNode) -> Result<(), ClaimSkipReason> {
+        if self.skip_claims_below_rent
+            && tree_node.claim < self.settlement_claim_rent
+            && !self
+                .whitelist_stake_authority
+                .contains(&tree_node.stake_authority)
+        {
+            return Err(ClaimSkipReason::RentExceedsClaim);
+        }
+        if let Some(max_claims_per_settlement) = self.max_claims_per_settlement {
+            if self
+                .claims_per_settlement
+                .get(settlement)
+                .map_or(false, |claims| *claims >= max_claims_per_settlement)
+            {
+                return Err(ClaimSkipReason::SettlementClaimCap);
+            }
+        }
+        if let Some(rent_budget) = self.rent_budget {
+            if self.rent_spent + self.settlement_claim_rent > rent_budget {
+                return Err(ClaimSkipReason::RentBudgetExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Accounts the claim that passed the [ClaimPolicy::check] and is to be sent
+    fn accept(&mut self, settlement: &Pubkey) {
+        self.rent_spent += self.settlement_claim_rent;
+        *self.claims_per_settlement.entry(*settlement).or_insert(0) += 1;
+    }
+}
+
+/// Not yet existing claims of all the settlements ordered by the claim amount descending,
+/// returned as indexes of the settlement and of its tree node
+fn prioritize_claims(settlements_to_claim: &[SettlementToClaim]) -> Vec<(usize, usize)> {
+    let mut claims: Vec<(usize, usize)> = settlements_to_claim
+        .iter()
+        .enumerate()
+        .flat_map(|(settlement_index, settlement_to_claim)| {
+            settlement_to_claim
+                .settlement_claims
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, settlement_claim_exists))| !settlement_claim_exists)
+                .map(move |(node_index, _)| (settlement_index, node_index))
+        })
+        .collect();
+    claims.sort_by_key(|(settlement_index, node_index)| {
+        std::cmp::Reverse(
+            settlements_to_claim[*settlement_index]
+                .json_record
+                .tree_nodes[*node_index]
+                .claim,
+        )
+    });
+    claims
+}
+
+/// Selects the claims to be sent by the [ClaimPolicy] in order of [prioritize_claims]
+/// and resolves the stake accounts to claim from and to, returns the prepared claims per settlement
+async fn prepare_claims<'a>(
+    rpc_client: Arc<RpcClient>,
+    settlements_to_claim: &'a [SettlementToClaim],
+    claim_policy: &mut ClaimPolicy,
+    reporting: &mut ReportHandler<ClaimSettlementReport>,
+    minimal_stake_lamports: u64,
+    clock: &Clock,
+    stake_history: &StakeHistory,
+) -> Vec<Vec<PreparedClaim<'a>>> {
+    let empty_stake_accounts: CollectedStakeAccounts = vec![];
+    let mut settlement_claimed_amounts: HashMap<Pubkey, u64> = HashMap::new();
+    let mut stake_accounts_to_cache = StakeAccountsCache::default();
+    let mut prepared_claims: Vec<Vec<PreparedClaim>> =
+        settlements_to_claim.iter().map(|_| vec![]).collect();
+    for (settlement_index, node_index) in prioritize_claims(settlements_to_claim) {
+        let SettlementToClaim {
+            claimable_settlement,
+            json_record: settlement_json_data,
+            settlement_claims,
+        } = &settlements_to_claim[settlement_index];
+        let tree_node = &settlement_json_data.tree_nodes[node_index];
+        let (settlement_claim_address, _) = settlement_claims[node_index];
+
+        if let Err(reason) = claim_policy.check(&settlement_json_data.settlement_address, tree_node)
+        {
+            debug!(
+                "Skipping claim {} of tree node stake:{}/withdrawer:{}/claim:{}, settlement {}: {}",
+                settlement_claim_address,
+                tree_node.stake_authority,
+                tree_node.withdraw_authority,
+                lamports_to_sol(tree_node.claim),
+                settlement_json_data.settlement_address,
+                reason.as_str()
+            );
+            reporting.reportable.update_skipped(
+                &settlement_json_data.settlement_address,
+                reason,
+                tree_node.claim,
+            );
+            continue;
+        }
+
+        let proof = if let Some(proof) = tree_node.proof.clone() {
+            proof
+        } else {
+            reporting.add_error_string(format!(
+                "No proof found for tree node stake:{}/withdrawer:{}/claim:{}, settlement {}",
+                tree_node.stake_authority,
+                tree_node.withdraw_authority,
+                lamports_to_sol(tree_node.claim),
+                settlement_json_data.settlement_address
+            ));
+            continue;
+        };
+
+        let stake_account_from = {
+            let stake_account_from =
+                claimable_settlement
+                    .stake_accounts
+                    .iter()
+                    .find(|(pubkey, lamports, _)| {
+                        let utilized_lamports =
+                            settlement_claimed_amounts.entry(*pubkey).or_insert(0);
+                        if lamports
+                            .saturating_sub(*utilized_lamports)
+                            .saturating_sub(minimal_stake_lamports)
+                            >= tree_node.claim
+                        {
+                            settlement_claimed_amounts
+                                .entry(*pubkey)
+                                .and_modify(|e| *e += tree_node.claim);
+                            true
+                        } else {
+                            false
+                        }
+                    });
+            if let Some((pubkey, _, _)) = stake_account_from {
+                *pubkey
+            } else {
+                reporting.add_error_string(format!(
+                    "No stake account found with enough SOLs to claim {} from, settlement {}, epoch {}",
+                    lamports_to_sol(tree_node.claim),
+                    settlement_json_data.settlement_address,
+                    claimable_settlement.settlement.epoch_created_for
+                ));
+                reporting.reportable.update_no_account_from(
+                    &settlement_json_data.settlement_address,
+                    tree_node.claim,
+                );
+                continue;
+            }
+        };
+
+        let stake_accounts_to = stake_accounts_to_cache
+            .get(
+                rpc_client.clone(),
+                &tree_node.withdraw_authority,
+                &tree_node.stake_authority,
+            )
+            .await
+            .map_or_else(
+                |e| {
+                    reporting.add_error(e);
+                    &empty_stake_accounts
+                },
+                |v| v,
+            );
+        let stake_account_to = prioritize_for_claiming(
+            stake_accounts_to,
+            clock,
+            stake_history,
+        ).map_or_else(|e| {
+            reporting.add_error_string(format!(
+                "No available stake account found where to claim into of staker/withdraw authorities {}/{}: {:?}",
+                tree_node.stake_authority, tree_node.withdraw_authority, e
+            ));
+            None
+        }, Some);
+        let stake_account_to: Pubkey = if let Some(stake_account_to) = stake_account_to {
+            stake_account_to
+        } else {
+            // stake accounts for these authorities were not found in this or some prior run (error was already reported)
+            reporting
+                .reportable
+                .update_no_account_to(&settlement_json_data.settlement_address, tree_node.claim);
+            continue;
+        };
+
+        claim_policy.accept(&settlement_json_data.settlement_address);
+        prepared_claims[settlement_index].push(PreparedClaim {
+            tree_node,
+            proof,
+            settlement_claim_address,
+            stake_account_from,
+            stake_account_to,
+        });
+    }
+    info!(
+        "Prepared {} claims of {} settlements, rent of the claim accounts {} SOL",
+        prepared_claims.iter().map(Vec::len).sum::<usize>(),
+        settlements_to_claim.len(),
+        lamports_to_sol(claim_policy.rent_spent)
+    );
+    prepared_claims
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn claim_settlement(
+    program: &Program<Arc<DynSigner>>,
+    rpc_client: Arc<RpcClient>,
+    transaction_builder: &mut TransactionBuilder,
+    transaction_executor: Arc<PipelineExecutor>,
+    settlement_to_claim: &SettlementToClaim,
+    prepared_claims: Vec<PreparedClaim<'_>>,
+    config_address: &Pubkey,
+    rent_payer: &Pubkey,
+    priority_fee_policy: &PriorityFeePolicy,
+    reporting: &mut ReportHandler<ClaimSettlementReport>,
+) -> anyhow::Result<()> {
+    let claimable_settlement = &settlement_to_claim.claimable_settlement;
+    let settlement_json_data = &settlement_to_claim.json_record;
+    let (bonds_withdrawer_authority, _) = find_bonds_withdrawer_authority(config_address);
+    let mut sent_claims: Vec<(Pubkey, u64)> = vec![];
+    for PreparedClaim {
+        tree_node,
+        proof,
+        settlement_claim_address,
+        stake_account_from,
+        stake_account_to,
+    } in prepared_claims
+    {
+        let req = program
+            .request()
+            .accounts(validator_bonds::accounts::ClaimSettlement {
+                config: *config_address,
+                bond: settlement_json_data.bond_address,
+                settlement: settlement_json_data.settlement_address,
+                settlement_claim: settlement_claim_address,
+                stake_account_from,
+                stake_account_to,
+                bonds_withdrawer_authority,
+                stake_history: stake_history_id,
+                stake_program: stake_program_id,
+                rent_payer: *rent_payer,
+                program: validator_bonds_id,
+                system_program: system_program::ID,
+                clock: clock_id,
+                event_authority: find_event_authority().0,
+            })
+            .args(validator_bonds::instruction::ClaimSettlement {
+                claim_settlement_args: ClaimSettlementArgs {
+                    proof,
+                    stake_account_staker: tree_node.stake_authority,
+                    stake_account_withdrawer: tree_node.withdraw_authority,
+                    claim: tree_node.claim,
+                    tree_node_hash: get_tree_node_hash(tree_node),
+                },
+            });
+        add_instruction_to_builder(
+            transaction_builder,
+            &req,
+            format!(
+                "Claim {} settlement {}, from {}, to {}",
+                settlement_claim_address,
+                settlement_json_data.settlement_address,
+                stake_account_from,
+                stake_account_to
+            ),
+        )?;
+        sent_claims.push((settlement_claim_address, tree_node.claim));
+    }
+
+    let execution_result = execute_parallel_with_rate(
+        rpc_client.clone(),
+        transaction_executor.clone(),
+        transaction_builder,
+        priority_fee_policy,
+        300,
+    )
+    .await;
+
+    // claims are journaled with the signatures of the transactions they landed with,
+    // on a failed execution only the claims found on chain are taken as sent
+    let claim_signatures = if transaction_executor.simulation_rpc_client.is_some() {
+        HashMap::new()
+    } else {
+        let settlement_claims = sent_claims
+            .iter()
+            .map(|(settlement_claim, _)| *settlement_claim)
+            .collect::<Vec<Pubkey>>();
+        get_creation_signatures(rpc_client.clone(), &settlement_claims)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    "Cannot load claim signatures of settlement {}: {:?}",
+                    claimable_settlement.settlement_address, e
+                );
+                HashMap::new()
+            })
+    };
+    if execution_result.is_ok() {
+        // dry-run only simulates the claims, nothing was claimed
+        if transaction_executor.simulation_rpc_client.is_none() {
+            metrics().record_lamports(
+                claimable_settlement.settlement.epoch_created_for,
+                "claimed",
+                sent_claims.iter().map(|(_, claim)| claim).sum(),
+            );
+        }
+    } else {
+        sent_claims.retain(|(settlement_claim, _)| claim_signatures.contains_key(settlement_claim));
+    }
+    if !sent_claims.is_empty() {
+        reporting.reportable.pipeline_state.add_claims(
+            claimable_settlement.settlement.epoch_created_for,
+            &claimable_settlement.settlement_address,
+            &sent_claims,
+            &claim_signatures,
+        );
+        reporting.reportable.pipeline_state.save()?;
+    }
+    reporting.add_tx_execution_result(
+        execution_result,
+        format!(
+            "ClaimSettlement {}",
+            claimable_settlement.settlement_address
+        ),
+    );
+
+    Ok(())
+}
+
+fn get_tree_node_hash(tree_node: &TreeNode) -> [u8; 32] {
+    let mut no_proof_tree_node = tree_node.clone();
+    no_proof_tree_node.proof = None;
+    no_proof_tree_node.hash().to_bytes()
+}
+
+struct MerkleTreeLoadedData {
+    merkle_tree_collection: Option<MerkleTreeCollection>,
+    settlement_collection: Option<SettlementCollection>,
+}
+
+fn insert_merkle_tree_loaded_data(
+    loaded_data: &mut HashMap<u64, MerkleTreeLoadedData>,
+    merkle_tree_collection: Option<MerkleTreeCollection>,
+    settlement_collection: Option<SettlementCollection>,
+) -> anyhow::Result<()> {
+    // Get the epoch and handle mismatches
+    let epoch = match (&merkle_tree_collection, &settlement_collection) {
+        (Some(mc), Some(sc)) if mc.epoch != sc.epoch => {
+            return Err(CliError::processing(format!(
+                "Epoch mismatch between merkle tree collection and settlement collection: {} != {}",
+                mc.epoch, sc.epoch
+            )));
+        }
+        (Some(mc), _) => mc.epoch,
+        (_, Some(sc)) => sc.epoch,
+        _ => {
+            return Err(CliError::processing(
+                "No epoch found in either merkle tree collection or settlement collection",
+            ));
+        }
+    };
+
+    let record = loaded_data
+        .entry(epoch)
+        .or_insert_with(|| MerkleTreeLoadedData {
+            merkle_tree_collection: None,
+            settlement_collection: None,
+        });
+    if record.merkle_tree_collection.is_none() {
+        record.merkle_tree_collection = merkle_tree_collection;
+    }
+    if record.settlement_collection.is_none() {
+        record.settlement_collection = settlement_collection;
+    }
+
+    Ok(())
+}
+
+fn get_settlement_from_json(
+    json_per_epoch_claim_records: &HashMap<u64, Vec<JsonClaimSettlementRecord>>,
+    on_chain_settlement: &ClaimableSettlementsReturn,
+) -> Result<JsonClaimSettlementRecord, CliError> {
+    let settlement_epoch = on_chain_settlement.settlement.epoch_created_for;
+    let settlement_merkle_tree =
+        if let Some(settlement_merkle_tree) = json_per_epoch_claim_records.get(&settlement_epoch) {
+            settlement_merkle_tree
+        } else {
+            return Err(CliError::Processing(anyhow!(
+                "No JSON merkle tree data found for settlement epoch {}",
+                settlement_epoch
+            )));
+        };
+
+    // find on-chain data match with json data,
+    // an amended settlement keeps the address derived from the original merkle root
+    let matching_settlement = settlement_merkle_tree.iter().find(|settlement_from_json| {
+        settlement_from_json.settlement_address == on_chain_settlement.settlement_address
+            || (settlement_from_json.bond_address == on_chain_settlement.settlement.bond
+                && settlement_from_json.merkle_root
+                    == on_chain_settlement.settlement.claim_merkle_root())
+    });
+    let matching_settlement = if let Some(settlement) = matching_settlement {
+        settlement
+    } else {
+        return Err(CliError::Processing(anyhow!(
+            "No matching JSON merkle-tree data has been found for on-chain settlement {}, bond {} in epoch {}",
+            on_chain_settlement.settlement_address,
+            on_chain_settlement.settlement.bond,
+            settlement_epoch
+        )));
+    };
+
+    if on_chain_settlement.settlement.max_total_claim != matching_settlement.max_total_claim_sum
+        || on_chain_settlement.settlement.claim_merkle_root() != matching_settlement.merkle_root
+    {
+        return Err(CliError::Processing(anyhow!(
+            "Mismatch between on-chain settlement and JSON data for settlement {}, bond {} in epoch {}",
+            on_chain_settlement.settlement_address,
+            on_chain_settlement.settlement.bond,
+            settlement_epoch
+        )));
+    }
+    if on_chain_settlement.stake_accounts.is_empty() {
+        return Err(CliError::Processing(anyhow!(
+            "No stake accounts found on-chain for settlement {}",
+            on_chain_settlement.settlement_address
+        )));
+    }
+    Ok(JsonClaimSettlementRecord {
+        settlement_address: on_chain_settlement.settlement_address,
+        ..matching_settlement.clone()
+    })
+}
+
+/// Existence of settlement claims, the claims sent in a prior run are re-fetched from RPC
+/// as the journal is a hint only and the claim might not have landed on chain
+async fn get_existence_of_settlement_claims(
+    rpc_client: Arc<RpcClient>,
+    settlement_json_data: &JsonClaimSettlementRecord,
+    epoch: u64,
+    pipeline_state: &PipelineStateStore,
+) -> Result<Vec<(Pubkey, bool)>, CliError> {
+    let settlement_claim_addresses = settlement_json_data
+        .tree_nodes
+        .iter()
+        .map(|tree_node| {
+            let tree_node_hash = get_tree_node_hash(tree_node);
+            find_settlement_claim_address(&settlement_json_data.settlement_address, &tree_node_hash)
+                .0
+        })
+        .collect::<Vec<Pubkey>>();
+    let settlement_claims = collect_existence_settlement_claims_from_addresses(
+        rpc_client.clone(),
+        &settlement_claim_addresses,
+    )
+    .await
+    .map_err(|e| {
+        CliError::Processing(anyhow!(
+            "Error fetching settlement claim accounts for settlement {}: {:?}",
+            settlement_json_data.settlement_address,
+            e
+        ))
+    })?;
+    for (settlement_claim_address, exists) in settlement_claims.iter() {
+        if !exists
+            && pipeline_state.is_claim_sent(
+                epoch,
+                &settlement_json_data.settlement_address,
+                settlement_claim_address,
+            )
+        {
+            warn!(
+                "Settlement {}: claim {} recorded as sent in a prior run does not exist on chain, claiming again",
+                settlement_json_data.settlement_address, settlement_claim_address
+            );
+        }
+    }
+    assert_eq!(
+        // we searched for all the settlement claims addresses available in json
+        settlement_json_data.tree_nodes.len(),
+        settlement_claims.len()
+    );
+    Ok(settlement_claims)
+}
+
+#[derive(Debug, Clone)]
+struct JsonClaimSettlementRecord {
+    vote_account_address: Pubkey,
+    bond_address: Pubkey,
+    settlement_address: Pubkey,
+    merkle_root: [u8; 32],
+    tree_nodes: Vec<TreeNode>,
+    max_total_claim_sum: u64,
+}
+
+impl JsonClaimSettlementRecord {
+    fn load_as_vec_per_epoch(
+        json_loaded_claiming_data: HashMap<u64, CombinedMerkleTreeSettlementCollections>,
+        config_address: Pubkey,
+        args_epoch: Option<u64>,
+    ) -> HashMap<u64, Vec<JsonClaimSettlementRecord>> {
+        json_loaded_claiming_data
+            .into_iter()
+            .map(|(epoch, combined_data)| {
+                (
+                    epoch,
+                    combined_data
+                        .merkle_tree_settlements
+                        .into_iter()
+                        .filter(|d| d.merkle_tree.merkle_root.is_some())
+                        .map(|d| {
+                            assert_eq!(epoch, args_epoch.unwrap_or(combined_data.epoch));
+                            let merkle_root = d.merkle_tree.merkle_root.unwrap().to_bytes();
+                            let (bond_address, _) =
+                                find_bond_address(&config_address, &d.merkle_tree.vote_account);
+                            let (settlement_address, _) =
+                                find_settlement_address(&bond_address, &merkle_root, epoch);
+                            JsonClaimSettlementRecord {
+                                vote_account_address: d.merkle_tree.vote_account,
+                                max_total_claim_sum: d.merkle_tree.max_total_claim_sum,
+                                bond_address,
+                                settlement_address,
+                                merkle_root,
+                                tree_nodes: d.merkle_tree.tree_nodes,
+                            }
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<HashMap<u64, Vec<JsonClaimSettlementRecord>>>()
+    }
+}
+
+pub struct ClaimSettlementReport {
+    rpc_client: Option<Arc<RpcClient>>,
+    settlements_claimable_before: HashMap<Pubkey, ClaimSettlementReportData>,
+    claimed_before: HashMap<Pubkey, u64>,
+    settlements_claimable_no_account_to: HashMap<Pubkey, u64>,
+    settlements_claimable_no_account_from: HashMap<Pubkey, u64>,
+    settlements_claimable_skipped: HashMap<Pubkey, BTreeMap<ClaimSkipReason, SkippedClaims>>,
+    rent_budget: Option<u64>,
+    rent_spent: u64,
+    pipeline_state: PipelineStateStore,
+}
+
+/// Claims not claimed by the [ClaimPolicy] for the same reason
+#[derive(Default, Clone, Copy)]
+struct SkippedClaims {
+    count: u64,
+    lamports: u64,
+}
+
+impl PrintReportable for ClaimSettlementReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async { self.get_report_with_data().await.0 })
+    }
+
+    fn get_report_with_data(&self) -> Pin<Box<dyn Future<Output = ReportWithData> + '_>> {
+        Box::pin(async {
+            let rpc_client = if let Some(rpc_client) = &self.rpc_client {
+                rpc_client
+            } else {
+                return (vec![], serde_json::Value::Null);
+            };
+            let claimable_settlements_addresses: Vec<Pubkey> =
+                self.settlements_claimable_before.keys().copied().collect();
+            sleep(std::time::Duration::from_secs(8)).await; // waiting for data finalization on-chain
+            let settlements_claimable_after =
+                get_settlements_for_pubkeys(rpc_client.clone(), &claimable_settlements_addresses)
+                    .await;
+            match settlements_claimable_after {
+                Ok(settlements_claimable_after) => {
+                    let settlement_claim_rent = rpc_client
+                        .get_minimum_balance_for_rent_exemption(SETTLEMENT_CLAIM_ACCOUNT_SIZE)
+                        .await
+                        .map_or_else(
+                            |e| {
+                                error!("Error fetching SettlementClaim account rent: {:?}", e);
+                                0_u64
+                            },
+                            |v| v,
+                        );
+                    let mut grouped_by_epoch: HashMap<_, Vec<_>> = HashMap::new();
+                    for (pubkey, settlement) in settlements_claimable_after {
+                        let epoch = settlement.as_ref().map_or(0, |s| s.epoch_created_for);
+                        grouped_by_epoch
+                            .entry(epoch)
+                            .or_insert_with(Vec::new)
+                            .push((pubkey, settlement));
+                    }
+                    let mut report: Vec<String> = vec![];
+                    if let Some(rent_budget) = self.rent_budget {
+                        report.push(format!(
+                            "Rent budget of claim accounts spent {}/{} SOL",
+                            lamports_to_sol(self.rent_spent),
+                            lamports_to_sol(rent_budget)
+                        ));
+                    }
+                    let mut data = ClaimSettlementReportJson {
+                        command: "claim-settlement",
+                        claimable_settlements_count: claimable_settlements_addresses.len() as u64,
+                        rent_budget_lamports: self.rent_budget,
+                        rent_spent_lamports: self.rent_spent,
+                        epochs: vec![],
+                    };
+                    for epoch in grouped_by_epoch.keys() {
+                        let mut claim_settlements_accounts_created: u64 = 0;
+                        let settlements_claimable_after_group = grouped_by_epoch
+                            .get(epoch)
+                            .expect("Epoch key expected to exist when iterating over keys");
+                        let mut epoch_report: Vec<String> = vec![];
+                        let mut epoch_settlements: Vec<ClaimSettlementReportSettlement> = vec![];
+                        for (settlement_address, settlement) in settlements_claimable_after_group {
+                            let max_claimed =
+                                settlement.as_ref().map_or_else(|| 0, |s| s.max_total_claim);
+                            let max_nodes = settlement
+                                .as_ref()
+                                .map_or_else(|| 0, |s| s.max_merkle_nodes);
+                            let claimed_before = self
+                                .claimed_before
+                                .get(settlement_address)
+                                .map_or_else(|| 0, |claimed_before| *claimed_before);
+                            let claimed_after = settlement
+                                .as_ref()
+                                .map_or_else(|| 0, |s| s.lamports_claimed);
+                            let claimed_diff = claimed_after.saturating_sub(claimed_before);
+                            let stake_account_to = self
+                                .settlements_claimable_no_account_to
+                                .get(settlement_address)
+                                .unwrap_or(&0);
+                            let stake_account_from = self
+                                .settlements_claimable_no_account_from
+                                .get(settlement_address)
+                                .unwrap_or(&0);
+                            let claim_accounts_count_before = self
+                                .settlements_claimable_before
+                                .get(settlement_address)
+                                .map_or_else(
+                                    || None,
+                                    |d| {
+                                        Some(
+                                            d.claim_records
+                                                .values()
+                                                .map(|v| v.map_or_else(|| 0_u64, |v| v))
+                                                .sum::<u64>(),
+                                        )
+                                    },
+                                );
+                            let claim_accounts_count_after = get_settlement_claims_for_settlement(
+                                rpc_client.clone(),
+                                settlement_address,
+                            )
+                            .await
+                            .map_or_else(
+                                |e| {
+                                    error!("Cannot report settlement claiming: {:?}", e);
+                                    0_u64
+                                },
+                                |s| s.len() as u64,
+                            );
+                            let claim_accounts_count_diff = claim_accounts_count_after
+                                .saturating_sub(
+                                    claim_accounts_count_before.map_or_else(|| 0, |v| v),
+                                );
+                            claim_settlements_accounts_created += claim_accounts_count_diff;
+                            epoch_report.push(format!(
+                                "  Settlement {} in sum claimed SOLs {}/{} SOLs, claim accounts {}/{}. \n    This time claimed SOLs {}, ClaimSettlement accounts {} (not claimed reason: no target {}, no source: {})",
+                                settlement_address,
+                                lamports_to_sol(claimed_after),
+                                lamports_to_sol(max_claimed),
+                                claim_accounts_count_after,
+                                max_nodes,
+                                lamports_to_sol(claimed_diff),
+                                claim_accounts_count_diff,
+                                lamports_to_sol(*stake_account_to),
+                                lamports_to_sol(*stake_account_from),
+                            ));
+                            let skipped_claims = self
+                                .settlements_claimable_skipped
+                                .get(settlement_address)
+                                .cloned()
+                                .unwrap_or_default();
+                            if !skipped_claims.is_empty() {
+                                epoch_report.push(format!(
+                                    "    Not claimed by claim policy: {}",
+                                    skipped_claims
+                                        .iter()
+                                        .map(|(reason, skipped)| format!(
+                                            "{} {} claims of {} SOLs",
+                                            reason.as_str(),
+                                            skipped.count,
+                                            lamports_to_sol(skipped.lamports)
+                                        ))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                ));
+                            }
+                            epoch_settlements.push(ClaimSettlementReportSettlement {
+                                settlement: settlement_address.to_string(),
+                                claimed_lamports: claimed_after,
+                                max_total_claim_lamports: max_claimed,
+                                claim_accounts_count: claim_accounts_count_after,
+                                max_merkle_nodes: max_nodes,
+                                claimed_now_lamports: claimed_diff,
+                                created_claim_accounts_count: claim_accounts_count_diff,
+                                not_claimed_no_target_lamports: *stake_account_to,
+                                not_claimed_no_source_lamports: *stake_account_from,
+                                not_claimed_by_policy: skipped_claims
+                                    .into_iter()
+                                    .map(|(reason, skipped)| ClaimSettlementReportSkipped {
+                                        reason,
+                                        claims_count: skipped.count,
+                                        lamports: skipped.lamports,
+                                    })
+                                    .collect(),
+                            });
+                        }
+                        report.push(format!(
+                            "Epoch {}, this time created {} claim accounts in sum of rent {} SOL",
+                            epoch,
+                            claim_settlements_accounts_created,
+                            lamports_to_sol(
+                                settlement_claim_rent * claim_settlements_accounts_created
+                            )
+                        ));
+                        report.extend(epoch_report);
+                        data.epochs.push(ClaimSettlementReportEpoch {
+                            epoch: *epoch,
+                            created_claim_accounts_count: claim_settlements_accounts_created,
+                            created_claim_accounts_rent_lamports: settlement_claim_rent
+                                * claim_settlements_accounts_created,
+                            settlements: epoch_settlements,
+                        });
+                    }
+                    report.extend(self.pipeline_state.progress_report());
+                    (report, to_report_data(&data))
+                }
+                Err(e) => (
+                    vec![format!("Error reporting settlement claiming: {:?}", e)],
+                    serde_json::Value::Null,
+                ),
+            }
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ClaimSettlementReportJson {
+    command: &'static str,
+    claimable_settlements_count: u64,
+    rent_budget_lamports: Option<u64>,
+    /// rent of the claim accounts prepared to be created in this run
+    rent_spent_lamports: u64,
+    epochs: Vec<ClaimSettlementReportEpoch>,
+}
+
+#[derive(Serialize)]
+struct ClaimSettlementReportEpoch {
+    epoch: u64,
+    created_claim_accounts_count: u64,
+    created_claim_accounts_rent_lamports: u64,
+    settlements: Vec<ClaimSettlementReportSettlement>,
+}
+
+#[derive(Serialize)]
+struct ClaimSettlementReportSettlement {
+    settlement: String,
+    claimed_lamports: u64,
+    max_total_claim_lamports: u64,
+    claim_accounts_count: u64,
+    max_merkle_nodes: u64,
+    /// claimed in this run
+    claimed_now_lamports: u64,
+    /// created in this run
+    created_claim_accounts_count: u64,
+    /// not claimed as no stake account to claim to
+    not_claimed_no_target_lamports: u64,
+    /// not claimed as no stake account to claim from
+    not_claimed_no_source_lamports: u64,
+    /// not claimed as of the claim policy limits
+    not_claimed_by_policy: Vec<ClaimSettlementReportSkipped>,
+}
+
+#[derive(Serialize)]
+struct ClaimSettlementReportSkipped {
+    reason: ClaimSkipReason,
+    claims_count: u64,
+    lamports: u64,
+}
+
+struct ClaimSettlementReportData {
+    claim_records: HashMap<Pubkey, Option<u64>>,
+}
+
+impl ClaimSettlementReport {
+    pub fn report_handler() -> ReportHandler<Self> {
+        let reportable = Self {
+            rpc_client: None,
+            claimed_before: HashMap::new(),
+            settlements_claimable_before: HashMap::new(),
+            settlements_claimable_no_account_to: HashMap::new(),
+            settlements_claimable_no_account_from: HashMap::new(),
+            settlements_claimable_skipped: HashMap::new(),
+            rent_budget: None,
+            rent_spent: 0,
+            pipeline_state: PipelineStateStore::default(),
+        };
+        ReportHandler::new(reportable)
+    }
+
+    fn init(
+        &mut self,
+        rpc_client: Arc<RpcClient>,
+        claimable_settlements: &Vec<ClaimableSettlementsReturn>,
+    ) {
+        info!(
+            "Number of claimable settlements: {}",
+            claimable_settlements.len()
+        );
+        self.rpc_client = Some(rpc_client);
+        self.settlements_claimable_before = claimable_settlements
+            .iter()
+            .map(|s| {
+                (
+                    s.settlement_address,
+                    ClaimSettlementReportData {
+                        claim_records: HashMap::new(),
+                    },
+                )
+            })
+            .collect::<HashMap<Pubkey, ClaimSettlementReportData>>();
+        self.settlements_claimable_no_account_to = claimable_settlements
+            .iter()
+            .map(|s| (s.settlement_address, 0_u64))
+            .collect::<HashMap<Pubkey, u64>>();
+        self.settlements_claimable_no_account_from = claimable_settlements
+            .iter()
+            .map(|s| (s.settlement_address, 0_u64))
+            .collect::<HashMap<Pubkey, u64>>();
+        self.claimed_before = claimable_settlements
+            .iter()
+            .map(|s| (s.settlement_address, s.settlement.lamports_claimed))
+            .collect::<HashMap<Pubkey, u64>>();
+    }
+
+    /// Update claim records before claiming while returning the number of claimable accounts before the update
+    fn update_claim_records_before(
+        &mut self,
+        settlement_address: &Pubkey,
+        claim_records: &[(Pubkey, bool)],
+    ) -> usize {
+        let settlement_claim_account_count = claim_records.iter().filter(|(_, b)| *b).count();
+        if let Some(s) = self
+            .settlements_claimable_before
+            .get_mut(settlement_address)
+        {
+            s.claim_records.insert(
+                *settlement_address,
+                Some(settlement_claim_account_count as u64),
+            );
+        }
+        settlement_claim_account_count
+    }
+
+    /// issue of no stake account to claim from, adding to report
+    fn update_no_account_from(&mut self, settlement_address: &Pubkey, tree_node_claim: u64) {
+        if let Some(value) = self
+            .settlements_claimable_no_account_from
+            .get_mut(settlement_address)
+        {
+            *value += tree_node_claim;
+        }
+    }
+
+    /// claim not claimed by the claim policy, adding to report
+    fn update_skipped(
+        &mut self,
+        settlement_address: &Pubkey,
+        reason: ClaimSkipReason,
+        tree_node_claim: u64,
+    ) {
+        let skipped = self
+            .settlements_claimable_skipped
+            .entry(*settlement_address)
+            .or_default()
+            .entry(reason)
+            .or_default();
+        skipped.count += 1;
+        skipped.lamports += tree_node_claim;
+    }
+
+    fn set_rent_budget(&mut self, rent_budget: Option<u64>, rent_spent: u64) {
+        self.rent_budget = rent_budget;
+        self.rent_spent = rent_spent;
+    }
+
+    /// issue of no stake account to claim to, adding to report
+    fn update_no_account_to(&mut self, settlement_address: &Pubkey, tree_node_claim: u64) {
+        if let Some(value) = self
+            .settlements_claimable_no_account_to
+            .get_mut(settlement_address)
+        {
+            *value += tree_node_claim;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree_node(stake_authority: Pubkey, claim: u64) -> TreeNode {
+        TreeNode {
+            stake_authority,
+            withdraw_authority: Pubkey::new_unique(),
+            claim,
+            proof: None,
+        }
+    }
+
+    #[test]
+    fn test_claim_policy() {
+        let rent = 1_000;
+        let whitelisted = Pubkey::new_unique();
+        let settlement = Pubkey::new_unique();
+        let other_settlement = Pubkey::new_unique();
+        let mut claim_policy =
+            ClaimPolicy::new(rent, Some(3 * rent), Some(2), true, &[whitelisted]);
+
+        assert_eq!(
+            claim_policy.check(&settlement, &tree_node(Pubkey::new_unique(), rent - 1)),
+            Err(ClaimSkipReason::RentExceedsClaim)
+        );
+        assert_eq!(
+            claim_policy.check(&settlement, &tree_node(whitelisted, rent - 1)),
+            Ok(())
+        );
+
+        let node = tree_node(Pubkey::new_unique(), 10 * rent);
+        assert_eq!(claim_policy.check(&settlement, &node), Ok(()));
+        claim_policy.accept(&settlement);
+        claim_policy.accept(&settlement);
+        assert_eq!(
+            claim_policy.check(&settlement, &node),
+            Err(ClaimSkipReason::SettlementClaimCap)
+        );
+        assert_eq!(claim_policy.check(&other_settlement, &node), Ok(()));
+        claim_policy.accept(&other_settlement);
+        assert_eq!(
+            claim_policy.check(&other_settlement, &node),
+            Err(ClaimSkipReason::RentBudgetExceeded)
+        );
+        assert_eq!(claim_policy.rent_spent, 3 * rent);
+    }
+
+    #[test]
+    fn test_claim_policy_no_limits() {
+        let mut claim_policy = ClaimPolicy::new(1_000, None, None, false, &[]);
+        let settlement = Pubkey::new_unique();
+        assert_eq!(
+            claim_policy.check(&settlement, &tree_node(Pubkey::new_unique(), 999)),
+            Ok(())
+        );
+        let node = tree_node(Pubkey::new_unique(), 1_000);
+        for _ in 0..100 {
+            assert_eq!(claim_policy.check(&settlement, &node), Ok(()));
+            claim_policy.accept(&settlement);
+        }
+    }
+}
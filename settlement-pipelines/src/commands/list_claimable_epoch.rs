@@ -0,0 +1,51 @@
+use crate::arguments::{get_rpc_client, GlobalOpts};
+use crate::settlements::list_claimable_settlements;
+use clap::Parser;
+use log::info;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::io;
+use std::sync::Arc;
+use validator_bonds_common::config::get_config;
+
+// Printing on std out the list of epochs that contains
+// settlements that could be claimed
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    #[clap(flatten)]
+    pub global_opts: GlobalOpts,
+}
+
+pub async fn run(args: Args) -> anyhow::Result<()> {
+    let config_address = args.global_opts.config;
+    info!(
+        "Listing claimable epochs for validator-bonds config: {}",
+        config_address
+    );
+
+    let (rpc_client, _) = get_rpc_client(&args.global_opts)?;
+    let claimable_epochs = list_claimable_epochs(rpc_client, &config_address).await?;
+
+    info!("Claimable epochs: {:?}", claimable_epochs);
+    serde_json::to_writer(io::stdout(), &claimable_epochs)?;
+    Ok(())
+}
+
+pub async fn list_claimable_epochs(
+    rpc_client: Arc<RpcClient>,
+    config_address: &Pubkey,
+) -> anyhow::Result<Vec<u64>> {
+    let config = get_config(rpc_client.clone(), *config_address).await?;
+
+    let claimable_settlements =
+        list_claimable_settlements(rpc_client.clone(), config_address, &config).await?;
+
+    Ok(claimable_settlements
+        .iter()
+        .map(|d| d.settlement.epoch_created_for)
+        .collect::<HashSet<_>>() // be unique
+        .into_iter()
+        .collect::<Vec<u64>>())
+}
@@ -0,0 +1,9 @@
+pub mod claim_settlement;
+pub mod close_settlement;
+pub mod generate_settlements;
+pub mod init_settlement;
+pub mod list_claimable_epoch;
+pub mod list_settlement;
+pub mod parse_snapshot;
+pub mod public_report;
+pub mod reconcile;
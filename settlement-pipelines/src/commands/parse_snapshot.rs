@@ -0,0 +1,37 @@
+use clap::Parser;
+use log::info;
+use snapshot_parser::bank_loader::create_bank_from_ledger;
+use snapshot_parser::utils::write_to_json_file;
+use snapshot_parser::{stake_meta, validator_meta};
+use std::path::PathBuf;
+
+/// Parsing the ledger snapshot to the validator and stake meta collections (as snapshot-parser-cli)
+#[derive(Parser, Debug)]
+pub struct Args {
+    #[arg(long, env)]
+    pub ledger_path: PathBuf,
+
+    #[arg(long, env)]
+    pub output_validator_meta_collection: String,
+
+    #[arg(long, env)]
+    pub output_stake_meta_collection: String,
+}
+
+pub fn run(args: Args) -> anyhow::Result<()> {
+    info!("Creating bank from ledger path: {:?}", &args.ledger_path);
+    let bank = create_bank_from_ledger(&args.ledger_path)?;
+
+    info!("Creating validator meta collection...");
+    let validator_meta_collection = validator_meta::generate_validator_collection(&bank)?;
+    write_to_json_file(
+        &validator_meta_collection,
+        &args.output_validator_meta_collection,
+    )?;
+
+    info!("Creating stake meta collection...");
+    let stake_meta_collection = stake_meta::generate_stake_meta_collection(&bank)?;
+    write_to_json_file(&stake_meta_collection, &args.output_stake_meta_collection)?;
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use crate::notification::DiscordNotifier;
+use anyhow::anyhow;
+use clap::Parser;
+use log::info;
+use settlement_engine::protected_events::ProtectedEvent;
+use settlement_engine::settlement_claims::{
+    Settlement, SettlementCollection, SettlementFunder, SettlementReason,
+};
+use settlement_engine::utils::read_from_json_file;
+use solana_sdk::native_token::lamports_to_sol;
+use std::cmp::Ordering;
+
+const SI_UNITS: [&str; 6] = ["", "K", "M", "G", "T", "P"];
+
+/// Public report of the Settlements of an epoch (as scripts/generate-discord-public-report.bash),
+/// printed on std out and posted to the Discord webhook when provided
+#[derive(Parser, Debug)]
+pub struct Args {
+    #[arg(long, env)]
+    pub settlement_collection: String,
+
+    /// Discord webhook the report is posted to
+    #[arg(long, env)]
+    pub discord_webhook_url: Option<String>,
+}
+
+pub async fn run(args: Args) -> anyhow::Result<()> {
+    info!(
+        "Loading settlement collection: {:?}",
+        args.settlement_collection
+    );
+    let settlement_collection: SettlementCollection =
+        read_from_json_file(&args.settlement_collection)
+            .map_err(|e| anyhow!("Cannot load settlement collection: {:?}", e))?;
+
+    let report = render_public_report(&settlement_collection);
+    for line in &report {
+        println!("{}", line);
+    }
+
+    if let Some(discord_webhook_url) = args.discord_webhook_url {
+        DiscordNotifier::new(discord_webhook_url)
+            .post_code_blocks(None, &report)
+            .await?;
+        info!("Public report posted to Discord");
+    }
+    Ok(())
+}
+
+/// Table of the Settlements of the epoch, sorted by the actual EPR and then by the claimed amount
+pub fn render_public_report(settlement_collection: &SettlementCollection) -> Vec<String> {
+    let epoch = settlement_collection.epoch;
+    if settlement_collection.settlements.is_empty() {
+        return vec![format!("No settlements in epoch {epoch}.")];
+    }
+
+    let total_claims_amount: u64 = settlement_collection
+        .settlements
+        .iter()
+        .map(|settlement| settlement.claims_amount)
+        .sum();
+    let mut report = vec![
+        format!(
+            "Total settlements in epoch {epoch}: ☉{:.9}",
+            lamports_to_sol(total_claims_amount)
+        ),
+        String::new(),
+        "                                vote account    settlement                   reason   stake     funded by".to_string(),
+        "--------------------------------------------+-------------+------------------------+-------+-------------".to_string(),
+    ];
+
+    let mut settlements: Vec<&Settlement> = settlement_collection.settlements.iter().collect();
+    settlements.sort_by(|a, b| {
+        actual_epr(a)
+            .partial_cmp(&actual_epr(b))
+            .unwrap_or(Ordering::Equal)
+            .then(b.claims_amount.cmp(&a.claims_amount))
+    });
+    for settlement in settlements {
+        let protected_stake: u64 = settlement
+            .claims
+            .iter()
+            .map(|claim| claim.active_stake)
+            .sum();
+        let funder_info = match settlement.meta.funder {
+            SettlementFunder::Marinade => "Marinade DAO",
+            SettlementFunder::ValidatorBond => "Validator",
+        };
+        report.push(format!(
+            "{:>44} {:>13} {:>24} {:>7} {:>13}",
+            settlement.vote_account.to_string(),
+            format!("☉{:.9}", lamports_to_sol(settlement.claims_amount)),
+            reason(settlement),
+            format!("☉{}", fmt_human_number(lamports_to_sol(protected_stake))),
+            funder_info
+        ));
+    }
+    report
+}
+
+fn actual_epr(settlement: &Settlement) -> f64 {
+    match &settlement.reason {
+        SettlementReason::ProtectedEvent(ProtectedEvent::CommissionIncrease {
+            actual_epr, ..
+        }) => *actual_epr,
+        SettlementReason::ProtectedEvent(ProtectedEvent::LowCredits { actual_epr, .. }) => {
+            *actual_epr
+        }
+    }
+}
+
+fn reason(settlement: &Settlement) -> String {
+    match &settlement.reason {
+        SettlementReason::ProtectedEvent(ProtectedEvent::LowCredits {
+            actual_credits,
+            expected_credits,
+            ..
+        }) => {
+            // uptime percentage with 2 decimals truncated
+            let uptime_bps = (10_000u128 * *actual_credits as u128)
+                .checked_div(*expected_credits as u128)
+                .unwrap_or_default();
+            format!("Uptime {}.{:02}%", uptime_bps / 100, uptime_bps % 100)
+        }
+        SettlementReason::ProtectedEvent(ProtectedEvent::CommissionIncrease {
+            previous_commission,
+            current_commission,
+            ..
+        }) => format!("Commission {previous_commission}% -> {current_commission}%"),
+    }
+}
+
+/// Human readable number with SI suffix rounded up (as `numfmt --to si`),
+/// one decimal place is shown for the scaled values lower than 10
+fn fmt_human_number(number: f64) -> String {
+    let mut scaled = number;
+    let mut unit = 0;
+    while scaled >= 1000.0 && unit < SI_UNITS.len() - 1 {
+        scaled /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        return format!("{}", scaled.ceil());
+    }
+    if scaled < 10.0 {
+        let rounded = (scaled * 10.0).ceil() / 10.0;
+        if rounded < 10.0 {
+            return format!("{:.1}{}", rounded, SI_UNITS[unit]);
+        }
+    }
+    let rounded = scaled.ceil();
+    if rounded >= 1000.0 && unit < SI_UNITS.len() - 1 {
+        return format!("1.0{}", SI_UNITS[unit + 1]);
+    }
+    format!("{}{}", rounded, SI_UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use settlement_engine::settlement_claims::{SettlementClaim, SettlementMeta};
+    use solana_sdk::native_token::LAMPORTS_PER_SOL;
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::HashMap;
+
+    fn settlement(
+        event: ProtectedEvent,
+        funder: SettlementFunder,
+        claims_amount: u64,
+        active_stake: u64,
+    ) -> Settlement {
+        Settlement {
+            vote_account: *event.vote_account(),
+            reason: SettlementReason::ProtectedEvent(event),
+            meta: SettlementMeta { funder },
+            claims_count: 1,
+            claims_amount,
+            claims: vec![SettlementClaim {
+                withdraw_authority: Pubkey::new_unique(),
+                stake_authority: Pubkey::new_unique(),
+                stake_accounts: HashMap::new(),
+                active_stake,
+                claim_amount: claims_amount,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_fmt_human_number() {
+        assert_eq!(fmt_human_number(0.0), "0");
+        assert_eq!(fmt_human_number(123.4), "124");
+        assert_eq!(fmt_human_number(1234.0), "1.3K");
+        assert_eq!(fmt_human_number(9999.0), "10K");
+        assert_eq!(fmt_human_number(12345.0), "13K");
+        assert_eq!(fmt_human_number(999_999.0), "1.0M");
+        assert_eq!(fmt_human_number(2_500_000.0), "2.5M");
+    }
+
+    #[test]
+    fn test_render_public_report() {
+        assert_eq!(
+            render_public_report(&SettlementCollection {
+                slot: 1,
+                epoch: 600,
+                settlements: vec![],
+            }),
+            vec!["No settlements in epoch 600."]
+        );
+
+        let low_credits = ProtectedEvent::LowCredits {
+            vote_account: Pubkey::new_unique(),
+            expected_credits: 3,
+            actual_credits: 2,
+            commission: 0,
+            expected_epr: 0.0002,
+            actual_epr: 0.0001,
+            epr_loss_bps: 5000,
+            stake: 0.0,
+        };
+        let commission_increase = ProtectedEvent::CommissionIncrease {
+            vote_account: Pubkey::new_unique(),
+            previous_commission: 5,
+            current_commission: 100,
+            expected_epr: 0.0002,
+            actual_epr: 0.0,
+            epr_loss_bps: 10000,
+            stake: 0.0,
+        };
+        let report = render_public_report(&SettlementCollection {
+            slot: 1,
+            epoch: 600,
+            settlements: vec![
+                settlement(
+                    low_credits.clone(),
+                    SettlementFunder::ValidatorBond,
+                    LAMPORTS_PER_SOL,
+                    20_000 * LAMPORTS_PER_SOL,
+                ),
+                settlement(
+                    commission_increase.clone(),
+                    SettlementFunder::Marinade,
+                    LAMPORTS_PER_SOL / 2,
+                    500 * LAMPORTS_PER_SOL,
+                ),
+            ],
+        });
+        assert_eq!(report.len(), 6);
+        assert_eq!(report[0], "Total settlements in epoch 600: ☉1.500000000");
+        // lowest actual EPR goes first
+        assert_eq!(
+            report[4],
+            format!(
+                "{:>44}  ☉0.500000000    Commission 5% -> 100%    ☉500  Marinade DAO",
+                commission_increase.vote_account().to_string()
+            )
+        );
+        assert_eq!(
+            report[5],
+            format!(
+                "{:>44}  ☉1.000000000            Uptime 66.66%    ☉20K     Validator",
+                low_credits.vote_account().to_string()
+            )
+        );
+        assert_eq!(
+            report[2].chars().count(),
+            report[5].chars().count(),
+            "header is aligned with the rows"
+        );
+    }
+}
@@ -0,0 +1,829 @@
+use crate::arguments::{get_rpc_client, GlobalOpts, NotificationOpts, ReportOpts};
+use crate::cli_result::CliError;
+use crate::commands::list_settlement::load_merkle_tree_files;
+use crate::notification::Notifiers;
+use crate::reporting::{to_report_data, PrintReportable, ReportHandler, ReportWithData};
+use anyhow::anyhow;
+use clap::Parser;
+use log::{error, info};
+use serde::Serialize;
+use settlement_engine::merkle_tree_collection::MerkleTreeMeta;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::Hash;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use validator_bonds::state::bond::find_bond_address;
+use validator_bonds::state::config::find_bonds_withdrawer_authority;
+use validator_bonds::state::settlement::{
+    find_settlement_address, find_settlement_staker_authority, Settlement,
+};
+use validator_bonds::state::settlement_claim::find_settlement_claim_address;
+use validator_bonds_common::bonds::get_bonds_for_pubkeys;
+use validator_bonds_common::settlement_claims::collect_existence_settlement_claims_from_addresses;
+use validator_bonds_common::settlements::get_settlements;
+use validator_bonds_common::stake_accounts::collect_stake_accounts;
+
+/// Reconciles the on-chain Settlements of the epochs with the JSON merkle trees,
+/// lists the funded and claimed amounts and the claim status of every tree node
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+pub struct Args {
+    #[clap(flatten)]
+    pub global_opts: GlobalOpts,
+
+    /// Paths to json files with tree collection
+    #[arg(short = 'm', long, value_delimiter = ' ', num_args(1..))]
+    pub merkle_tree_files: Vec<PathBuf>,
+
+    /// epochs to reconcile, when not provided all the epochs of the merkle tree files are reconciled
+    #[arg(long, value_delimiter = ',')]
+    pub epoch: Vec<u64>,
+
+    #[clap(flatten)]
+    report_opts: ReportOpts,
+
+    #[clap(flatten)]
+    notification_opts: NotificationOpts,
+}
+
+pub async fn run(args: Args, reporting: &mut ReportHandler<ReconcileReport>) -> anyhow::Result<()> {
+    reporting.set_report_opts(&args.report_opts);
+    reporting.set_notifiers(Notifiers::new("reconcile", &args.notification_opts));
+    real_main(args, reporting).await
+}
+
+async fn real_main(
+    args: Args,
+    reporting: &mut ReportHandler<ReconcileReport>,
+) -> anyhow::Result<()> {
+    let config_address = args.global_opts.config;
+    let (rpc_client, _) = get_rpc_client(&args.global_opts)?;
+
+    let merkle_tree_collections = load_merkle_tree_files(&args.merkle_tree_files)?;
+    let mut merkle_trees_per_epoch: BTreeMap<u64, Vec<JsonSettlement>> = BTreeMap::new();
+    for merkle_tree_collection in merkle_tree_collections {
+        let epoch = merkle_tree_collection.epoch;
+        if !args.epoch.is_empty() && !args.epoch.contains(&epoch) {
+            continue;
+        }
+        let json_settlements = merkle_trees_per_epoch.entry(epoch).or_default();
+        for merkle_tree in merkle_tree_collection.merkle_trees {
+            let merkle_root = if let Some(merkle_root) = merkle_tree.merkle_root {
+                merkle_root.to_bytes()
+            } else {
+                error!(
+                    "Merkle tree [epoch {}, vote account {}] does not have a root, skipping",
+                    epoch, merkle_tree.vote_account
+                );
+                continue;
+            };
+            let (bond_address, _) = find_bond_address(&config_address, &merkle_tree.vote_account);
+            let (settlement_address, _) =
+                find_settlement_address(&bond_address, &merkle_root, epoch);
+            json_settlements.push(JsonSettlement {
+                bond_address,
+                settlement_address,
+                merkle_root,
+                merkle_tree,
+            });
+        }
+    }
+    let epochs: BTreeSet<u64> = merkle_trees_per_epoch
+        .keys()
+        .copied()
+        .chain(args.epoch.iter().copied())
+        .collect();
+    info!(
+        "Reconciling settlements of epochs {:?} for validator-bonds config: {}",
+        epochs, config_address
+    );
+
+    let settlements = get_settlements(rpc_client.clone())
+        .await
+        .map_err(CliError::retry_able)?
+        .into_iter()
+        .filter(|(_, settlement)| epochs.contains(&settlement.epoch_created_for))
+        .collect::<Vec<(Pubkey, Settlement)>>();
+    let bond_addresses = settlements
+        .iter()
+        .map(|(_, settlement)| settlement.bond)
+        .collect::<HashSet<_>>() // be unique
+        .into_iter()
+        .collect::<Vec<Pubkey>>();
+    let bonds = get_bonds_for_pubkeys(rpc_client.clone(), &bond_addresses)
+        .await
+        .map_err(CliError::retry_able)?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    // settlements of bonds of other configs are not reconciled,
+    // the settlement of a closed bond cannot be assigned to a config and is reported as orphaned
+    let mut on_chain_settlements = settlements
+        .into_iter()
+        .filter_map(
+            |(settlement_address, settlement)| match bonds.get(&settlement.bond) {
+                Some(Some(bond)) if bond.config != config_address => None,
+                Some(Some(_)) => Some((settlement_address, (settlement, true))),
+                _ => Some((settlement_address, (settlement, false))),
+            },
+        )
+        .collect::<HashMap<Pubkey, (Settlement, bool)>>();
+
+    let (bonds_withdrawer_authority, _) = find_bonds_withdrawer_authority(&config_address);
+    let stake_accounts =
+        collect_stake_accounts(rpc_client.clone(), Some(&bonds_withdrawer_authority), None)
+            .await
+            .map_err(CliError::retry_able)?;
+
+    for epoch in epochs {
+        let mut reconciled_epoch = ReconciledEpoch {
+            epoch,
+            settlements: vec![],
+            stuck_stake_accounts: vec![],
+        };
+        // staker authority -> settlement that does not exist on-chain
+        let mut non_existing_settlements_staker_authority: HashMap<Pubkey, Pubkey> = HashMap::new();
+
+        for json_settlement in merkle_trees_per_epoch.remove(&epoch).unwrap_or_default() {
+            // an amended settlement keeps the address derived from the original merkle root
+            let on_chain_address = on_chain_settlements
+                .iter()
+                .find(|(settlement_address, (settlement, _))| {
+                    **settlement_address == json_settlement.settlement_address
+                        || (settlement.bond == json_settlement.bond_address
+                            && settlement.claim_merkle_root() == json_settlement.merkle_root)
+                })
+                .map(|(settlement_address, _)| *settlement_address);
+            let reconciled = if let Some(on_chain_address) = on_chain_address {
+                let (settlement, _) = match on_chain_settlements.remove(&on_chain_address) {
+                    Some(on_chain_settlement) => on_chain_settlement,
+                    None => {
+                        reporting.add_error(anyhow!(
+                            "Epoch {}, settlement {}: on-chain settlement {} matched the JSON merkle tree but was not loaded",
+                            epoch,
+                            json_settlement.settlement_address,
+                            on_chain_address
+                        ));
+                        continue;
+                    }
+                };
+                let tree_nodes =
+                    get_tree_nodes_claims(rpc_client.clone(), &on_chain_address, &json_settlement)
+                        .await?;
+                ReconciledSettlement::on_chain(
+                    &on_chain_address,
+                    &settlement,
+                    &json_settlement,
+                    tree_nodes,
+                )
+            } else {
+                non_existing_settlements_staker_authority.insert(
+                    find_settlement_staker_authority(&json_settlement.settlement_address).0,
+                    json_settlement.settlement_address,
+                );
+                ReconciledSettlement::not_on_chain(&json_settlement)
+            };
+            reconciled_epoch.settlements.push(reconciled);
+        }
+
+        let orphaned_settlements = on_chain_settlements
+            .iter()
+            .filter(|(_, (settlement, _))| settlement.epoch_created_for == epoch)
+            .map(|(settlement_address, _)| *settlement_address)
+            .collect::<Vec<Pubkey>>();
+        for settlement_address in orphaned_settlements {
+            let (settlement, bond_exists) = match on_chain_settlements.remove(&settlement_address) {
+                Some(on_chain_settlement) => on_chain_settlement,
+                None => {
+                    reporting.add_error(anyhow!(
+                        "Epoch {}, settlement {}: orphaned settlement was not loaded",
+                        epoch,
+                        settlement_address
+                    ));
+                    continue;
+                }
+            };
+            reconciled_epoch
+                .settlements
+                .push(ReconciledSettlement::orphaned(
+                    &settlement_address,
+                    &settlement,
+                    bond_exists,
+                ));
+        }
+
+        for (stake_account, lamports, stake_state) in &stake_accounts {
+            let staker_authority = if let Some(authorized) = stake_state.authorized() {
+                authorized.staker
+            } else {
+                continue;
+            };
+            if let Some(settlement_address) =
+                non_existing_settlements_staker_authority.get(&staker_authority)
+            {
+                reconciled_epoch
+                    .stuck_stake_accounts
+                    .push(StuckStakeAccount {
+                        stake_account: stake_account.to_string(),
+                        lamports: *lamports,
+                        settlement: settlement_address.to_string(),
+                    });
+            }
+        }
+
+        for settlement in &reconciled_epoch.settlements {
+            for issue in &settlement.issues {
+                reporting.add_error_string(format!(
+                    "Epoch {}, settlement {}: {}",
+                    epoch, settlement.settlement, issue
+                ));
+            }
+        }
+        for stuck_stake_account in &reconciled_epoch.stuck_stake_accounts {
+            reporting.add_error_string(format!(
+                "Epoch {}, stake account {} ({} SOL) is stuck under staker authority of non-existing settlement {}",
+                epoch,
+                stuck_stake_account.stake_account,
+                lamports_to_sol(stuck_stake_account.lamports),
+                stuck_stake_account.settlement
+            ));
+        }
+        reporting.reportable.epochs.push(reconciled_epoch);
+    }
+
+    Ok(())
+}
+
+/// Claim status of the tree nodes, the SettlementClaim account exists when the node was claimed
+async fn get_tree_nodes_claims(
+    rpc_client: Arc<RpcClient>,
+    settlement_address: &Pubkey,
+    json_settlement: &JsonSettlement,
+) -> anyhow::Result<Vec<TreeNodeClaim>> {
+    let settlement_claim_addresses = json_settlement
+        .merkle_tree
+        .tree_nodes
+        .iter()
+        .map(|tree_node| {
+            find_settlement_claim_address(settlement_address, &tree_node.hash().to_bytes()).0
+        })
+        .collect::<Vec<Pubkey>>();
+    let settlement_claims =
+        collect_existence_settlement_claims_from_addresses(rpc_client, &settlement_claim_addresses)
+            .await
+            .map_err(CliError::retry_able)?
+            .into_iter()
+            .collect::<HashMap<Pubkey, bool>>();
+    Ok(json_settlement
+        .merkle_tree
+        .tree_nodes
+        .iter()
+        .zip(settlement_claim_addresses)
+        .map(|(tree_node, settlement_claim_address)| TreeNodeClaim {
+            settlement_claim: settlement_claim_address.to_string(),
+            stake_authority: tree_node.stake_authority.to_string(),
+            withdraw_authority: tree_node.withdraw_authority.to_string(),
+            claim_lamports: tree_node.claim,
+            claimed: *settlement_claims
+                .get(&settlement_claim_address)
+                .unwrap_or(&false),
+        })
+        .collect())
+}
+
+struct JsonSettlement {
+    bond_address: Pubkey,
+    settlement_address: Pubkey,
+    merkle_root: [u8; 32],
+    merkle_tree: MerkleTreeMeta,
+}
+
+/// Discrepancy between the on-chain Settlement and the JSON merkle tree
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ReconcileIssue {
+    /// on-chain settlement without any JSON merkle tree
+    OrphanedSettlement {
+        bond_exists: bool,
+    },
+    MaxTotalClaimMismatch {
+        on_chain: u64,
+        json: u64,
+    },
+    MaxMerkleNodesMismatch {
+        on_chain: u64,
+        json: u64,
+    },
+    MerkleRootMismatch {
+        on_chain: String,
+        json: String,
+    },
+    UnderFunded {
+        lamports_funded: u64,
+        max_total_claim: u64,
+    },
+    OverFunded {
+        lamports_funded: u64,
+        max_total_claim: u64,
+    },
+    /// the settlement claimed counters do not match the existing SettlementClaim accounts
+    ClaimsMismatch {
+        merkle_nodes_claimed: u64,
+        claimed_tree_nodes: u64,
+        lamports_claimed: u64,
+        claimed_tree_nodes_lamports: u64,
+    },
+}
+
+impl fmt::Display for ReconcileIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReconcileIssue::OrphanedSettlement { bond_exists } => write!(
+                f,
+                "orphaned, no JSON merkle tree found for the settlement{}",
+                if *bond_exists {
+                    ""
+                } else {
+                    " (bond account does not exist)"
+                }
+            ),
+            ReconcileIssue::MaxTotalClaimMismatch { on_chain, json } => write!(
+                f,
+                "max total claim mismatch, on-chain {} SOL, JSON {} SOL",
+                lamports_to_sol(*on_chain),
+                lamports_to_sol(*json)
+            ),
+            ReconcileIssue::MaxMerkleNodesMismatch { on_chain, json } => write!(
+                f,
+                "max merkle nodes mismatch, on-chain {}, JSON {}",
+                on_chain, json
+            ),
+            ReconcileIssue::MerkleRootMismatch { on_chain, json } => write!(
+                f,
+                "merkle root mismatch, on-chain {}, JSON {}",
+                on_chain, json
+            ),
+            ReconcileIssue::UnderFunded {
+                lamports_funded,
+                max_total_claim,
+            } => write!(
+                f,
+                "under-funded, funded {}/{} SOL",
+                lamports_to_sol(*lamports_funded),
+                lamports_to_sol(*max_total_claim)
+            ),
+            ReconcileIssue::OverFunded {
+                lamports_funded,
+                max_total_claim,
+            } => write!(
+                f,
+                "over-funded, funded {}/{} SOL",
+                lamports_to_sol(*lamports_funded),
+                lamports_to_sol(*max_total_claim)
+            ),
+            ReconcileIssue::ClaimsMismatch {
+                merkle_nodes_claimed,
+                claimed_tree_nodes,
+                lamports_claimed,
+                claimed_tree_nodes_lamports,
+            } => write!(
+                f,
+                "claims mismatch, on-chain claimed {} nodes of {} SOL, SettlementClaim accounts exist for {} tree nodes of {} SOL",
+                merkle_nodes_claimed,
+                lamports_to_sol(*lamports_claimed),
+                claimed_tree_nodes,
+                lamports_to_sol(*claimed_tree_nodes_lamports)
+            ),
+        }
+    }
+}
+
+/// Compares the on-chain Settlement with the JSON merkle tree and the claim status of its tree nodes
+fn check_settlement(
+    settlement: &Settlement,
+    json_settlement: &JsonSettlement,
+    tree_nodes: &[TreeNodeClaim],
+) -> Vec<ReconcileIssue> {
+    let mut issues = vec![];
+    let merkle_tree = &json_settlement.merkle_tree;
+    if settlement.max_total_claim != merkle_tree.max_total_claim_sum {
+        issues.push(ReconcileIssue::MaxTotalClaimMismatch {
+            on_chain: settlement.max_total_claim,
+            json: merkle_tree.max_total_claim_sum,
+        });
+    }
+    if settlement.max_merkle_nodes != merkle_tree.max_total_claims as u64 {
+        issues.push(ReconcileIssue::MaxMerkleNodesMismatch {
+            on_chain: settlement.max_merkle_nodes,
+            json: merkle_tree.max_total_claims as u64,
+        });
+    }
+    if settlement.claim_merkle_root() != json_settlement.merkle_root {
+        issues.push(ReconcileIssue::MerkleRootMismatch {
+            on_chain: Hash::new_from_array(settlement.claim_merkle_root()).to_string(),
+            json: Hash::new_from_array(json_settlement.merkle_root).to_string(),
+        });
+    }
+    if settlement.lamports_funded < settlement.max_total_claim {
+        issues.push(ReconcileIssue::UnderFunded {
+            lamports_funded: settlement.lamports_funded,
+            max_total_claim: settlement.max_total_claim,
+        });
+    } else if settlement.lamports_funded > settlement.max_total_claim {
+        issues.push(ReconcileIssue::OverFunded {
+            lamports_funded: settlement.lamports_funded,
+            max_total_claim: settlement.max_total_claim,
+        });
+    }
+    let claimed_tree_nodes = tree_nodes.iter().filter(|node| node.claimed).count() as u64;
+    let claimed_tree_nodes_lamports: u64 = tree_nodes
+        .iter()
+        .filter(|node| node.claimed)
+        .map(|node| node.claim_lamports)
+        .sum();
+    if settlement.merkle_nodes_claimed != claimed_tree_nodes
+        || settlement.lamports_claimed != claimed_tree_nodes_lamports
+    {
+        issues.push(ReconcileIssue::ClaimsMismatch {
+            merkle_nodes_claimed: settlement.merkle_nodes_claimed,
+            claimed_tree_nodes,
+            lamports_claimed: settlement.lamports_claimed,
+            claimed_tree_nodes_lamports,
+        });
+    }
+    issues
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SettlementState {
+    /// the settlement exists on-chain and matches a JSON merkle tree
+    OnChain,
+    /// the JSON merkle tree has no on-chain settlement, it was closed or not initialized
+    NotOnChain,
+    /// the on-chain settlement has no JSON merkle tree
+    Orphaned,
+}
+
+#[derive(Serialize)]
+pub struct TreeNodeClaim {
+    settlement_claim: String,
+    stake_authority: String,
+    withdraw_authority: String,
+    claim_lamports: u64,
+    claimed: bool,
+}
+
+#[derive(Serialize)]
+pub struct ReconciledSettlement {
+    settlement: String,
+    bond: String,
+    vote_account: Option<String>,
+    state: SettlementState,
+    max_total_claim_lamports: u64,
+    max_merkle_nodes: u64,
+    lamports_funded: u64,
+    lamports_claimed: u64,
+    /// funded and not claimed yet
+    remaining_lamports: u64,
+    merkle_nodes_claimed: u64,
+    tree_nodes: Vec<TreeNodeClaim>,
+    issues: Vec<ReconcileIssue>,
+}
+
+impl ReconciledSettlement {
+    fn on_chain(
+        settlement_address: &Pubkey,
+        settlement: &Settlement,
+        json_settlement: &JsonSettlement,
+        tree_nodes: Vec<TreeNodeClaim>,
+    ) -> Self {
+        let issues = check_settlement(settlement, json_settlement, &tree_nodes);
+        Self {
+            vote_account: Some(json_settlement.merkle_tree.vote_account.to_string()),
+            state: SettlementState::OnChain,
+            tree_nodes,
+            issues,
+            ..Self::from_settlement(settlement_address, settlement)
+        }
+    }
+
+    fn not_on_chain(json_settlement: &JsonSettlement) -> Self {
+        Self {
+            settlement: json_settlement.settlement_address.to_string(),
+            bond: json_settlement.bond_address.to_string(),
+            vote_account: Some(json_settlement.merkle_tree.vote_account.to_string()),
+            state: SettlementState::NotOnChain,
+            max_total_claim_lamports: json_settlement.merkle_tree.max_total_claim_sum,
+            max_merkle_nodes: json_settlement.merkle_tree.max_total_claims as u64,
+            lamports_funded: 0,
+            lamports_claimed: 0,
+            remaining_lamports: 0,
+            merkle_nodes_claimed: 0,
+            tree_nodes: vec![],
+            issues: vec![],
+        }
+    }
+
+    fn orphaned(settlement_address: &Pubkey, settlement: &Settlement, bond_exists: bool) -> Self {
+        Self {
+            issues: vec![ReconcileIssue::OrphanedSettlement { bond_exists }],
+            ..Self::from_settlement(settlement_address, settlement)
+        }
+    }
+
+    fn from_settlement(settlement_address: &Pubkey, settlement: &Settlement) -> Self {
+        Self {
+            settlement: settlement_address.to_string(),
+            bond: settlement.bond.to_string(),
+            vote_account: None,
+            state: SettlementState::Orphaned,
+            max_total_claim_lamports: settlement.max_total_claim,
+            max_merkle_nodes: settlement.max_merkle_nodes,
+            lamports_funded: settlement.lamports_funded,
+            lamports_claimed: settlement.lamports_claimed,
+            remaining_lamports: settlement
+                .lamports_funded
+                .saturating_sub(settlement.lamports_claimed),
+            merkle_nodes_claimed: settlement.merkle_nodes_claimed,
+            tree_nodes: vec![],
+            issues: vec![],
+        }
+    }
+
+    fn report_lines(&self) -> Vec<String> {
+        let owner = if let Some(vote_account) = &self.vote_account {
+            format!("vote account {}", vote_account)
+        } else {
+            format!("bond {}", self.bond)
+        };
+        let mut lines = vec![match self.state {
+            SettlementState::NotOnChain => format!(
+                "  Settlement {} ({}): not on-chain (closed or not initialized), max total claim {} SOL, max merkle nodes {}",
+                self.settlement,
+                owner,
+                lamports_to_sol(self.max_total_claim_lamports),
+                self.max_merkle_nodes
+            ),
+            SettlementState::OnChain | SettlementState::Orphaned => format!(
+                "  Settlement {} ({}){}: funded {}/{} SOL, claimed {} SOL, remaining {} SOL, claimed nodes {}/{}",
+                self.settlement,
+                owner,
+                if self.state == SettlementState::Orphaned {
+                    " ORPHANED"
+                } else {
+                    ""
+                },
+                lamports_to_sol(self.lamports_funded),
+                lamports_to_sol(self.max_total_claim_lamports),
+                lamports_to_sol(self.lamports_claimed),
+                lamports_to_sol(self.remaining_lamports),
+                self.merkle_nodes_claimed,
+                self.max_merkle_nodes
+            ),
+        }];
+        lines.extend(self.tree_nodes.iter().map(|tree_node| {
+            format!(
+                "    {} {:>9} staker/withdrawer {}/{}, claim {} SOL",
+                tree_node.settlement_claim,
+                if tree_node.claimed {
+                    "claimed"
+                } else {
+                    "unclaimed"
+                },
+                tree_node.stake_authority,
+                tree_node.withdraw_authority,
+                lamports_to_sol(tree_node.claim_lamports)
+            )
+        }));
+        lines
+    }
+}
+
+#[derive(Serialize)]
+pub struct StuckStakeAccount {
+    stake_account: String,
+    lamports: u64,
+    /// the non-existing settlement the staker authority belongs to
+    settlement: String,
+}
+
+#[derive(Serialize)]
+pub struct ReconciledEpoch {
+    epoch: u64,
+    settlements: Vec<ReconciledSettlement>,
+    stuck_stake_accounts: Vec<StuckStakeAccount>,
+}
+
+#[derive(Serialize)]
+struct ReconcileReportJson<'a> {
+    command: &'static str,
+    epochs: &'a [ReconciledEpoch],
+}
+
+#[derive(Default)]
+pub struct ReconcileReport {
+    epochs: Vec<ReconciledEpoch>,
+}
+
+impl PrintReportable for ReconcileReport {
+    fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>> {
+        Box::pin(async { self.get_report_with_data().await.0 })
+    }
+
+    fn get_report_with_data(&self) -> Pin<Box<dyn Future<Output = ReportWithData> + '_>> {
+        Box::pin(async {
+            let mut report: Vec<String> = vec![];
+            for reconciled_epoch in &self.epochs {
+                let issues_count = reconciled_epoch
+                    .settlements
+                    .iter()
+                    .map(|settlement| settlement.issues.len())
+                    .sum::<usize>()
+                    + reconciled_epoch.stuck_stake_accounts.len();
+                report.push(format!(
+                    "Epoch {}: {} settlements, {} issues",
+                    reconciled_epoch.epoch,
+                    reconciled_epoch.settlements.len(),
+                    issues_count
+                ));
+                for settlement in &reconciled_epoch.settlements {
+                    report.extend(settlement.report_lines());
+                }
+                for stuck_stake_account in &reconciled_epoch.stuck_stake_accounts {
+                    report.push(format!(
+                        "  Stuck stake account {} {} SOL, staker authority of non-existing settlement {}",
+                        stuck_stake_account.stake_account,
+                        lamports_to_sol(stuck_stake_account.lamports),
+                        stuck_stake_account.settlement
+                    ));
+                }
+            }
+            let data = ReconcileReportJson {
+                command: "reconcile",
+                epochs: &self.epochs,
+            };
+            (report, to_report_data(&data))
+        })
+    }
+}
+
+impl ReconcileReport {
+    pub fn report_handler() -> ReportHandler<Self> {
+        ReportHandler::new(Self::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use merkle_tree::psr_claim::TreeNode;
+    use validator_bonds::state::settlement::Bumps;
+
+    fn json_settlement(max_total_claim_sum: u64, claims: &[u64]) -> JsonSettlement {
+        JsonSettlement {
+            bond_address: Pubkey::new_unique(),
+            settlement_address: Pubkey::new_unique(),
+            merkle_root: [1; 32],
+            merkle_tree: MerkleTreeMeta {
+                merkle_root: Some(Hash::new_from_array([1; 32])),
+                max_total_claim_sum,
+                max_total_claims: claims.len(),
+                vote_account: Pubkey::new_unique(),
+                tree_nodes: claims
+                    .iter()
+                    .map(|claim| TreeNode {
+                        stake_authority: Pubkey::new_unique(),
+                        withdraw_authority: Pubkey::new_unique(),
+                        claim: *claim,
+                        proof: None,
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    fn settlement(
+        json_settlement: &JsonSettlement,
+        lamports_funded: u64,
+        lamports_claimed: u64,
+        merkle_nodes_claimed: u64,
+    ) -> Settlement {
+        Settlement {
+            bond: json_settlement.bond_address,
+            staker_authority: Pubkey::new_unique(),
+            merkle_root: json_settlement.merkle_root,
+            max_total_claim: json_settlement.merkle_tree.max_total_claim_sum,
+            max_merkle_nodes: json_settlement.merkle_tree.max_total_claims as u64,
+            lamports_funded,
+            lamports_claimed,
+            merkle_nodes_claimed,
+            epoch_created_for: 600,
+            slot_created_at: 1,
+            rent_collector: Pubkey::new_unique(),
+            split_rent_collector: None,
+            split_rent_amount: 0,
+            bumps: Bumps::default(),
+            collateral_funded: 0,
+            collateral_claimed: 0,
+            amended: false,
+            amended_merkle_root: [0; 32],
+            disputed: false,
+            dispute_reason_hash: [0; 32],
+            claim_expiry_extension: 0,
+            account_version: 0,
+            reserved: [0; 4],
+        }
+    }
+
+    fn tree_nodes(json_settlement: &JsonSettlement, claimed: &[bool]) -> Vec<TreeNodeClaim> {
+        json_settlement
+            .merkle_tree
+            .tree_nodes
+            .iter()
+            .zip(claimed)
+            .map(|(tree_node, claimed)| TreeNodeClaim {
+                settlement_claim: Pubkey::new_unique().to_string(),
+                stake_authority: tree_node.stake_authority.to_string(),
+                withdraw_authority: tree_node.withdraw_authority.to_string(),
+                claim_lamports: tree_node.claim,
+                claimed: *claimed,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_check_settlement_reconciled() {
+        let json_settlement = json_settlement(300, &[100, 200]);
+        let settlement = settlement(&json_settlement, 300, 200, 1);
+        let tree_nodes = tree_nodes(&json_settlement, &[false, true]);
+        assert_eq!(
+            check_settlement(&settlement, &json_settlement, &tree_nodes),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_check_settlement_issues() {
+        let json_settlement = json_settlement(300, &[100, 200]);
+
+        let settlement_under_funded = settlement(&json_settlement, 100, 0, 0);
+        assert_eq!(
+            check_settlement(
+                &settlement_under_funded,
+                &json_settlement,
+                &tree_nodes(&json_settlement, &[false, false])
+            ),
+            vec![ReconcileIssue::UnderFunded {
+                lamports_funded: 100,
+                max_total_claim: 300
+            }]
+        );
+
+        let mut settlement_mismatched = settlement(&json_settlement, 400, 100, 1);
+        settlement_mismatched.max_total_claim = 400;
+        settlement_mismatched.amended = true;
+        settlement_mismatched.amended_merkle_root = [2; 32];
+        assert_eq!(
+            check_settlement(
+                &settlement_mismatched,
+                &json_settlement,
+                &tree_nodes(&json_settlement, &[false, true])
+            ),
+            vec![
+                ReconcileIssue::MaxTotalClaimMismatch {
+                    on_chain: 400,
+                    json: 300
+                },
+                ReconcileIssue::MerkleRootMismatch {
+                    on_chain: Hash::new_from_array([2; 32]).to_string(),
+                    json: Hash::new_from_array([1; 32]).to_string(),
+                },
+                ReconcileIssue::ClaimsMismatch {
+                    merkle_nodes_claimed: 1,
+                    claimed_tree_nodes: 1,
+                    lamports_claimed: 100,
+                    claimed_tree_nodes_lamports: 200,
+                },
+            ]
+        );
+
+        let settlement_over_funded = settlement(&json_settlement, 500, 0, 0);
+        assert_eq!(
+            check_settlement(
+                &settlement_over_funded,
+                &json_settlement,
+                &tree_nodes(&json_settlement, &[false, false])
+            ),
+            vec![ReconcileIssue::OverFunded {
+                lamports_funded: 500,
+                max_total_claim: 300
+            }]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::anyhow;
+use serde::Deserialize;
+use settlement_engine::utils::read_from_yaml_file;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+/// YAML plan of the `settlement-pipeline run-epoch` command, the lifecycle of Settlements of one epoch.
+/// All the JSON files of the epoch are read from and written to the `epoch_dir`
+/// with the same names as used by the buildkite pipelines.
+#[derive(Debug, Deserialize)]
+pub struct EpochPlan {
+    pub epoch: u64,
+    pub epoch_dir: PathBuf,
+    /// pipeline state journal shared by the on-chain steps, used for the idempotency checks
+    #[serde(default)]
+    pub state_file: Option<PathBuf>,
+    /// global arguments passed to every on-chain step (e.g., --rpc-url, --fee-payer),
+    /// the command specific ones belong to the step `args`;
+    /// environment variables in form of `$VAR` or `${VAR}` are expanded
+    #[serde(default)]
+    pub args: Vec<String>,
+    pub steps: Vec<PlanStep>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "step", rename_all = "kebab-case")]
+pub enum PlanStep {
+    ParseSnapshot {
+        ledger_path: PathBuf,
+    },
+    GenerateSettlements {
+        settlement_config: PathBuf,
+        #[serde(default)]
+        past_validator_meta_collection: Option<PathBuf>,
+        #[serde(default)]
+        whitelist_stake_authority: Option<Vec<String>>,
+    },
+    ListSettlement,
+    InitSettlement {
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    ClaimSettlement {
+        #[serde(default)]
+        args: Vec<String>,
+    },
+    CloseSettlement {
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
+
+impl PlanStep {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PlanStep::ParseSnapshot { .. } => "parse-snapshot",
+            PlanStep::GenerateSettlements { .. } => "generate-settlements",
+            PlanStep::ListSettlement => "list-settlement",
+            PlanStep::InitSettlement { .. } => "init-settlement",
+            PlanStep::ClaimSettlement { .. } => "claim-settlement",
+            PlanStep::CloseSettlement { .. } => "close-settlement",
+        }
+    }
+}
+
+impl Display for PlanStep {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl EpochPlan {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        read_from_yaml_file(&path)
+            .map_err(|e| anyhow!("Cannot load epoch plan from '{:?}': {:?}", path, e))
+    }
+
+    /// Plan arguments of the on-chain steps with expanded environment variables
+    pub fn expand_args(&self, step_args: &[String]) -> anyhow::Result<Vec<String>> {
+        self.args
+            .iter()
+            .chain(step_args.iter())
+            .map(|arg| {
+                shellexpand::env(arg)
+                    .map(|expanded| expanded.to_string())
+                    .map_err(|e| anyhow!("Cannot expand plan argument '{}': {}", arg, e))
+            })
+            .collect()
+    }
+
+    pub fn validator_meta_collection(&self) -> PathBuf {
+        self.epoch_dir.join("validators.json")
+    }
+
+    pub fn stake_meta_collection(&self) -> PathBuf {
+        self.epoch_dir.join("stakes.json")
+    }
+
+    pub fn protected_event_collection(&self) -> PathBuf {
+        self.epoch_dir.join("protected-events.json")
+    }
+
+    pub fn settlement_collection(&self) -> PathBuf {
+        self.epoch_dir.join("settlements.json")
+    }
+
+    pub fn merkle_tree_collection(&self) -> PathBuf {
+        self.epoch_dir.join("settlement-merkle-trees.json")
+    }
+
+    /// Settlement addresses of the epoch as listed by list-settlement
+    pub fn settlement_list(&self) -> PathBuf {
+        self.epoch_dir.join("settlements-list.json")
+    }
+}
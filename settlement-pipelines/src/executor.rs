@@ -1,16 +1,91 @@
+use crate::metrics::metrics;
+use crate::rpc::{classify_error, throttled_requests, EndpointFailure};
+use crate::simulation::{simulate_transactions, SimulatedAccounts};
+use anyhow::anyhow;
+use log::{info, warn};
+use solana_client::client_error::ClientError;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::SignerError;
+use solana_sdk::transaction::Transaction;
 use solana_transaction_builder::TransactionBuilder;
 use solana_transaction_builder_executor::{
     builder_to_execution_data, execute_transactions_in_parallel, execute_transactions_in_sequence,
 };
-use solana_transaction_executor::{PriorityFeePolicy, TransactionExecutor};
-use std::sync::Arc;
+use solana_transaction_executor::{PriorityFeePolicy, SignedTransaction, TransactionExecutor};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 const PARALLEL_EXECUTION_RATE_DEFAULT: usize = 100;
+const PARALLEL_EXECUTION_RATE_MIN: usize = 5;
+
+/// Executes the built transactions, in dry-run mode the transactions are only simulated
+pub struct PipelineExecutor {
+    pub transaction_executor: Arc<TransactionExecutor>,
+    pub simulation_rpc_client: Option<Arc<RpcClient>>,
+    /// accounts created by the transactions simulated in the dry-run, shared by all executions of the run
+    pub simulated_accounts: SimulatedAccounts,
+    pub parallelism: AdaptiveParallelism,
+}
+
+/// Parallelism of the transaction execution adapted to the load of the RPC endpoints,
+/// halved when the requests are throttled (rate limited, timed out)
+/// and raised back step by step with every execution without throttling
+#[derive(Default)]
+pub struct AdaptiveParallelism {
+    /// upper limit of the requested parallelism, none until a throttling is observed
+    limit: Mutex<Option<usize>>,
+}
+
+impl AdaptiveParallelism {
+    pub fn rate(&self, requested_rate: usize) -> usize {
+        self.limit
+            .lock()
+            .expect("parallelism lock poisoned")
+            .map_or(requested_rate, |limit| limit.min(requested_rate))
+    }
+
+    pub fn on_execution(&self, rate: usize, throttled: bool) {
+        let mut limit = self.limit.lock().expect("parallelism lock poisoned");
+        if throttled {
+            let lowered = (rate / 2).max(PARALLEL_EXECUTION_RATE_MIN);
+            warn!(
+                "Transaction execution throttled by RPC, lowering parallelism {} -> {}",
+                rate, lowered
+            );
+            *limit = Some(lowered);
+        } else if let Some(current) = *limit {
+            let raised = current.saturating_add((current / 4).max(1));
+            info!("Raising parallelism of transaction execution to {}", raised);
+            *limit = Some(raised);
+        }
+    }
+}
+
+/// The execution failed on an RPC client error of a rate limited or timed out request
+fn is_throttling_error(err: &anyhow::Error) -> bool {
+    err.chain()
+        .filter_map(|cause| cause.downcast_ref::<ClientError>())
+        .any(|client_error| classify_error(client_error) == Some(EndpointFailure::Throttled))
+}
+
+/// When all executed successfully then the builder is drained
+fn check_builder_processed(builder: &TransactionBuilder, execution: &str) -> anyhow::Result<()> {
+    let remaining = builder.instructions().len();
+    if remaining > 0 {
+        return Err(anyhow!(
+            "{}: expected to get all instructions from builder processed, {} remaining",
+            execution,
+            remaining
+        ));
+    }
+    Ok(())
+}
 
 pub async fn execute_parallel(
     rpc_client: Arc<RpcClient>,
-    executor: Arc<TransactionExecutor>,
+    executor: Arc<PipelineExecutor>,
     builder: &mut TransactionBuilder,
     priority_fee_policy: &PriorityFeePolicy,
 ) -> anyhow::Result<(usize, usize)> {
@@ -26,46 +101,183 @@ pub async fn execute_parallel(
 
 pub async fn execute_parallel_with_rate(
     rpc_client: Arc<RpcClient>,
-    executor: Arc<TransactionExecutor>,
+    executor: Arc<PipelineExecutor>,
     builder: &mut TransactionBuilder,
     priority_fee_policy: &PriorityFeePolicy,
     parallel_execution_rate: usize,
 ) -> anyhow::Result<(usize, usize)> {
+    let started = Instant::now();
+    if let Some(simulation_rpc_client) = &executor.simulation_rpc_client {
+        let result = simulate_transactions(
+            simulation_rpc_client.clone(),
+            builder,
+            &executor.simulated_accounts,
+        )
+        .await;
+        metrics().record_execution("parallel", started.elapsed(), true, &result);
+        return result;
+    }
     let executed_instruction_count = builder.instructions().len();
     let execution_data =
         builder_to_execution_data(rpc_client.url(), builder, Some(priority_fee_policy.clone()));
     let executed_transaction_count = execution_data.len();
-    execute_transactions_in_parallel(
-        executor.clone(),
+    let rate = executor.parallelism.rate(parallel_execution_rate);
+    metrics().parallel_execution_rate.set(rate as i64);
+    let throttled_before = throttled_requests();
+    let execution_result = execute_transactions_in_parallel(
+        executor.transaction_executor.clone(),
         execution_data,
-        Some(parallel_execution_rate),
+        Some(rate),
     )
-    .await?;
-    // when all executed successfully then builder should be empty
-    assert_eq!(
-        builder.instructions().len(),
-        0,
-        "execute_parallel: expected to get all instructions from builder processed"
-    );
+    .await
+    .map_err(anyhow::Error::from);
+    let throttled = throttled_requests() > throttled_before
+        || execution_result
+            .as_ref()
+            .err()
+            .map_or(false, is_throttling_error);
+    executor.parallelism.on_execution(rate, throttled);
+    if execution_result.is_err() {
+        metrics().record_failed_transactions(
+            "parallel",
+            executed_transaction_count,
+            executed_instruction_count,
+        );
+    }
+    let result = execution_result.map(|_| (executed_transaction_count, executed_instruction_count));
+    metrics().record_execution("parallel", started.elapsed(), false, &result);
+    result?;
+    check_builder_processed(builder, "execute_parallel")?;
     Ok((executed_transaction_count, executed_instruction_count))
 }
 
 pub async fn execute_in_sequence(
     rpc_client: Arc<RpcClient>,
-    executor: Arc<TransactionExecutor>,
+    executor: Arc<PipelineExecutor>,
     builder: &mut TransactionBuilder,
     priority_fee_policy: &PriorityFeePolicy,
 ) -> anyhow::Result<(usize, usize)> {
+    let started = Instant::now();
+    if let Some(simulation_rpc_client) = &executor.simulation_rpc_client {
+        let result = simulate_transactions(
+            simulation_rpc_client.clone(),
+            builder,
+            &executor.simulated_accounts,
+        )
+        .await;
+        metrics().record_execution("sequence", started.elapsed(), true, &result);
+        return result;
+    }
     let executed_instruction_count = builder.instructions().len();
     let execution_data =
         builder_to_execution_data(rpc_client.url(), builder, Some(priority_fee_policy.clone()));
     let executed_transaction_count = execution_data.len();
-    execute_transactions_in_sequence(executor.clone(), execution_data).await?;
-    // when all executed successfully then builder should be empty
-    assert_eq!(
-        builder.instructions().len(),
-        0,
-        "execute_in_sequence: expected to get all instructions from builder processed"
-    );
+    let execution_result =
+        execute_transactions_in_sequence(executor.transaction_executor.clone(), execution_data)
+            .await;
+    if execution_result.is_err() {
+        metrics().record_failed_transactions(
+            "sequence",
+            executed_transaction_count,
+            executed_instruction_count,
+        );
+    }
+    let result = execution_result
+        .map(|_| (executed_transaction_count, executed_instruction_count))
+        .map_err(anyhow::Error::from);
+    metrics().record_execution("sequence", started.elapsed(), false, &result);
+    result?;
+    check_builder_processed(builder, "execute_in_sequence")?;
     Ok((executed_transaction_count, executed_instruction_count))
 }
+
+/// Transaction signed offline with a durable nonce, it cannot be re-signed with a fresh blockhash
+/// and the executor re-sends it as it is until it is confirmed
+struct OfflineSignedTransaction(Transaction);
+
+impl SignedTransaction for OfflineSignedTransaction {
+    fn sign(&mut self, _recent_blockhash: Hash) -> Result<&Transaction, SignerError> {
+        Ok(&self.0)
+    }
+}
+
+/// Executes the transaction signed offline (see [crate::export]),
+/// the priority fee is the one fixed at the export of the transaction
+pub async fn execute_signed_transaction(
+    executor: Arc<PipelineExecutor>,
+    transaction: Transaction,
+) -> anyhow::Result<Signature> {
+    executor
+        .transaction_executor
+        .execute_transaction(&mut OfflineSignedTransaction(transaction))
+        .await
+        .map_err(|e| anyhow!("Transaction execution failed: {:?}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::http_stand_in;
+    use solana_client::http_sender::HttpSender;
+    use solana_client::rpc_request::RpcRequest;
+    use solana_client::rpc_sender::RpcSender;
+
+    #[test]
+    fn test_adaptive_parallelism() {
+        let parallelism = AdaptiveParallelism::default();
+        assert_eq!(parallelism.rate(100), 100);
+
+        parallelism.on_execution(100, true);
+        assert_eq!(parallelism.rate(100), 50);
+        assert_eq!(parallelism.rate(30), 30);
+        parallelism.on_execution(50, true);
+        parallelism.on_execution(25, true);
+        parallelism.on_execution(12, true);
+        parallelism.on_execution(6, true);
+        assert_eq!(parallelism.rate(100), PARALLEL_EXECUTION_RATE_MIN);
+
+        parallelism.on_execution(PARALLEL_EXECUTION_RATE_MIN, false);
+        assert_eq!(parallelism.rate(100), 6);
+        for _ in 0..20 {
+            parallelism.on_execution(parallelism.rate(100), false);
+        }
+        assert_eq!(parallelism.rate(100), 100);
+    }
+
+    #[tokio::test]
+    async fn test_is_throttling_error() {
+        let (throttling_url, _) = http_stand_in(429, "").await;
+        let client_error = HttpSender::new(throttling_url)
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap_err();
+        let err = anyhow::Error::from(client_error).context("Transaction execution failed");
+        assert!(is_throttling_error(&err));
+
+        let (rate_limited_url, _) = http_stand_in(
+            200,
+            r#"{"jsonrpc":"2.0","error":{"code":429,"message":"Too many requests"},"id":1}"#,
+        )
+        .await;
+        let client_error = HttpSender::new(rate_limited_url)
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap_err();
+        assert!(is_throttling_error(&anyhow::Error::from(client_error)));
+
+        let (unavailable_url, _) = http_stand_in(503, "").await;
+        let client_error = HttpSender::new(unavailable_url)
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap_err();
+        assert!(!is_throttling_error(&anyhow::Error::from(client_error)));
+
+        // error messages are not matched
+        assert!(!is_throttling_error(&anyhow!(
+            "HTTP status client error (429 Too Many Requests)"
+        )));
+        assert!(!is_throttling_error(&anyhow!(
+            "Transaction simulation failed: Error processing Instruction 4290"
+        )));
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::anyhow;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use settlement_engine::utils::{read_from_json_file, write_to_json_file};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::nonce_utils::nonblocking::{data_from_account, get_account_with_commitment};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction::advance_nonce_account;
+use solana_sdk::transaction::Transaction;
+use std::path::Path;
+
+/// Transactions exported to be signed offline (e.g., by a hardware wallet or a multisig)
+/// and submitted later with the `submit-transactions` command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionsExport {
+    /// the wallet expected to sign the exported transactions, paying the fees as well
+    pub signer: String,
+    pub transactions: Vec<ExportedTransaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedTransaction {
+    pub description: String,
+    /// durable nonce account the transaction is built with, the transaction is valid until the nonce is advanced
+    #[serde(default)]
+    pub nonce_account: Option<String>,
+    /// instruction set of the transaction, in format importable to Squads multisig
+    pub instructions: Vec<ExportedInstruction>,
+    /// base64 encoded transaction, unsigned on export, expected to be replaced with the signed one before submit
+    pub transaction: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedInstruction {
+    pub program_id: String,
+    pub keys: Vec<ExportedAccountMeta>,
+    /// base64 encoded instruction data
+    pub data: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// Durable nonce the exported transaction is built with, the transaction signed offline
+/// does not expire with the recent blockhash and can be submitted at any time later
+#[derive(Debug, Clone)]
+pub struct ExportNonce {
+    pub nonce_account: Pubkey,
+    pub nonce_authority: Pubkey,
+    pub nonce_hash: Hash,
+}
+
+impl ExportNonce {
+    /// Loads the current nonce hash and authority of the nonce account
+    pub async fn load(rpc_client: &RpcClient, nonce_account: &Pubkey) -> anyhow::Result<Self> {
+        let account =
+            get_account_with_commitment(rpc_client, nonce_account, rpc_client.commitment())
+                .await
+                .map_err(|e| anyhow!("Cannot load nonce account {}: {:?}", nonce_account, e))?;
+        let nonce_data = data_from_account(&account)
+            .map_err(|e| anyhow!("Invalid nonce account {}: {:?}", nonce_account, e))?;
+        Ok(Self {
+            nonce_account: *nonce_account,
+            nonce_authority: nonce_data.authority,
+            nonce_hash: nonce_data.blockhash(),
+        })
+    }
+}
+
+impl ExportedTransaction {
+    /// The transaction advances the durable nonce as the first instruction,
+    /// the priority fee is fixed at export as the offline signed transaction cannot be changed later
+    pub fn new(
+        description: String,
+        instructions: &[Instruction],
+        signer: &Pubkey,
+        nonce: &ExportNonce,
+        micro_lamports_per_cu: Option<u64>,
+    ) -> anyhow::Result<Self> {
+        let instructions = std::iter::once(advance_nonce_account(
+            &nonce.nonce_account,
+            &nonce.nonce_authority,
+        ))
+        .chain(micro_lamports_per_cu.map(ComputeBudgetInstruction::set_compute_unit_price))
+        .chain(instructions.iter().cloned())
+        .collect::<Vec<Instruction>>();
+        let mut transaction = Transaction::new_with_payer(&instructions, Some(signer));
+        transaction.message.recent_blockhash = nonce.nonce_hash;
+        let transaction_data = bincode::serialize(&transaction)
+            .map_err(|e| anyhow!("Cannot serialize transaction '{}': {:?}", description, e))?;
+        Ok(Self {
+            description,
+            nonce_account: Some(nonce.nonce_account.to_string()),
+            instructions: instructions
+                .iter()
+                .map(|instruction| ExportedInstruction {
+                    program_id: instruction.program_id.to_string(),
+                    keys: instruction
+                        .accounts
+                        .iter()
+                        .map(|account| ExportedAccountMeta {
+                            pubkey: account.pubkey.to_string(),
+                            is_signer: account.is_signer,
+                            is_writable: account.is_writable,
+                        })
+                        .collect(),
+                    data: BASE64.encode(&instruction.data),
+                })
+                .collect(),
+            transaction: BASE64.encode(transaction_data),
+        })
+    }
+
+    pub fn to_transaction(&self) -> anyhow::Result<Transaction> {
+        let transaction_data = BASE64.decode(&self.transaction).map_err(|e| {
+            anyhow!(
+                "Cannot decode base64 transaction '{}': {:?}",
+                self.description,
+                e
+            )
+        })?;
+        bincode::deserialize::<Transaction>(&transaction_data).map_err(|e| {
+            anyhow!(
+                "Cannot deserialize transaction '{}': {:?}",
+                self.description,
+                e
+            )
+        })
+    }
+}
+
+pub fn write_transactions_export(
+    path: &Path,
+    transactions_export: &TransactionsExport,
+) -> anyhow::Result<()> {
+    let out_path = path
+        .to_str()
+        .ok_or_else(|| anyhow!("Invalid export file path '{:?}'", path))?;
+    write_to_json_file(transactions_export, out_path)
+}
+
+pub fn read_transactions_export(path: &Path) -> anyhow::Result<TransactionsExport> {
+    read_from_json_file(&path).map_err(|e| {
+        anyhow!(
+            "Cannot read exported transactions from '{:?}': {:?}",
+            path,
+            e
+        )
+    })
+}
@@ -1,19 +1,28 @@
 use crate::arguments::GlobalOpts;
+use crate::executor::{AdaptiveParallelism, PipelineExecutor};
+use crate::simulation::SimulatedAccounts;
 use env_logger::{Builder, Env};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_transaction_executor::{
-    SendTransactionWithGrowingTipProvider, TipPolicy, TransactionExecutor,
-    TransactionExecutorBuilder,
+    SendTransactionWithGrowingTipProvider, TipPolicy, TransactionExecutorBuilder,
 };
 use std::sync::Arc;
 
 pub fn init_log(global_opts: &GlobalOpts) {
-    let verbosity = if global_opts.verbose { "debug" } else { "info" };
+    init_log_with_verbosity(global_opts.verbose);
+}
+
+pub fn init_log_with_verbosity(verbose: bool) {
+    let verbosity = if verbose { "debug" } else { "info" };
     let mut builder = Builder::from_env(Env::default().default_filter_or(verbosity));
     builder.init();
 }
 
-pub fn get_executor(rpc_client: Arc<RpcClient>, tip_policy: TipPolicy) -> Arc<TransactionExecutor> {
+pub fn get_executor(
+    rpc_client: Arc<RpcClient>,
+    tip_policy: TipPolicy,
+    simulation_rpc_client: Option<Arc<RpcClient>>,
+) -> Arc<PipelineExecutor> {
     let transaction_executor_builder = TransactionExecutorBuilder::new()
         .with_default_providers(rpc_client.clone())
         .with_send_transaction_provider(SendTransactionWithGrowingTipProvider {
@@ -21,5 +30,10 @@ pub fn get_executor(rpc_client: Arc<RpcClient>, tip_policy: TipPolicy) -> Arc<Tr
             query_param: "tip".into(),
             tip_policy,
         });
-    Arc::new(transaction_executor_builder.build())
+    Arc::new(PipelineExecutor {
+        transaction_executor: Arc::new(transaction_executor_builder.build()),
+        simulation_rpc_client,
+        simulated_accounts: SimulatedAccounts::default(),
+        parallelism: AdaptiveParallelism::default(),
+    })
 }
@@ -1,10 +1,20 @@
 pub mod anchor;
 pub mod arguments;
 pub mod cli_result;
+pub mod commands;
+pub mod epoch_plan;
 pub mod executor;
+pub mod export;
 pub mod init;
 pub mod json_data;
+pub mod metrics;
+pub mod notification;
+pub mod pipeline_state;
 pub mod reporting;
+pub mod rpc;
 pub mod settlements;
+pub mod simulation;
 pub mod stake_accounts;
 pub mod stake_accounts_cache;
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,463 @@
+use crate::arguments::MetricsOpts;
+use crate::pipeline_state::PipelineStateStore;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use log::{error, info};
+use prometheus::{
+    exponential_buckets, Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge,
+    IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use solana_client::client_error::Result as ClientResult;
+use solana_client::rpc_request::RpcRequest;
+use solana_client::rpc_sender::{RpcSender, RpcTransportStats};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const METRICS_NAMESPACE: &str = "settlement_pipeline";
+
+static METRICS: OnceLock<PipelineMetrics> = OnceLock::new();
+
+/// Prometheus metrics of the pipeline run, all labeled with the `pipeline` (command) name
+pub struct PipelineMetrics {
+    registry: Registry,
+    /// transaction batches passed to the executor, by execution (parallel, sequence) and result
+    pub executions: IntCounterVec,
+    /// duration of the transaction batch execution
+    pub execution_duration: HistogramVec,
+    /// transactions of the executed batches, by execution and result (executed, simulated, failed)
+    pub transactions: IntCounterVec,
+    /// instructions of the executed batches, by execution and result
+    pub instructions: IntCounterVec,
+    /// failed transaction executions expected to succeed when the pipeline is retried
+    pub retry_able_errors: IntCounter,
+    /// lamports funded to and claimed from the Settlements in this run, by epoch and operation
+    pub lamports: IntCounterVec,
+    /// latency of the RPC requests, by method
+    pub rpc_request_duration: HistogramVec,
+    /// failed RPC requests, by method
+    pub rpc_errors: IntCounterVec,
+    /// failures of the RPC endpoints leading to failover, by endpoint index and failure kind
+    pub rpc_endpoint_failures: IntCounterVec,
+    /// parallelism of the transaction execution lowered by the adaptive controller
+    pub parallel_execution_rate: IntGauge,
+    /// Settlements per epoch and state as recorded in the pipeline state journal
+    pub settlements: IntGaugeVec,
+    /// lamports of the claims sent per epoch as recorded in the pipeline state journal
+    pub claimed_lamports: IntGaugeVec,
+    /// 1 when the last run finished without errors
+    pub last_run_success: IntGauge,
+    pub last_run_timestamp: IntGauge,
+}
+
+impl PipelineMetrics {
+    fn new(pipeline: &str) -> anyhow::Result<Self> {
+        let registry = Registry::new_custom(
+            Some(METRICS_NAMESPACE.to_string()),
+            Some(HashMap::from([(
+                "pipeline".to_string(),
+                pipeline.to_string(),
+            )])),
+        )?;
+        let metrics = Self {
+            executions: IntCounterVec::new(
+                Opts::new("executions_total", "Transaction batches executed"),
+                &["execution", "result"],
+            )?,
+            execution_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "execution_duration_seconds",
+                    "Duration of the transaction batch execution",
+                )
+                .buckets(exponential_buckets(0.5, 2.0, 12)?),
+                &["execution"],
+            )?,
+            transactions: IntCounterVec::new(
+                Opts::new("transactions_total", "Transactions of the executed batches"),
+                &["execution", "result"],
+            )?,
+            instructions: IntCounterVec::new(
+                Opts::new("instructions_total", "Instructions of the executed batches"),
+                &["execution", "result"],
+            )?,
+            retry_able_errors: IntCounter::new(
+                "retry_able_errors_total",
+                "Failed transaction executions to be retried by a re-run",
+            )?,
+            lamports: IntCounterVec::new(
+                Opts::new(
+                    "lamports_total",
+                    "Lamports funded to and claimed from the Settlements",
+                ),
+                &["epoch", "operation"],
+            )?,
+            rpc_request_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "rpc_request_duration_seconds",
+                    "Latency of the RPC requests",
+                )
+                .buckets(exponential_buckets(0.01, 2.0, 12)?),
+                &["method"],
+            )?,
+            rpc_errors: IntCounterVec::new(
+                Opts::new("rpc_errors_total", "Failed RPC requests"),
+                &["method"],
+            )?,
+            rpc_endpoint_failures: IntCounterVec::new(
+                Opts::new(
+                    "rpc_endpoint_failures_total",
+                    "Failures of the RPC endpoints leading to failover",
+                ),
+                &["endpoint", "failure"],
+            )?,
+            parallel_execution_rate: IntGauge::new(
+                "parallel_execution_rate",
+                "Current parallelism of the transaction execution",
+            )?,
+            settlements: IntGaugeVec::new(
+                Opts::new(
+                    "settlements",
+                    "Settlements per state in the pipeline journal",
+                ),
+                &["epoch", "state"],
+            )?,
+            claimed_lamports: IntGaugeVec::new(
+                Opts::new(
+                    "claimed_lamports",
+                    "Lamports of the sent claims in the pipeline journal",
+                ),
+                &["epoch"],
+            )?,
+            last_run_success: IntGauge::new(
+                "last_run_success",
+                "1 when the last run finished without errors",
+            )?,
+            last_run_timestamp: IntGauge::new(
+                "last_run_timestamp_seconds",
+                "Unix timestamp of the end of the last run",
+            )?,
+            registry,
+        };
+        metrics.register()?;
+        Ok(metrics)
+    }
+
+    fn register(&self) -> anyhow::Result<()> {
+        self.registry.register(Box::new(self.executions.clone()))?;
+        self.registry
+            .register(Box::new(self.execution_duration.clone()))?;
+        self.registry
+            .register(Box::new(self.transactions.clone()))?;
+        self.registry
+            .register(Box::new(self.instructions.clone()))?;
+        self.registry
+            .register(Box::new(self.retry_able_errors.clone()))?;
+        self.registry.register(Box::new(self.lamports.clone()))?;
+        self.registry
+            .register(Box::new(self.rpc_request_duration.clone()))?;
+        self.registry.register(Box::new(self.rpc_errors.clone()))?;
+        self.registry
+            .register(Box::new(self.rpc_endpoint_failures.clone()))?;
+        self.registry
+            .register(Box::new(self.parallel_execution_rate.clone()))?;
+        self.registry.register(Box::new(self.settlements.clone()))?;
+        self.registry
+            .register(Box::new(self.claimed_lamports.clone()))?;
+        self.registry
+            .register(Box::new(self.last_run_success.clone()))?;
+        self.registry
+            .register(Box::new(self.last_run_timestamp.clone()))?;
+        Ok(())
+    }
+
+    /// Records the result of the transaction batch execution
+    pub fn record_execution(
+        &self,
+        execution: &str,
+        duration: Duration,
+        simulated: bool,
+        result: &anyhow::Result<(usize, usize)>,
+    ) {
+        self.execution_duration
+            .with_label_values(&[execution])
+            .observe(duration.as_secs_f64());
+        let result_label = match result {
+            Ok(_) if simulated => "simulated",
+            Ok(_) => "executed",
+            Err(_) => "failed",
+        };
+        self.executions
+            .with_label_values(&[execution, result_label])
+            .inc();
+        if let Ok((transaction_count, instruction_count)) = result {
+            self.transactions
+                .with_label_values(&[execution, result_label])
+                .inc_by(*transaction_count as u64);
+            self.instructions
+                .with_label_values(&[execution, result_label])
+                .inc_by(*instruction_count as u64);
+        }
+    }
+
+    /// Records the transactions of the batch whose execution failed
+    pub fn record_failed_transactions(
+        &self,
+        execution: &str,
+        transaction_count: usize,
+        instruction_count: usize,
+    ) {
+        self.transactions
+            .with_label_values(&[execution, "failed"])
+            .inc_by(transaction_count as u64);
+        self.instructions
+            .with_label_values(&[execution, "failed"])
+            .inc_by(instruction_count as u64);
+    }
+
+    pub fn record_lamports(&self, epoch: u64, operation: &str, lamports: u64) {
+        self.lamports
+            .with_label_values(&[&epoch.to_string(), operation])
+            .inc_by(lamports);
+    }
+
+    pub fn record_rpc_request(&self, method: &str, duration: Duration, is_error: bool) {
+        self.rpc_request_duration
+            .with_label_values(&[method])
+            .observe(duration.as_secs_f64());
+        if is_error {
+            self.rpc_errors.with_label_values(&[method]).inc();
+        }
+    }
+
+    /// Settlement progress per epoch taken from the pipeline state journal
+    pub fn record_pipeline_state(&self, pipeline_state: &PipelineStateStore) {
+        for (epoch, settlements) in pipeline_state.epochs() {
+            let epoch = epoch.to_string();
+            let states = [
+                ("listed", settlements.len()),
+                (
+                    "initialized",
+                    settlements
+                        .values()
+                        .filter(|s| s.initialized_at_run.is_some())
+                        .count(),
+                ),
+                (
+                    "funded",
+                    settlements
+                        .values()
+                        .filter(|s| s.funded_at_run.is_some())
+                        .count(),
+                ),
+                (
+                    "closed",
+                    settlements
+                        .values()
+                        .filter(|s| s.closed_at_run.is_some())
+                        .count(),
+                ),
+            ];
+            for (state, count) in states {
+                self.settlements
+                    .with_label_values(&[&epoch, state])
+                    .set(count as i64);
+            }
+            self.claimed_lamports
+                .with_label_values(&[&epoch])
+                .set(settlements.values().map(|s| s.claimed_amount).sum::<u64>() as i64);
+        }
+    }
+
+    pub fn record_run_result(&self, is_success: bool) {
+        self.last_run_success.set(i64::from(is_success));
+        self.last_run_timestamp.set(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |duration| duration.as_secs() as i64),
+        );
+    }
+
+    /// Metrics in the Prometheus text format
+    pub fn encode(&self) -> anyhow::Result<String> {
+        let mut buffer = vec![];
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+/// Initializes the metrics labeled with the pipeline name, the first initialization wins
+/// (i.e., the steps executed by `settlement-pipeline run-epoch` are labeled as the `run-epoch`)
+pub fn init_metrics(pipeline: &str) -> &'static PipelineMetrics {
+    METRICS.get_or_init(|| {
+        PipelineMetrics::new(pipeline).expect("Prometheus metrics definitions are valid")
+    })
+}
+
+/// Metrics of the pipeline, initialized with an unknown pipeline name when not done before
+pub fn metrics() -> &'static PipelineMetrics {
+    init_metrics("unknown")
+}
+
+/// Writes the metrics to the Prometheus text file and/or pushes them to the push gateway
+#[derive(Default)]
+pub struct MetricsExporter {
+    pipeline: String,
+    metrics_file: Option<PathBuf>,
+    pushgateway_url: Option<String>,
+    job: String,
+}
+
+impl MetricsExporter {
+    pub fn new(pipeline: &str, metrics_opts: &MetricsOpts) -> Self {
+        init_metrics(pipeline);
+        Self {
+            pipeline: pipeline.to_string(),
+            metrics_file: metrics_opts.metrics_file.clone(),
+            pushgateway_url: metrics_opts.metrics_pushgateway_url.clone(),
+            job: metrics_opts.metrics_job.clone(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.metrics_file.is_none() && self.pushgateway_url.is_none()
+    }
+
+    /// Exports the metrics at the end of the run, a failure is only logged
+    pub async fn export(&self, is_success: bool) {
+        if self.is_empty() {
+            return;
+        }
+        let metrics = metrics();
+        metrics.record_run_result(is_success);
+        let encoded = match metrics.encode() {
+            Ok(encoded) => encoded,
+            Err(e) => {
+                error!("Cannot encode Prometheus metrics: {:?}", e);
+                return;
+            }
+        };
+        if let Some(metrics_file) = &self.metrics_file {
+            match write_metrics_file(metrics_file, &encoded) {
+                Ok(_) => info!("Prometheus metrics written to '{:?}'", metrics_file),
+                Err(e) => error!("{:?}", e),
+            }
+        }
+        if let Some(pushgateway_url) = &self.pushgateway_url {
+            match push_metrics(pushgateway_url, &self.job, &self.pipeline, encoded).await {
+                Ok(_) => info!("Prometheus metrics pushed to '{}'", pushgateway_url),
+                Err(e) => error!("{:?}", e),
+            }
+        }
+    }
+}
+
+/// The file is renamed into place to not be read half-written by the textfile collector
+fn write_metrics_file(metrics_file: &Path, encoded: &str) -> anyhow::Result<()> {
+    let tmp_file = metrics_file.with_extension("prom.tmp");
+    fs::write(&tmp_file, encoded)
+        .and_then(|_| fs::rename(&tmp_file, metrics_file))
+        .map_err(|e| anyhow!("Cannot write metrics file '{:?}': {:?}", metrics_file, e))
+}
+
+/// Replaces the metrics of the pipeline grouping key at the push gateway
+async fn push_metrics(
+    pushgateway_url: &str,
+    job: &str,
+    pipeline: &str,
+    encoded: String,
+) -> anyhow::Result<()> {
+    let url = format!(
+        "{}/metrics/job/{}/pipeline/{}",
+        pushgateway_url.trim_end_matches('/'),
+        job,
+        pipeline
+    );
+    let response = reqwest::Client::new()
+        .put(&url)
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            TextEncoder::new().format_type(),
+        )
+        .body(encoded)
+        .send()
+        .await
+        .map_err(|e| anyhow!("Cannot push metrics to '{}': {}", url, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(anyhow!(
+            "Push gateway '{}' responded with status {}: {}",
+            url,
+            status,
+            response.text().await.unwrap_or_default()
+        ));
+    }
+    Ok(())
+}
+
+/// RPC sender measuring the latency of the requests of the wrapped sender
+pub struct MetricsRpcSender<T: RpcSender> {
+    sender: T,
+}
+
+impl<T: RpcSender> MetricsRpcSender<T> {
+    pub fn new(sender: T) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait]
+impl<T: RpcSender + Send + Sync> RpcSender for MetricsRpcSender<T> {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        let method = request.to_string();
+        let started = Instant::now();
+        let result = self.sender.send(request, params).await;
+        metrics().record_rpc_request(&method, started.elapsed(), result.is_err());
+        result
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.sender.get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.sender.url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_metrics() {
+        let metrics = PipelineMetrics::new("claim-settlement").unwrap();
+        metrics.record_execution("parallel", Duration::from_secs(2), false, &Ok((3, 10)));
+        metrics.record_execution(
+            "parallel",
+            Duration::from_secs(1),
+            false,
+            &Err(anyhow!("failed")),
+        );
+        metrics.record_lamports(600, "claimed", 1_000_000_000);
+
+        let encoded = metrics.encode().unwrap();
+        assert!(encoded.contains(
+            r#"settlement_pipeline_transactions_total{execution="parallel",pipeline="claim-settlement",result="executed"} 3"#
+        ));
+        assert!(encoded.contains(
+            r#"settlement_pipeline_executions_total{execution="parallel",pipeline="claim-settlement",result="failed"} 1"#
+        ));
+        assert!(encoded.contains(
+            r#"settlement_pipeline_lamports_total{epoch="600",operation="claimed",pipeline="claim-settlement"} 1000000000"#
+        ));
+        assert!(encoded.contains(
+            r#"settlement_pipeline_execution_duration_seconds_count{execution="parallel",pipeline="claim-settlement"} 2"#
+        ));
+    }
+}
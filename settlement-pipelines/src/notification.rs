@@ -0,0 +1,409 @@
+use crate::arguments::NotificationOpts;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use log::{error, info};
+use serde::Serialize;
+use serde_json::json;
+
+/// Discord rejects messages with content longer than 2000 characters
+pub const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+const CODE_BLOCK_FENCE: &str = "```";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotificationLevel {
+    /// report of the finished run
+    Summary,
+    /// the run finished with errors
+    Alert,
+}
+
+/// Message posted by the notifiers, the generic webhook receives it as JSON
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    /// pipeline (command) the notification comes from
+    pub source: String,
+    pub title: String,
+    pub lines: Vec<String>,
+    /// structured report data, see [crate::reporting::PrintReportable::get_report_with_data]
+    pub data: serde_json::Value,
+}
+
+impl Notification {
+    pub fn headline(&self) -> String {
+        match self.level {
+            NotificationLevel::Summary => format!("[{}] {}", self.source, self.title),
+            NotificationLevel::Alert => {
+                format!(":rotating_light: [{}] {}", self.source, self.title)
+            }
+        }
+    }
+}
+
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn notify(&self, notification: &Notification) -> anyhow::Result<()>;
+}
+
+/// Posts the [Notification] as JSON to any HTTP endpoint
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn notify(&self, notification: &Notification) -> anyhow::Result<()> {
+        post_json(&self.client, &self.url, &json!(notification)).await
+    }
+}
+
+/// Posts to Slack incoming webhook, the report lines are formatted as a code block
+pub struct SlackNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl SlackNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for SlackNotifier {
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+
+    async fn notify(&self, notification: &Notification) -> anyhow::Result<()> {
+        let mut text = notification.headline();
+        if !notification.lines.is_empty() {
+            text = format!(
+                "{}\n{}\n{}\n{}",
+                text,
+                CODE_BLOCK_FENCE,
+                notification.lines.join("\n"),
+                CODE_BLOCK_FENCE
+            );
+        }
+        post_json(&self.client, &self.url, &json!({ "text": text })).await
+    }
+}
+
+/// Posts to Discord webhook, the report lines are split to several code block messages
+/// when not fitting into the Discord message limit
+pub struct DiscordNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl DiscordNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+        }
+    }
+
+    /// Posts the lines as code block messages, the headline (when provided) goes first
+    pub async fn post_code_blocks(
+        &self,
+        headline: Option<&str>,
+        lines: &[String],
+    ) -> anyhow::Result<()> {
+        for content in discord_messages(headline, lines) {
+            post_json(
+                &self.client,
+                &self.url,
+                &json!({ "content": content, "embeds": null, "attachments": [] }),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for DiscordNotifier {
+    fn name(&self) -> &'static str {
+        "discord"
+    }
+
+    async fn notify(&self, notification: &Notification) -> anyhow::Result<()> {
+        self.post_code_blocks(Some(&notification.headline()), &notification.lines)
+            .await
+    }
+}
+
+/// Splits the lines to Discord messages wrapped in code blocks, each message fits into the limit,
+/// a single line that is longer than the limit is truncated
+pub fn discord_messages(headline: Option<&str>, lines: &[String]) -> Vec<String> {
+    // opening fence with new line, closing fence
+    let wrapping_len = CODE_BLOCK_FENCE.len() * 2 + 1;
+    let max_block_len = DISCORD_MESSAGE_LIMIT - wrapping_len;
+
+    let mut blocks: Vec<String> = vec![];
+    let mut block = String::new();
+    for line in lines {
+        let line = truncate(line, max_block_len - 1);
+        if !block.is_empty() && block.len() + line.len() + 1 > max_block_len {
+            blocks.push(std::mem::take(&mut block));
+        }
+        block.push_str(line);
+        block.push('\n');
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    let mut messages: Vec<String> = blocks
+        .into_iter()
+        .map(|block| format!("{CODE_BLOCK_FENCE}\n{block}{CODE_BLOCK_FENCE}"))
+        .collect();
+    if let Some(headline) = headline {
+        let headline = truncate(headline, DISCORD_MESSAGE_LIMIT);
+        match messages.first_mut() {
+            Some(first) if headline.len() + first.len() + 1 <= DISCORD_MESSAGE_LIMIT => {
+                *first = format!("{}\n{}", headline, first);
+            }
+            _ => messages.insert(0, headline.to_string()),
+        }
+    }
+    messages
+}
+
+/// Truncates to the max byte length at a char boundary
+fn truncate(text: &str, max_len: usize) -> &str {
+    if text.len() <= max_len {
+        return text;
+    }
+    let mut end = max_len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+async fn post_json(
+    client: &reqwest::Client,
+    url: &str,
+    body: &serde_json::Value,
+) -> anyhow::Result<()> {
+    let response = client
+        .post(url)
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| anyhow!("Cannot post notification: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        let response_text = response.text().await.unwrap_or_default();
+        return Err(anyhow!(
+            "Notification endpoint responded with status {}: {}",
+            status,
+            response_text
+        ));
+    }
+    Ok(())
+}
+
+/// Notifiers configured by the [NotificationOpts] of a pipeline,
+/// a failure to notify is logged and never fails the pipeline
+#[derive(Default)]
+pub struct Notifiers {
+    source: String,
+    alerts_only: bool,
+    notifiers: Vec<Box<dyn Notifier>>,
+}
+
+impl Notifiers {
+    pub fn new(source: &str, notification_opts: &NotificationOpts) -> Self {
+        let mut notifiers: Vec<Box<dyn Notifier>> = vec![];
+        if let Some(url) = &notification_opts.notify_webhook_url {
+            notifiers.push(Box::new(WebhookNotifier::new(url.clone())));
+        }
+        if let Some(url) = &notification_opts.notify_slack_webhook_url {
+            notifiers.push(Box::new(SlackNotifier::new(url.clone())));
+        }
+        if let Some(url) = &notification_opts.notify_discord_webhook_url {
+            notifiers.push(Box::new(DiscordNotifier::new(url.clone())));
+        }
+        Self {
+            source: source.to_string(),
+            alerts_only: notification_opts.notify_alerts_only,
+            notifiers,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifiers.is_empty()
+    }
+
+    pub async fn notify_summary(&self, title: &str, lines: &[String], data: &serde_json::Value) {
+        if self.alerts_only {
+            return;
+        }
+        self.notify(NotificationLevel::Summary, title, lines, data)
+            .await;
+    }
+
+    pub async fn notify_alert(&self, title: &str, errors: &[String]) {
+        self.notify(
+            NotificationLevel::Alert,
+            title,
+            errors,
+            &serde_json::Value::Null,
+        )
+        .await;
+    }
+
+    async fn notify(
+        &self,
+        level: NotificationLevel,
+        title: &str,
+        lines: &[String],
+        data: &serde_json::Value,
+    ) {
+        let notification = Notification {
+            level,
+            source: self.source.clone(),
+            title: title.to_string(),
+            lines: lines.to_vec(),
+            data: data.clone(),
+        };
+        for notifier in &self.notifiers {
+            match notifier.notify(&notification).await {
+                Ok(_) => info!("Notification {:?} posted to {}", level, notifier.name()),
+                Err(e) => error!(
+                    "Cannot post notification {:?} to {}: {:?}",
+                    level,
+                    notifier.name(),
+                    e
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::http_stand_in;
+
+    fn notification(level: NotificationLevel, lines: Vec<String>) -> Notification {
+        Notification {
+            level,
+            source: "init-settlement".to_string(),
+            title: "report".to_string(),
+            lines,
+            data: json!({ "count": 1 }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhook_notification() {
+        let (url, mut receiver) = http_stand_in(200, "").await;
+        WebhookNotifier::new(url)
+            .notify(&notification(
+                NotificationLevel::Summary,
+                vec!["line".to_string()],
+            ))
+            .await
+            .unwrap();
+        let body = receiver.recv().await.unwrap();
+        assert_eq!(body["level"], "summary");
+        assert_eq!(body["source"], "init-settlement");
+        assert_eq!(body["lines"], json!(["line"]));
+        assert_eq!(body["data"]["count"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_slack_notification() {
+        let (url, mut receiver) = http_stand_in(200, "").await;
+        SlackNotifier::new(url)
+            .notify(&notification(
+                NotificationLevel::Alert,
+                vec!["error 1".to_string(), "error 2".to_string()],
+            ))
+            .await
+            .unwrap();
+        let body = receiver.recv().await.unwrap();
+        assert_eq!(
+            body["text"],
+            ":rotating_light: [init-settlement] report\n```\nerror 1\nerror 2\n```"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_discord_notification_split() {
+        let (url, mut receiver) = http_stand_in(204, "").await;
+        let lines: Vec<String> = (0..100).map(|i| format!("{:0>50}", i)).collect();
+        DiscordNotifier::new(url)
+            .notify(&notification(NotificationLevel::Summary, lines))
+            .await
+            .unwrap();
+        // headline does not fit to the first block of 39 lines, it goes separately
+        assert_eq!(
+            receiver.recv().await.unwrap()["content"],
+            "[init-settlement] report"
+        );
+        let mut received_lines = 0;
+        for _ in 0..3 {
+            let content = receiver.recv().await.unwrap()["content"]
+                .as_str()
+                .unwrap()
+                .to_string();
+            assert!(content.len() <= DISCORD_MESSAGE_LIMIT);
+            assert!(content.ends_with(CODE_BLOCK_FENCE));
+            received_lines += content.lines().filter(|line| line.len() == 50).count();
+        }
+        assert_eq!(received_lines, 100);
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_notification_error_status() {
+        let (url, _receiver) = http_stand_in(500, "").await;
+        let result = WebhookNotifier::new(url)
+            .notify(&notification(NotificationLevel::Summary, vec![]))
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_discord_messages() {
+        assert_eq!(
+            discord_messages(Some("headline"), &["a".to_string(), "b".to_string()]),
+            vec!["headline\n```\na\nb\n```".to_string()]
+        );
+        assert_eq!(
+            discord_messages(Some("headline"), &[]),
+            vec!["headline".to_string()]
+        );
+        let long_line = "x".repeat(DISCORD_MESSAGE_LIMIT * 2);
+        let messages = discord_messages(None, &[long_line]);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].len(), DISCORD_MESSAGE_LIMIT);
+    }
+}
@@ -0,0 +1,486 @@
+use anyhow::anyhow;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::Hash;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Journal of the work done by the settlement pipelines persisted in between runs.
+/// The journal is keyed by epoch and settlement address and it permits a re-run
+/// of a pipeline (e.g., after a retry-able error) to resume only with the pending work.
+/// The journal is only a hint, the recorded progress is always reconciled with the on-chain state.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PipelineState {
+    /// validator-bonds config the journal was recorded for
+    #[serde(default)]
+    pub config_address: Option<String>,
+    /// genesis hash of the cluster the journal was recorded for
+    #[serde(default)]
+    pub cluster: Option<String>,
+    /// number of runs per pipeline command
+    pub runs: BTreeMap<String, u32>,
+    /// epoch -> settlement address -> progress
+    pub epochs: BTreeMap<u64, BTreeMap<String, SettlementProgress>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct SettlementProgress {
+    pub vote_account: Option<String>,
+    /// run of init-settlement in which the Settlement was initialized
+    pub initialized_at_run: Option<u32>,
+    /// run of init-settlement in which the Settlement funding was executed
+    pub funded_at_run: Option<u32>,
+    /// run of close-settlement in which the Settlement was closed
+    pub closed_at_run: Option<u32>,
+    /// settlement claim address -> run of claim-settlement in which the claim was sent
+    pub claims: BTreeMap<String, u32>,
+    /// settlement claim address -> signature of the transaction the claim was sent with
+    #[serde(default)]
+    pub claim_signatures: BTreeMap<String, String>,
+    /// sum of lamports of the sent claims
+    pub claimed_amount: u64,
+}
+
+/// Pipeline state loaded from the journal file, when no file is provided the state is kept in memory only
+#[derive(Debug, Default)]
+pub struct PipelineStateStore {
+    path: Option<PathBuf>,
+    command: String,
+    run: u32,
+    /// in dry-run mode the journal is loaded but never written
+    dry_run: bool,
+    state: PipelineState,
+}
+
+impl PipelineStateStore {
+    /// Loads the journal from the file (when exists) and registers a new run of the command.
+    /// The journal recorded for a different config or cluster is refused.
+    pub fn load(
+        path: Option<&Path>,
+        command: &str,
+        dry_run: bool,
+        config_address: &Pubkey,
+        cluster: &Hash,
+    ) -> anyhow::Result<Self> {
+        let mut state = read_state(path)?;
+        verify_origin(&state, path, config_address, cluster)?;
+        state.config_address = Some(config_address.to_string());
+        state.cluster = Some(cluster.to_string());
+        let run = state
+            .runs
+            .entry(command.to_string())
+            .and_modify(|r| *r += 1)
+            .or_insert(1);
+        let run = *run;
+        if let Some(path) = path {
+            info!(
+                "Pipeline state loaded from '{:?}', {} run {}",
+                path, command, run
+            );
+        }
+        Ok(Self {
+            path: path.map(|p| p.to_path_buf()),
+            command: command.to_string(),
+            run,
+            dry_run,
+            state,
+        })
+    }
+
+    /// Loads the journal only to inspect the recorded progress, no run is registered and nothing is written
+    pub fn read_only(path: &Path, config_address: &Pubkey, cluster: &Hash) -> anyhow::Result<Self> {
+        let state = read_state(Some(path))?;
+        verify_origin(&state, Some(path), config_address, cluster)?;
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            command: String::new(),
+            run: 0,
+            dry_run: true,
+            state,
+        })
+    }
+
+    /// Writes the journal to the file, written to a temporary file first and then renamed
+    pub fn save(&self) -> anyhow::Result<()> {
+        let path = match &self.path {
+            Some(path) if !self.dry_run => path,
+            _ => return Ok(()),
+        };
+        let data = serde_json::to_string_pretty(&self.state)
+            .map_err(|e| anyhow!("Cannot serialize pipeline state: {:?}", e))?;
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, data)
+            .and_then(|_| fs::rename(&tmp_path, path))
+            .map_err(|e| anyhow!("Cannot write pipeline state file '{:?}': {:?}", path, e))?;
+        debug!("Pipeline state saved to '{:?}'", path);
+        Ok(())
+    }
+
+    pub fn run(&self) -> u32 {
+        self.run
+    }
+
+    /// Recorded progress, epoch -> settlement address -> progress
+    pub fn epochs(&self) -> &BTreeMap<u64, BTreeMap<String, SettlementProgress>> {
+        &self.state.epochs
+    }
+
+    pub fn settlement(&self, epoch: u64, settlement: &Pubkey) -> Option<&SettlementProgress> {
+        self.state
+            .epochs
+            .get(&epoch)
+            .and_then(|settlements| settlements.get(&settlement.to_string()))
+    }
+
+    fn settlement_mut(&mut self, epoch: u64, settlement: &Pubkey) -> &mut SettlementProgress {
+        self.state
+            .epochs
+            .entry(epoch)
+            .or_default()
+            .entry(settlement.to_string())
+            .or_default()
+    }
+
+    pub fn is_initialized(&self, epoch: u64, settlement: &Pubkey) -> bool {
+        self.settlement(epoch, settlement)
+            .map_or(false, |s| s.initialized_at_run.is_some())
+    }
+
+    pub fn is_funded(&self, epoch: u64, settlement: &Pubkey) -> bool {
+        self.settlement(epoch, settlement)
+            .map_or(false, |s| s.funded_at_run.is_some())
+    }
+
+    pub fn is_closed(&self, epoch: u64, settlement: &Pubkey) -> bool {
+        self.settlement(epoch, settlement)
+            .map_or(false, |s| s.closed_at_run.is_some())
+    }
+
+    pub fn is_claim_sent(
+        &self,
+        epoch: u64,
+        settlement: &Pubkey,
+        settlement_claim: &Pubkey,
+    ) -> bool {
+        self.settlement(epoch, settlement).map_or(false, |s| {
+            s.claims.contains_key(&settlement_claim.to_string())
+        })
+    }
+
+    pub fn mark_initialized(&mut self, epoch: u64, settlement: &Pubkey, vote_account: &Pubkey) {
+        let run = self.run;
+        let progress = self.settlement_mut(epoch, settlement);
+        progress.vote_account = Some(vote_account.to_string());
+        progress.initialized_at_run.get_or_insert(run);
+    }
+
+    pub fn mark_funded(&mut self, epoch: u64, settlement: &Pubkey) {
+        let run = self.run;
+        self.settlement_mut(epoch, settlement)
+            .funded_at_run
+            .get_or_insert(run);
+    }
+
+    pub fn mark_closed(&mut self, epoch: u64, settlement: &Pubkey) {
+        let run = self.run;
+        self.settlement_mut(epoch, settlement)
+            .closed_at_run
+            .get_or_insert(run);
+    }
+
+    /// Records the settlement claims as sent, the claim amount is added only for newly recorded claims.
+    /// The transaction signatures are recorded for the claims they were found for.
+    pub fn add_claims(
+        &mut self,
+        epoch: u64,
+        settlement: &Pubkey,
+        claims: &[(Pubkey, u64)],
+        signatures: &HashMap<Pubkey, Signature>,
+    ) {
+        let run = self.run;
+        let progress = self.settlement_mut(epoch, settlement);
+        for (settlement_claim, claim) in claims {
+            if let Entry::Vacant(entry) = progress.claims.entry(settlement_claim.to_string()) {
+                entry.insert(run);
+                progress.claimed_amount += claim;
+            }
+            if let Some(signature) = signatures.get(settlement_claim) {
+                progress
+                    .claim_signatures
+                    .insert(settlement_claim.to_string(), signature.to_string());
+            }
+        }
+    }
+
+    pub fn claim_signature(
+        &self,
+        epoch: u64,
+        settlement: &Pubkey,
+        settlement_claim: &Pubkey,
+    ) -> Option<&String> {
+        self.settlement(epoch, settlement)
+            .and_then(|s| s.claim_signatures.get(&settlement_claim.to_string()))
+    }
+
+    /// Cumulative progress across all runs of the command recorded in the journal
+    pub fn progress_report(&self) -> Vec<String> {
+        if self.path.is_none() {
+            return vec![];
+        }
+        let mut report = vec![format!("Pipeline state: {} run {}", self.command, self.run)];
+        for (epoch, settlements) in &self.state.epochs {
+            let initialized = settlements
+                .values()
+                .filter(|s| s.initialized_at_run.is_some())
+                .count();
+            let funded = settlements
+                .values()
+                .filter(|s| s.funded_at_run.is_some())
+                .count();
+            let closed = settlements
+                .values()
+                .filter(|s| s.closed_at_run.is_some())
+                .count();
+            let claims = settlements.values().map(|s| s.claims.len()).sum::<usize>();
+            let claimed_amount = settlements.values().map(|s| s.claimed_amount).sum::<u64>();
+            report.push(format!(
+                "  Epoch {}: settlements {}, initialized {}, funded {}, closed {}, sent claims {} of {} SOLs",
+                epoch,
+                settlements.len(),
+                initialized,
+                funded,
+                closed,
+                claims,
+                lamports_to_sol(claimed_amount)
+            ));
+        }
+        report
+    }
+}
+
+fn read_state(path: Option<&Path>) -> anyhow::Result<PipelineState> {
+    match path {
+        Some(path) if path.exists() => {
+            let data = fs::read_to_string(path)
+                .map_err(|e| anyhow!("Cannot read pipeline state file '{:?}': {:?}", path, e))?;
+            serde_json::from_str::<PipelineState>(&data)
+                .map_err(|e| anyhow!("Cannot parse pipeline state file '{:?}': {:?}", path, e))
+        }
+        _ => Ok(PipelineState::default()),
+    }
+}
+
+/// The journal without the recorded origin (i.e., created before it was recorded) is accepted
+fn verify_origin(
+    state: &PipelineState,
+    path: Option<&Path>,
+    config_address: &Pubkey,
+    cluster: &Hash,
+) -> anyhow::Result<()> {
+    if let Some(recorded_config) = &state.config_address {
+        if *recorded_config != config_address.to_string() {
+            return Err(anyhow!(
+                "Pipeline state file '{:?}' was recorded for config {}, cannot be used for config {}",
+                path,
+                recorded_config,
+                config_address
+            ));
+        }
+    }
+    if let Some(recorded_cluster) = &state.cluster {
+        if *recorded_cluster != cluster.to_string() {
+            return Err(anyhow!(
+                "Pipeline state file '{:?}' was recorded for cluster with genesis hash {}, cannot be used for cluster {}",
+                path,
+                recorded_cluster,
+                cluster
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: Pubkey = Pubkey::new_from_array([1; 32]);
+    const CLUSTER: Hash = Hash::new_from_array([2; 32]);
+
+    fn load(
+        path: Option<&Path>,
+        command: &str,
+        dry_run: bool,
+    ) -> anyhow::Result<PipelineStateStore> {
+        PipelineStateStore::load(path, command, dry_run, &CONFIG, &CLUSTER)
+    }
+
+    fn state_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "pipeline-state-{}-{}.json",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_load_registers_runs() {
+        let path = state_file("load");
+        let store = load(Some(&path), "claim-settlement", false).unwrap();
+        assert_eq!(store.run(), 1);
+        store.save().unwrap();
+
+        let store = load(Some(&path), "claim-settlement", false).unwrap();
+        assert_eq!(store.run(), 2);
+        store.save().unwrap();
+        let store = load(Some(&path), "init-settlement", false).unwrap();
+        assert_eq!(store.run(), 1);
+
+        let read_only = PipelineStateStore::read_only(&path, &CONFIG, &CLUSTER).unwrap();
+        assert_eq!(read_only.state.runs.get("claim-settlement"), Some(&2));
+        assert_eq!(read_only.state.runs.get("init-settlement"), None);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_save_and_reload() {
+        let path = state_file("save");
+        let settlement = Pubkey::new_unique();
+        let vote_account = Pubkey::new_unique();
+        let settlement_claim = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        let mut store = load(Some(&path), "init-settlement", false).unwrap();
+        store.mark_initialized(600, &settlement, &vote_account);
+        store.mark_funded(600, &settlement);
+        store.add_claims(
+            600,
+            &settlement,
+            &[(settlement_claim, 1_000)],
+            &HashMap::from([(settlement_claim, signature)]),
+        );
+        store.save().unwrap();
+
+        let reloaded = load(Some(&path), "close-settlement", false).unwrap();
+        assert!(reloaded.is_initialized(600, &settlement));
+        assert!(reloaded.is_funded(600, &settlement));
+        assert!(!reloaded.is_closed(600, &settlement));
+        assert!(reloaded.is_claim_sent(600, &settlement, &settlement_claim));
+        assert_eq!(
+            reloaded.claim_signature(600, &settlement, &settlement_claim),
+            Some(&signature.to_string())
+        );
+        assert_eq!(
+            reloaded.settlement(600, &settlement).unwrap().vote_account,
+            Some(vote_account.to_string())
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_refuse_different_origin() {
+        let path = state_file("origin");
+        load(Some(&path), "init-settlement", false)
+            .unwrap()
+            .save()
+            .unwrap();
+
+        let other_config = Pubkey::new_unique();
+        assert!(PipelineStateStore::load(
+            Some(&path),
+            "init-settlement",
+            false,
+            &other_config,
+            &CLUSTER
+        )
+        .is_err());
+        let other_cluster = Hash::new_unique();
+        assert!(PipelineStateStore::load(
+            Some(&path),
+            "init-settlement",
+            false,
+            &CONFIG,
+            &other_cluster
+        )
+        .is_err());
+        assert!(PipelineStateStore::read_only(&path, &other_config, &CLUSTER).is_err());
+        assert_eq!(
+            load(Some(&path), "init-settlement", false).unwrap().run(),
+            2
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_dry_run_does_not_save() {
+        let path = state_file("dry-run");
+        let mut store = load(Some(&path), "close-settlement", true).unwrap();
+        store.mark_closed(600, &Pubkey::new_unique());
+        store.save().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_merge_claims() {
+        let settlement = Pubkey::new_unique();
+        let first_claim = Pubkey::new_unique();
+        let second_claim = Pubkey::new_unique();
+        let signature = Signature::new_unique();
+
+        let mut store = load(None, "claim-settlement", false).unwrap();
+        // failed execution, only the claim landed on chain is recorded with the signature
+        store.add_claims(
+            600,
+            &settlement,
+            &[(first_claim, 100)],
+            &HashMap::from([(first_claim, signature)]),
+        );
+        // re-sent claims are not counted twice, the known signature is kept
+        store.add_claims(
+            600,
+            &settlement,
+            &[(first_claim, 100), (second_claim, 50)],
+            &HashMap::new(),
+        );
+
+        let progress = store.settlement(600, &settlement).unwrap();
+        assert_eq!(progress.claims.len(), 2);
+        assert_eq!(progress.claimed_amount, 150);
+        assert_eq!(
+            store.claim_signature(600, &settlement, &first_claim),
+            Some(&signature.to_string())
+        );
+        assert_eq!(store.claim_signature(600, &settlement, &second_claim), None);
+    }
+
+    #[test]
+    fn test_load_journal_without_signatures() {
+        let path = state_file("legacy");
+        let settlement = Pubkey::new_unique();
+        let settlement_claim = Pubkey::new_unique();
+        fs::write(
+            &path,
+            format!(
+                r#"{{"runs":{{"claim-settlement":3}},"epochs":{{"600":{{"{}":{{"vote_account":null,"initialized_at_run":1,"funded_at_run":1,"closed_at_run":null,"claims":{{"{}":2}},"claimed_amount":10}}}}}}}}"#,
+                settlement, settlement_claim
+            ),
+        )
+        .unwrap();
+
+        let store = load(Some(&path), "claim-settlement", false).unwrap();
+        assert_eq!(store.run(), 4);
+        assert!(store.is_claim_sent(600, &settlement, &settlement_claim));
+        assert_eq!(
+            store.claim_signature(600, &settlement, &settlement_claim),
+            None
+        );
+        fs::remove_file(&path).unwrap();
+    }
+}
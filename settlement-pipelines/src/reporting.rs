@@ -1,16 +1,79 @@
+use crate::arguments::{ReportFormat, ReportOpts};
 use crate::cli_result::{CliError, CliResult};
+use crate::metrics::{metrics, MetricsExporter};
+use crate::notification::Notifiers;
 use log::{error, info};
+use serde::Serialize;
 use std::fmt::Display;
+use std::fs;
 use std::future::Future;
 use std::ops::{Deref, DerefMut};
+use std::path::PathBuf;
 use std::pin::Pin;
 
+/// Report lines with the structured report data
+pub type ReportWithData = (Vec<String>, serde_json::Value);
+
 pub trait PrintReportable {
     fn get_report(&self) -> Pin<Box<dyn Future<Output = Vec<String>> + '_>>;
+
+    /// Structured data (counts, lamports, per-settlement status) of the report for the JSON report format,
+    /// to be overridden when the report is ingested by other tools
+    fn get_report_with_data(&self) -> Pin<Box<dyn Future<Output = ReportWithData> + '_>> {
+        Box::pin(async { (self.get_report().await, serde_json::Value::Null) })
+    }
+}
+
+/// Stable JSON format of the report printed (or written to file) with `--report-format json`
+#[derive(Debug, Serialize)]
+pub struct JsonReport {
+    pub status: ReportStatus,
+    pub report: serde_json::Value,
+    pub lines: Vec<String>,
+    pub errors: Vec<ReportError>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportStatus {
+    Success,
+    Failed,
+    RetryAble,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReportError {
+    pub category: ErrorCategory,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorCategory {
+    /// processing error, a re-run does not help
+    Processing,
+    /// transaction error, a re-run is expected to fix it
+    RetryAble,
+    /// the run was terminated by this error
+    Fatal,
+}
+
+impl Display for ErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorCategory::Processing => write!(f, "ERROR"),
+            ErrorCategory::RetryAble => write!(f, "TRANSACTION ERROR"),
+            ErrorCategory::Fatal => write!(f, "FATAL"),
+        }
+    }
 }
 
 pub struct ReportHandler<T: PrintReportable> {
     error_handler: ErrorHandler,
+    report_format: ReportFormat,
+    report_file: Option<PathBuf>,
+    notifiers: Notifiers,
+    metrics_exporter: MetricsExporter,
     pub reportable: T,
 }
 
@@ -18,15 +81,111 @@ impl<T: PrintReportable> ReportHandler<T> {
     pub fn new(reportable: T) -> Self {
         Self {
             error_handler: ErrorHandler::default(),
+            report_format: ReportFormat::Text,
+            report_file: None,
+            notifiers: Notifiers::default(),
+            metrics_exporter: MetricsExporter::default(),
             reportable,
         }
     }
 
+    pub fn set_report_opts(&mut self, report_opts: &ReportOpts) {
+        self.report_format = report_opts.report_format;
+        self.report_file = report_opts.report_file.clone();
+    }
+
+    pub fn set_notifiers(&mut self, notifiers: Notifiers) {
+        self.notifiers = notifiers;
+    }
+
+    pub fn set_metrics_exporter(&mut self, metrics_exporter: MetricsExporter) {
+        self.metrics_exporter = metrics_exporter;
+    }
+
     pub async fn print_report(&self) {
         for report in self.reportable.get_report().await {
             println!("{}", report);
         }
     }
+
+    fn write_report_file(&self, content: &str) {
+        if let Some(report_file) = &self.report_file {
+            if let Err(e) = fs::write(report_file, content) {
+                error!("Cannot write report file '{:?}': {:?}", report_file, e);
+            }
+        }
+    }
+
+    fn json_report(
+        &self,
+        (lines, report): &ReportWithData,
+        result: &anyhow::Result<()>,
+    ) -> JsonReport {
+        let status = match result {
+            Ok(_) => ReportStatus::Success,
+            Err(err) => match err.downcast_ref::<CliError>() {
+                Some(CliError::RetryAble(_)) => ReportStatus::RetryAble,
+                _ => ReportStatus::Failed,
+            },
+        };
+        JsonReport {
+            status,
+            report: report.clone(),
+            lines: lines.clone(),
+            errors: self.report_errors(result),
+        }
+    }
+
+    /// Errors gathered by the error handler, the terminating error is added when there is no other
+    fn report_errors(&self, result: &anyhow::Result<()>) -> Vec<ReportError> {
+        let mut errors: Vec<ReportError> = self
+            .error_handler
+            .errors
+            .iter()
+            .map(|message| ReportError {
+                category: ErrorCategory::Processing,
+                message: message.clone(),
+            })
+            .chain(
+                self.error_handler
+                    .retry_able_errors
+                    .iter()
+                    .map(|message| ReportError {
+                        category: ErrorCategory::RetryAble,
+                        message: message.clone(),
+                    }),
+            )
+            .collect();
+        if let Err(err) = result {
+            if !self.error_handler.has_errors() {
+                errors.push(ReportError {
+                    category: ErrorCategory::Fatal,
+                    message: err.to_string(),
+                });
+            }
+        }
+        errors
+    }
+
+    /// Posts the summary of the run and the alert when the run finished with errors
+    async fn notify(&self, (lines, report): &ReportWithData, result: &anyhow::Result<()>) {
+        if self.notifiers.is_empty() {
+            return;
+        }
+        let title = match result {
+            Ok(_) => "finished successfully".to_string(),
+            Err(err) => format!("finished with errors: {}", err),
+        };
+        self.notifiers.notify_summary(&title, lines, report).await;
+        if result.is_err() {
+            let errors: Vec<String> = self
+                .report_errors(result)
+                .iter()
+                .map(|error| format!("{}: {}", error.category, error.message))
+                .collect();
+            self.notifiers.notify_alert(&title, &errors).await;
+        }
+    }
 }
 
 impl<T: PrintReportable> Deref for ReportHandler<T> {
@@ -62,6 +221,7 @@ impl ErrorHandler {
 
     pub fn add_retry_able_error(&mut self, error: anyhow::Error) {
         error!("{:?}", error);
+        metrics().retry_able_errors.inc();
         self.retry_able_errors.push(format!("{}", error));
     }
 
@@ -88,18 +248,32 @@ impl ErrorHandler {
         }
     }
 
-    pub fn finalize(&self) -> anyhow::Result<()> {
-        let mut result = anyhow::Ok(());
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty() || !self.retry_able_errors.is_empty()
+    }
+
+    /// Takes over errors of another handler (already logged there), prefixed with the source
+    pub fn merge_errors<D: Display>(&mut self, source: D, other: &ErrorHandler) {
+        self.errors.extend(
+            other
+                .errors
+                .iter()
+                .map(|error| format!("[{}] {}", source, error)),
+        );
+        self.retry_able_errors.extend(
+            other
+                .retry_able_errors
+                .iter()
+                .map(|error| format!("[{}] {}", source, error)),
+        );
+    }
 
+    pub fn finalize(&self) -> anyhow::Result<()> {
         if !self.errors.is_empty() {
             println!("ERRORS:");
             for error in &self.errors {
                 println!("{}", error);
             }
-            result = Err(CliError::processing(format!(
-                "Errors occurred during processing: {} errors",
-                self.errors.len()
-            )));
         }
 
         if !self.retry_able_errors.is_empty() {
@@ -111,29 +285,73 @@ impl ErrorHandler {
             for error in &self.retry_able_errors {
                 println!("{}", error);
             }
-            result = Err(CliError::retry_able(format!(
+        }
+
+        self.result()
+    }
+
+    /// Result of the processing based on the errors, retry-able errors take precedence
+    fn result(&self) -> anyhow::Result<()> {
+        if !self.retry_able_errors.is_empty() {
+            Err(CliError::retry_able(format!(
                 "Retry-able errors occurred: {} errors",
                 self.retry_able_errors.len()
-            )));
+            )))
+        } else if !self.errors.is_empty() {
+            Err(CliError::processing(format!(
+                "Errors occurred during processing: {} errors",
+                self.errors.len()
+            )))
+        } else {
+            Ok(())
         }
-
-        result
     }
 }
 
+/// Serializes the structured report data, a failure is only logged to not break the text report
+pub fn to_report_data<T: Serialize>(data: &T) -> serde_json::Value {
+    serde_json::to_value(data).unwrap_or_else(|e| {
+        error!("Cannot serialize report data: {:?}", e);
+        serde_json::Value::Null
+    })
+}
+
 pub async fn with_reporting<T: PrintReportable>(
     report_handler: &ReportHandler<T>,
     main_result: anyhow::Result<()>,
 ) -> CliResult {
-    // print report in whatever case
-    report_handler.print_report().await;
-    match main_result {
-        // when Ok is returned we consult the reality with report handler
-        Ok(_) => CliResult(report_handler.finalize()),
-        // when main returned some error we pass it to terminate with it
-        Err(err) => {
-            println!("ERROR: {}", err);
-            CliResult(Err(err))
+    let report_with_data = report_handler.reportable.get_report_with_data().await;
+
+    let result = if report_handler.report_format == ReportFormat::Json {
+        let result = main_result.and_then(|_| report_handler.result());
+        let json_report = report_handler.json_report(&report_with_data, &result);
+        match serde_json::to_string_pretty(&json_report) {
+            Ok(json) => {
+                println!("{}", json);
+                report_handler.write_report_file(&json);
+            }
+            Err(e) => error!("Cannot serialize JSON report: {:?}", e),
         }
-    }
+        result
+    } else {
+        // print report in whatever case
+        let (report, _) = &report_with_data;
+        for line in report {
+            println!("{}", line);
+        }
+        report_handler.write_report_file(&report.join("\n"));
+        match main_result {
+            // when Ok is returned we consult the reality with report handler
+            Ok(_) => report_handler.finalize(),
+            // when main returned some error we pass it to terminate with it
+            Err(err) => {
+                println!("ERROR: {}", err);
+                Err(err)
+            }
+        }
+    };
+
+    report_handler.notify(&report_with_data, &result).await;
+    report_handler.metrics_exporter.export(result.is_ok()).await;
+    CliResult(result)
 }
@@ -0,0 +1,366 @@
+use crate::metrics::metrics;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use log::{info, warn};
+use reqwest::StatusCode;
+use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
+use solana_client::http_sender::HttpSender;
+use solana_client::rpc_request::{RpcError, RpcRequest};
+use solana_client::rpc_sender::{RpcSender, RpcTransportStats};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// JSON RPC error of a node that is behind the cluster (JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY)
+const NODE_UNHEALTHY_ERROR_CODE: i64 = -32005;
+const UNHEALTHY_COOLDOWN_MIN: Duration = Duration::from_secs(5);
+const UNHEALTHY_COOLDOWN_MAX: Duration = Duration::from_secs(120);
+
+/// Requests rejected by the RPC endpoints as rate limited (429) or timed out,
+/// the signal for the executor to lower the parallelism
+static THROTTLED_REQUESTS: AtomicU64 = AtomicU64::new(0);
+
+pub fn throttled_requests() -> u64 {
+    THROTTLED_REQUESTS.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointFailure {
+    /// rate limited or timed out, the endpoint is healthy but overloaded
+    Throttled,
+    /// connection failure, server error or the node is behind
+    Unavailable,
+}
+
+impl EndpointFailure {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EndpointFailure::Throttled => "throttled",
+            EndpointFailure::Unavailable => "unavailable",
+        }
+    }
+}
+
+/// Failures of the endpoint that may succeed on another endpoint or later,
+/// other errors (e.g., invalid params, transaction errors) are returned as they are
+pub fn classify_error(error: &ClientError) -> Option<EndpointFailure> {
+    match error.kind() {
+        ClientErrorKind::Reqwest(err) => {
+            if err.status() == Some(StatusCode::TOO_MANY_REQUESTS) || err.is_timeout() {
+                Some(EndpointFailure::Throttled)
+            } else if err.is_connect()
+                || err.is_request()
+                || err
+                    .status()
+                    .map_or(false, |status| status.is_server_error())
+            {
+                Some(EndpointFailure::Unavailable)
+            } else {
+                None
+            }
+        }
+        ClientErrorKind::Io(_) => Some(EndpointFailure::Unavailable),
+        // rate limit reported by the RPC provider in the JSON-RPC error response
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. })
+            if *code == i64::from(StatusCode::TOO_MANY_REQUESTS.as_u16()) =>
+        {
+            Some(EndpointFailure::Throttled)
+        }
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. })
+            if *code == NODE_UNHEALTHY_ERROR_CODE =>
+        {
+            Some(EndpointFailure::Unavailable)
+        }
+        _ => None,
+    }
+}
+
+/// Token bucket limiting the requests per second, the bucket holds tokens of one second
+pub struct TokenBucket {
+    rate: f64,
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    pub fn new(requests_per_second: u32) -> Self {
+        let rate = requests_per_second.max(1) as f64;
+        Self {
+            rate,
+            state: Mutex::new((rate, Instant::now())),
+        }
+    }
+
+    /// Takes a token when available, otherwise returns the time to wait for one
+    fn try_acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().expect("token bucket lock poisoned");
+        let (tokens, last_refill) = &mut *state;
+        let now = Instant::now();
+        *tokens =
+            (*tokens + now.duration_since(*last_refill).as_secs_f64() * self.rate).min(self.rate);
+        *last_refill = now;
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - *tokens) / self.rate))
+        }
+    }
+
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[derive(Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    unhealthy_until: Option<Instant>,
+}
+
+struct Endpoint {
+    index: usize,
+    sender: HttpSender,
+    rate_limit: Option<TokenBucket>,
+    health: Mutex<EndpointHealth>,
+}
+
+impl Endpoint {
+    fn unhealthy_until(&self) -> Option<Instant> {
+        self.health
+            .lock()
+            .expect("endpoint health lock poisoned")
+            .unhealthy_until
+    }
+
+    fn mark_healthy(&self) {
+        let mut health = self.health.lock().expect("endpoint health lock poisoned");
+        if health.unhealthy_until.is_some() {
+            info!("RPC endpoint #{} is healthy again", self.index);
+        }
+        *health = EndpointHealth::default();
+    }
+
+    /// The endpoint is avoided for a cooldown growing with the consecutive failures
+    fn mark_failed(&self, failure: EndpointFailure) {
+        let mut health = self.health.lock().expect("endpoint health lock poisoned");
+        health.consecutive_failures += 1;
+        let cooldown = UNHEALTHY_COOLDOWN_MIN
+            .saturating_mul(2u32.saturating_pow(health.consecutive_failures - 1))
+            .min(UNHEALTHY_COOLDOWN_MAX);
+        health.unhealthy_until = Some(Instant::now() + cooldown);
+        metrics()
+            .rpc_endpoint_failures
+            .with_label_values(&[&self.index.to_string(), failure.as_str()])
+            .inc();
+    }
+
+    /// Health check with `getHealth` before the endpoint is used again after the cooldown
+    async fn probe(&self) -> bool {
+        match self
+            .sender
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+        {
+            Ok(_) => true,
+            Err(err) => {
+                warn!("RPC endpoint #{} health check failed: {}", self.index, err);
+                false
+            }
+        }
+    }
+
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        if let Some(rate_limit) = &self.rate_limit {
+            rate_limit.acquire().await;
+        }
+        self.sender.send(request, params).await
+    }
+}
+
+/// RPC sender over multiple endpoints, the requests go to the first healthy endpoint in the order
+/// of preference and fail over to the next one when the endpoint is rate limited or unavailable.
+/// A failed endpoint is avoided for a cooldown and health-checked before it is used again.
+pub struct FailoverRpcSender {
+    endpoints: Vec<Endpoint>,
+    active: AtomicUsize,
+}
+
+impl FailoverRpcSender {
+    /// `rate_limit` is the maximum of requests per second sent to every endpoint
+    pub fn new(urls: &[String], rate_limit: Option<u32>) -> anyhow::Result<Self> {
+        if urls.is_empty() {
+            return Err(anyhow!("No RPC url provided"));
+        }
+        let endpoints = urls
+            .iter()
+            .enumerate()
+            .map(|(index, url)| Endpoint {
+                index,
+                sender: HttpSender::new(url),
+                rate_limit: rate_limit.map(TokenBucket::new),
+                health: Mutex::new(EndpointHealth::default()),
+            })
+            .collect();
+        Ok(Self {
+            endpoints,
+            active: AtomicUsize::new(0),
+        })
+    }
+
+    /// Healthy endpoints in the order of preference, then the failed ones by the end of cooldown
+    fn endpoint_order(&self) -> Vec<&Endpoint> {
+        let now = Instant::now();
+        let (mut healthy, mut unhealthy): (Vec<_>, Vec<_>) = self
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint, endpoint.unhealthy_until()))
+            .partition(|(_, unhealthy_until)| unhealthy_until.map_or(true, |until| until <= now));
+        unhealthy.sort_by_key(|(_, unhealthy_until)| *unhealthy_until);
+        healthy.append(&mut unhealthy);
+        healthy.into_iter().map(|(endpoint, _)| endpoint).collect()
+    }
+}
+
+#[async_trait]
+impl RpcSender for FailoverRpcSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        let mut last_error: Option<ClientError> = None;
+        for endpoint in self.endpoint_order() {
+            if endpoint.unhealthy_until().is_some() && !endpoint.probe().await {
+                endpoint.mark_failed(EndpointFailure::Unavailable);
+                continue;
+            }
+            match endpoint.send(request, params.clone()).await {
+                Ok(result) => {
+                    endpoint.mark_healthy();
+                    if self.active.swap(endpoint.index, Ordering::Relaxed) != endpoint.index {
+                        info!("RPC requests are sent to endpoint #{}", endpoint.index);
+                    }
+                    return Ok(result);
+                }
+                Err(err) => match classify_error(&err) {
+                    Some(failure) => {
+                        if failure == EndpointFailure::Throttled {
+                            THROTTLED_REQUESTS.fetch_add(1, Ordering::Relaxed);
+                        }
+                        warn!(
+                            "RPC endpoint #{} {} on {}: {}",
+                            endpoint.index,
+                            failure.as_str(),
+                            request,
+                            err
+                        );
+                        endpoint.mark_failed(failure);
+                        last_error = Some(err);
+                    }
+                    None => return Err(err),
+                },
+            }
+        }
+        Err(last_error.expect("at least one RPC endpoint is configured"))
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.endpoints[self.active.load(Ordering::Relaxed)]
+            .sender
+            .get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.endpoints[self.active.load(Ordering::Relaxed)]
+            .sender
+            .url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::http_stand_in;
+
+    const HEALTH_OK: &str = r#"{"jsonrpc":"2.0","result":"ok","id":1}"#;
+
+    #[tokio::test]
+    async fn test_failover() {
+        let (unavailable_url, mut unavailable_requests) = http_stand_in(503, "").await;
+        let (available_url, mut available_requests) = http_stand_in(200, HEALTH_OK).await;
+        let sender =
+            FailoverRpcSender::new(&[unavailable_url.clone(), available_url.clone()], None)
+                .unwrap();
+
+        let result = sender
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(result, "ok");
+        assert_eq!(sender.url(), available_url);
+        assert_eq!(
+            unavailable_requests.recv().await.unwrap()["method"],
+            "getHealth"
+        );
+        assert_eq!(
+            available_requests.recv().await.unwrap()["method"],
+            "getHealth"
+        );
+
+        // the failed endpoint is in cooldown, the request goes directly to the available one
+        sender
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap();
+        assert!(available_requests.recv().await.is_some());
+        assert!(unavailable_requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_all_endpoints_failed() {
+        let (first_url, _) = http_stand_in(503, "").await;
+        let (second_url, _) = http_stand_in(502, "").await;
+        let sender = FailoverRpcSender::new(&[first_url, second_url], None).unwrap();
+        let err = sender
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap_err();
+        assert_eq!(classify_error(&err), Some(EndpointFailure::Unavailable));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_no_failover() {
+        let (first_url, _) = http_stand_in(
+            200,
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid params"},"id":1}"#,
+        )
+        .await;
+        let (second_url, mut second_requests) = http_stand_in(200, HEALTH_OK).await;
+        let sender = FailoverRpcSender::new(&[first_url, second_url], None).unwrap();
+        let err = sender
+            .send(RpcRequest::GetHealth, serde_json::Value::Null)
+            .await
+            .unwrap_err();
+        assert_eq!(classify_error(&err), None);
+        assert!(second_requests.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_token_bucket() {
+        let bucket = TokenBucket::new(10);
+        for _ in 0..10 {
+            assert!(bucket.try_acquire().is_ok());
+        }
+        let wait = bucket.try_acquire().unwrap_err();
+        assert!(wait <= Duration::from_millis(100));
+        let started = Instant::now();
+        bucket.acquire().await;
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+}
@@ -1,14 +1,21 @@
 use anyhow::anyhow;
 use log::{debug, info};
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::cli_result::CliError;
 use validator_bonds::state::config::{find_bonds_withdrawer_authority, Config};
 use validator_bonds::state::settlement::{find_settlement_staker_authority, Settlement};
 use validator_bonds::state::settlement_claim::SettlementClaim;
-use validator_bonds_common::settlements::{get_bonds_for_settlements, get_settlements};
+use validator_bonds_common::settlements::{
+    get_bonds_for_settlements, get_settlements, is_settlement_claimable, is_settlement_expired,
+};
 use validator_bonds_common::stake_accounts::{
     collect_stake_accounts, get_clock, obtain_claimable_stake_accounts_for_settlement,
     CollectedStakeAccounts,
@@ -31,8 +38,6 @@ pub async fn list_claimable_settlements(
     let clock = get_clock(rpc_client.clone())
         .await
         .map_err(CliError::RetryAble)?;
-    let current_epoch = clock.epoch;
-    let current_slot = clock.slot;
 
     let (withdraw_authority, _) = find_bonds_withdrawer_authority(config_address);
 
@@ -43,21 +48,22 @@ pub async fn list_claimable_settlements(
     let claimable_settlements = all_settlements
         .into_iter()
         .filter(|(settlement_address, settlement)| {
-            let is_epoch_in_range = current_epoch <= settlement.epoch_created_for + config.epochs_to_claim_settlement;
-            let is_slot_past_threshold = current_slot >= settlement.slot_created_at + config.slots_to_start_settlement_claiming;
+            let is_claimable = is_settlement_claimable(settlement, config, &clock);
             info!(
-                "Settlement {} epoch_created_for: {}, current_epoch: {}, epochs_to_claim_settlement: {}, slot_created_at: {}, slots_to_start_settlement_claiming: {}, is_epoch_in_range: {}, is_slot_past_threshold: {}",
+                "Settlement {} epoch_created_for: {}, current_epoch: {}, epochs_to_claim_settlement: {}, claim_expiry_extension: {}, slot_created_at: {}, slots_to_start_settlement_claiming: {}, current_slot: {}, disputed: {}, is_claimable: {}",
                 settlement_address,
                 settlement.epoch_created_for,
-                current_epoch,
+                clock.epoch,
                 config.epochs_to_claim_settlement,
+                settlement.claim_expiry_extension,
                 settlement.slot_created_at,
                 config.slots_to_start_settlement_claiming,
-                is_epoch_in_range,
-                is_slot_past_threshold
+                clock.slot,
+                settlement.disputed,
+                is_claimable
             );
 
-            is_epoch_in_range && is_slot_past_threshold
+            is_claimable
         }).collect::<Vec<(Pubkey, Settlement)>>();
 
     let stake_accounts =
@@ -123,14 +129,15 @@ pub async fn load_expired_settlements(
     let filtered_settlements: (Vec<_>, Vec<_>) = all_settlements.into_iter().zip(bonds_for_settlements.into_iter())
         .filter(|((settlement_address, settlement), (_, bond))| {
             let is_for_config = bond.is_none() || bond.as_ref().unwrap().config == *config_address;
-            let is_expired = current_epoch > settlement.epoch_created_for + config.epochs_to_claim_settlement;
+            let is_expired = is_settlement_expired(settlement, config, current_epoch);
 
         debug!(
-            "Settlement {} epoch_created_for: {}, current_epoch: {}, epochs_to_claim_settlement: {}, is_for_config: {}, is_expired: {}",
+            "Settlement {} epoch_created_for: {}, current_epoch: {}, epochs_to_claim_settlement: {}, claim_expiry_extension: {}, is_for_config: {}, is_expired: {}",
             settlement_address,
             settlement.epoch_created_for,
             current_epoch,
             config.epochs_to_claim_settlement,
+            settlement.claim_expiry_extension,
             is_for_config,
             is_expired
         );
@@ -142,6 +149,42 @@ pub async fn load_expired_settlements(
     Ok(filtered_settlements.0)
 }
 
+/// Signatures of the transactions the accounts were created with,
+/// i.e., the latest successful transaction of a just created account.
+/// Accounts with no successful transaction (not created) are omitted.
+pub async fn get_creation_signatures(
+    rpc_client: Arc<RpcClient>,
+    addresses: &[Pubkey],
+) -> anyhow::Result<HashMap<Pubkey, Signature>> {
+    let mut signatures = HashMap::new();
+    for address in addresses {
+        let statuses = rpc_client
+            .get_signatures_for_address_with_config(
+                address,
+                GetConfirmedSignaturesForAddress2Config {
+                    before: None,
+                    until: None,
+                    limit: Some(1),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )
+            .await
+            .map_err(|e| anyhow!("Cannot get signatures of account {}: {:?}", address, e))?;
+        if let Some(status) = statuses.into_iter().find(|status| status.err.is_none()) {
+            let signature = Signature::from_str(&status.signature).map_err(|e| {
+                anyhow!(
+                    "Cannot parse signature '{}' of account {}: {:?}",
+                    status.signature,
+                    address,
+                    e
+                )
+            })?;
+            signatures.insert(*address, signature);
+        }
+    }
+    Ok(signatures)
+}
+
 pub struct SettlementRefundPubkeys {
     pub split_rent_collector: Pubkey,
     pub split_rent_refund_account: Pubkey,
@@ -0,0 +1,295 @@
+use anyhow::anyhow;
+use log::{error, info};
+use regex::Regex;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{
+    RpcSimulateTransactionAccountsConfig, RpcSimulateTransactionConfig,
+};
+use solana_sdk::account::Account;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_transaction_builder::TransactionBuilder;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+/// Anchor error codes of the program start at this number
+const ANCHOR_ERROR_CODE_OFFSET: u32 = 6000;
+
+/// Accounts not existing on chain that would be created by the transactions simulated in the run.
+/// Every simulation runs against the on-chain state only, a transaction using such an account
+/// (e.g., funding of a `Settlement` initialized in the same run) depends on a prior step
+/// and it is not simulated.
+#[derive(Default)]
+pub struct SimulatedAccounts {
+    created: Mutex<HashSet<Pubkey>>,
+}
+
+impl SimulatedAccounts {
+    fn find_created(&self, account_keys: &[Pubkey]) -> Option<Pubkey> {
+        let created = self
+            .created
+            .lock()
+            .expect("simulated accounts lock poisoned");
+        account_keys
+            .iter()
+            .find(|key| created.contains(*key))
+            .cloned()
+    }
+
+    fn add_created(&self, accounts: impl IntoIterator<Item = Pubkey>) {
+        self.created
+            .lock()
+            .expect("simulated accounts lock poisoned")
+            .extend(accounts);
+    }
+}
+
+/// Simulates the transactions of the builder (draining it) against the RPC without sending anything.
+/// Compute units, lamport movements of accounts and failures are reported for every transaction.
+pub async fn simulate_transactions(
+    simulation_rpc_client: Arc<RpcClient>,
+    builder: &mut TransactionBuilder,
+    simulated_accounts: &SimulatedAccounts,
+) -> anyhow::Result<(usize, usize)> {
+    let simulated_instruction_count = builder.instructions().len();
+    let transactions = builder
+        .sequence_combined()
+        .map(|prepared_transaction| prepared_transaction.transaction)
+        .collect::<Vec<Transaction>>();
+    let simulated_transaction_count = transactions.len();
+    simulate_transaction_list(simulation_rpc_client, &transactions, simulated_accounts).await?;
+    Ok((simulated_transaction_count, simulated_instruction_count))
+}
+
+/// Simulates the list of transactions, returning an error listing the failed ones.
+/// The transactions depending on an account created by a prior simulated transaction
+/// are reported as dependent on the prior step and are not counted as failures.
+pub async fn simulate_transaction_list(
+    simulation_rpc_client: Arc<RpcClient>,
+    transactions: &[Transaction],
+    simulated_accounts: &SimulatedAccounts,
+) -> anyhow::Result<()> {
+    let simulated_transaction_count = transactions.len();
+    let mut units_consumed_sum: u64 = 0;
+    let mut dependent_count: usize = 0;
+    let mut failures: Vec<String> = vec![];
+    for (index, transaction) in transactions.iter().enumerate() {
+        let account_keys = &transaction.message.account_keys;
+        let accounts_before = match simulation_rpc_client
+            .get_multiple_accounts(account_keys)
+            .await
+        {
+            Ok(accounts) => accounts,
+            Err(e) => {
+                error!(
+                    "[dry-run] tx {}/{}: cannot fetch transaction accounts: {:?}",
+                    index + 1,
+                    simulated_transaction_count,
+                    e
+                );
+                failures.push(format!(
+                    "[dry-run] tx {}/{} cannot fetch transaction accounts: {}",
+                    index + 1,
+                    simulated_transaction_count,
+                    e
+                ));
+                continue;
+            }
+        };
+        if let Some(created_account) = simulated_accounts.find_created(account_keys) {
+            info!(
+                "[dry-run] tx {}/{}: not simulated, dependent on prior step creating account {}",
+                index + 1,
+                simulated_transaction_count,
+                created_account
+            );
+            dependent_count += 1;
+            // accounts missing on chain are expected to be created by this dependent transaction
+            simulated_accounts.add_created(
+                account_keys
+                    .iter()
+                    .zip(accounts_before.iter())
+                    .filter(|(_, before)| before.is_none())
+                    .map(|(key, _)| *key),
+            );
+            continue;
+        }
+        match simulate_transaction(simulation_rpc_client.clone(), transaction, &accounts_before)
+            .await
+        {
+            Ok(simulation) => {
+                units_consumed_sum += simulation.units_consumed;
+                info!(
+                    "[dry-run] tx {}/{}: {}, compute units {}{}",
+                    index + 1,
+                    simulated_transaction_count,
+                    simulation
+                        .error
+                        .as_ref()
+                        .map_or("success".to_string(), |e| format!("failed: {}", e)),
+                    simulation.units_consumed,
+                    format_lamport_changes(&simulation.lamport_changes),
+                );
+                if let Some(simulation_error) = simulation.error {
+                    failures.push(format!(
+                        "[dry-run] tx {}/{} failed: {}",
+                        index + 1,
+                        simulated_transaction_count,
+                        simulation_error
+                    ));
+                } else {
+                    simulated_accounts.add_created(simulation.created_accounts);
+                }
+            }
+            Err(e) => {
+                error!(
+                    "[dry-run] tx {}/{}: cannot be simulated: {:?}",
+                    index + 1,
+                    simulated_transaction_count,
+                    e
+                );
+                failures.push(format!(
+                    "[dry-run] tx {}/{} cannot be simulated: {}",
+                    index + 1,
+                    simulated_transaction_count,
+                    e
+                ));
+            }
+        }
+    }
+    info!(
+        "[dry-run] simulated txes {}, compute units in sum {}, dependent on prior step txes {}, failed txes {}",
+        simulated_transaction_count - dependent_count,
+        units_consumed_sum,
+        dependent_count,
+        failures.len()
+    );
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!("{}", failures.join("\n")))
+    }
+}
+
+struct TransactionSimulation {
+    units_consumed: u64,
+    error: Option<String>,
+    /// account address, lamports change
+    lamport_changes: Vec<(String, i128)>,
+    /// accounts not existing before the transaction and existing after it
+    created_accounts: Vec<Pubkey>,
+}
+
+async fn simulate_transaction(
+    rpc_client: Arc<RpcClient>,
+    transaction: &Transaction,
+    accounts_before: &[Option<Account>],
+) -> anyhow::Result<TransactionSimulation> {
+    let account_keys = &transaction.message.account_keys;
+    let simulation = rpc_client
+        .simulate_transaction_with_config(
+            transaction,
+            RpcSimulateTransactionConfig {
+                // the transaction is not signed, the recent blockhash is provided by RPC
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(rpc_client.commitment()),
+                accounts: Some(RpcSimulateTransactionAccountsConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    addresses: account_keys.iter().map(|key| key.to_string()).collect(),
+                }),
+                ..RpcSimulateTransactionConfig::default()
+            },
+        )
+        .await
+        .map_err(|e| anyhow!("Simulation RPC call failed: {:?}", e))?
+        .value;
+
+    let logs = simulation.logs.unwrap_or_default();
+    let accounts_after = simulation.accounts.unwrap_or_default();
+    let lamport_changes = account_keys
+        .iter()
+        .zip(accounts_before.iter())
+        .zip(accounts_after.iter())
+        .filter_map(|((key, before), after)| {
+            let lamports_before = before.as_ref().map_or(0, |a| a.lamports) as i128;
+            let lamports_after = after.as_ref().map_or(0, |a| a.lamports) as i128;
+            if lamports_before != lamports_after {
+                Some((key.to_string(), lamports_after - lamports_before))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let created_accounts = account_keys
+        .iter()
+        .zip(accounts_before.iter())
+        .zip(accounts_after.iter())
+        .filter(|((_, before), after)| before.is_none() && after.is_some())
+        .map(|((key, _), _)| *key)
+        .collect();
+    Ok(TransactionSimulation {
+        units_consumed: simulation.units_consumed.unwrap_or(0),
+        error: simulation
+            .err
+            .map(|err| decode_transaction_error(&err, &logs)),
+        lamport_changes,
+        created_accounts,
+    })
+}
+
+/// Decoding the custom program error to the name of `validator_bonds::error::ErrorCode`
+/// as logged by Anchor, e.g., `Error Code: SettlementNotExpired. Error Number: 6021.`
+fn decode_transaction_error(err: &TransactionError, logs: &[String]) -> String {
+    if let TransactionError::InstructionError(index, InstructionError::Custom(code)) = err {
+        if *code >= ANCHOR_ERROR_CODE_OFFSET {
+            let anchor_error_regex = Regex::new(r"Error Code: (\w+)\. Error Number: (\d+)")
+                .expect("Anchor error regex is valid");
+            let error_code_name = logs.iter().find_map(|log| {
+                anchor_error_regex.captures(log).and_then(|captures| {
+                    if captures[2] == code.to_string() {
+                        Some(captures[1].to_string())
+                    } else {
+                        None
+                    }
+                })
+            });
+            if let Some(error_code_name) = error_code_name {
+                return format!(
+                    "instruction {} failed with ErrorCode::{} ({})",
+                    index, error_code_name, code
+                );
+            }
+        }
+        return format!(
+            "instruction {} failed with custom program error {} ({:#x})",
+            index, code, code
+        );
+    }
+    format!("{:?}", err)
+}
+
+fn format_lamport_changes(lamport_changes: &[(String, i128)]) -> String {
+    if lamport_changes.is_empty() {
+        return String::new();
+    }
+    format!(
+        ", lamport changes: [{}]",
+        lamport_changes
+            .iter()
+            .map(|(address, change)| {
+                let sign = if *change < 0 { "-" } else { "+" };
+                format!(
+                    "{}: {}{} SOLs",
+                    address,
+                    sign,
+                    lamports_to_sol(change.unsigned_abs() as u64)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}